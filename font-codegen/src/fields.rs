@@ -53,8 +53,7 @@ impl Fields {
 
     /// If this table has a version field, return it.
     pub(crate) fn version_field(&self) -> Option<&Field> {
-        self.iter()
-            .find_map(|fld| fld.attrs.version.is_some().then_some(fld))
+        self.iter().find(|fld| fld.attrs.version.is_some())
     }
 
     // used for validating lengths. handles both fields and 'virtual fields',
@@ -189,13 +188,20 @@ fn traversal_arm_for_field(
             FieldType::Other { typ } if typ.is_ident("u8") => {
                 quote!(Field::new( #name_str, self.#name()#maybe_unwrap))
             }
-            FieldType::Other { typ } if !in_record => {
+            FieldType::Other { typ } => {
+                // records don't have their own data; they use the data of
+                // their parent table
+                let data = if in_record {
+                    quote!(_data)
+                } else {
+                    quote!(self.offset_data())
+                };
                 quote!(Field::new(
                         #name_str,
                         traversal::FieldType::array_of_records(
                             stringify!(#typ),
                             self.#name()#maybe_unwrap,
-                            self.offset_data(),
+                            #data,
                         )
                 ))
             }
@@ -235,9 +241,11 @@ fn traversal_arm_for_field(
             // if we are in a record, we pass in empty data. This lets us
             // avoid a special case in the single instance where this happens, in
             // Class1Record
-            let data = in_record
-                .then(|| quote!(FontData::new(&[])))
-                .unwrap_or_else(|| quote!(self.offset_data()));
+            let data = if in_record {
+                quote!(FontData::new(&[]))
+            } else {
+                quote!(self.offset_data())
+            };
             // in a record we return things by value, so clone
             let maybe_clone = in_record.then(|| quote!(.clone()));
             let typ_str = arr.raw_inner_type().to_string();
@@ -250,12 +258,32 @@ fn traversal_arm_for_field(
                     )
             ))
         }
+        FieldType::VarLenArray(arr) => {
+            let typ_str = arr.raw_inner_type().to_string();
+            quote!(Field::new(
+                    #name_str,
+                    traversal::FieldType::var_array(
+                        #typ_str,
+                        self.#name()#maybe_unwrap,
+                        self.offset_data()
+                    )
+            ))
+        }
         FieldType::Other { typ } if typ.is_ident("ValueRecord") => {
             let clone = in_record.then(|| quote!(.clone()));
             quote!(Field::new(#name_str, self.#name() #clone #maybe_unwrap))
         }
+        // a record inlined in a record or table; it shares the parent's data
         FieldType::Other { .. } => {
-            quote!(compile_error!(concat!("another weird type: ", #name_str)))
+            let data = if in_record {
+                quote!(_data)
+            } else {
+                quote!(self.offset_data())
+            };
+            quote!(Field::new(
+                #name_str,
+                traversal::FieldType::Record((*self.#name()).traverse(#data))
+            ))
         }
     }
 }
@@ -272,6 +300,10 @@ impl Field {
                 let inner = array.type_with_lifetime();
                 quote!(ComputedArray<'a, #inner>)
             }
+            FieldType::VarLenArray(array) => {
+                let inner = array.type_with_lifetime();
+                quote!(VarLenArray<'a, #inner>)
+            }
             FieldType::Array { inner_typ } => match inner_typ.as_ref() {
                 FieldType::Offset { typ, .. } if self.is_nullable() => {
                     quote!(&'a [BigEndian<Nullable<#typ>>])
@@ -279,7 +311,7 @@ impl Field {
                 FieldType::Offset { typ, .. } | FieldType::Scalar { typ } => {
                     quote!(&'a [BigEndian<#typ>])
                 }
-                FieldType::Other { typ } => quote!( &[#typ] ),
+                FieldType::Other { typ } => quote!( &'a [#typ] ),
                 _ => unreachable!("no nested arrays"),
             },
         }
@@ -306,12 +338,21 @@ impl Field {
         matches!(&self.typ, FieldType::ComputedArray { .. })
     }
 
+    pub(crate) fn is_var_len_array(&self) -> bool {
+        matches!(&self.typ, FieldType::VarLenArray { .. })
+    }
+
     pub(crate) fn has_computed_len(&self) -> bool {
         self.attrs.len.is_some()
             || self.attrs.count.is_some()
             || self.attrs.read_with_args.is_some()
     }
 
+    /// true if this is a fixed-size record stored inline in its parent
+    pub(crate) fn is_inline_record(&self) -> bool {
+        matches!(self.typ, FieldType::Other { .. }) && !self.has_computed_len()
+    }
+
     pub(crate) fn is_version_dependent(&self) -> bool {
         self.attrs.available.is_some()
     }
@@ -322,7 +363,7 @@ impl Field {
         if let FieldType::Array { inner_typ } = &self.typ {
             if matches!(
                 inner_typ.as_ref(),
-                FieldType::Array { .. } | FieldType::ComputedArray(_)
+                FieldType::Array { .. } | FieldType::ComputedArray(_) | FieldType::VarLenArray(_)
             ) {
                 return Err(syn::Error::new(
                     self.name.span(),
@@ -330,7 +371,9 @@ impl Field {
                 ));
             }
         }
-        if self.is_array() && (self.attrs.count.is_none() && self.attrs.len.is_none()) {
+        if (self.is_array() || self.is_var_len_array())
+            && (self.attrs.count.is_none() && self.attrs.len.is_none())
+        {
             return Err(syn::Error::new(
                 self.name.span(),
                 "array requires #[count] attribute",
@@ -387,7 +430,11 @@ impl Field {
             FieldType::Offset { typ, .. } | FieldType::Scalar { typ } => {
                 quote!(#typ::RAW_BYTE_LEN)
             }
-            FieldType::Other { .. } | FieldType::Array { .. } | FieldType::ComputedArray { .. } => {
+            FieldType::Other { typ } if self.is_inline_record() => quote!(#typ::RAW_BYTE_LEN),
+            FieldType::Other { .. }
+            | FieldType::Array { .. }
+            | FieldType::ComputedArray { .. }
+            | FieldType::VarLenArray { .. } => {
                 let len_field = self.shape_byte_len_field_name();
                 let try_op = self.is_version_dependent().then(|| quote!(?));
                 quote!(self.#len_field #try_op)
@@ -454,6 +501,10 @@ impl Field {
                 let inner = array.type_with_lifetime();
                 quote!(ComputedArray<'a, #inner>)
             }
+            FieldType::VarLenArray(array) => {
+                let inner = array.type_with_lifetime();
+                quote!(VarLenArray<'a, #inner>)
+            }
         }
     }
 
@@ -475,6 +526,9 @@ impl Field {
         let is_versioned = self.is_version_dependent();
 
         let mut return_type = self.raw_getter_return_type();
+        if self.is_inline_record() {
+            return_type = quote!(&'a #return_type);
+        }
         if is_versioned {
             return_type = quote!(Option<#return_type>);
         }
@@ -483,8 +537,12 @@ impl Field {
         let mut read_stmt = if let Some(args) = &self.attrs.read_with_args {
             let get_args = args.to_tokens_for_table_getter();
             quote!( self.data.read_with_args(range, &#get_args).unwrap() )
+        } else if self.is_var_len_array() {
+            quote!(VarLenArray::read(self.data.slice(range).unwrap()).unwrap())
         } else if is_array {
             quote!(self.data.read_array(range).unwrap())
+        } else if self.is_inline_record() {
+            quote!(self.data.read_ref_at(range.start).unwrap())
         } else {
             quote!(self.data.read_at(range.start).unwrap())
         };
@@ -518,13 +576,17 @@ impl Field {
         // their fields on access.
         let add_borrow_just_for_record = matches!(
             self.typ,
-            FieldType::Other { .. } | FieldType::ComputedArray { .. }
+            FieldType::Other { .. }
+                | FieldType::ComputedArray { .. }
+                | FieldType::VarLenArray { .. }
         )
         .then(|| quote!(&));
 
         let getter_expr = match &self.typ {
             FieldType::Scalar { .. } | FieldType::Offset { .. } => quote!(self.#name.get()),
-            FieldType::Other { .. } | FieldType::ComputedArray { .. } => quote!(&self.#name),
+            FieldType::Other { .. }
+            | FieldType::ComputedArray { .. }
+            | FieldType::VarLenArray { .. } => quote!(&self.#name),
             FieldType::Array { .. } => quote!(self.#name),
         };
 
@@ -657,6 +719,9 @@ impl Field {
             && !self.is_version_dependent()
         {
             let typ = self.typ.cooked_type_tokens();
+            if self.is_inline_record() {
+                return quote!( cursor.advance_by(#typ::RAW_BYTE_LEN); );
+            }
             return quote!( cursor.advance::<#typ>(); );
         }
 
@@ -735,6 +800,10 @@ impl Field {
                 .map(Count::count_expr)
                 .expect("must have one of count/count_expr/len");
             let size_expr = match &self.typ {
+                // for byte arrays the count is the length
+                FieldType::Array { inner_typ } if *inner_typ.cooked_type_tokens() == "u8" => {
+                    return Some(quote!( #count_expr ));
+                }
                 FieldType::Array { inner_typ } => {
                     let inner_typ = inner_typ.cooked_type_tokens();
                     quote!( #inner_typ::RAW_BYTE_LEN )
@@ -743,9 +812,24 @@ impl Field {
                     let inner = array.raw_inner_type();
                     quote!( <#inner as ComputeSize>::compute_size(&#read_args) )
                 }
+                // each item has its own length, so we have to walk them
+                FieldType::VarLenArray(array) => {
+                    let inner = array.raw_inner_type();
+                    return Some(quote! {
+                        {
+                            let data = cursor.remaining().ok_or(ReadError::OutOfBounds)?;
+                            <#inner as VarSize>::total_len_for_count(data, #count_expr)?
+                        }
+                    });
+                }
                 _ => unreachable!("count not valid here"),
             };
-            quote!(  #count_expr * #size_expr )
+            // a single inline item (avoids clippy::identity_op in the output)
+            if self.attrs.count.as_deref().is_some_and(Count::is_one) {
+                size_expr
+            } else {
+                quote!(  #count_expr * #size_expr )
+            }
         };
         Some(len_expr)
     }
@@ -774,6 +858,9 @@ impl Field {
                 let count = self.attrs.count.as_ref().unwrap().count_expr();
                 quote!(cursor.read_computed_array(#count, &#args)?)
             }
+            FieldType::VarLenArray(_) => {
+                quote!(compile_error!("VarLenArray is not supported in records"))
+            }
             _ => match self
                 .attrs
                 .read_with_args
@@ -855,7 +942,10 @@ impl Field {
         match &self.typ {
             FieldType::Scalar { .. } | FieldType::Other { .. } => false,
             FieldType::Offset { target: None, .. } => false,
-            FieldType::Offset { .. } | FieldType::ComputedArray { .. } => true,
+            FieldType::Offset { .. }
+            | FieldType::ComputedArray { .. }
+            | FieldType::VarLenArray { .. } => true,
+            FieldType::Array { .. } if self.is_byte_array() => false,
             FieldType::Array { inner_typ } => matches!(
                 inner_typ.as_ref(),
                 FieldType::Offset { .. } | FieldType::Other { .. }
@@ -863,12 +953,23 @@ impl Field {
         }
     }
 
+    /// `true` if this is a raw array of bytes (`[u8]`)
+    fn is_byte_array(&self) -> bool {
+        match &self.typ {
+            FieldType::Array { inner_typ } => {
+                matches!(inner_typ.as_ref(), FieldType::Other { typ } if typ.is_ident("u8"))
+            }
+            _ => false,
+        }
+    }
+
     #[allow(clippy::wrong_self_convention)]
     fn from_obj_requires_offset_data(&self, in_record: bool) -> bool {
         match &self.typ {
             FieldType::Offset { .. } => in_record,
-            FieldType::ComputedArray(_) => true,
+            FieldType::ComputedArray(_) | FieldType::VarLenArray(_) => true,
             FieldType::Other { .. } => true,
+            FieldType::Array { .. } if self.is_byte_array() => false,
             FieldType::Array { inner_typ } => match inner_typ.as_ref() {
                 FieldType::Offset { .. } => in_record,
                 FieldType::Other { .. } => true,
@@ -899,6 +1000,7 @@ impl Field {
                 let offset_getter = self.offset_getter_name().unwrap();
                 quote!(obj.#offset_getter(#pass_offset_data).into())
             }
+            FieldType::Array { .. } if self.is_byte_array() => quote!(obj.#name().to_owned()),
             FieldType::Array { inner_typ } => match inner_typ.as_ref() {
                 FieldType::Scalar { .. } => quote!(obj.#name().iter().map(|x| x.get()).collect()),
                 FieldType::Offset { .. } => {
@@ -910,7 +1012,7 @@ impl Field {
                 }
                 _ => quote!(compile_error!("requires custom to_owned impl")),
             },
-            FieldType::ComputedArray(_array) => {
+            FieldType::ComputedArray(_) | FieldType::VarLenArray(_) => {
                 quote!(obj.#name().iter().filter_map(|x| x.map(|x| FromObjRef::from_obj_ref(&x, offset_data)).ok()).collect())
             }
             _ => quote!(compile_error!("requires custom to_owned impl")),
@@ -927,7 +1029,9 @@ impl FieldType {
             FieldType::Other { typ } => typ
                 .get_ident()
                 .expect("non-trivial custom types never cooked"),
-            FieldType::Array { .. } | FieldType::ComputedArray { .. } => {
+            FieldType::Array { .. }
+            | FieldType::ComputedArray { .. }
+            | FieldType::VarLenArray { .. } => {
                 panic!("array tokens never cooked")
             }
         }
@@ -959,7 +1063,7 @@ impl FieldType {
                 let inner_tokens = inner_typ.compile_type(nullable, false);
                 quote!( Vec<#inner_tokens> )
             }
-            FieldType::ComputedArray(array) => array.compile_type(),
+            FieldType::ComputedArray(array) | FieldType::VarLenArray(array) => array.compile_type(),
         };
         if version_dependent {
             quote!( Option<#raw_type> )
@@ -41,7 +41,7 @@ fn run_plan(path: &Path) -> miette::Result<()> {
                 std::fs::create_dir_all(path)
                     .map_err(|e| miette!("failed to create directory '{}': {e}", path.display()))?;
             } else {
-                std::fs::remove_file(&path)
+                std::fs::remove_file(path)
                     .map_err(|e| miette!("failed to clean path '{}': {e}", path.display()))?;
             }
         }
@@ -88,7 +88,7 @@ struct CodegenOp {
 }
 
 fn read_contents(path: &Path) -> miette::Result<String> {
-    std::fs::read_to_string(&path).map_err(|e| {
+    std::fs::read_to_string(path).map_err(|e| {
         { ErrorReport::message(format!("error reading '{}': {}", path.display(), e)) }.into()
     })
 }
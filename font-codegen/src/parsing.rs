@@ -50,6 +50,8 @@ pub(crate) struct TableAttrs {
     pub(crate) validation_method: Option<Attr<syn::Path>>,
     pub(crate) read_args: Option<Attr<TableReadArgs>>,
     pub(crate) phantom: Option<Attr<syn::Ident>>,
+    /// derive `Copy`, so that a record can be a field of a packed record
+    pub(crate) copy: Option<syn::Path>,
 }
 
 #[derive(Debug, Clone)]
@@ -263,6 +265,7 @@ pub(crate) enum FieldType {
         inner_typ: Box<FieldType>,
     },
     ComputedArray(ComputedArray),
+    VarLenArray(ComputedArray),
 }
 
 #[derive(Debug, Clone)]
@@ -437,7 +440,11 @@ impl Parse for BitFlags {
         let docs = get_optional_docs(input)?;
         let _kw = input.parse::<kw::flags>()?;
         let typ = input.parse::<syn::Ident>()?;
-        validate_ident(&typ, &["u8", "u16"], "allowed bitflag types: u8, u16")?;
+        validate_ident(
+            &typ,
+            &["u8", "u16", "u32"],
+            "allowed bitflag types: u8, u16, u32",
+        )?;
         let name = input.parse::<syn::Ident>()?;
 
         let content;
@@ -481,7 +488,7 @@ impl Parse for TableFormat {
         let attrs: TableAttrs = input.parse()?;
         let _kw = input.parse::<kw::format>()?;
         let format: syn::Ident = input.parse()?;
-        validate_ident(&format, &["u16", "i16"], "unexpected format type")?;
+        validate_ident(&format, &["u8", "u16", "i16"], "unexpected format type")?;
         let name = input.parse::<syn::Ident>()?;
 
         let content;
@@ -582,15 +589,21 @@ impl Parse for FieldType {
         let path = input.parse::<syn::Path>()?;
         let last = get_single_path_segment(&path)?;
 
-        if last.ident == "ComputedArray" {
+        if last.ident == "ComputedArray" || last.ident == "VarLenArray" {
+            let is_var_len = last.ident == "VarLenArray";
             let inner_typ = get_single_generic_type_arg(&last.arguments)?;
             let last = get_single_path_segment(&inner_typ)?;
             let lifetime = get_single_lifetime(&last.arguments)?;
-            return Ok(FieldType::ComputedArray(ComputedArray {
+            let array = ComputedArray {
                 span: last.span(),
                 inner: last.ident.clone(),
                 lifetime,
-            }));
+            };
+            return Ok(if is_var_len {
+                FieldType::VarLenArray(array)
+            } else {
+                FieldType::ComputedArray(array)
+            });
         }
 
         if last.ident != "BigEndian" {
@@ -760,6 +773,7 @@ static SKIP_FONT_WRITE: &str = "skip_font_write";
 static VALIDATION_METHOD: &str = "validation_method";
 static READ_ARGS: &str = "read_args";
 static PHANTOM: &str = "phantom";
+static COPY: &str = "copy";
 
 impl Parse for TableAttrs {
     fn parse(input: ParseStream) -> syn::Result<Self> {
@@ -787,6 +801,8 @@ impl Parse for TableAttrs {
                 this.read_args = Some(Attr::new(ident.clone(), attr.parse_args()?));
             } else if ident == PHANTOM {
                 this.phantom = Some(Attr::new(ident.clone(), attr.parse_args()?));
+            } else if ident == COPY {
+                this.copy = Some(attr.path);
             } else {
                 return Err(syn::Error::new(
                     ident.span(),
@@ -890,6 +906,18 @@ impl Count {
             Count::All => panic!("count_to annotation is handled before here"),
         }
     }
+
+    /// `true` if the count is the integer literal `1`.
+    pub(crate) fn is_one(&self) -> bool {
+        match self {
+            Count::Expr(expr) => matches!(
+                expr.expr.as_ref(),
+                syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(lit), .. })
+                    if lit.base10_parse::<usize>().ok() == Some(1)
+            ),
+            _ => false,
+        }
+    }
 }
 
 impl Parse for InlineExpr {
@@ -945,6 +973,15 @@ impl NeededWhen {
     fn at_runtime(&self) -> bool {
         matches!(self, NeededWhen::Runtime | NeededWhen::Both)
     }
+
+    /// Combine the requirements of two references to the same field.
+    fn merge(self, other: NeededWhen) -> NeededWhen {
+        match (self, other) {
+            (NeededWhen::Parse, NeededWhen::Parse) => NeededWhen::Parse,
+            (NeededWhen::Runtime, NeededWhen::Runtime) => NeededWhen::Runtime,
+            _ => NeededWhen::Both,
+        }
+    }
 }
 
 impl ReferencedFields {
@@ -965,7 +1002,14 @@ impl ReferencedFields {
 
 impl FromIterator<(syn::Ident, NeededWhen)> for ReferencedFields {
     fn from_iter<T: IntoIterator<Item = (syn::Ident, NeededWhen)>>(iter: T) -> Self {
-        Self(iter.into_iter().collect())
+        let mut fields = HashMap::new();
+        for (ident, needed) in iter {
+            fields
+                .entry(ident)
+                .and_modify(|prev: &mut NeededWhen| *prev = prev.merge(needed))
+                .or_insert(needed);
+        }
+        Self(fields)
     }
 }
 
@@ -26,18 +26,23 @@ pub(crate) fn generate(item: &Record) -> syn::Result<TokenStream> {
     let extra_traits = generate_extra_traits(item)?;
     let traversal_impl = generate_traversal(item)?;
 
-    let repr_packed = item.lifetime.is_none().then(|| {
+    // records that are read with args are constructed field-by-field, and
+    // may contain non-Copy fields, so they cannot be packed.
+    let repr_packed = (item.lifetime.is_none() && item.attrs.read_args.is_none()).then(|| {
         quote! {
             #[repr(C)]
             #[repr(packed)]
         }
     });
+    // derived impls on packed structs can only copy their fields, so records
+    // that are nested in packed records must opt in to being Copy
+    let derive_copy = item.attrs.copy.is_some().then(|| quote!(Copy,));
 
     let lifetime = &item.lifetime;
 
     Ok(quote! {
     #( #docs )*
-    #[derive(Clone, Debug)]
+    #[derive(Clone, #derive_copy Debug)]
     #repr_packed
     pub struct #name #lifetime {
         #( #field_docs pub #field_names: #field_types, )*
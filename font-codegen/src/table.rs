@@ -21,16 +21,10 @@ pub(crate) fn generate(item: &Table) -> syn::Result<TokenStream> {
     let shape_fields = item.iter_shape_fields();
     let derive_clone_copy = generic.is_none().then(|| quote!(Clone, Copy));
     let impl_clone_copy = generic.is_some().then(|| {
-        let clone_fields = item
-            .iter_shape_field_names()
-            .map(|name| quote!(#name: self.#name));
         quote! {
             impl<#generic> Clone for #marker_name<#generic> {
                 fn clone(&self) -> Self {
-                    Self {
-                        #( #clone_fields, )*
-                        phantom: std::marker::PhantomData,
-                    }
+                    *self
                 }
             }
 
@@ -299,9 +293,7 @@ fn generate_to_owned_impl(item: &Table, parse_module: &syn::Path) -> syn::Result
     let parse_generic = comp_generic
         .is_some()
         .then(|| syn::Ident::new("U", Span::call_site()));
-    let impl_generics = comp_generic
-        .into_iter()
-        .chain(parse_generic.as_ref().into_iter());
+    let impl_generics = comp_generic.into_iter().chain(parse_generic.as_ref());
     let impl_generics2 = impl_generics.clone();
     let where_clause = comp_generic.map(|t| {
         quote! {
@@ -535,6 +527,7 @@ pub(crate) fn generate_format_group(item: &TableFormat) -> syn::Result<TokenStre
 
     Ok(quote! {
         #( #docs )*
+        #[derive(Clone)]
         pub enum #name<'a> {
             #( #variants ),*
         }
@@ -638,12 +631,12 @@ impl Table {
         let mut result = Vec::new();
         // if an input arg is needed later, save it in the shape.
         if let Some(args) = &self.attrs.read_args {
-            result.extend(args.args.iter().filter_map(|arg| {
-                self.fields
-                    .referenced_fields
-                    .needs_at_runtime(&arg.ident)
-                    .then(|| (arg.ident.clone(), arg.typ.to_token_stream()))
-            }));
+            result.extend(
+                args.args
+                    .iter()
+                    .filter(|arg| self.fields.referenced_fields.needs_at_runtime(&arg.ident))
+                    .map(|arg| (arg.ident.clone(), arg.typ.to_token_stream())),
+            );
         }
 
         for next in self.fields.iter() {
@@ -726,14 +719,15 @@ impl TableReadArgs {
         &'a self,
         referenced_fields: &'a ReferencedFields,
     ) -> impl Iterator<Item = TokenStream> + 'a {
-        self.args.iter().filter_map(|TableReadArg { ident, typ }| {
-            referenced_fields.needs_at_runtime(ident).then(|| {
+        self.args
+            .iter()
+            .filter(|arg| referenced_fields.needs_at_runtime(&arg.ident))
+            .map(|TableReadArg { ident, typ }| {
                 quote! {
                     pub(crate) fn #ident(&self) -> #typ {
                         self.shape.#ident
                     }
                 }
             })
-        })
     }
 }
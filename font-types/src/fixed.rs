@@ -1,10 +1,10 @@
 //! fixed-point numerical types
 
-use std::ops::{Add, AddAssign, Sub, SubAssign};
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
 
 // shared between Fixed and F2Dot14
 macro_rules! fixed_impl {
-    ($name:ident, $bits:literal, $fract_bits:literal, $ty:ty) => {
+    ($name:ident, $bits:literal, $fract_bits:literal, $ty:ty, $wide:ty) => {
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Default)]
         #[doc = concat!(stringify!($bits), "-bit signed fixed point number with ", stringify!($fract_bits), " bits of fraction." )]
         pub struct $name($ty);
@@ -18,9 +18,14 @@ macro_rules! fixed_impl {
             /// This type's smallest representable value
             pub const EPSILON: Self = Self(1);
 
+            /// Zero.
+            pub const ZERO: Self = Self(0);
+
+            /// One.
+            pub const ONE: Self = Self(1 << $fract_bits);
+
             const INT_MASK: $ty = !0 << $fract_bits;
             const ROUND: $ty = 1 << ($fract_bits - 1);
-            const ONE: $ty = 1 << $fract_bits;
             const FRACT_BITS: usize = $fract_bits;
 
             //TODO: is this actually useful?
@@ -64,12 +69,83 @@ macro_rules! fixed_impl {
                 Self(self.0.saturating_sub(other.0))
             }
 
+            /// Creates a new value from its raw bit representation.
+            pub const fn from_bits(bits: $ty) -> Self {
+                Self(bits)
+            }
+
+            /// Returns the raw bit representation of this value.
+            pub const fn to_bits(self) -> $ty {
+                self.0
+            }
+
+            /// Multiplication, rounding the result to the nearest representable
+            /// value and saturating on overflow.
+            pub fn saturating_mul(self, other: Self) -> Self {
+                let product = self.0 as $wide * other.0 as $wide;
+                // round half away from zero
+                let round = (1 as $wide) << ($fract_bits - 1);
+                let result = if product < 0 {
+                    -((-product + round) >> $fract_bits)
+                } else {
+                    (product + round) >> $fract_bits
+                };
+                Self(result.clamp(<$ty>::MIN as $wide, <$ty>::MAX as $wide) as $ty)
+            }
+
+            /// Division, rounding the result to the nearest representable value
+            /// and saturating on overflow.
+            ///
+            /// Dividing by zero returns [`MAX`](Self::MAX) or [`MIN`](Self::MIN),
+            /// depending on the sign of the dividend.
+            pub fn saturating_div(self, other: Self) -> Self {
+                if other.0 == 0 {
+                    return if self.0 < 0 { Self::MIN } else { Self::MAX };
+                }
+                let numerator = (self.0 as $wide) << $fract_bits;
+                let denominator = other.0 as $wide;
+                // round half away from zero
+                let (abs_num, abs_den) = (numerator.abs(), denominator.abs());
+                let quotient = (abs_num + abs_den / 2) / abs_den;
+                let result = if (numerator < 0) != (denominator < 0) {
+                    -quotient
+                } else {
+                    quotient
+                };
+                Self(result.clamp(<$ty>::MIN as $wide, <$ty>::MAX as $wide) as $ty)
+            }
+
             /// The representation of this number as a big-endian byte array.
             pub fn to_be_bytes(self) -> [u8; $bits / 8] {
                 self.0.to_be_bytes()
             }
         }
 
+        impl Mul for $name {
+            type Output = Self;
+            #[inline]
+            fn mul(self, other: Self) -> Self {
+                self.saturating_mul(other)
+            }
+        }
+
+        impl Div for $name {
+            type Output = Self;
+            #[inline]
+            fn div(self, other: Self) -> Self {
+                self.saturating_div(other)
+            }
+        }
+
+        impl Neg for $name {
+            type Output = Self;
+            #[inline]
+            fn neg(self) -> Self {
+                // MIN has no positive counterpart, and is its own negation
+                Self(self.0.wrapping_neg())
+            }
+        }
+
         impl Add for $name {
             type Output = Self;
             #[inline(always)]
@@ -114,12 +190,12 @@ macro_rules! float_conv {
             /// representable value.
             pub fn $from(x: $ty) -> Self {
                 #[cfg(any(feature = "std", test))]
-                return Self((x * Self::ONE as $ty).round() as _);
+                return Self((x * Self::ONE.0 as $ty).round() as _);
                 //NOTE: this behaviour is not exactly equivalent, but should be okay?
                 //what matters is that we are rounding *away from zero*.
                 #[cfg(all(not(feature = "std"), not(test)))]
                 Self(
-                    (x * Self::ONE as $ty + (0.5 * (-1.0 * x.is_sign_negative() as u8 as $ty)))
+                    (x * Self::ONE.0 as $ty + (0.5 * (-1.0 * x.is_sign_negative() as u8 as $ty)))
                         as _,
                 )
             }
@@ -130,7 +206,7 @@ macro_rules! float_conv {
             /// round-tripped.
             pub fn $to(self) -> $ty {
                 let int = ((self.0 & Self::INT_MASK) >> Self::FRACT_BITS) as $ty;
-                let fract = (self.0 & !Self::INT_MASK) as $ty / Self::ONE as $ty;
+                let fract = (self.0 & !Self::INT_MASK) as $ty / Self::ONE.0 as $ty;
                 int + fract
             }
         }
@@ -150,10 +226,47 @@ macro_rules! float_conv {
     };
 }
 
-fixed_impl!(F2Dot14, 16, 14, i16);
-fixed_impl!(Fixed, 32, 16, i32);
+fixed_impl!(F2Dot14, 16, 14, i16, i32);
+fixed_impl!(Fixed, 32, 16, i32, i64);
 float_conv!(F2Dot14, to_f32, from_f32, f32);
 float_conv!(Fixed, to_f64, from_f64, f64);
+impl Fixed {
+    /// Creates a fixed point value from an integer.
+    ///
+    /// The integer is truncated to 16 bits.
+    pub const fn from_i32(int: i32) -> Self {
+        Self(int << 16)
+    }
+
+    /// Returns the integer part of this value, rounding towards negative
+    /// infinity.
+    pub const fn to_i32(self) -> i32 {
+        self.0 >> 16
+    }
+
+    /// Converts this value to an [F2Dot14], rounding to the nearest
+    /// representable value.
+    ///
+    /// Values outside of the range of an `F2Dot14` are clamped.
+    pub fn to_f2dot14(self) -> F2Dot14 {
+        let bits = (self.0.saturating_add(2) >> 2).clamp(i16::MIN as i32, i16::MAX as i32);
+        F2Dot14(bits as i16)
+    }
+}
+
+impl F2Dot14 {
+    /// Converts this value to a [Fixed]. This conversion is lossless.
+    pub fn to_fixed(self) -> Fixed {
+        Fixed(self.0 as i32 * 4)
+    }
+}
+
+impl From<F2Dot14> for Fixed {
+    fn from(value: F2Dot14) -> Self {
+        value.to_fixed()
+    }
+}
+
 crate::newtype_scalar!(F2Dot14, [u8; 2]);
 crate::newtype_scalar!(Fixed, [u8; 4]);
 
@@ -205,6 +318,37 @@ mod tests {
     //}
     //}
 
+    #[test]
+    fn fixed_mul_div() {
+        let a = Fixed::from_f64(1.5);
+        let b = Fixed::from_f64(-2.25);
+        assert_eq!(a * b, Fixed::from_f64(-3.375));
+        assert_eq!(b / a, Fixed::from_f64(-1.5));
+        assert_eq!(Fixed::ONE / Fixed::from_f64(3.0), Fixed(0x5555));
+        assert_eq!(Fixed::from_f64(2.0) / Fixed::from_f64(3.0), Fixed(0xAAAB));
+        assert_eq!(Fixed::MAX * Fixed::from_f64(2.0), Fixed::MAX);
+        assert_eq!(Fixed::ONE / Fixed::ZERO, Fixed::MAX);
+        assert_eq!(-Fixed::ONE / Fixed::ZERO, Fixed::MIN);
+    }
+
+    #[test]
+    fn neg_min() {
+        assert_eq!(-Fixed::MIN, Fixed::MIN);
+        assert_eq!(-F2Dot14::MIN, F2Dot14::MIN);
+        assert_eq!(-Fixed::ONE, Fixed::from_f64(-1.0));
+    }
+
+    #[test]
+    fn fixed_f2dot14_conversion() {
+        assert_eq!(F2Dot14::from_f32(-0.5).to_fixed(), Fixed::from_f64(-0.5));
+        assert_eq!(Fixed::from_f64(0.25).to_f2dot14(), F2Dot14::from_f32(0.25));
+        // rounds to nearest
+        assert_eq!(Fixed(0x0002).to_f2dot14(), F2Dot14(1));
+        assert_eq!(Fixed(0x0001).to_f2dot14(), F2Dot14(0));
+        // clamps
+        assert_eq!(Fixed::from_f64(5.0).to_f2dot14(), F2Dot14::MAX);
+    }
+
     #[test]
     fn fixed_floats() {
         assert_eq!(Fixed(0x7fff_0000), Fixed::from_f64(32767.));
@@ -18,6 +18,7 @@ mod fword;
 mod glyph_id;
 mod longdatetime;
 mod offset;
+mod point;
 mod raw;
 mod tag;
 mod uint24;
@@ -28,6 +29,7 @@ pub use fword::{FWord, UfWord};
 pub use glyph_id::GlyphId;
 pub use longdatetime::LongDateTime;
 pub use offset::{Nullable, Offset16, Offset24, Offset32};
+pub use point::Point;
 pub use raw::{BigEndian, FixedSized, ReadScalar, Scalar};
 pub use tag::Tag;
 pub use uint24::Uint24;
@@ -0,0 +1,68 @@
+//! A two-dimensional point
+
+use std::ops::{Add, AddAssign, Mul, Sub, SubAssign};
+
+/// A point in a two-dimensional coordinate space.
+///
+/// This is not a type defined by the spec, but it is a convenient
+/// representation for glyph outlines and variation deltas.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Point<T> {
+    /// The horizontal component.
+    pub x: T,
+    /// The vertical component.
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    /// Create a new point.
+    pub const fn new(x: T, y: T) -> Self {
+        Point { x, y }
+    }
+
+    /// Apply a function to both components of this point.
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> Point<U> {
+        Point {
+            x: f(self.x),
+            y: f(self.y),
+        }
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Add<Output = T> + Copy> AddAssign for Point<T> {
+    #[inline]
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Sub<Output = T> + Copy> SubAssign for Point<T> {
+    #[inline]
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point<T> {
+    type Output = Self;
+    #[inline]
+    fn mul(self, scale: T) -> Self {
+        Point::new(self.x * scale, self.y * scale)
+    }
+}
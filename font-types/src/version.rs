@@ -1,4 +1,4 @@
-/// A legacy 16/16 version encoding
+//! A legacy 16/16 version encoding
 
 /// Packed 32-bit value with major and minor version numbers.
 ///
@@ -35,6 +35,8 @@ impl Version16Dot16 {
     pub const VERSION_0_5: Version16Dot16 = Version16Dot16::new(0, 5);
     /// Version 1.0
     pub const VERSION_1_0: Version16Dot16 = Version16Dot16::new(1, 0);
+    /// Version 1.1
+    pub const VERSION_1_1: Version16Dot16 = Version16Dot16::new(1, 1);
     /// Version 2.0
     pub const VERSION_2_0: Version16Dot16 = Version16Dot16::new(2, 0);
     /// Version 2.5
@@ -80,6 +82,8 @@ impl MajorMinor {
     pub const VERSION_1_2: MajorMinor = MajorMinor::new(1, 2);
     /// Version 1.3
     pub const VERSION_1_3: MajorMinor = MajorMinor::new(1, 3);
+    /// Version 2.0
+    pub const VERSION_2_0: MajorMinor = MajorMinor::new(2, 0);
 
     /// Create a new version with major and minor parts.
     #[inline]
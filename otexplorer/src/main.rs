@@ -78,7 +78,7 @@ fn get_offset_width(font: &FontRef) -> usize {
 fn hex_width(val: u32) -> usize {
     match val {
         0..=0xffff => 4usize,
-        0x10000..=0xffff_ff => 6,
+        0x10000..=0xff_ffff => 6,
         0x1000000.. => 8,
     }
 }
@@ -90,12 +90,41 @@ fn get_some_table<'a>(
     match tag {
         read_fonts::tables::gpos::TAG => font.gpos().map(|x| Box::new(x) as _),
         read_fonts::tables::gsub::TAG => font.gsub().map(|x| Box::new(x) as _),
+        read_fonts::tables::cff::TAG => font.cff().map(|x| Box::new(x) as _),
+        read_fonts::tables::cff2::TAG => font.cff2().map(|x| Box::new(x) as _),
         read_fonts::tables::cmap::TAG => font.cmap().map(|x| Box::new(x) as _),
+        read_fonts::tables::colr::TAG => font.colr().map(|x| Box::new(x) as _),
+        read_fonts::tables::cpal::TAG => font.cpal().map(|x| Box::new(x) as _),
+        read_fonts::tables::cblc::TAG => font.cblc().map(|x| Box::new(x) as _),
+        read_fonts::tables::cbdt::TAG => font.cbdt().map(|x| Box::new(x) as _),
+        read_fonts::tables::eblc::TAG => font.eblc().map(|x| Box::new(x) as _),
+        read_fonts::tables::ebdt::TAG => font.ebdt().map(|x| Box::new(x) as _),
+        read_fonts::tables::sbix::TAG => font.sbix().map(|x| Box::new(x) as _),
+        read_fonts::tables::svg::TAG => font.svg().map(|x| Box::new(x) as _),
+        read_fonts::tables::fvar::TAG => font.fvar().map(|x| Box::new(x) as _),
+        read_fonts::tables::avar::TAG => font.avar().map(|x| Box::new(x) as _),
+        read_fonts::tables::gvar::TAG => font.gvar().map(|x| Box::new(x) as _),
+        read_fonts::tables::hvar::TAG => font.hvar().map(|x| Box::new(x) as _),
+        read_fonts::tables::vvar::TAG => font.vvar().map(|x| Box::new(x) as _),
+        read_fonts::tables::mvar::TAG => font.mvar().map(|x| Box::new(x) as _),
+        read_fonts::tables::os2::TAG => font.os2().map(|x| Box::new(x) as _),
+        read_fonts::tables::stat::TAG => font.stat().map(|x| Box::new(x) as _),
+        read_fonts::tables::base::TAG => font.base().map(|x| Box::new(x) as _),
         read_fonts::tables::gdef::TAG => font.gdef().map(|x| Box::new(x) as _),
+        read_fonts::tables::jstf::TAG => font.jstf().map(|x| Box::new(x) as _),
+        read_fonts::tables::math::TAG => font.math().map(|x| Box::new(x) as _),
+        read_fonts::tables::cvt::TAG => font.cvt().map(|x| Box::new(x) as _),
+        read_fonts::tables::fpgm::TAG => font.fpgm().map(|x| Box::new(x) as _),
+        read_fonts::tables::prep::TAG => font.prep().map(|x| Box::new(x) as _),
+        read_fonts::tables::gasp::TAG => font.gasp().map(|x| Box::new(x) as _),
         read_fonts::tables::glyf::TAG => font.glyf().map(|x| Box::new(x) as _),
         read_fonts::tables::head::TAG => font.head().map(|x| Box::new(x) as _),
         read_fonts::tables::hhea::TAG => font.hhea().map(|x| Box::new(x) as _),
         read_fonts::tables::hmtx::TAG => font.hmtx().map(|x| Box::new(x) as _),
+        read_fonts::tables::vhea::TAG => font.vhea().map(|x| Box::new(x) as _),
+        read_fonts::tables::vmtx::TAG => font.vmtx().map(|x| Box::new(x) as _),
+        read_fonts::tables::vorg::TAG => font.vorg().map(|x| Box::new(x) as _),
+        read_fonts::tables::kern::TAG => font.kern().map(|x| Box::new(x) as _),
         read_fonts::tables::loca::TAG => font.loca(None).map(|x| Box::new(x) as _),
         read_fonts::tables::maxp::TAG => font.maxp().map(|x| Box::new(x) as _),
         read_fonts::tables::name::TAG => font.name().map(|x| Box::new(x) as _),
@@ -0,0 +1,1353 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+#[allow(unused_imports)]
+use crate::codegen_prelude::*;
+
+/// [BitmapSize](https://learn.microsoft.com/en-us/typography/opentype/spec/eblc#bitmapsize-record) record.
+#[derive(Clone, Debug)]
+#[repr(C)]
+#[repr(packed)]
+pub struct BitmapSize {
+    /// Offset to index subtable from beginning of EBLC/CBLC.
+    pub index_subtable_list_offset: BigEndian<Offset32>,
+    /// Number of bytes in corresponding index subtables and array.
+    pub index_subtable_list_size: BigEndian<u32>,
+    /// There is an IndexSubtableRecord for each range or format change.
+    pub number_of_index_subtables: BigEndian<u32>,
+    /// Not used; set to 0.
+    pub color_ref: BigEndian<u32>,
+    /// Line metrics for text rendered horizontally.
+    pub hori: SbitLineMetrics,
+    /// Line metrics for text rendered vertically.
+    pub vert: SbitLineMetrics,
+    /// Lowest glyph index for this size.
+    pub start_glyph_index: BigEndian<GlyphId>,
+    /// Highest glyph index for this size.
+    pub end_glyph_index: BigEndian<GlyphId>,
+    /// Horizontal pixels per em.
+    pub ppem_x: BigEndian<u8>,
+    /// Vertical pixels per em.
+    pub ppem_y: BigEndian<u8>,
+    /// The Microsoft rasterizer v.1.7 or greater supports the following
+    /// bitDepth values, as described below: 1, 2, 4, and 8 (and 32 for CBLC).
+    pub bit_depth: BigEndian<u8>,
+    /// Vertical or horizontal.
+    pub flags: BigEndian<BitmapFlags>,
+}
+
+impl BitmapSize {
+    /// Offset to index subtable from beginning of EBLC/CBLC.
+    pub fn index_subtable_list_offset(&self) -> Offset32 {
+        self.index_subtable_list_offset.get()
+    }
+
+    /// Attempt to resolve [`index_subtable_list_offset`][Self::index_subtable_list_offset].
+    pub fn index_subtable_list<'a>(
+        &self,
+        data: FontData<'a>,
+    ) -> Result<IndexSubtableList<'a>, ReadError> {
+        let args = self.number_of_index_subtables();
+        self.index_subtable_list_offset()
+            .resolve_with_args(data, &args)
+    }
+
+    /// Number of bytes in corresponding index subtables and array.
+    pub fn index_subtable_list_size(&self) -> u32 {
+        self.index_subtable_list_size.get()
+    }
+
+    /// There is an IndexSubtableRecord for each range or format change.
+    pub fn number_of_index_subtables(&self) -> u32 {
+        self.number_of_index_subtables.get()
+    }
+
+    /// Not used; set to 0.
+    pub fn color_ref(&self) -> u32 {
+        self.color_ref.get()
+    }
+
+    /// Line metrics for text rendered horizontally.
+    pub fn hori(&self) -> &SbitLineMetrics {
+        &self.hori
+    }
+
+    /// Line metrics for text rendered vertically.
+    pub fn vert(&self) -> &SbitLineMetrics {
+        &self.vert
+    }
+
+    /// Lowest glyph index for this size.
+    pub fn start_glyph_index(&self) -> GlyphId {
+        self.start_glyph_index.get()
+    }
+
+    /// Highest glyph index for this size.
+    pub fn end_glyph_index(&self) -> GlyphId {
+        self.end_glyph_index.get()
+    }
+
+    /// Horizontal pixels per em.
+    pub fn ppem_x(&self) -> u8 {
+        self.ppem_x.get()
+    }
+
+    /// Vertical pixels per em.
+    pub fn ppem_y(&self) -> u8 {
+        self.ppem_y.get()
+    }
+
+    /// The Microsoft rasterizer v.1.7 or greater supports the following
+    /// bitDepth values, as described below: 1, 2, 4, and 8 (and 32 for CBLC).
+    pub fn bit_depth(&self) -> u8 {
+        self.bit_depth.get()
+    }
+
+    /// Vertical or horizontal.
+    pub fn flags(&self) -> BitmapFlags {
+        self.flags.get()
+    }
+}
+
+impl FixedSized for BitmapSize {
+    const RAW_BYTE_LEN: usize = Offset32::RAW_BYTE_LEN
+        + u32::RAW_BYTE_LEN
+        + u32::RAW_BYTE_LEN
+        + u32::RAW_BYTE_LEN
+        + SbitLineMetrics::RAW_BYTE_LEN
+        + SbitLineMetrics::RAW_BYTE_LEN
+        + GlyphId::RAW_BYTE_LEN
+        + GlyphId::RAW_BYTE_LEN
+        + u8::RAW_BYTE_LEN
+        + u8::RAW_BYTE_LEN
+        + u8::RAW_BYTE_LEN
+        + BitmapFlags::RAW_BYTE_LEN;
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeRecord<'a> for BitmapSize {
+    fn traverse(self, data: FontData<'a>) -> RecordResolver<'a> {
+        RecordResolver {
+            name: "BitmapSize",
+            get_field: Box::new(move |idx, _data| match idx {
+                0usize => Some(Field::new(
+                    "index_subtable_list_offset",
+                    FieldType::offset(
+                        self.index_subtable_list_offset(),
+                        self.index_subtable_list(_data),
+                    ),
+                )),
+                1usize => Some(Field::new(
+                    "index_subtable_list_size",
+                    self.index_subtable_list_size(),
+                )),
+                2usize => Some(Field::new(
+                    "number_of_index_subtables",
+                    self.number_of_index_subtables(),
+                )),
+                3usize => Some(Field::new("color_ref", self.color_ref())),
+                4usize => Some(Field::new(
+                    "hori",
+                    traversal::FieldType::Record((*self.hori()).traverse(_data)),
+                )),
+                5usize => Some(Field::new(
+                    "vert",
+                    traversal::FieldType::Record((*self.vert()).traverse(_data)),
+                )),
+                6usize => Some(Field::new("start_glyph_index", self.start_glyph_index())),
+                7usize => Some(Field::new("end_glyph_index", self.end_glyph_index())),
+                8usize => Some(Field::new("ppem_x", self.ppem_x())),
+                9usize => Some(Field::new("ppem_y", self.ppem_y())),
+                10usize => Some(Field::new("bit_depth", self.bit_depth())),
+                11usize => Some(Field::new("flags", self.flags())),
+                _ => None,
+            }),
+            data,
+        }
+    }
+}
+
+/// [SbitLineMetrics](https://learn.microsoft.com/en-us/typography/opentype/spec/eblc#sbitlinemetrics-record) record.
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+#[repr(packed)]
+pub struct SbitLineMetrics {
+    /// Distance from the baseline to the top of the line, in pixels.
+    pub ascender: BigEndian<i8>,
+    /// Distance from the baseline to the bottom of the line, in pixels.
+    pub descender: BigEndian<i8>,
+    /// Maximum pixel width of glyphs in the strike.
+    pub width_max: BigEndian<u8>,
+    /// Rise of the caret slope, typically set to 1 for non-italic fonts.
+    pub caret_slope_numerator: BigEndian<i8>,
+    /// Run of the caret slope, typically set to 0 for non-italic fonts.
+    pub caret_slope_denominator: BigEndian<u8>,
+    /// Offset in pixels to move the caret for proper positioning.
+    pub caret_offset: BigEndian<i8>,
+    /// Minimum of horiBearingX (vertBearingY for vertical metrics).
+    pub min_origin_sb: BigEndian<i8>,
+    /// Minimum of horiAdvance - horiBearingX - width (vertAdvance -
+    /// vertBearingY - height for vertical metrics).
+    pub min_advance_sb: BigEndian<i8>,
+    /// Maximum of horiBearingY (vertBearingX for vertical metrics).
+    pub max_before_bl: BigEndian<i8>,
+    /// Minimum of horiBearingY - height (vertBearingX - width for vertical
+    /// metrics).
+    pub min_after_bl: BigEndian<i8>,
+    pub pad1: BigEndian<i8>,
+    pub pad2: BigEndian<i8>,
+}
+
+impl SbitLineMetrics {
+    /// Distance from the baseline to the top of the line, in pixels.
+    pub fn ascender(&self) -> i8 {
+        self.ascender.get()
+    }
+
+    /// Distance from the baseline to the bottom of the line, in pixels.
+    pub fn descender(&self) -> i8 {
+        self.descender.get()
+    }
+
+    /// Maximum pixel width of glyphs in the strike.
+    pub fn width_max(&self) -> u8 {
+        self.width_max.get()
+    }
+
+    /// Rise of the caret slope, typically set to 1 for non-italic fonts.
+    pub fn caret_slope_numerator(&self) -> i8 {
+        self.caret_slope_numerator.get()
+    }
+
+    /// Run of the caret slope, typically set to 0 for non-italic fonts.
+    pub fn caret_slope_denominator(&self) -> u8 {
+        self.caret_slope_denominator.get()
+    }
+
+    /// Offset in pixels to move the caret for proper positioning.
+    pub fn caret_offset(&self) -> i8 {
+        self.caret_offset.get()
+    }
+
+    /// Minimum of horiBearingX (vertBearingY for vertical metrics).
+    pub fn min_origin_sb(&self) -> i8 {
+        self.min_origin_sb.get()
+    }
+
+    /// Minimum of horiAdvance - horiBearingX - width (vertAdvance -
+    /// vertBearingY - height for vertical metrics).
+    pub fn min_advance_sb(&self) -> i8 {
+        self.min_advance_sb.get()
+    }
+
+    /// Maximum of horiBearingY (vertBearingX for vertical metrics).
+    pub fn max_before_bl(&self) -> i8 {
+        self.max_before_bl.get()
+    }
+
+    /// Minimum of horiBearingY - height (vertBearingX - width for vertical
+    /// metrics).
+    pub fn min_after_bl(&self) -> i8 {
+        self.min_after_bl.get()
+    }
+}
+
+impl FixedSized for SbitLineMetrics {
+    const RAW_BYTE_LEN: usize = i8::RAW_BYTE_LEN
+        + i8::RAW_BYTE_LEN
+        + u8::RAW_BYTE_LEN
+        + i8::RAW_BYTE_LEN
+        + u8::RAW_BYTE_LEN
+        + i8::RAW_BYTE_LEN
+        + i8::RAW_BYTE_LEN
+        + i8::RAW_BYTE_LEN
+        + i8::RAW_BYTE_LEN
+        + i8::RAW_BYTE_LEN
+        + i8::RAW_BYTE_LEN
+        + i8::RAW_BYTE_LEN;
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeRecord<'a> for SbitLineMetrics {
+    fn traverse(self, data: FontData<'a>) -> RecordResolver<'a> {
+        RecordResolver {
+            name: "SbitLineMetrics",
+            get_field: Box::new(move |idx, _data| match idx {
+                0usize => Some(Field::new("ascender", self.ascender())),
+                1usize => Some(Field::new("descender", self.descender())),
+                2usize => Some(Field::new("width_max", self.width_max())),
+                3usize => Some(Field::new(
+                    "caret_slope_numerator",
+                    self.caret_slope_numerator(),
+                )),
+                4usize => Some(Field::new(
+                    "caret_slope_denominator",
+                    self.caret_slope_denominator(),
+                )),
+                5usize => Some(Field::new("caret_offset", self.caret_offset())),
+                6usize => Some(Field::new("min_origin_sb", self.min_origin_sb())),
+                7usize => Some(Field::new("min_advance_sb", self.min_advance_sb())),
+                8usize => Some(Field::new("max_before_bl", self.max_before_bl())),
+                9usize => Some(Field::new("min_after_bl", self.min_after_bl())),
+                _ => None,
+            }),
+            data,
+        }
+    }
+}
+
+bitflags::bitflags! { # [doc = " [Bitmap flags](https://learn.microsoft.com/en-us/typography/opentype/spec/eblc#bitmap-flags)."] pub struct BitmapFlags : u8 { # [doc = " Horizontal."] const HORIZONTAL_METRICS = 0x01 ; # [doc = " Vertical."] const VERTICAL_METRICS = 0x02 ; } }
+
+impl font_types::Scalar for BitmapFlags {
+    type Raw = <u8 as font_types::Scalar>::Raw;
+    fn to_raw(self) -> Self::Raw {
+        self.bits().to_raw()
+    }
+    fn from_raw(raw: Self::Raw) -> Self {
+        let t = <u8>::from_raw(raw);
+        Self::from_bits_truncate(t)
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> From<BitmapFlags> for FieldType<'a> {
+    fn from(src: BitmapFlags) -> FieldType<'a> {
+        src.bits().into()
+    }
+}
+
+/// [IndexSubtableList](https://learn.microsoft.com/en-us/typography/opentype/spec/eblc#indexsubtablelist-table) table.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct IndexSubtableListMarker {
+    index_subtable_records_byte_len: usize,
+}
+
+impl IndexSubtableListMarker {
+    fn index_subtable_records_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + self.index_subtable_records_byte_len
+    }
+}
+
+impl ReadArgs for IndexSubtableListMarker {
+    type Args = u32;
+}
+
+impl TableInfoWithArgs for IndexSubtableListMarker {
+    #[allow(unused_parens)]
+    fn parse_with_args<'a>(
+        data: FontData<'a>,
+        args: &u32,
+    ) -> Result<TableRef<'a, Self>, ReadError> {
+        let number_of_index_subtables = *args;
+        let mut cursor = data.cursor();
+        let index_subtable_records_byte_len =
+            number_of_index_subtables as usize * IndexSubtableRecord::RAW_BYTE_LEN;
+        cursor.advance_by(index_subtable_records_byte_len);
+        cursor.finish(IndexSubtableListMarker {
+            index_subtable_records_byte_len,
+        })
+    }
+}
+
+/// [IndexSubtableList](https://learn.microsoft.com/en-us/typography/opentype/spec/eblc#indexsubtablelist-table) table.
+pub type IndexSubtableList<'a> = TableRef<'a, IndexSubtableListMarker>;
+
+impl<'a> IndexSubtableList<'a> {
+    /// Array of IndexSubtableRecords.
+    pub fn index_subtable_records(&self) -> &'a [IndexSubtableRecord] {
+        let range = self.shape.index_subtable_records_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for IndexSubtableList<'a> {
+    fn type_name(&self) -> &str {
+        "IndexSubtableList"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(Field::new(
+                "index_subtable_records",
+                traversal::FieldType::array_of_records(
+                    stringify!(IndexSubtableRecord),
+                    self.index_subtable_records(),
+                    self.offset_data(),
+                ),
+            )),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for IndexSubtableList<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// [IndexSubtableRecord](https://learn.microsoft.com/en-us/typography/opentype/spec/eblc#indexsubtablerecord-record) record.
+#[derive(Clone, Debug)]
+#[repr(C)]
+#[repr(packed)]
+pub struct IndexSubtableRecord {
+    /// First glyph ID of this range.
+    pub first_glyph_index: BigEndian<GlyphId>,
+    /// Last glyph ID of this range (inclusive).
+    pub last_glyph_index: BigEndian<GlyphId>,
+    /// Offset to an IndexSubtable from the start of the IndexSubtableList.
+    pub index_subtable_offset: BigEndian<Offset32>,
+}
+
+impl IndexSubtableRecord {
+    /// First glyph ID of this range.
+    pub fn first_glyph_index(&self) -> GlyphId {
+        self.first_glyph_index.get()
+    }
+
+    /// Last glyph ID of this range (inclusive).
+    pub fn last_glyph_index(&self) -> GlyphId {
+        self.last_glyph_index.get()
+    }
+
+    /// Offset to an IndexSubtable from the start of the IndexSubtableList.
+    pub fn index_subtable_offset(&self) -> Offset32 {
+        self.index_subtable_offset.get()
+    }
+
+    /// Attempt to resolve [`index_subtable_offset`][Self::index_subtable_offset].
+    pub fn index_subtable<'a>(&self, data: FontData<'a>) -> Result<IndexSubtable<'a>, ReadError> {
+        self.index_subtable_offset().resolve(data)
+    }
+}
+
+impl FixedSized for IndexSubtableRecord {
+    const RAW_BYTE_LEN: usize =
+        GlyphId::RAW_BYTE_LEN + GlyphId::RAW_BYTE_LEN + Offset32::RAW_BYTE_LEN;
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeRecord<'a> for IndexSubtableRecord {
+    fn traverse(self, data: FontData<'a>) -> RecordResolver<'a> {
+        RecordResolver {
+            name: "IndexSubtableRecord",
+            get_field: Box::new(move |idx, _data| match idx {
+                0usize => Some(Field::new("first_glyph_index", self.first_glyph_index())),
+                1usize => Some(Field::new("last_glyph_index", self.last_glyph_index())),
+                2usize => Some(Field::new(
+                    "index_subtable_offset",
+                    FieldType::offset(self.index_subtable_offset(), self.index_subtable(_data)),
+                )),
+                _ => None,
+            }),
+            data,
+        }
+    }
+}
+
+/// [IndexSubtables](https://learn.microsoft.com/en-us/typography/opentype/spec/eblc#indexsubtables) format type.
+#[derive(Clone)]
+pub enum IndexSubtable<'a> {
+    Format1(IndexSubtable1<'a>),
+    Format2(IndexSubtable2<'a>),
+    Format3(IndexSubtable3<'a>),
+    Format4(IndexSubtable4<'a>),
+    Format5(IndexSubtable5<'a>),
+}
+
+impl<'a> FontRead<'a> for IndexSubtable<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let format: u16 = data.read_at(0)?;
+        match format {
+            IndexSubtable1Marker::FORMAT => Ok(Self::Format1(FontRead::read(data)?)),
+            IndexSubtable2Marker::FORMAT => Ok(Self::Format2(FontRead::read(data)?)),
+            IndexSubtable3Marker::FORMAT => Ok(Self::Format3(FontRead::read(data)?)),
+            IndexSubtable4Marker::FORMAT => Ok(Self::Format4(FontRead::read(data)?)),
+            IndexSubtable5Marker::FORMAT => Ok(Self::Format5(FontRead::read(data)?)),
+            other => Err(ReadError::InvalidFormat(other.into())),
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> IndexSubtable<'a> {
+    fn dyn_inner<'b>(&'b self) -> &'b dyn SomeTable<'a> {
+        match self {
+            Self::Format1(table) => table,
+            Self::Format2(table) => table,
+            Self::Format3(table) => table,
+            Self::Format4(table) => table,
+            Self::Format5(table) => table,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for IndexSubtable<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.dyn_inner().fmt(f)
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for IndexSubtable<'a> {
+    fn type_name(&self) -> &str {
+        self.dyn_inner().type_name()
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        self.dyn_inner().get_field(idx)
+    }
+}
+
+impl Format<u16> for IndexSubtable1Marker {
+    const FORMAT: u16 = 1;
+}
+
+/// [IndexSubTable1](https://learn.microsoft.com/en-us/typography/opentype/spec/eblc#indexsubtable1-variable-metrics-glyphs-with-4-byte-offsets): variable-metrics glyphs with 4-byte offsets.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct IndexSubtable1Marker {
+    sbit_offsets_byte_len: usize,
+}
+
+impl IndexSubtable1Marker {
+    fn index_format_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn image_format_byte_range(&self) -> Range<usize> {
+        let start = self.index_format_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn image_data_offset_byte_range(&self) -> Range<usize> {
+        let start = self.image_format_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn sbit_offsets_byte_range(&self) -> Range<usize> {
+        let start = self.image_data_offset_byte_range().end;
+        start..start + self.sbit_offsets_byte_len
+    }
+}
+
+impl TableInfo for IndexSubtable1Marker {
+    #[allow(unused_parens)]
+    fn parse(data: FontData) -> Result<TableRef<Self>, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<u16>();
+        cursor.advance::<u16>();
+        cursor.advance::<u32>();
+        let sbit_offsets_byte_len = cursor.remaining_bytes();
+        cursor.advance_by(sbit_offsets_byte_len);
+        cursor.finish(IndexSubtable1Marker {
+            sbit_offsets_byte_len,
+        })
+    }
+}
+
+/// [IndexSubTable1](https://learn.microsoft.com/en-us/typography/opentype/spec/eblc#indexsubtable1-variable-metrics-glyphs-with-4-byte-offsets): variable-metrics glyphs with 4-byte offsets.
+pub type IndexSubtable1<'a> = TableRef<'a, IndexSubtable1Marker>;
+
+impl<'a> IndexSubtable1<'a> {
+    /// Format of this IndexSubTable.
+    pub fn index_format(&self) -> u16 {
+        let range = self.shape.index_format_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Format of EBDT image data.
+    pub fn image_format(&self) -> u16 {
+        let range = self.shape.image_format_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Offset to image data in EBDT table.
+    pub fn image_data_offset(&self) -> u32 {
+        let range = self.shape.image_data_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Offsets into the image data, from imageDataOffset, for each glyph
+    /// in the range, followed by the end of the last glyph.
+    pub fn sbit_offsets(&self) -> &'a [BigEndian<u32>] {
+        let range = self.shape.sbit_offsets_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for IndexSubtable1<'a> {
+    fn type_name(&self) -> &str {
+        "IndexSubtable1"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(Field::new("index_format", self.index_format())),
+            1usize => Some(Field::new("image_format", self.image_format())),
+            2usize => Some(Field::new("image_data_offset", self.image_data_offset())),
+            3usize => Some(Field::new("sbit_offsets", self.sbit_offsets())),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for IndexSubtable1<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+impl Format<u16> for IndexSubtable2Marker {
+    const FORMAT: u16 = 2;
+}
+
+/// [IndexSubTable2](https://learn.microsoft.com/en-us/typography/opentype/spec/eblc#indexsubtable2-all-glyphs-have-identical-metrics): all glyphs have identical metrics.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct IndexSubtable2Marker {
+    big_metrics_byte_len: usize,
+}
+
+impl IndexSubtable2Marker {
+    fn index_format_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn image_format_byte_range(&self) -> Range<usize> {
+        let start = self.index_format_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn image_data_offset_byte_range(&self) -> Range<usize> {
+        let start = self.image_format_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn image_size_byte_range(&self) -> Range<usize> {
+        let start = self.image_data_offset_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn big_metrics_byte_range(&self) -> Range<usize> {
+        let start = self.image_size_byte_range().end;
+        start..start + self.big_metrics_byte_len
+    }
+}
+
+impl TableInfo for IndexSubtable2Marker {
+    #[allow(unused_parens)]
+    fn parse(data: FontData) -> Result<TableRef<Self>, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<u16>();
+        cursor.advance::<u16>();
+        cursor.advance::<u32>();
+        cursor.advance::<u32>();
+        let big_metrics_byte_len = BigGlyphMetrics::RAW_BYTE_LEN;
+        cursor.advance_by(big_metrics_byte_len);
+        cursor.finish(IndexSubtable2Marker {
+            big_metrics_byte_len,
+        })
+    }
+}
+
+/// [IndexSubTable2](https://learn.microsoft.com/en-us/typography/opentype/spec/eblc#indexsubtable2-all-glyphs-have-identical-metrics): all glyphs have identical metrics.
+pub type IndexSubtable2<'a> = TableRef<'a, IndexSubtable2Marker>;
+
+impl<'a> IndexSubtable2<'a> {
+    /// Format of this IndexSubTable.
+    pub fn index_format(&self) -> u16 {
+        let range = self.shape.index_format_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Format of EBDT image data.
+    pub fn image_format(&self) -> u16 {
+        let range = self.shape.image_format_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Offset to image data in EBDT table.
+    pub fn image_data_offset(&self) -> u32 {
+        let range = self.shape.image_data_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// All the glyphs are of the same size.
+    pub fn image_size(&self) -> u32 {
+        let range = self.shape.image_size_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// All glyphs have the same metrics; glyph data may be compressed,
+    /// byte-aligned, or bit-aligned.
+    pub fn big_metrics(&self) -> &'a [BigGlyphMetrics] {
+        let range = self.shape.big_metrics_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for IndexSubtable2<'a> {
+    fn type_name(&self) -> &str {
+        "IndexSubtable2"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(Field::new("index_format", self.index_format())),
+            1usize => Some(Field::new("image_format", self.image_format())),
+            2usize => Some(Field::new("image_data_offset", self.image_data_offset())),
+            3usize => Some(Field::new("image_size", self.image_size())),
+            4usize => Some(Field::new(
+                "big_metrics",
+                traversal::FieldType::array_of_records(
+                    stringify!(BigGlyphMetrics),
+                    self.big_metrics(),
+                    self.offset_data(),
+                ),
+            )),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for IndexSubtable2<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+impl Format<u16> for IndexSubtable3Marker {
+    const FORMAT: u16 = 3;
+}
+
+/// [IndexSubTable3](https://learn.microsoft.com/en-us/typography/opentype/spec/eblc#indexsubtable3-variable-metrics-glyphs-with-2-byte-offsets): variable-metrics glyphs with 2-byte offsets.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct IndexSubtable3Marker {
+    sbit_offsets_byte_len: usize,
+}
+
+impl IndexSubtable3Marker {
+    fn index_format_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn image_format_byte_range(&self) -> Range<usize> {
+        let start = self.index_format_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn image_data_offset_byte_range(&self) -> Range<usize> {
+        let start = self.image_format_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn sbit_offsets_byte_range(&self) -> Range<usize> {
+        let start = self.image_data_offset_byte_range().end;
+        start..start + self.sbit_offsets_byte_len
+    }
+}
+
+impl TableInfo for IndexSubtable3Marker {
+    #[allow(unused_parens)]
+    fn parse(data: FontData) -> Result<TableRef<Self>, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<u16>();
+        cursor.advance::<u16>();
+        cursor.advance::<u32>();
+        let sbit_offsets_byte_len = cursor.remaining_bytes();
+        cursor.advance_by(sbit_offsets_byte_len);
+        cursor.finish(IndexSubtable3Marker {
+            sbit_offsets_byte_len,
+        })
+    }
+}
+
+/// [IndexSubTable3](https://learn.microsoft.com/en-us/typography/opentype/spec/eblc#indexsubtable3-variable-metrics-glyphs-with-2-byte-offsets): variable-metrics glyphs with 2-byte offsets.
+pub type IndexSubtable3<'a> = TableRef<'a, IndexSubtable3Marker>;
+
+impl<'a> IndexSubtable3<'a> {
+    /// Format of this IndexSubTable.
+    pub fn index_format(&self) -> u16 {
+        let range = self.shape.index_format_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Format of EBDT image data.
+    pub fn image_format(&self) -> u16 {
+        let range = self.shape.image_format_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Offset to image data in EBDT table.
+    pub fn image_data_offset(&self) -> u32 {
+        let range = self.shape.image_data_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Offsets into the image data, from imageDataOffset, for each glyph
+    /// in the range, followed by the end of the last glyph.
+    pub fn sbit_offsets(&self) -> &'a [BigEndian<u16>] {
+        let range = self.shape.sbit_offsets_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for IndexSubtable3<'a> {
+    fn type_name(&self) -> &str {
+        "IndexSubtable3"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(Field::new("index_format", self.index_format())),
+            1usize => Some(Field::new("image_format", self.image_format())),
+            2usize => Some(Field::new("image_data_offset", self.image_data_offset())),
+            3usize => Some(Field::new("sbit_offsets", self.sbit_offsets())),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for IndexSubtable3<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+impl Format<u16> for IndexSubtable4Marker {
+    const FORMAT: u16 = 4;
+}
+
+/// [IndexSubTable4](https://learn.microsoft.com/en-us/typography/opentype/spec/eblc#indexsubtable4-variable-metrics-glyphs-with-sparse-glyph-codes): variable-metrics glyphs with sparse glyph codes.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct IndexSubtable4Marker {
+    glyph_array_byte_len: usize,
+}
+
+impl IndexSubtable4Marker {
+    fn index_format_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn image_format_byte_range(&self) -> Range<usize> {
+        let start = self.index_format_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn image_data_offset_byte_range(&self) -> Range<usize> {
+        let start = self.image_format_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn num_glyphs_byte_range(&self) -> Range<usize> {
+        let start = self.image_data_offset_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn glyph_array_byte_range(&self) -> Range<usize> {
+        let start = self.num_glyphs_byte_range().end;
+        start..start + self.glyph_array_byte_len
+    }
+}
+
+impl TableInfo for IndexSubtable4Marker {
+    #[allow(unused_parens)]
+    fn parse(data: FontData) -> Result<TableRef<Self>, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<u16>();
+        cursor.advance::<u16>();
+        cursor.advance::<u32>();
+        let num_glyphs: u32 = cursor.read()?;
+        let glyph_array_byte_len =
+            (num_glyphs as usize).saturating_add(1) * GlyphIdOffsetPair::RAW_BYTE_LEN;
+        cursor.advance_by(glyph_array_byte_len);
+        cursor.finish(IndexSubtable4Marker {
+            glyph_array_byte_len,
+        })
+    }
+}
+
+/// [IndexSubTable4](https://learn.microsoft.com/en-us/typography/opentype/spec/eblc#indexsubtable4-variable-metrics-glyphs-with-sparse-glyph-codes): variable-metrics glyphs with sparse glyph codes.
+pub type IndexSubtable4<'a> = TableRef<'a, IndexSubtable4Marker>;
+
+impl<'a> IndexSubtable4<'a> {
+    /// Format of this IndexSubTable.
+    pub fn index_format(&self) -> u16 {
+        let range = self.shape.index_format_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Format of EBDT image data.
+    pub fn image_format(&self) -> u16 {
+        let range = self.shape.image_format_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Offset to image data in EBDT table.
+    pub fn image_data_offset(&self) -> u32 {
+        let range = self.shape.image_data_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Array length.
+    pub fn num_glyphs(&self) -> u32 {
+        let range = self.shape.num_glyphs_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// One per glyph, sorted by glyph ID, followed by a final entry
+    /// giving the end of the last glyph.
+    pub fn glyph_array(&self) -> &'a [GlyphIdOffsetPair] {
+        let range = self.shape.glyph_array_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for IndexSubtable4<'a> {
+    fn type_name(&self) -> &str {
+        "IndexSubtable4"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(Field::new("index_format", self.index_format())),
+            1usize => Some(Field::new("image_format", self.image_format())),
+            2usize => Some(Field::new("image_data_offset", self.image_data_offset())),
+            3usize => Some(Field::new("num_glyphs", self.num_glyphs())),
+            4usize => Some(Field::new(
+                "glyph_array",
+                traversal::FieldType::array_of_records(
+                    stringify!(GlyphIdOffsetPair),
+                    self.glyph_array(),
+                    self.offset_data(),
+                ),
+            )),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for IndexSubtable4<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// [GlyphIdOffsetPair](https://learn.microsoft.com/en-us/typography/opentype/spec/eblc#glyphidoffsetpair-record) record.
+#[derive(Clone, Debug)]
+#[repr(C)]
+#[repr(packed)]
+pub struct GlyphIdOffsetPair {
+    /// Glyph ID of glyph present.
+    pub glyph_id: BigEndian<GlyphId>,
+    /// Location in EBDT, from imageDataOffset.
+    pub sbit_offset: BigEndian<u16>,
+}
+
+impl GlyphIdOffsetPair {
+    /// Glyph ID of glyph present.
+    pub fn glyph_id(&self) -> GlyphId {
+        self.glyph_id.get()
+    }
+
+    /// Location in EBDT, from imageDataOffset.
+    pub fn sbit_offset(&self) -> u16 {
+        self.sbit_offset.get()
+    }
+}
+
+impl FixedSized for GlyphIdOffsetPair {
+    const RAW_BYTE_LEN: usize = GlyphId::RAW_BYTE_LEN + u16::RAW_BYTE_LEN;
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeRecord<'a> for GlyphIdOffsetPair {
+    fn traverse(self, data: FontData<'a>) -> RecordResolver<'a> {
+        RecordResolver {
+            name: "GlyphIdOffsetPair",
+            get_field: Box::new(move |idx, _data| match idx {
+                0usize => Some(Field::new("glyph_id", self.glyph_id())),
+                1usize => Some(Field::new("sbit_offset", self.sbit_offset())),
+                _ => None,
+            }),
+            data,
+        }
+    }
+}
+
+impl Format<u16> for IndexSubtable5Marker {
+    const FORMAT: u16 = 5;
+}
+
+/// [IndexSubTable5](https://learn.microsoft.com/en-us/typography/opentype/spec/eblc#indexsubtable5-constant-metrics-glyphs-with-sparse-glyph-codes): constant-metrics glyphs with sparse glyph codes.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct IndexSubtable5Marker {
+    big_metrics_byte_len: usize,
+    glyph_array_byte_len: usize,
+}
+
+impl IndexSubtable5Marker {
+    fn index_format_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn image_format_byte_range(&self) -> Range<usize> {
+        let start = self.index_format_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn image_data_offset_byte_range(&self) -> Range<usize> {
+        let start = self.image_format_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn image_size_byte_range(&self) -> Range<usize> {
+        let start = self.image_data_offset_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn big_metrics_byte_range(&self) -> Range<usize> {
+        let start = self.image_size_byte_range().end;
+        start..start + self.big_metrics_byte_len
+    }
+    fn num_glyphs_byte_range(&self) -> Range<usize> {
+        let start = self.big_metrics_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn glyph_array_byte_range(&self) -> Range<usize> {
+        let start = self.num_glyphs_byte_range().end;
+        start..start + self.glyph_array_byte_len
+    }
+}
+
+impl TableInfo for IndexSubtable5Marker {
+    #[allow(unused_parens)]
+    fn parse(data: FontData) -> Result<TableRef<Self>, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<u16>();
+        cursor.advance::<u16>();
+        cursor.advance::<u32>();
+        cursor.advance::<u32>();
+        let big_metrics_byte_len = BigGlyphMetrics::RAW_BYTE_LEN;
+        cursor.advance_by(big_metrics_byte_len);
+        let num_glyphs: u32 = cursor.read()?;
+        let glyph_array_byte_len = num_glyphs as usize * GlyphId::RAW_BYTE_LEN;
+        cursor.advance_by(glyph_array_byte_len);
+        cursor.finish(IndexSubtable5Marker {
+            big_metrics_byte_len,
+            glyph_array_byte_len,
+        })
+    }
+}
+
+/// [IndexSubTable5](https://learn.microsoft.com/en-us/typography/opentype/spec/eblc#indexsubtable5-constant-metrics-glyphs-with-sparse-glyph-codes): constant-metrics glyphs with sparse glyph codes.
+pub type IndexSubtable5<'a> = TableRef<'a, IndexSubtable5Marker>;
+
+impl<'a> IndexSubtable5<'a> {
+    /// Format of this IndexSubTable.
+    pub fn index_format(&self) -> u16 {
+        let range = self.shape.index_format_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Format of EBDT image data.
+    pub fn image_format(&self) -> u16 {
+        let range = self.shape.image_format_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Offset to image data in EBDT table.
+    pub fn image_data_offset(&self) -> u32 {
+        let range = self.shape.image_data_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// All glyphs have the same data size.
+    pub fn image_size(&self) -> u32 {
+        let range = self.shape.image_size_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// All glyphs have the same metrics.
+    pub fn big_metrics(&self) -> &'a [BigGlyphMetrics] {
+        let range = self.shape.big_metrics_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+
+    /// Array length.
+    pub fn num_glyphs(&self) -> u32 {
+        let range = self.shape.num_glyphs_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// One per glyph, sorted by glyph ID.
+    pub fn glyph_array(&self) -> &'a [BigEndian<GlyphId>] {
+        let range = self.shape.glyph_array_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for IndexSubtable5<'a> {
+    fn type_name(&self) -> &str {
+        "IndexSubtable5"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(Field::new("index_format", self.index_format())),
+            1usize => Some(Field::new("image_format", self.image_format())),
+            2usize => Some(Field::new("image_data_offset", self.image_data_offset())),
+            3usize => Some(Field::new("image_size", self.image_size())),
+            4usize => Some(Field::new(
+                "big_metrics",
+                traversal::FieldType::array_of_records(
+                    stringify!(BigGlyphMetrics),
+                    self.big_metrics(),
+                    self.offset_data(),
+                ),
+            )),
+            5usize => Some(Field::new("num_glyphs", self.num_glyphs())),
+            6usize => Some(Field::new("glyph_array", self.glyph_array())),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for IndexSubtable5<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// [BigGlyphMetrics](https://learn.microsoft.com/en-us/typography/opentype/spec/eblc#bigglyphmetrics) record.
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+#[repr(packed)]
+pub struct BigGlyphMetrics {
+    /// Number of rows of data.
+    pub height: BigEndian<u8>,
+    /// Number of columns of data.
+    pub width: BigEndian<u8>,
+    /// Distance in pixels from the horizontal origin to the left edge of
+    /// the bitmap.
+    pub hori_bearing_x: BigEndian<i8>,
+    /// Distance in pixels from the horizontal origin to the top edge of
+    /// the bitmap.
+    pub hori_bearing_y: BigEndian<i8>,
+    /// Horizontal advance width in pixels.
+    pub hori_advance: BigEndian<u8>,
+    /// Distance in pixels from the vertical origin to the left edge of the
+    /// bitmap.
+    pub vert_bearing_x: BigEndian<i8>,
+    /// Distance in pixels from the vertical origin to the top edge of the
+    /// bitmap.
+    pub vert_bearing_y: BigEndian<i8>,
+    /// Vertical advance width in pixels.
+    pub vert_advance: BigEndian<u8>,
+}
+
+impl BigGlyphMetrics {
+    /// Number of rows of data.
+    pub fn height(&self) -> u8 {
+        self.height.get()
+    }
+
+    /// Number of columns of data.
+    pub fn width(&self) -> u8 {
+        self.width.get()
+    }
+
+    /// Distance in pixels from the horizontal origin to the left edge of
+    /// the bitmap.
+    pub fn hori_bearing_x(&self) -> i8 {
+        self.hori_bearing_x.get()
+    }
+
+    /// Distance in pixels from the horizontal origin to the top edge of
+    /// the bitmap.
+    pub fn hori_bearing_y(&self) -> i8 {
+        self.hori_bearing_y.get()
+    }
+
+    /// Horizontal advance width in pixels.
+    pub fn hori_advance(&self) -> u8 {
+        self.hori_advance.get()
+    }
+
+    /// Distance in pixels from the vertical origin to the left edge of the
+    /// bitmap.
+    pub fn vert_bearing_x(&self) -> i8 {
+        self.vert_bearing_x.get()
+    }
+
+    /// Distance in pixels from the vertical origin to the top edge of the
+    /// bitmap.
+    pub fn vert_bearing_y(&self) -> i8 {
+        self.vert_bearing_y.get()
+    }
+
+    /// Vertical advance width in pixels.
+    pub fn vert_advance(&self) -> u8 {
+        self.vert_advance.get()
+    }
+}
+
+impl FixedSized for BigGlyphMetrics {
+    const RAW_BYTE_LEN: usize = u8::RAW_BYTE_LEN
+        + u8::RAW_BYTE_LEN
+        + i8::RAW_BYTE_LEN
+        + i8::RAW_BYTE_LEN
+        + u8::RAW_BYTE_LEN
+        + i8::RAW_BYTE_LEN
+        + i8::RAW_BYTE_LEN
+        + u8::RAW_BYTE_LEN;
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeRecord<'a> for BigGlyphMetrics {
+    fn traverse(self, data: FontData<'a>) -> RecordResolver<'a> {
+        RecordResolver {
+            name: "BigGlyphMetrics",
+            get_field: Box::new(move |idx, _data| match idx {
+                0usize => Some(Field::new("height", self.height())),
+                1usize => Some(Field::new("width", self.width())),
+                2usize => Some(Field::new("hori_bearing_x", self.hori_bearing_x())),
+                3usize => Some(Field::new("hori_bearing_y", self.hori_bearing_y())),
+                4usize => Some(Field::new("hori_advance", self.hori_advance())),
+                5usize => Some(Field::new("vert_bearing_x", self.vert_bearing_x())),
+                6usize => Some(Field::new("vert_bearing_y", self.vert_bearing_y())),
+                7usize => Some(Field::new("vert_advance", self.vert_advance())),
+                _ => None,
+            }),
+            data,
+        }
+    }
+}
+
+/// [SmallGlyphMetrics](https://learn.microsoft.com/en-us/typography/opentype/spec/eblc#smallglyphmetrics) record.
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+#[repr(packed)]
+pub struct SmallGlyphMetrics {
+    /// Number of rows of data.
+    pub height: BigEndian<u8>,
+    /// Number of columns of data.
+    pub width: BigEndian<u8>,
+    /// Distance in pixels from the horizontal origin to the left edge of
+    /// the bitmap (for horizontal text); or distance in pixels from the
+    /// vertical origin to the top edge of the bitmap (for vertical text).
+    pub bearing_x: BigEndian<i8>,
+    /// Distance in pixels from the horizontal origin to the top edge of
+    /// the bitmap (for horizontal text); or distance in pixels from the
+    /// vertical origin to the left edge of the bitmap (for vertical text).
+    pub bearing_y: BigEndian<i8>,
+    /// Horizontal or vertical advance width in pixels.
+    pub advance: BigEndian<u8>,
+}
+
+impl SmallGlyphMetrics {
+    /// Number of rows of data.
+    pub fn height(&self) -> u8 {
+        self.height.get()
+    }
+
+    /// Number of columns of data.
+    pub fn width(&self) -> u8 {
+        self.width.get()
+    }
+
+    /// Distance in pixels from the horizontal origin to the left edge of
+    /// the bitmap (for horizontal text); or distance in pixels from the
+    /// vertical origin to the top edge of the bitmap (for vertical text).
+    pub fn bearing_x(&self) -> i8 {
+        self.bearing_x.get()
+    }
+
+    /// Distance in pixels from the horizontal origin to the top edge of
+    /// the bitmap (for horizontal text); or distance in pixels from the
+    /// vertical origin to the left edge of the bitmap (for vertical text).
+    pub fn bearing_y(&self) -> i8 {
+        self.bearing_y.get()
+    }
+
+    /// Horizontal or vertical advance width in pixels.
+    pub fn advance(&self) -> u8 {
+        self.advance.get()
+    }
+}
+
+impl FixedSized for SmallGlyphMetrics {
+    const RAW_BYTE_LEN: usize = u8::RAW_BYTE_LEN
+        + u8::RAW_BYTE_LEN
+        + i8::RAW_BYTE_LEN
+        + i8::RAW_BYTE_LEN
+        + u8::RAW_BYTE_LEN;
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeRecord<'a> for SmallGlyphMetrics {
+    fn traverse(self, data: FontData<'a>) -> RecordResolver<'a> {
+        RecordResolver {
+            name: "SmallGlyphMetrics",
+            get_field: Box::new(move |idx, _data| match idx {
+                0usize => Some(Field::new("height", self.height())),
+                1usize => Some(Field::new("width", self.width())),
+                2usize => Some(Field::new("bearing_x", self.bearing_x())),
+                3usize => Some(Field::new("bearing_y", self.bearing_y())),
+                4usize => Some(Field::new("advance", self.advance())),
+                _ => None,
+            }),
+            data,
+        }
+    }
+}
+
+/// [EbdtComponent](https://learn.microsoft.com/en-us/typography/opentype/spec/ebdt#ebdtcomponent-record) record.
+#[derive(Clone, Debug)]
+#[repr(C)]
+#[repr(packed)]
+pub struct EbdtComponent {
+    /// Component glyph ID.
+    pub glyph_id: BigEndian<GlyphId>,
+    /// Position of component left.
+    pub x_offset: BigEndian<i8>,
+    /// Position of component top.
+    pub y_offset: BigEndian<i8>,
+}
+
+impl EbdtComponent {
+    /// Component glyph ID.
+    pub fn glyph_id(&self) -> GlyphId {
+        self.glyph_id.get()
+    }
+
+    /// Position of component left.
+    pub fn x_offset(&self) -> i8 {
+        self.x_offset.get()
+    }
+
+    /// Position of component top.
+    pub fn y_offset(&self) -> i8 {
+        self.y_offset.get()
+    }
+}
+
+impl FixedSized for EbdtComponent {
+    const RAW_BYTE_LEN: usize = GlyphId::RAW_BYTE_LEN + i8::RAW_BYTE_LEN + i8::RAW_BYTE_LEN;
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeRecord<'a> for EbdtComponent {
+    fn traverse(self, data: FontData<'a>) -> RecordResolver<'a> {
+        RecordResolver {
+            name: "EbdtComponent",
+            get_field: Box::new(move |idx, _data| match idx {
+                0usize => Some(Field::new("glyph_id", self.glyph_id())),
+                1usize => Some(Field::new("x_offset", self.x_offset())),
+                2usize => Some(Field::new("y_offset", self.y_offset())),
+                _ => None,
+            }),
+            data,
+        }
+    }
+}
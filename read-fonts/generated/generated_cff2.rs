@@ -0,0 +1,134 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+#[allow(unused_imports)]
+use crate::codegen_prelude::*;
+
+/// [Compact Font Format (CFF) version 2](https://learn.microsoft.com/en-us/typography/opentype/spec/cff2) table header
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct Cff2Marker {
+    _padding_byte_len: usize,
+    top_dict_data_byte_len: usize,
+    trailing_data_byte_len: usize,
+}
+
+impl Cff2Marker {
+    fn major_version_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u8::RAW_BYTE_LEN
+    }
+    fn minor_version_byte_range(&self) -> Range<usize> {
+        let start = self.major_version_byte_range().end;
+        start..start + u8::RAW_BYTE_LEN
+    }
+    fn header_size_byte_range(&self) -> Range<usize> {
+        let start = self.minor_version_byte_range().end;
+        start..start + u8::RAW_BYTE_LEN
+    }
+    fn top_dict_length_byte_range(&self) -> Range<usize> {
+        let start = self.header_size_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn _padding_byte_range(&self) -> Range<usize> {
+        let start = self.top_dict_length_byte_range().end;
+        start..start + self._padding_byte_len
+    }
+    fn top_dict_data_byte_range(&self) -> Range<usize> {
+        let start = self._padding_byte_range().end;
+        start..start + self.top_dict_data_byte_len
+    }
+    fn trailing_data_byte_range(&self) -> Range<usize> {
+        let start = self.top_dict_data_byte_range().end;
+        start..start + self.trailing_data_byte_len
+    }
+}
+
+impl TableInfo for Cff2Marker {
+    #[allow(unused_parens)]
+    fn parse(data: FontData) -> Result<TableRef<Self>, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<u8>();
+        cursor.advance::<u8>();
+        let header_size: u8 = cursor.read()?;
+        let top_dict_length: u16 = cursor.read()?;
+        let _padding_byte_len = (header_size as usize).saturating_sub(5);
+        cursor.advance_by(_padding_byte_len);
+        let top_dict_data_byte_len = top_dict_length as usize;
+        cursor.advance_by(top_dict_data_byte_len);
+        let trailing_data_byte_len = cursor.remaining_bytes();
+        cursor.advance_by(trailing_data_byte_len);
+        cursor.finish(Cff2Marker {
+            _padding_byte_len,
+            top_dict_data_byte_len,
+            trailing_data_byte_len,
+        })
+    }
+}
+
+/// [Compact Font Format (CFF) version 2](https://learn.microsoft.com/en-us/typography/opentype/spec/cff2) table header
+pub type Cff2<'a> = TableRef<'a, Cff2Marker>;
+
+impl<'a> Cff2<'a> {
+    /// Format major version (set to 2).
+    pub fn major_version(&self) -> u8 {
+        let range = self.shape.major_version_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Format minor version (set to zero).
+    pub fn minor_version(&self) -> u8 {
+        let range = self.shape.minor_version_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Header size (bytes).
+    pub fn header_size(&self) -> u8 {
+        let range = self.shape.header_size_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Length of Top DICT structure in bytes.
+    pub fn top_dict_length(&self) -> u16 {
+        let range = self.shape.top_dict_length_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Data containing the Top DICT.
+    pub fn top_dict_data(&self) -> &'a [u8] {
+        let range = self.shape.top_dict_data_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+
+    /// Remaining table data.
+    pub fn trailing_data(&self) -> &'a [u8] {
+        let range = self.shape.trailing_data_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for Cff2<'a> {
+    fn type_name(&self) -> &str {
+        "Cff2"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(Field::new("major_version", self.major_version())),
+            1usize => Some(Field::new("minor_version", self.minor_version())),
+            2usize => Some(Field::new("header_size", self.header_size())),
+            3usize => Some(Field::new("top_dict_length", self.top_dict_length())),
+            4usize => Some(Field::new("top_dict_data", self.top_dict_data())),
+            5usize => Some(Field::new("trailing_data", self.trailing_data())),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for Cff2<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
@@ -199,6 +199,7 @@ impl<'a> From<PlatformId> for FieldType<'a> {
 }
 
 /// The different cmap subtable formats.
+#[derive(Clone)]
 pub enum CmapSubtable<'a> {
     Format0(Cmap0<'a>),
     Format2(Cmap2<'a>),
@@ -300,7 +301,7 @@ impl TableInfo for Cmap0Marker {
         cursor.advance::<u16>();
         cursor.advance::<u16>();
         cursor.advance::<u16>();
-        let glyph_id_array_byte_len = 256 * u8::RAW_BYTE_LEN;
+        let glyph_id_array_byte_len = 256;
         cursor.advance_by(glyph_id_array_byte_len);
         cursor.finish(Cmap0Marker {
             glyph_id_array_byte_len,
@@ -907,7 +908,7 @@ impl TableInfo for Cmap8Marker {
         cursor.advance::<u16>();
         cursor.advance::<u32>();
         cursor.advance::<u32>();
-        let is32_byte_len = 8192 * u8::RAW_BYTE_LEN;
+        let is32_byte_len = 8192;
         cursor.advance_by(is32_byte_len);
         let num_groups: u32 = cursor.read()?;
         let groups_byte_len = num_groups as usize * SequentialMapGroup::RAW_BYTE_LEN;
@@ -1589,10 +1590,10 @@ pub struct VariationSelector {
     pub var_selector: BigEndian<Uint24>,
     /// Offset from the start of the format 14 subtable to Default UVS
     /// Table. May be 0.
-    pub default_uvs_offset: BigEndian<Offset32>,
+    pub default_uvs_offset: BigEndian<Nullable<Offset32>>,
     /// Offset from the start of the format 14 subtable to Non-Default
     /// UVS Table. May be 0.
-    pub non_default_uvs_offset: BigEndian<Offset32>,
+    pub non_default_uvs_offset: BigEndian<Nullable<Offset32>>,
 }
 
 impl VariationSelector {
@@ -1603,15 +1604,28 @@ impl VariationSelector {
 
     /// Offset from the start of the format 14 subtable to Default UVS
     /// Table. May be 0.
-    pub fn default_uvs_offset(&self) -> Offset32 {
+    pub fn default_uvs_offset(&self) -> Nullable<Offset32> {
         self.default_uvs_offset.get()
     }
 
+    /// Attempt to resolve [`default_uvs_offset`][Self::default_uvs_offset].
+    pub fn default_uvs<'a>(&self, data: FontData<'a>) -> Option<Result<DefaultUvs<'a>, ReadError>> {
+        self.default_uvs_offset().resolve(data)
+    }
+
     /// Offset from the start of the format 14 subtable to Non-Default
     /// UVS Table. May be 0.
-    pub fn non_default_uvs_offset(&self) -> Offset32 {
+    pub fn non_default_uvs_offset(&self) -> Nullable<Offset32> {
         self.non_default_uvs_offset.get()
     }
+
+    /// Attempt to resolve [`non_default_uvs_offset`][Self::non_default_uvs_offset].
+    pub fn non_default_uvs<'a>(
+        &self,
+        data: FontData<'a>,
+    ) -> Option<Result<NonDefaultUvs<'a>, ReadError>> {
+        self.non_default_uvs_offset().resolve(data)
+    }
 }
 
 impl FixedSized for VariationSelector {
@@ -1628,11 +1642,11 @@ impl<'a> SomeRecord<'a> for VariationSelector {
                 0usize => Some(Field::new("var_selector", self.var_selector())),
                 1usize => Some(Field::new(
                     "default_uvs_offset",
-                    FieldType::unknown_offset(self.default_uvs_offset()),
+                    FieldType::offset(self.default_uvs_offset(), self.default_uvs(_data)),
                 )),
                 2usize => Some(Field::new(
                     "non_default_uvs_offset",
-                    FieldType::unknown_offset(self.non_default_uvs_offset()),
+                    FieldType::offset(self.non_default_uvs_offset(), self.non_default_uvs(_data)),
                 )),
                 _ => None,
             }),
@@ -1718,6 +1732,82 @@ impl<'a> std::fmt::Debug for DefaultUvs<'a> {
     }
 }
 
+/// [Non-Default UVS table](https://docs.microsoft.com/en-us/typography/opentype/spec/cmap#non-default-uvs-table)
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct NonDefaultUvsMarker {
+    uvs_mapping_byte_len: usize,
+}
+
+impl NonDefaultUvsMarker {
+    fn num_uvs_mappings_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn uvs_mapping_byte_range(&self) -> Range<usize> {
+        let start = self.num_uvs_mappings_byte_range().end;
+        start..start + self.uvs_mapping_byte_len
+    }
+}
+
+impl TableInfo for NonDefaultUvsMarker {
+    #[allow(unused_parens)]
+    fn parse(data: FontData) -> Result<TableRef<Self>, ReadError> {
+        let mut cursor = data.cursor();
+        let num_uvs_mappings: u32 = cursor.read()?;
+        let uvs_mapping_byte_len = num_uvs_mappings as usize * UVSMapping::RAW_BYTE_LEN;
+        cursor.advance_by(uvs_mapping_byte_len);
+        cursor.finish(NonDefaultUvsMarker {
+            uvs_mapping_byte_len,
+        })
+    }
+}
+
+/// [Non-Default UVS table](https://docs.microsoft.com/en-us/typography/opentype/spec/cmap#non-default-uvs-table)
+pub type NonDefaultUvs<'a> = TableRef<'a, NonDefaultUvsMarker>;
+
+impl<'a> NonDefaultUvs<'a> {
+    /// Number of UVS Mappings that follow
+    pub fn num_uvs_mappings(&self) -> u32 {
+        let range = self.shape.num_uvs_mappings_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Array of UVSMapping records.
+    pub fn uvs_mapping(&self) -> &'a [UVSMapping] {
+        let range = self.shape.uvs_mapping_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for NonDefaultUvs<'a> {
+    fn type_name(&self) -> &str {
+        "NonDefaultUvs"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(Field::new("num_uvs_mappings", self.num_uvs_mappings())),
+            1usize => Some(Field::new(
+                "uvs_mapping",
+                traversal::FieldType::array_of_records(
+                    stringify!(UVSMapping),
+                    self.uvs_mapping(),
+                    self.offset_data(),
+                ),
+            )),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for NonDefaultUvs<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
 /// Part of [Cmap14]
 #[derive(Clone, Debug)]
 #[repr(C)]
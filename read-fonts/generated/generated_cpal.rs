@@ -0,0 +1,615 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+#[allow(unused_imports)]
+use crate::codegen_prelude::*;
+
+/// [CPAL (Color Palette Table)](https://learn.microsoft.com/en-us/typography/opentype/spec/cpal#palette-table-header) table
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct CpalMarker {
+    color_record_indices_byte_len: usize,
+    palette_types_array_offset_byte_start: Option<usize>,
+    palette_labels_array_offset_byte_start: Option<usize>,
+    palette_entry_labels_array_offset_byte_start: Option<usize>,
+}
+
+impl CpalMarker {
+    fn version_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn num_palette_entries_byte_range(&self) -> Range<usize> {
+        let start = self.version_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn num_palettes_byte_range(&self) -> Range<usize> {
+        let start = self.num_palette_entries_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn num_color_records_byte_range(&self) -> Range<usize> {
+        let start = self.num_palettes_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn color_records_array_offset_byte_range(&self) -> Range<usize> {
+        let start = self.num_color_records_byte_range().end;
+        start..start + Offset32::RAW_BYTE_LEN
+    }
+    fn color_record_indices_byte_range(&self) -> Range<usize> {
+        let start = self.color_records_array_offset_byte_range().end;
+        start..start + self.color_record_indices_byte_len
+    }
+    fn palette_types_array_offset_byte_range(&self) -> Option<Range<usize>> {
+        let start = self.palette_types_array_offset_byte_start?;
+        Some(start..start + Offset32::RAW_BYTE_LEN)
+    }
+    fn palette_labels_array_offset_byte_range(&self) -> Option<Range<usize>> {
+        let start = self.palette_labels_array_offset_byte_start?;
+        Some(start..start + Offset32::RAW_BYTE_LEN)
+    }
+    fn palette_entry_labels_array_offset_byte_range(&self) -> Option<Range<usize>> {
+        let start = self.palette_entry_labels_array_offset_byte_start?;
+        Some(start..start + Offset32::RAW_BYTE_LEN)
+    }
+}
+
+impl TableInfo for CpalMarker {
+    #[allow(unused_parens)]
+    fn parse(data: FontData) -> Result<TableRef<Self>, ReadError> {
+        let mut cursor = data.cursor();
+        let version: u16 = cursor.read()?;
+        cursor.advance::<u16>();
+        let num_palettes: u16 = cursor.read()?;
+        cursor.advance::<u16>();
+        cursor.advance::<Offset32>();
+        let color_record_indices_byte_len = num_palettes as usize * u16::RAW_BYTE_LEN;
+        cursor.advance_by(color_record_indices_byte_len);
+        let palette_types_array_offset_byte_start = version
+            .compatible(1)
+            .then(|| cursor.position())
+            .transpose()?;
+        version.compatible(1).then(|| cursor.advance::<Offset32>());
+        let palette_labels_array_offset_byte_start = version
+            .compatible(1)
+            .then(|| cursor.position())
+            .transpose()?;
+        version.compatible(1).then(|| cursor.advance::<Offset32>());
+        let palette_entry_labels_array_offset_byte_start = version
+            .compatible(1)
+            .then(|| cursor.position())
+            .transpose()?;
+        version.compatible(1).then(|| cursor.advance::<Offset32>());
+        cursor.finish(CpalMarker {
+            color_record_indices_byte_len,
+            palette_types_array_offset_byte_start,
+            palette_labels_array_offset_byte_start,
+            palette_entry_labels_array_offset_byte_start,
+        })
+    }
+}
+
+/// [CPAL (Color Palette Table)](https://learn.microsoft.com/en-us/typography/opentype/spec/cpal#palette-table-header) table
+pub type Cpal<'a> = TableRef<'a, CpalMarker>;
+
+impl<'a> Cpal<'a> {
+    /// Table version number (=0 or 1).
+    pub fn version(&self) -> u16 {
+        let range = self.shape.version_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Number of palette entries in each palette.
+    pub fn num_palette_entries(&self) -> u16 {
+        let range = self.shape.num_palette_entries_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Number of palettes in the table.
+    pub fn num_palettes(&self) -> u16 {
+        let range = self.shape.num_palettes_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Total number of color records, combined for all palettes.
+    pub fn num_color_records(&self) -> u16 {
+        let range = self.shape.num_color_records_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Offset from the beginning of CPAL table to the first
+    /// ColorRecord.
+    pub fn color_records_array_offset(&self) -> Offset32 {
+        let range = self.shape.color_records_array_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`color_records_array_offset`][Self::color_records_array_offset].
+    pub fn color_records_array(&self) -> Result<ColorRecordArray<'a>, ReadError> {
+        let data = self.data;
+        let args = self.num_color_records();
+        self.color_records_array_offset()
+            .resolve_with_args(data, &args)
+    }
+
+    /// Index of each palette’s first color record in the combined
+    /// color record array.
+    pub fn color_record_indices(&self) -> &'a [BigEndian<u16>] {
+        let range = self.shape.color_record_indices_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+
+    /// Offset from the beginning of CPAL table to the [Palette Types Array][].
+    ///
+    /// This is an array of 32-bit flag fields that describe properties of each palette.
+    ///
+    /// [Palette Types Array]: https://learn.microsoft.com/en-us/typography/opentype/spec/cpal#palette-type-array
+    pub fn palette_types_array_offset(&self) -> Option<Nullable<Offset32>> {
+        let range = self.shape.palette_types_array_offset_byte_range()?;
+        Some(self.data.read_at(range.start).unwrap())
+    }
+
+    /// Attempt to resolve [`palette_types_array_offset`][Self::palette_types_array_offset].
+    pub fn palette_types_array(&self) -> Option<Result<PaletteTypeArray<'a>, ReadError>> {
+        let data = self.data;
+        let args = self.num_palettes();
+        self.palette_types_array_offset()?
+            .resolve_with_args(data, &args)
+    }
+
+    /// Offset from the beginning of CPAL table to the [Palette Labels Array][].
+    ///
+    /// This is an array of 'name' table IDs (typically in the font-specific name
+    /// ID range) that specify user interface strings associated with  each palette.
+    /// Use 0xFFFF if no name ID is provided for a palette.
+    ///
+    /// [Palette Labels Array]: https://learn.microsoft.com/en-us/typography/opentype/spec/cpal#palette-labels-array
+    pub fn palette_labels_array_offset(&self) -> Option<Nullable<Offset32>> {
+        let range = self.shape.palette_labels_array_offset_byte_range()?;
+        Some(self.data.read_at(range.start).unwrap())
+    }
+
+    /// Attempt to resolve [`palette_labels_array_offset`][Self::palette_labels_array_offset].
+    pub fn palette_labels_array(&self) -> Option<Result<PaletteLabelArray<'a>, ReadError>> {
+        let data = self.data;
+        let args = self.num_palettes();
+        self.palette_labels_array_offset()?
+            .resolve_with_args(data, &args)
+    }
+
+    /// Offset from the beginning of CPAL table to the [Palette Entry Labels Array][].
+    ///
+    /// This is an array of 'name' table IDs (typically in the font-specific name
+    /// ID range) that specify user interface strings associated with  each palette
+    /// entry, e.g. “Outline”, “Fill”. This set of palette entry labels applies
+    /// to all palettes in the font. Use  0xFFFF if no name ID is provided for a
+    /// palette entry.
+    ///
+    /// [Palette Entry Labels Array]: https://learn.microsoft.com/en-us/typography/opentype/spec/cpal#palette-entry-label-array
+    pub fn palette_entry_labels_array_offset(&self) -> Option<Nullable<Offset32>> {
+        let range = self.shape.palette_entry_labels_array_offset_byte_range()?;
+        Some(self.data.read_at(range.start).unwrap())
+    }
+
+    /// Attempt to resolve [`palette_entry_labels_array_offset`][Self::palette_entry_labels_array_offset].
+    pub fn palette_entry_labels_array(
+        &self,
+    ) -> Option<Result<PaletteEntryLabelArray<'a>, ReadError>> {
+        let data = self.data;
+        let args = self.num_palette_entries();
+        self.palette_entry_labels_array_offset()?
+            .resolve_with_args(data, &args)
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for Cpal<'a> {
+    fn type_name(&self) -> &str {
+        "Cpal"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        let version = self.version();
+        match idx {
+            0usize => Some(Field::new("version", self.version())),
+            1usize => Some(Field::new(
+                "num_palette_entries",
+                self.num_palette_entries(),
+            )),
+            2usize => Some(Field::new("num_palettes", self.num_palettes())),
+            3usize => Some(Field::new("num_color_records", self.num_color_records())),
+            4usize => Some(Field::new(
+                "color_records_array_offset",
+                FieldType::offset(
+                    self.color_records_array_offset(),
+                    self.color_records_array(),
+                ),
+            )),
+            5usize => Some(Field::new(
+                "color_record_indices",
+                self.color_record_indices(),
+            )),
+            6usize if version.compatible(1) => Some(Field::new(
+                "palette_types_array_offset",
+                FieldType::offset(
+                    self.palette_types_array_offset().unwrap(),
+                    self.palette_types_array().unwrap(),
+                ),
+            )),
+            7usize if version.compatible(1) => Some(Field::new(
+                "palette_labels_array_offset",
+                FieldType::offset(
+                    self.palette_labels_array_offset().unwrap(),
+                    self.palette_labels_array().unwrap(),
+                ),
+            )),
+            8usize if version.compatible(1) => Some(Field::new(
+                "palette_entry_labels_array_offset",
+                FieldType::offset(
+                    self.palette_entry_labels_array_offset().unwrap(),
+                    self.palette_entry_labels_array().unwrap(),
+                ),
+            )),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for Cpal<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// Shim table to handle the array of [ColorRecord] entries.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct ColorRecordArrayMarker {
+    color_records_byte_len: usize,
+}
+
+impl ColorRecordArrayMarker {
+    fn color_records_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + self.color_records_byte_len
+    }
+}
+
+impl ReadArgs for ColorRecordArrayMarker {
+    type Args = u16;
+}
+
+impl TableInfoWithArgs for ColorRecordArrayMarker {
+    #[allow(unused_parens)]
+    fn parse_with_args<'a>(
+        data: FontData<'a>,
+        args: &u16,
+    ) -> Result<TableRef<'a, Self>, ReadError> {
+        let num_color_records = *args;
+        let mut cursor = data.cursor();
+        let color_records_byte_len = num_color_records as usize * ColorRecord::RAW_BYTE_LEN;
+        cursor.advance_by(color_records_byte_len);
+        cursor.finish(ColorRecordArrayMarker {
+            color_records_byte_len,
+        })
+    }
+}
+
+/// Shim table to handle the array of [ColorRecord] entries.
+pub type ColorRecordArray<'a> = TableRef<'a, ColorRecordArrayMarker>;
+
+impl<'a> ColorRecordArray<'a> {
+    /// Color records for all palettes.
+    pub fn color_records(&self) -> &'a [ColorRecord] {
+        let range = self.shape.color_records_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for ColorRecordArray<'a> {
+    fn type_name(&self) -> &str {
+        "ColorRecordArray"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(Field::new(
+                "color_records",
+                traversal::FieldType::array_of_records(
+                    stringify!(ColorRecord),
+                    self.color_records(),
+                    self.offset_data(),
+                ),
+            )),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for ColorRecordArray<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// Shim table to handle the array of palette types.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct PaletteTypeArrayMarker {
+    palette_types_byte_len: usize,
+}
+
+impl PaletteTypeArrayMarker {
+    fn palette_types_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + self.palette_types_byte_len
+    }
+}
+
+impl ReadArgs for PaletteTypeArrayMarker {
+    type Args = u16;
+}
+
+impl TableInfoWithArgs for PaletteTypeArrayMarker {
+    #[allow(unused_parens)]
+    fn parse_with_args<'a>(
+        data: FontData<'a>,
+        args: &u16,
+    ) -> Result<TableRef<'a, Self>, ReadError> {
+        let num_palettes = *args;
+        let mut cursor = data.cursor();
+        let palette_types_byte_len = num_palettes as usize * PaletteType::RAW_BYTE_LEN;
+        cursor.advance_by(palette_types_byte_len);
+        cursor.finish(PaletteTypeArrayMarker {
+            palette_types_byte_len,
+        })
+    }
+}
+
+/// Shim table to handle the array of palette types.
+pub type PaletteTypeArray<'a> = TableRef<'a, PaletteTypeArrayMarker>;
+
+impl<'a> PaletteTypeArray<'a> {
+    /// Array of 32-bit flag fields that describe properties of each palette.
+    pub fn palette_types(&self) -> &'a [BigEndian<PaletteType>] {
+        let range = self.shape.palette_types_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for PaletteTypeArray<'a> {
+    fn type_name(&self) -> &str {
+        "PaletteTypeArray"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(Field::new("palette_types", self.palette_types())),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for PaletteTypeArray<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// Shim table to handle the array of palette labels.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct PaletteLabelArrayMarker {
+    palette_labels_byte_len: usize,
+}
+
+impl PaletteLabelArrayMarker {
+    fn palette_labels_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + self.palette_labels_byte_len
+    }
+}
+
+impl ReadArgs for PaletteLabelArrayMarker {
+    type Args = u16;
+}
+
+impl TableInfoWithArgs for PaletteLabelArrayMarker {
+    #[allow(unused_parens)]
+    fn parse_with_args<'a>(
+        data: FontData<'a>,
+        args: &u16,
+    ) -> Result<TableRef<'a, Self>, ReadError> {
+        let num_palettes = *args;
+        let mut cursor = data.cursor();
+        let palette_labels_byte_len = num_palettes as usize * u16::RAW_BYTE_LEN;
+        cursor.advance_by(palette_labels_byte_len);
+        cursor.finish(PaletteLabelArrayMarker {
+            palette_labels_byte_len,
+        })
+    }
+}
+
+/// Shim table to handle the array of palette labels.
+pub type PaletteLabelArray<'a> = TableRef<'a, PaletteLabelArrayMarker>;
+
+impl<'a> PaletteLabelArray<'a> {
+    /// Array of 'name' table IDs that specify user interface strings
+    /// associated with each palette. Use 0xFFFF if no name ID is provided
+    /// for a palette.
+    pub fn palette_labels(&self) -> &'a [BigEndian<u16>] {
+        let range = self.shape.palette_labels_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for PaletteLabelArray<'a> {
+    fn type_name(&self) -> &str {
+        "PaletteLabelArray"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(Field::new("palette_labels", self.palette_labels())),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for PaletteLabelArray<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// Shim table to handle the array of palette entry labels.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct PaletteEntryLabelArrayMarker {
+    palette_entry_labels_byte_len: usize,
+}
+
+impl PaletteEntryLabelArrayMarker {
+    fn palette_entry_labels_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + self.palette_entry_labels_byte_len
+    }
+}
+
+impl ReadArgs for PaletteEntryLabelArrayMarker {
+    type Args = u16;
+}
+
+impl TableInfoWithArgs for PaletteEntryLabelArrayMarker {
+    #[allow(unused_parens)]
+    fn parse_with_args<'a>(
+        data: FontData<'a>,
+        args: &u16,
+    ) -> Result<TableRef<'a, Self>, ReadError> {
+        let num_palette_entries = *args;
+        let mut cursor = data.cursor();
+        let palette_entry_labels_byte_len = num_palette_entries as usize * u16::RAW_BYTE_LEN;
+        cursor.advance_by(palette_entry_labels_byte_len);
+        cursor.finish(PaletteEntryLabelArrayMarker {
+            palette_entry_labels_byte_len,
+        })
+    }
+}
+
+/// Shim table to handle the array of palette entry labels.
+pub type PaletteEntryLabelArray<'a> = TableRef<'a, PaletteEntryLabelArrayMarker>;
+
+impl<'a> PaletteEntryLabelArray<'a> {
+    /// Array of 'name' table IDs that specify user interface strings
+    /// associated with each palette entry. Use 0xFFFF if no name ID is
+    /// provided for a palette entry.
+    pub fn palette_entry_labels(&self) -> &'a [BigEndian<u16>] {
+        let range = self.shape.palette_entry_labels_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for PaletteEntryLabelArray<'a> {
+    fn type_name(&self) -> &str {
+        "PaletteEntryLabelArray"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(Field::new(
+                "palette_entry_labels",
+                self.palette_entry_labels(),
+            )),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for PaletteEntryLabelArray<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// [CPAL (Color Record)](https://learn.microsoft.com/en-us/typography/opentype/spec/cpal#palette-entries-and-color-records) record
+#[derive(Clone, Debug)]
+#[repr(C)]
+#[repr(packed)]
+pub struct ColorRecord {
+    /// Blue value (B0).
+    pub blue: BigEndian<u8>,
+    /// Green value (B1).
+    pub green: BigEndian<u8>,
+    /// Red value (B2).
+    pub red: BigEndian<u8>,
+    /// Alpha value (B3).
+    pub alpha: BigEndian<u8>,
+}
+
+impl ColorRecord {
+    /// Blue value (B0).
+    pub fn blue(&self) -> u8 {
+        self.blue.get()
+    }
+
+    /// Green value (B1).
+    pub fn green(&self) -> u8 {
+        self.green.get()
+    }
+
+    /// Red value (B2).
+    pub fn red(&self) -> u8 {
+        self.red.get()
+    }
+
+    /// Alpha value (B3).
+    pub fn alpha(&self) -> u8 {
+        self.alpha.get()
+    }
+}
+
+impl FixedSized for ColorRecord {
+    const RAW_BYTE_LEN: usize =
+        u8::RAW_BYTE_LEN + u8::RAW_BYTE_LEN + u8::RAW_BYTE_LEN + u8::RAW_BYTE_LEN;
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeRecord<'a> for ColorRecord {
+    fn traverse(self, data: FontData<'a>) -> RecordResolver<'a> {
+        RecordResolver {
+            name: "ColorRecord",
+            get_field: Box::new(move |idx, _data| match idx {
+                0usize => Some(Field::new("blue", self.blue())),
+                1usize => Some(Field::new("green", self.green())),
+                2usize => Some(Field::new("red", self.red())),
+                3usize => Some(Field::new("alpha", self.alpha())),
+                _ => None,
+            }),
+            data,
+        }
+    }
+}
+
+bitflags::bitflags! { # [doc = " [Palette Type](https://learn.microsoft.com/en-us/typography/opentype/spec/cpal#palette-type-array) flags"] pub struct PaletteType : u32 { # [doc = " Bit 0: palette is appropriate to use when displaying the font on a light background such as white."] const USABLE_WITH_LIGHT_BACKGROUND = 0x0001 ; # [doc = " Bit 1: palette is appropriate to use when displaying the font on a dark background such as black."] const USABLE_WITH_DARK_BACKGROUND = 0x0002 ; } }
+
+impl font_types::Scalar for PaletteType {
+    type Raw = <u32 as font_types::Scalar>::Raw;
+    fn to_raw(self) -> Self::Raw {
+        self.bits().to_raw()
+    }
+    fn from_raw(raw: Self::Raw) -> Self {
+        let t = <u32>::from_raw(raw);
+        Self::from_bits_truncate(t)
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> From<PaletteType> for FieldType<'a> {
+    fn from(src: PaletteType) -> FieldType<'a> {
+        src.bits().into()
+    }
+}
@@ -0,0 +1,159 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+#[allow(unused_imports)]
+use crate::codegen_prelude::*;
+
+/// The [gasp (Grid-fitting and Scan-conversion Procedure)](https://learn.microsoft.com/en-us/typography/opentype/spec/gasp) table
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct GaspMarker {
+    gasp_ranges_byte_len: usize,
+}
+
+impl GaspMarker {
+    fn version_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn num_ranges_byte_range(&self) -> Range<usize> {
+        let start = self.version_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn gasp_ranges_byte_range(&self) -> Range<usize> {
+        let start = self.num_ranges_byte_range().end;
+        start..start + self.gasp_ranges_byte_len
+    }
+}
+
+impl TableInfo for GaspMarker {
+    #[allow(unused_parens)]
+    fn parse(data: FontData) -> Result<TableRef<Self>, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<u16>();
+        let num_ranges: u16 = cursor.read()?;
+        let gasp_ranges_byte_len = num_ranges as usize * GaspRange::RAW_BYTE_LEN;
+        cursor.advance_by(gasp_ranges_byte_len);
+        cursor.finish(GaspMarker {
+            gasp_ranges_byte_len,
+        })
+    }
+}
+
+/// The [gasp (Grid-fitting and Scan-conversion Procedure)](https://learn.microsoft.com/en-us/typography/opentype/spec/gasp) table
+pub type Gasp<'a> = TableRef<'a, GaspMarker>;
+
+impl<'a> Gasp<'a> {
+    /// Version number (set to 1)
+    pub fn version(&self) -> u16 {
+        let range = self.shape.version_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Number of records to follow
+    pub fn num_ranges(&self) -> u16 {
+        let range = self.shape.num_ranges_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Sorted by ppem
+    pub fn gasp_ranges(&self) -> &'a [GaspRange] {
+        let range = self.shape.gasp_ranges_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for Gasp<'a> {
+    fn type_name(&self) -> &str {
+        "Gasp"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(Field::new("version", self.version())),
+            1usize => Some(Field::new("num_ranges", self.num_ranges())),
+            2usize => Some(Field::new(
+                "gasp_ranges",
+                traversal::FieldType::array_of_records(
+                    stringify!(GaspRange),
+                    self.gasp_ranges(),
+                    self.offset_data(),
+                ),
+            )),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for Gasp<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// A [gasp range](https://learn.microsoft.com/en-us/typography/opentype/spec/gasp#gasp-table-formats) record
+#[derive(Clone, Debug)]
+#[repr(C)]
+#[repr(packed)]
+pub struct GaspRange {
+    /// Upper limit of range, in PPEM
+    pub range_max_ppem: BigEndian<u16>,
+    /// Flags describing desired rasterizer behavior.
+    pub range_gasp_behavior: BigEndian<GaspRangeBehavior>,
+}
+
+impl GaspRange {
+    /// Upper limit of range, in PPEM
+    pub fn range_max_ppem(&self) -> u16 {
+        self.range_max_ppem.get()
+    }
+
+    /// Flags describing desired rasterizer behavior.
+    pub fn range_gasp_behavior(&self) -> GaspRangeBehavior {
+        self.range_gasp_behavior.get()
+    }
+}
+
+impl FixedSized for GaspRange {
+    const RAW_BYTE_LEN: usize = u16::RAW_BYTE_LEN + GaspRangeBehavior::RAW_BYTE_LEN;
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeRecord<'a> for GaspRange {
+    fn traverse(self, data: FontData<'a>) -> RecordResolver<'a> {
+        RecordResolver {
+            name: "GaspRange",
+            get_field: Box::new(move |idx, _data| match idx {
+                0usize => Some(Field::new("range_max_ppem", self.range_max_ppem())),
+                1usize => Some(Field::new(
+                    "range_gasp_behavior",
+                    self.range_gasp_behavior(),
+                )),
+                _ => None,
+            }),
+            data,
+        }
+    }
+}
+
+bitflags::bitflags! { # [doc = " Flags describing the desired rasterizer behavior for a [GaspRange]."] pub struct GaspRangeBehavior : u16 { # [doc = " Use gridfitting"] const GASP_GRIDFIT = 0x0001 ; # [doc = " Use grayscale rendering"] const GASP_DOGRAY = 0x0002 ; # [doc = " Use gridfitting with ClearType symmetric smoothing. Only"] # [doc = " supported in version 1 'gasp'"] const GASP_SYMMETRIC_GRIDFIT = 0x0004 ; # [doc = " Use smoothing along multiple axes with ClearType®. Only"] # [doc = " supported in version 1 'gasp'"] const GASP_SYMMETRIC_SMOOTHING = 0x0008 ; } }
+
+impl font_types::Scalar for GaspRangeBehavior {
+    type Raw = <u16 as font_types::Scalar>::Raw;
+    fn to_raw(self) -> Self::Raw {
+        self.bits().to_raw()
+    }
+    fn from_raw(raw: Self::Raw) -> Self {
+        let t = <u16>::from_raw(raw);
+        Self::from_bits_truncate(t)
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> From<GaspRangeBehavior> for FieldType<'a> {
+    fn from(src: GaspRangeBehavior) -> FieldType<'a> {
+        src.bits().into()
+    }
+}
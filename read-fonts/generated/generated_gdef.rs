@@ -156,7 +156,7 @@ impl<'a> Gdef<'a> {
     }
 
     /// Attempt to resolve [`item_var_store_offset`][Self::item_var_store_offset].
-    pub fn item_var_store(&self) -> Option<Result<ClassDef<'a>, ReadError>> {
+    pub fn item_var_store(&self) -> Option<Result<ItemVariationStore<'a>, ReadError>> {
         let data = self.data;
         self.item_var_store_offset()?.resolve(data)
     }
@@ -642,6 +642,7 @@ impl<'a> std::fmt::Debug for LigGlyph<'a> {
 }
 
 /// [Caret Value Tables](https://docs.microsoft.com/en-us/typography/opentype/spec/gdef#caret-value-tables)
+#[derive(Clone)]
 pub enum CaretValue<'a> {
     Format1(CaretValueFormat1<'a>),
     Format2(CaretValueFormat2<'a>),
@@ -106,7 +106,7 @@ impl TableInfo for SimpleGlyphMarker {
         let end_pts_of_contours_byte_len = number_of_contours.max(0) as usize * u16::RAW_BYTE_LEN;
         cursor.advance_by(end_pts_of_contours_byte_len);
         let instruction_length: u16 = cursor.read()?;
-        let instructions_byte_len = instruction_length as usize * u8::RAW_BYTE_LEN;
+        let instructions_byte_len = instruction_length as usize;
         cursor.advance_by(instructions_byte_len);
         let glyph_data_byte_len = cursor.remaining_bytes();
         cursor.advance_by(glyph_data_byte_len);
@@ -170,7 +170,7 @@ impl<'a> SimpleGlyph<'a> {
     }
 
     /// Array of instruction byte code for the glyph.
-    pub fn instructions(&self) -> &'a [BigEndian<u8>] {
+    pub fn instructions(&self) -> &'a [u8] {
         let range = self.shape.instructions_byte_range();
         self.data.read_array(range).unwrap()
     }
@@ -373,6 +373,7 @@ impl<'a> From<CompositeGlyphFlags> for FieldType<'a> {
     }
 }
 
+#[derive(Clone)]
 pub enum Glyph<'a> {
     Simple(SimpleGlyph<'a>),
     Composite(CompositeGlyph<'a>),
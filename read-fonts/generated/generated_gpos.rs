@@ -235,6 +235,7 @@ impl<'a> From<ValueFormat> for FieldType<'a> {
 
 /// [Anchor Tables](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#anchor-tables)
 /// position one glyph with respect to another.
+#[derive(Clone)]
 pub enum AnchorTable<'a> {
     Format1(AnchorFormat1<'a>),
     Format2(AnchorFormat2<'a>),
@@ -704,6 +705,7 @@ impl<'a> SomeRecord<'a> for MarkRecord {
 }
 
 /// [Lookup Type 1](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#lookup-type-1-single-adjustment-positioning-subtable): Single Adjustment Positioning Subtable
+#[derive(Clone)]
 pub enum SinglePos<'a> {
     Format1(SinglePosFormat1<'a>),
     Format2(SinglePosFormat2<'a>),
@@ -986,6 +988,7 @@ impl<'a> std::fmt::Debug for SinglePosFormat2<'a> {
 }
 
 /// [Lookup Type 1](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#lookup-type-1-single-adjustment-positioning-subtable): Single Adjustment Positioning Subtable
+#[derive(Clone)]
 pub enum PairPos<'a> {
     Format1(PairPosFormat1<'a>),
     Format2(PairPosFormat2<'a>),
@@ -1286,8 +1289,6 @@ impl<'a> std::fmt::Debug for PairSet<'a> {
 
 /// Part of [PairSet]
 #[derive(Clone, Debug)]
-#[repr(C)]
-#[repr(packed)]
 pub struct PairValueRecord {
     /// Glyph ID of second glyph in the pair (first glyph is listed in
     /// the Coverage table).
@@ -1634,8 +1635,6 @@ impl<'a> SomeRecord<'a> for Class1Record<'a> {
 
 /// Part of [PairPosFormat2]
 #[derive(Clone, Debug)]
-#[repr(C)]
-#[repr(packed)]
 pub struct Class2Record {
     /// Positioning for first glyph — empty if valueFormat1 = 0.
     pub value_record1: ValueRecord,
@@ -2987,9 +2986,7 @@ impl<T> ExtensionPosFormat1Marker<T> {
 
 impl<T> Clone for ExtensionPosFormat1Marker<T> {
     fn clone(&self) -> Self {
-        Self {
-            phantom: std::marker::PhantomData,
-        }
+        *self
     }
 }
 
@@ -211,6 +211,7 @@ impl<'a> SomeTable<'a> for SubstitutionLookup<'a> {
 }
 
 /// LookupType 1: [Single Substitution](https://learn.microsoft.com/en-us/typography/opentype/spec/gsub#lookuptype-1-single-substitution-subtable) Subtable
+#[derive(Clone)]
 pub enum SingleSubst<'a> {
     Format1(SingleSubstFormat1<'a>),
     Format2(SingleSubstFormat2<'a>),
@@ -1188,9 +1189,7 @@ impl<T> ExtensionSubstFormat1Marker<T> {
 
 impl<T> Clone for ExtensionSubstFormat1Marker<T> {
     fn clone(&self) -> Self {
-        Self {
-            phantom: std::marker::PhantomData,
-        }
+        *self
     }
 }
 
@@ -0,0 +1,328 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+#[allow(unused_imports)]
+use crate::codegen_prelude::*;
+
+/// The ['gvar' header](https://learn.microsoft.com/en-us/typography/opentype/spec/gvar#gvar-header)
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct GvarMarker {
+    glyph_variation_data_offsets_byte_len: usize,
+}
+
+impl GvarMarker {
+    fn version_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + MajorMinor::RAW_BYTE_LEN
+    }
+    fn axis_count_byte_range(&self) -> Range<usize> {
+        let start = self.version_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn shared_tuple_count_byte_range(&self) -> Range<usize> {
+        let start = self.axis_count_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn shared_tuples_offset_byte_range(&self) -> Range<usize> {
+        let start = self.shared_tuple_count_byte_range().end;
+        start..start + Offset32::RAW_BYTE_LEN
+    }
+    fn glyph_count_byte_range(&self) -> Range<usize> {
+        let start = self.shared_tuples_offset_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn flags_byte_range(&self) -> Range<usize> {
+        let start = self.glyph_count_byte_range().end;
+        start..start + GvarFlags::RAW_BYTE_LEN
+    }
+    fn glyph_variation_data_array_offset_byte_range(&self) -> Range<usize> {
+        let start = self.flags_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn glyph_variation_data_offsets_byte_range(&self) -> Range<usize> {
+        let start = self.glyph_variation_data_array_offset_byte_range().end;
+        start..start + self.glyph_variation_data_offsets_byte_len
+    }
+}
+
+impl TableInfo for GvarMarker {
+    #[allow(unused_parens)]
+    fn parse(data: FontData) -> Result<TableRef<Self>, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<MajorMinor>();
+        cursor.advance::<u16>();
+        cursor.advance::<u16>();
+        cursor.advance::<Offset32>();
+        let glyph_count: u16 = cursor.read()?;
+        let flags: GvarFlags = cursor.read()?;
+        cursor.advance::<u32>();
+        let glyph_variation_data_offsets_byte_len = offsets_byte_len(glyph_count, flags);
+        cursor.advance_by(glyph_variation_data_offsets_byte_len);
+        cursor.finish(GvarMarker {
+            glyph_variation_data_offsets_byte_len,
+        })
+    }
+}
+
+/// The ['gvar' header](https://learn.microsoft.com/en-us/typography/opentype/spec/gvar#gvar-header)
+pub type Gvar<'a> = TableRef<'a, GvarMarker>;
+
+impl<'a> Gvar<'a> {
+    /// Major/minor version number of the glyph variations table — set to (1,0).
+    pub fn version(&self) -> MajorMinor {
+        let range = self.shape.version_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// The number of variation axes for this font. This must be the
+    /// same number as axisCount in the 'fvar' table.
+    pub fn axis_count(&self) -> u16 {
+        let range = self.shape.axis_count_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// The number of shared tuple records. Shared tuple records can be
+    /// referenced within glyph variation data tables for multiple
+    /// glyphs, as opposed to other tuple records stored directly
+    /// within a glyph variation data table.
+    pub fn shared_tuple_count(&self) -> u16 {
+        let range = self.shape.shared_tuple_count_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Offset from the start of this table to the shared tuple records.
+    pub fn shared_tuples_offset(&self) -> Offset32 {
+        let range = self.shape.shared_tuples_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`shared_tuples_offset`][Self::shared_tuples_offset].
+    pub fn shared_tuples(&self) -> Result<SharedTuples<'a>, ReadError> {
+        let data = self.data;
+        let args = (self.shared_tuple_count(), self.axis_count());
+        self.shared_tuples_offset().resolve_with_args(data, &args)
+    }
+
+    /// The number of glyphs in this font. This must match the number
+    /// of glyphs stored elsewhere in the font.
+    pub fn glyph_count(&self) -> u16 {
+        let range = self.shape.glyph_count_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Bit-field that gives the format of the offset array that
+    /// follows. If bit 0 is clear, the offsets are uint16; if bit 0 is
+    /// set, the offsets are uint32.
+    pub fn flags(&self) -> GvarFlags {
+        let range = self.shape.flags_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Offset from the start of this table to the array of
+    /// GlyphVariationData tables.
+    pub fn glyph_variation_data_array_offset(&self) -> u32 {
+        let range = self.shape.glyph_variation_data_array_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Offsets from the start of the GlyphVariationData array to each
+    /// GlyphVariationData table; these are either u16 or u32, depending
+    /// on the value of [flags](Self::flags).
+    pub fn glyph_variation_data_offsets(&self) -> &'a [u8] {
+        let range = self.shape.glyph_variation_data_offsets_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for Gvar<'a> {
+    fn type_name(&self) -> &str {
+        "Gvar"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(Field::new("version", self.version())),
+            1usize => Some(Field::new("axis_count", self.axis_count())),
+            2usize => Some(Field::new("shared_tuple_count", self.shared_tuple_count())),
+            3usize => Some(Field::new(
+                "shared_tuples_offset",
+                FieldType::offset(self.shared_tuples_offset(), self.shared_tuples()),
+            )),
+            4usize => Some(Field::new("glyph_count", self.glyph_count())),
+            5usize => Some(Field::new("flags", self.flags())),
+            6usize => Some(Field::new(
+                "glyph_variation_data_array_offset",
+                self.glyph_variation_data_array_offset(),
+            )),
+            7usize => Some(Field::new(
+                "glyph_variation_data_offsets",
+                self.glyph_variation_data_offsets(),
+            )),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for Gvar<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+bitflags::bitflags! { # [doc = " Flags used in the [Gvar] header"] pub struct GvarFlags : u16 { # [doc = " If set, the offsets to GlyphVariationData tables are uint32;"] # [doc = " otherwise they are uint16 values divided by 2."] const LONG_OFFSETS = 1 ; } }
+
+impl font_types::Scalar for GvarFlags {
+    type Raw = <u16 as font_types::Scalar>::Raw;
+    fn to_raw(self) -> Self::Raw {
+        self.bits().to_raw()
+    }
+    fn from_raw(raw: Self::Raw) -> Self {
+        let t = <u16>::from_raw(raw);
+        Self::from_bits_truncate(t)
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> From<GvarFlags> for FieldType<'a> {
+    fn from(src: GvarFlags) -> FieldType<'a> {
+        src.bits().into()
+    }
+}
+
+/// Array of tuple records shared across all glyph variation data tables.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct SharedTuplesMarker {
+    axis_count: u16,
+    tuples_byte_len: usize,
+}
+
+impl SharedTuplesMarker {
+    fn tuples_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + self.tuples_byte_len
+    }
+}
+
+impl ReadArgs for SharedTuplesMarker {
+    type Args = (u16, u16);
+}
+
+impl TableInfoWithArgs for SharedTuplesMarker {
+    #[allow(unused_parens)]
+    fn parse_with_args<'a>(
+        data: FontData<'a>,
+        args: &(u16, u16),
+    ) -> Result<TableRef<'a, Self>, ReadError> {
+        let (shared_tuple_count, axis_count) = *args;
+        let mut cursor = data.cursor();
+        let tuples_byte_len =
+            shared_tuple_count as usize * <Tuple as ComputeSize>::compute_size(&axis_count);
+        cursor.advance_by(tuples_byte_len);
+        cursor.finish(SharedTuplesMarker {
+            axis_count,
+            tuples_byte_len,
+        })
+    }
+}
+
+/// Array of tuple records shared across all glyph variation data tables.
+pub type SharedTuples<'a> = TableRef<'a, SharedTuplesMarker>;
+
+impl<'a> SharedTuples<'a> {
+    /// The shared tuple records.
+    pub fn tuples(&self) -> ComputedArray<'a, Tuple<'a>> {
+        let range = self.shape.tuples_byte_range();
+        self.data.read_with_args(range, &self.axis_count()).unwrap()
+    }
+
+    pub(crate) fn axis_count(&self) -> u16 {
+        self.shape.axis_count
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for SharedTuples<'a> {
+    fn type_name(&self) -> &str {
+        "SharedTuples"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(Field::new(
+                "tuples",
+                traversal::FieldType::computed_array("Tuple", self.tuples(), self.offset_data()),
+            )),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for SharedTuples<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// [Tuple Records](https://learn.microsoft.com/en-us/typography/opentype/spec/otvarcommonformats#tuple-records)
+///
+/// The tuple variation store formats reference regions within the font’s
+/// variation space using tuple records. A tuple record identifies a position
+/// in terms of normalized coordinates, which use F2DOT14 values.
+#[derive(Clone, Debug)]
+pub struct Tuple<'a> {
+    /// Coordinate array specifying a position within the font’s variation space.
+    ///
+    /// The number of elements must match the axisCount specified in the
+    /// 'fvar' table.
+    pub values: &'a [BigEndian<F2Dot14>],
+}
+
+impl<'a> Tuple<'a> {
+    /// Coordinate array specifying a position within the font’s variation space.
+    ///
+    /// The number of elements must match the axisCount specified in the
+    /// 'fvar' table.
+    pub fn values(&self) -> &'a [BigEndian<F2Dot14>] {
+        self.values
+    }
+}
+
+impl ReadArgs for Tuple<'_> {
+    type Args = u16;
+}
+
+impl ComputeSize for Tuple<'_> {
+    fn compute_size(args: &u16) -> usize {
+        let axis_count = *args;
+        axis_count as usize * F2Dot14::RAW_BYTE_LEN
+    }
+}
+
+impl<'a> FontReadWithArgs<'a> for Tuple<'a> {
+    #[allow(unused_parens)]
+    fn read_with_args(data: FontData<'a>, args: &u16) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        let axis_count = *args;
+        Ok(Self {
+            values: cursor.read_array(axis_count as usize)?,
+        })
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeRecord<'a> for Tuple<'a> {
+    fn traverse(self, data: FontData<'a>) -> RecordResolver<'a> {
+        RecordResolver {
+            name: "Tuple",
+            get_field: Box::new(move |idx, _data| match idx {
+                0usize => Some(Field::new("values", self.values())),
+                _ => None,
+            }),
+            data,
+        }
+    }
+}
@@ -0,0 +1,840 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+#[allow(unused_imports)]
+use crate::codegen_prelude::*;
+
+/// The [JSTF](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf) (Justification) table
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct JstfMarker {
+    jstf_script_records_byte_len: usize,
+}
+
+impl JstfMarker {
+    fn version_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + MajorMinor::RAW_BYTE_LEN
+    }
+    fn jstf_script_count_byte_range(&self) -> Range<usize> {
+        let start = self.version_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn jstf_script_records_byte_range(&self) -> Range<usize> {
+        let start = self.jstf_script_count_byte_range().end;
+        start..start + self.jstf_script_records_byte_len
+    }
+}
+
+impl TableInfo for JstfMarker {
+    #[allow(unused_parens)]
+    fn parse(data: FontData) -> Result<TableRef<Self>, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<MajorMinor>();
+        let jstf_script_count: u16 = cursor.read()?;
+        let jstf_script_records_byte_len =
+            jstf_script_count as usize * JstfScriptRecord::RAW_BYTE_LEN;
+        cursor.advance_by(jstf_script_records_byte_len);
+        cursor.finish(JstfMarker {
+            jstf_script_records_byte_len,
+        })
+    }
+}
+
+/// The [JSTF](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf) (Justification) table
+pub type Jstf<'a> = TableRef<'a, JstfMarker>;
+
+impl<'a> Jstf<'a> {
+    /// Major and minor version of the JSTF table, = (1, 0)
+    pub fn version(&self) -> MajorMinor {
+        let range = self.shape.version_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Number of JstfScriptRecords in this table
+    pub fn jstf_script_count(&self) -> u16 {
+        let range = self.shape.jstf_script_count_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Array of JstfScriptRecords, in alphabetical order by
+    /// jstfScriptTag
+    pub fn jstf_script_records(&self) -> &'a [JstfScriptRecord] {
+        let range = self.shape.jstf_script_records_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for Jstf<'a> {
+    fn type_name(&self) -> &str {
+        "Jstf"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(Field::new("version", self.version())),
+            1usize => Some(Field::new("jstf_script_count", self.jstf_script_count())),
+            2usize => Some(Field::new(
+                "jstf_script_records",
+                traversal::FieldType::array_of_records(
+                    stringify!(JstfScriptRecord),
+                    self.jstf_script_records(),
+                    self.offset_data(),
+                ),
+            )),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for Jstf<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// [JstfScriptRecord](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#jstfscriptrecord)
+#[derive(Clone, Debug)]
+#[repr(C)]
+#[repr(packed)]
+pub struct JstfScriptRecord {
+    /// 4-byte JstfScript identification
+    pub jstf_script_tag: BigEndian<Tag>,
+    /// Offset to JstfScript table, from beginning of JSTF Header
+    pub jstf_script_offset: BigEndian<Offset16>,
+}
+
+impl JstfScriptRecord {
+    /// 4-byte JstfScript identification
+    pub fn jstf_script_tag(&self) -> Tag {
+        self.jstf_script_tag.get()
+    }
+
+    /// Offset to JstfScript table, from beginning of JSTF Header
+    pub fn jstf_script_offset(&self) -> Offset16 {
+        self.jstf_script_offset.get()
+    }
+
+    /// Attempt to resolve [`jstf_script_offset`][Self::jstf_script_offset].
+    pub fn jstf_script<'a>(&self, data: FontData<'a>) -> Result<JstfScript<'a>, ReadError> {
+        self.jstf_script_offset().resolve(data)
+    }
+}
+
+impl FixedSized for JstfScriptRecord {
+    const RAW_BYTE_LEN: usize = Tag::RAW_BYTE_LEN + Offset16::RAW_BYTE_LEN;
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeRecord<'a> for JstfScriptRecord {
+    fn traverse(self, data: FontData<'a>) -> RecordResolver<'a> {
+        RecordResolver {
+            name: "JstfScriptRecord",
+            get_field: Box::new(move |idx, _data| match idx {
+                0usize => Some(Field::new("jstf_script_tag", self.jstf_script_tag())),
+                1usize => Some(Field::new(
+                    "jstf_script_offset",
+                    FieldType::offset(self.jstf_script_offset(), self.jstf_script(_data)),
+                )),
+                _ => None,
+            }),
+            data,
+        }
+    }
+}
+
+/// [JstfScript Table](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#jstfscript-table)
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct JstfScriptMarker {
+    jstf_lang_sys_records_byte_len: usize,
+}
+
+impl JstfScriptMarker {
+    fn extender_glyph_offset_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + Offset16::RAW_BYTE_LEN
+    }
+    fn def_jstf_lang_sys_offset_byte_range(&self) -> Range<usize> {
+        let start = self.extender_glyph_offset_byte_range().end;
+        start..start + Offset16::RAW_BYTE_LEN
+    }
+    fn jstf_lang_sys_count_byte_range(&self) -> Range<usize> {
+        let start = self.def_jstf_lang_sys_offset_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn jstf_lang_sys_records_byte_range(&self) -> Range<usize> {
+        let start = self.jstf_lang_sys_count_byte_range().end;
+        start..start + self.jstf_lang_sys_records_byte_len
+    }
+}
+
+impl TableInfo for JstfScriptMarker {
+    #[allow(unused_parens)]
+    fn parse(data: FontData) -> Result<TableRef<Self>, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<Offset16>();
+        cursor.advance::<Offset16>();
+        let jstf_lang_sys_count: u16 = cursor.read()?;
+        let jstf_lang_sys_records_byte_len =
+            jstf_lang_sys_count as usize * JstfLangSysRecord::RAW_BYTE_LEN;
+        cursor.advance_by(jstf_lang_sys_records_byte_len);
+        cursor.finish(JstfScriptMarker {
+            jstf_lang_sys_records_byte_len,
+        })
+    }
+}
+
+/// [JstfScript Table](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#jstfscript-table)
+pub type JstfScript<'a> = TableRef<'a, JstfScriptMarker>;
+
+impl<'a> JstfScript<'a> {
+    /// Offset to ExtenderGlyph table, from beginning of JstfScript
+    /// table (may be NULL)
+    pub fn extender_glyph_offset(&self) -> Nullable<Offset16> {
+        let range = self.shape.extender_glyph_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`extender_glyph_offset`][Self::extender_glyph_offset].
+    pub fn extender_glyph(&self) -> Option<Result<ExtenderGlyph<'a>, ReadError>> {
+        let data = self.data;
+        self.extender_glyph_offset().resolve(data)
+    }
+
+    /// Offset to default JstfLangSys table, from beginning of
+    /// JstfScript table (may be NULL)
+    pub fn def_jstf_lang_sys_offset(&self) -> Nullable<Offset16> {
+        let range = self.shape.def_jstf_lang_sys_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`def_jstf_lang_sys_offset`][Self::def_jstf_lang_sys_offset].
+    pub fn def_jstf_lang_sys(&self) -> Option<Result<JstfLangSys<'a>, ReadError>> {
+        let data = self.data;
+        self.def_jstf_lang_sys_offset().resolve(data)
+    }
+
+    /// Number of JstfLangSysRecords in this table — may be zero (0)
+    pub fn jstf_lang_sys_count(&self) -> u16 {
+        let range = self.shape.jstf_lang_sys_count_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Array of JstfLangSysRecords, in alphabetical order by
+    /// JstfLangSysTag
+    pub fn jstf_lang_sys_records(&self) -> &'a [JstfLangSysRecord] {
+        let range = self.shape.jstf_lang_sys_records_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for JstfScript<'a> {
+    fn type_name(&self) -> &str {
+        "JstfScript"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(Field::new(
+                "extender_glyph_offset",
+                FieldType::offset(self.extender_glyph_offset(), self.extender_glyph()),
+            )),
+            1usize => Some(Field::new(
+                "def_jstf_lang_sys_offset",
+                FieldType::offset(self.def_jstf_lang_sys_offset(), self.def_jstf_lang_sys()),
+            )),
+            2usize => Some(Field::new(
+                "jstf_lang_sys_count",
+                self.jstf_lang_sys_count(),
+            )),
+            3usize => Some(Field::new(
+                "jstf_lang_sys_records",
+                traversal::FieldType::array_of_records(
+                    stringify!(JstfLangSysRecord),
+                    self.jstf_lang_sys_records(),
+                    self.offset_data(),
+                ),
+            )),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for JstfScript<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// [JstfLangSysRecord](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#jstfscript-table)
+#[derive(Clone, Debug)]
+#[repr(C)]
+#[repr(packed)]
+pub struct JstfLangSysRecord {
+    /// 4-byte JstfLangSys identifier
+    pub jstf_lang_sys_tag: BigEndian<Tag>,
+    /// Offset to JstfLangSys table, from beginning of JstfScript table
+    pub jstf_lang_sys_offset: BigEndian<Offset16>,
+}
+
+impl JstfLangSysRecord {
+    /// 4-byte JstfLangSys identifier
+    pub fn jstf_lang_sys_tag(&self) -> Tag {
+        self.jstf_lang_sys_tag.get()
+    }
+
+    /// Offset to JstfLangSys table, from beginning of JstfScript table
+    pub fn jstf_lang_sys_offset(&self) -> Offset16 {
+        self.jstf_lang_sys_offset.get()
+    }
+
+    /// Attempt to resolve [`jstf_lang_sys_offset`][Self::jstf_lang_sys_offset].
+    pub fn jstf_lang_sys<'a>(&self, data: FontData<'a>) -> Result<JstfLangSys<'a>, ReadError> {
+        self.jstf_lang_sys_offset().resolve(data)
+    }
+}
+
+impl FixedSized for JstfLangSysRecord {
+    const RAW_BYTE_LEN: usize = Tag::RAW_BYTE_LEN + Offset16::RAW_BYTE_LEN;
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeRecord<'a> for JstfLangSysRecord {
+    fn traverse(self, data: FontData<'a>) -> RecordResolver<'a> {
+        RecordResolver {
+            name: "JstfLangSysRecord",
+            get_field: Box::new(move |idx, _data| match idx {
+                0usize => Some(Field::new("jstf_lang_sys_tag", self.jstf_lang_sys_tag())),
+                1usize => Some(Field::new(
+                    "jstf_lang_sys_offset",
+                    FieldType::offset(self.jstf_lang_sys_offset(), self.jstf_lang_sys(_data)),
+                )),
+                _ => None,
+            }),
+            data,
+        }
+    }
+}
+
+/// [Extender Glyph Table](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#extender-glyph-table)
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct ExtenderGlyphMarker {
+    extender_glyphs_byte_len: usize,
+}
+
+impl ExtenderGlyphMarker {
+    fn glyph_count_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn extender_glyphs_byte_range(&self) -> Range<usize> {
+        let start = self.glyph_count_byte_range().end;
+        start..start + self.extender_glyphs_byte_len
+    }
+}
+
+impl TableInfo for ExtenderGlyphMarker {
+    #[allow(unused_parens)]
+    fn parse(data: FontData) -> Result<TableRef<Self>, ReadError> {
+        let mut cursor = data.cursor();
+        let glyph_count: u16 = cursor.read()?;
+        let extender_glyphs_byte_len = glyph_count as usize * GlyphId::RAW_BYTE_LEN;
+        cursor.advance_by(extender_glyphs_byte_len);
+        cursor.finish(ExtenderGlyphMarker {
+            extender_glyphs_byte_len,
+        })
+    }
+}
+
+/// [Extender Glyph Table](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#extender-glyph-table)
+pub type ExtenderGlyph<'a> = TableRef<'a, ExtenderGlyphMarker>;
+
+impl<'a> ExtenderGlyph<'a> {
+    /// Number of extender glyphs in this script
+    pub fn glyph_count(&self) -> u16 {
+        let range = self.shape.glyph_count_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Extender glyph IDs — in increasing numerical order
+    pub fn extender_glyphs(&self) -> &'a [BigEndian<GlyphId>] {
+        let range = self.shape.extender_glyphs_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for ExtenderGlyph<'a> {
+    fn type_name(&self) -> &str {
+        "ExtenderGlyph"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(Field::new("glyph_count", self.glyph_count())),
+            1usize => Some(Field::new("extender_glyphs", self.extender_glyphs())),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for ExtenderGlyph<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// [JstfLangSys Table](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#jstflangsys-table)
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct JstfLangSysMarker {
+    jstf_priority_offsets_byte_len: usize,
+}
+
+impl JstfLangSysMarker {
+    fn jstf_priority_count_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn jstf_priority_offsets_byte_range(&self) -> Range<usize> {
+        let start = self.jstf_priority_count_byte_range().end;
+        start..start + self.jstf_priority_offsets_byte_len
+    }
+}
+
+impl TableInfo for JstfLangSysMarker {
+    #[allow(unused_parens)]
+    fn parse(data: FontData) -> Result<TableRef<Self>, ReadError> {
+        let mut cursor = data.cursor();
+        let jstf_priority_count: u16 = cursor.read()?;
+        let jstf_priority_offsets_byte_len = jstf_priority_count as usize * Offset16::RAW_BYTE_LEN;
+        cursor.advance_by(jstf_priority_offsets_byte_len);
+        cursor.finish(JstfLangSysMarker {
+            jstf_priority_offsets_byte_len,
+        })
+    }
+}
+
+/// [JstfLangSys Table](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#jstflangsys-table)
+pub type JstfLangSys<'a> = TableRef<'a, JstfLangSysMarker>;
+
+impl<'a> JstfLangSys<'a> {
+    /// Number of JstfPriority tables
+    pub fn jstf_priority_count(&self) -> u16 {
+        let range = self.shape.jstf_priority_count_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Array of offsets to JstfPriority tables, from beginning of
+    /// JstfLangSys table, in priority order
+    pub fn jstf_priority_offsets(&self) -> &'a [BigEndian<Offset16>] {
+        let range = self.shape.jstf_priority_offsets_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+
+    pub fn jstf_priority(&self) -> impl Iterator<Item = Result<JstfPriority<'a>, ReadError>> + 'a {
+        let data = self.data;
+        self.jstf_priority_offsets()
+            .iter()
+            .map(move |off| off.get().resolve(data))
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for JstfLangSys<'a> {
+    fn type_name(&self) -> &str {
+        "JstfLangSys"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(Field::new(
+                "jstf_priority_count",
+                self.jstf_priority_count(),
+            )),
+            1usize => Some({
+                let data = self.data;
+                Field::new(
+                    "jstf_priority_offsets",
+                    FieldType::offset_array(
+                        better_type_name::<JstfPriority>(),
+                        self.jstf_priority_offsets(),
+                        move |off| {
+                            let target = off.get().resolve::<JstfPriority>(data);
+                            FieldType::offset(off.get(), target)
+                        },
+                    ),
+                )
+            }),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for JstfLangSys<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// [JstfPriority Table](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#jstfpriority-table)
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct JstfPriorityMarker {}
+
+impl JstfPriorityMarker {
+    fn gsub_shrinkage_enable_offset_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + Offset16::RAW_BYTE_LEN
+    }
+    fn gsub_shrinkage_disable_offset_byte_range(&self) -> Range<usize> {
+        let start = self.gsub_shrinkage_enable_offset_byte_range().end;
+        start..start + Offset16::RAW_BYTE_LEN
+    }
+    fn gpos_shrinkage_enable_offset_byte_range(&self) -> Range<usize> {
+        let start = self.gsub_shrinkage_disable_offset_byte_range().end;
+        start..start + Offset16::RAW_BYTE_LEN
+    }
+    fn gpos_shrinkage_disable_offset_byte_range(&self) -> Range<usize> {
+        let start = self.gpos_shrinkage_enable_offset_byte_range().end;
+        start..start + Offset16::RAW_BYTE_LEN
+    }
+    fn shrinkage_jstf_max_offset_byte_range(&self) -> Range<usize> {
+        let start = self.gpos_shrinkage_disable_offset_byte_range().end;
+        start..start + Offset16::RAW_BYTE_LEN
+    }
+    fn gsub_extension_enable_offset_byte_range(&self) -> Range<usize> {
+        let start = self.shrinkage_jstf_max_offset_byte_range().end;
+        start..start + Offset16::RAW_BYTE_LEN
+    }
+    fn gsub_extension_disable_offset_byte_range(&self) -> Range<usize> {
+        let start = self.gsub_extension_enable_offset_byte_range().end;
+        start..start + Offset16::RAW_BYTE_LEN
+    }
+    fn gpos_extension_enable_offset_byte_range(&self) -> Range<usize> {
+        let start = self.gsub_extension_disable_offset_byte_range().end;
+        start..start + Offset16::RAW_BYTE_LEN
+    }
+    fn gpos_extension_disable_offset_byte_range(&self) -> Range<usize> {
+        let start = self.gpos_extension_enable_offset_byte_range().end;
+        start..start + Offset16::RAW_BYTE_LEN
+    }
+    fn extension_jstf_max_offset_byte_range(&self) -> Range<usize> {
+        let start = self.gpos_extension_disable_offset_byte_range().end;
+        start..start + Offset16::RAW_BYTE_LEN
+    }
+}
+
+impl TableInfo for JstfPriorityMarker {
+    fn parse(data: FontData) -> Result<TableRef<Self>, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<Offset16>();
+        cursor.advance::<Offset16>();
+        cursor.advance::<Offset16>();
+        cursor.advance::<Offset16>();
+        cursor.advance::<Offset16>();
+        cursor.advance::<Offset16>();
+        cursor.advance::<Offset16>();
+        cursor.advance::<Offset16>();
+        cursor.advance::<Offset16>();
+        cursor.advance::<Offset16>();
+        cursor.finish(JstfPriorityMarker {})
+    }
+}
+
+/// [JstfPriority Table](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#jstfpriority-table)
+pub type JstfPriority<'a> = TableRef<'a, JstfPriorityMarker>;
+
+impl<'a> JstfPriority<'a> {
+    /// Offset to shrinkage-enable JstfGSUBModList table, from
+    /// beginning of JstfPriority table (may be NULL)
+    pub fn gsub_shrinkage_enable_offset(&self) -> Nullable<Offset16> {
+        let range = self.shape.gsub_shrinkage_enable_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`gsub_shrinkage_enable_offset`][Self::gsub_shrinkage_enable_offset].
+    pub fn gsub_shrinkage_enable(&self) -> Option<Result<JstfModList<'a>, ReadError>> {
+        let data = self.data;
+        self.gsub_shrinkage_enable_offset().resolve(data)
+    }
+
+    /// Offset to shrinkage-disable JstfGSUBModList table, from
+    /// beginning of JstfPriority table (may be NULL)
+    pub fn gsub_shrinkage_disable_offset(&self) -> Nullable<Offset16> {
+        let range = self.shape.gsub_shrinkage_disable_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`gsub_shrinkage_disable_offset`][Self::gsub_shrinkage_disable_offset].
+    pub fn gsub_shrinkage_disable(&self) -> Option<Result<JstfModList<'a>, ReadError>> {
+        let data = self.data;
+        self.gsub_shrinkage_disable_offset().resolve(data)
+    }
+
+    /// Offset to shrinkage-enable JstfGPOSModList table, from
+    /// beginning of JstfPriority table (may be NULL)
+    pub fn gpos_shrinkage_enable_offset(&self) -> Nullable<Offset16> {
+        let range = self.shape.gpos_shrinkage_enable_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`gpos_shrinkage_enable_offset`][Self::gpos_shrinkage_enable_offset].
+    pub fn gpos_shrinkage_enable(&self) -> Option<Result<JstfModList<'a>, ReadError>> {
+        let data = self.data;
+        self.gpos_shrinkage_enable_offset().resolve(data)
+    }
+
+    /// Offset to shrinkage-disable JstfGPOSModList table, from
+    /// beginning of JstfPriority table (may be NULL)
+    pub fn gpos_shrinkage_disable_offset(&self) -> Nullable<Offset16> {
+        let range = self.shape.gpos_shrinkage_disable_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`gpos_shrinkage_disable_offset`][Self::gpos_shrinkage_disable_offset].
+    pub fn gpos_shrinkage_disable(&self) -> Option<Result<JstfModList<'a>, ReadError>> {
+        let data = self.data;
+        self.gpos_shrinkage_disable_offset().resolve(data)
+    }
+
+    /// Offset to shrinkage JstfMax table, from beginning of
+    /// JstfPriority table (may be NULL)
+    pub fn shrinkage_jstf_max_offset(&self) -> Nullable<Offset16> {
+        let range = self.shape.shrinkage_jstf_max_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`shrinkage_jstf_max_offset`][Self::shrinkage_jstf_max_offset].
+    pub fn shrinkage_jstf_max(&self) -> Option<Result<PositionLookupList<'a>, ReadError>> {
+        let data = self.data;
+        self.shrinkage_jstf_max_offset().resolve(data)
+    }
+
+    /// Offset to extension-enable JstfGSUBModList table, from
+    /// beginnning of JstfPriority table (may be NULL)
+    pub fn gsub_extension_enable_offset(&self) -> Nullable<Offset16> {
+        let range = self.shape.gsub_extension_enable_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`gsub_extension_enable_offset`][Self::gsub_extension_enable_offset].
+    pub fn gsub_extension_enable(&self) -> Option<Result<JstfModList<'a>, ReadError>> {
+        let data = self.data;
+        self.gsub_extension_enable_offset().resolve(data)
+    }
+
+    /// Offset to extension-disable JstfGSUBModList table, from
+    /// beginning of JstfPriority table (may be NULL)
+    pub fn gsub_extension_disable_offset(&self) -> Nullable<Offset16> {
+        let range = self.shape.gsub_extension_disable_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`gsub_extension_disable_offset`][Self::gsub_extension_disable_offset].
+    pub fn gsub_extension_disable(&self) -> Option<Result<JstfModList<'a>, ReadError>> {
+        let data = self.data;
+        self.gsub_extension_disable_offset().resolve(data)
+    }
+
+    /// Offset to extension-enable JstfGPOSModList table, from
+    /// beginning of JstfPriority table (may be NULL)
+    pub fn gpos_extension_enable_offset(&self) -> Nullable<Offset16> {
+        let range = self.shape.gpos_extension_enable_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`gpos_extension_enable_offset`][Self::gpos_extension_enable_offset].
+    pub fn gpos_extension_enable(&self) -> Option<Result<JstfModList<'a>, ReadError>> {
+        let data = self.data;
+        self.gpos_extension_enable_offset().resolve(data)
+    }
+
+    /// Offset to extension-disable JstfGPOSModList table, from
+    /// beginning of JstfPriority table (may be NULL)
+    pub fn gpos_extension_disable_offset(&self) -> Nullable<Offset16> {
+        let range = self.shape.gpos_extension_disable_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`gpos_extension_disable_offset`][Self::gpos_extension_disable_offset].
+    pub fn gpos_extension_disable(&self) -> Option<Result<JstfModList<'a>, ReadError>> {
+        let data = self.data;
+        self.gpos_extension_disable_offset().resolve(data)
+    }
+
+    /// Offset to extension JstfMax table, from beginning of
+    /// JstfPriority table (may be NULL)
+    pub fn extension_jstf_max_offset(&self) -> Nullable<Offset16> {
+        let range = self.shape.extension_jstf_max_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`extension_jstf_max_offset`][Self::extension_jstf_max_offset].
+    pub fn extension_jstf_max(&self) -> Option<Result<PositionLookupList<'a>, ReadError>> {
+        let data = self.data;
+        self.extension_jstf_max_offset().resolve(data)
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for JstfPriority<'a> {
+    fn type_name(&self) -> &str {
+        "JstfPriority"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(Field::new(
+                "gsub_shrinkage_enable_offset",
+                FieldType::offset(
+                    self.gsub_shrinkage_enable_offset(),
+                    self.gsub_shrinkage_enable(),
+                ),
+            )),
+            1usize => Some(Field::new(
+                "gsub_shrinkage_disable_offset",
+                FieldType::offset(
+                    self.gsub_shrinkage_disable_offset(),
+                    self.gsub_shrinkage_disable(),
+                ),
+            )),
+            2usize => Some(Field::new(
+                "gpos_shrinkage_enable_offset",
+                FieldType::offset(
+                    self.gpos_shrinkage_enable_offset(),
+                    self.gpos_shrinkage_enable(),
+                ),
+            )),
+            3usize => Some(Field::new(
+                "gpos_shrinkage_disable_offset",
+                FieldType::offset(
+                    self.gpos_shrinkage_disable_offset(),
+                    self.gpos_shrinkage_disable(),
+                ),
+            )),
+            4usize => Some(Field::new(
+                "shrinkage_jstf_max_offset",
+                FieldType::offset(self.shrinkage_jstf_max_offset(), self.shrinkage_jstf_max()),
+            )),
+            5usize => Some(Field::new(
+                "gsub_extension_enable_offset",
+                FieldType::offset(
+                    self.gsub_extension_enable_offset(),
+                    self.gsub_extension_enable(),
+                ),
+            )),
+            6usize => Some(Field::new(
+                "gsub_extension_disable_offset",
+                FieldType::offset(
+                    self.gsub_extension_disable_offset(),
+                    self.gsub_extension_disable(),
+                ),
+            )),
+            7usize => Some(Field::new(
+                "gpos_extension_enable_offset",
+                FieldType::offset(
+                    self.gpos_extension_enable_offset(),
+                    self.gpos_extension_enable(),
+                ),
+            )),
+            8usize => Some(Field::new(
+                "gpos_extension_disable_offset",
+                FieldType::offset(
+                    self.gpos_extension_disable_offset(),
+                    self.gpos_extension_disable(),
+                ),
+            )),
+            9usize => Some(Field::new(
+                "extension_jstf_max_offset",
+                FieldType::offset(self.extension_jstf_max_offset(), self.extension_jstf_max()),
+            )),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for JstfPriority<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// [JstfGSUBModList](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#jstfgsubmodlist-table)
+/// and [JstfGPOSModList](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#jstfgposmodlist-table)
+/// tables
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct JstfModListMarker {
+    lookup_indices_byte_len: usize,
+}
+
+impl JstfModListMarker {
+    fn lookup_count_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn lookup_indices_byte_range(&self) -> Range<usize> {
+        let start = self.lookup_count_byte_range().end;
+        start..start + self.lookup_indices_byte_len
+    }
+}
+
+impl TableInfo for JstfModListMarker {
+    #[allow(unused_parens)]
+    fn parse(data: FontData) -> Result<TableRef<Self>, ReadError> {
+        let mut cursor = data.cursor();
+        let lookup_count: u16 = cursor.read()?;
+        let lookup_indices_byte_len = lookup_count as usize * u16::RAW_BYTE_LEN;
+        cursor.advance_by(lookup_indices_byte_len);
+        cursor.finish(JstfModListMarker {
+            lookup_indices_byte_len,
+        })
+    }
+}
+
+/// [JstfGSUBModList](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#jstfgsubmodlist-table)
+/// and [JstfGPOSModList](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#jstfgposmodlist-table)
+/// tables
+pub type JstfModList<'a> = TableRef<'a, JstfModListMarker>;
+
+impl<'a> JstfModList<'a> {
+    /// Number of lookups for this modification
+    pub fn lookup_count(&self) -> u16 {
+        let range = self.shape.lookup_count_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Array of indices into the GSUB or GPOS LookupList, in
+    /// increasing numerical order
+    pub fn lookup_indices(&self) -> &'a [BigEndian<u16>] {
+        let range = self.shape.lookup_indices_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for JstfModList<'a> {
+    fn type_name(&self) -> &str {
+        "JstfModList"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(Field::new("lookup_count", self.lookup_count())),
+            1usize => Some(Field::new("lookup_indices", self.lookup_indices())),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for JstfModList<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
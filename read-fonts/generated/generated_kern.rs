@@ -0,0 +1,266 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+#[allow(unused_imports)]
+use crate::codegen_prelude::*;
+
+/// The body of a [format 0](https://docs.microsoft.com/en-us/typography/opentype/spec/kern#format-0)
+/// kerning subtable.
+///
+/// This follows the subtable header, which differs between the
+/// OpenType and Apple variants of the table.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct Subtable0Marker {
+    pairs_byte_len: usize,
+}
+
+impl Subtable0Marker {
+    fn n_pairs_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn search_range_byte_range(&self) -> Range<usize> {
+        let start = self.n_pairs_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn entry_selector_byte_range(&self) -> Range<usize> {
+        let start = self.search_range_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn range_shift_byte_range(&self) -> Range<usize> {
+        let start = self.entry_selector_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn pairs_byte_range(&self) -> Range<usize> {
+        let start = self.range_shift_byte_range().end;
+        start..start + self.pairs_byte_len
+    }
+}
+
+impl TableInfo for Subtable0Marker {
+    #[allow(unused_parens)]
+    fn parse(data: FontData) -> Result<TableRef<Self>, ReadError> {
+        let mut cursor = data.cursor();
+        let n_pairs: u16 = cursor.read()?;
+        cursor.advance::<u16>();
+        cursor.advance::<u16>();
+        cursor.advance::<u16>();
+        let pairs_byte_len = n_pairs as usize * KernPair::RAW_BYTE_LEN;
+        cursor.advance_by(pairs_byte_len);
+        cursor.finish(Subtable0Marker { pairs_byte_len })
+    }
+}
+
+/// The body of a [format 0](https://docs.microsoft.com/en-us/typography/opentype/spec/kern#format-0)
+/// kerning subtable.
+///
+/// This follows the subtable header, which differs between the
+/// OpenType and Apple variants of the table.
+pub type Subtable0<'a> = TableRef<'a, Subtable0Marker>;
+
+impl<'a> Subtable0<'a> {
+    /// The number of kerning pairs in the table.
+    pub fn n_pairs(&self) -> u16 {
+        let range = self.shape.n_pairs_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// The largest power of two less than or equal to the value of nPairs,
+    /// multiplied by the size in bytes of an entry in the table.
+    pub fn search_range(&self) -> u16 {
+        let range = self.shape.search_range_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// This is calculated as log2 of the largest power of two less than or
+    /// equal to the value of nPairs. This value indicates how many
+    /// iterations of the search loop will have to be made.
+    pub fn entry_selector(&self) -> u16 {
+        let range = self.shape.entry_selector_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// The value of nPairs minus the largest power of two less than or
+    /// equal to nPairs, and then multiplied by the size in bytes of an
+    /// entry in the table.
+    pub fn range_shift(&self) -> u16 {
+        let range = self.shape.range_shift_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Array of KernPair records, sorted by left and then right glyph.
+    pub fn pairs(&self) -> &'a [KernPair] {
+        let range = self.shape.pairs_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for Subtable0<'a> {
+    fn type_name(&self) -> &str {
+        "Subtable0"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(Field::new("n_pairs", self.n_pairs())),
+            1usize => Some(Field::new("search_range", self.search_range())),
+            2usize => Some(Field::new("entry_selector", self.entry_selector())),
+            3usize => Some(Field::new("range_shift", self.range_shift())),
+            4usize => Some(Field::new(
+                "pairs",
+                traversal::FieldType::array_of_records(
+                    stringify!(KernPair),
+                    self.pairs(),
+                    self.offset_data(),
+                ),
+            )),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for Subtable0<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// A kerning pair record.
+#[derive(Clone, Debug)]
+#[repr(C)]
+#[repr(packed)]
+pub struct KernPair {
+    /// The glyph index for the left-hand glyph in the kerning pair.
+    pub left: BigEndian<GlyphId>,
+    /// The glyph index for the right-hand glyph in the kerning pair.
+    pub right: BigEndian<GlyphId>,
+    /// The kerning value for the above pair, in design units. If this
+    /// value is greater than zero, the characters will be moved apart. If
+    /// this value is less than zero, the character will be moved closer
+    /// together.
+    pub value: BigEndian<FWord>,
+}
+
+impl KernPair {
+    /// The glyph index for the left-hand glyph in the kerning pair.
+    pub fn left(&self) -> GlyphId {
+        self.left.get()
+    }
+
+    /// The glyph index for the right-hand glyph in the kerning pair.
+    pub fn right(&self) -> GlyphId {
+        self.right.get()
+    }
+
+    /// The kerning value for the above pair, in design units. If this
+    /// value is greater than zero, the characters will be moved apart. If
+    /// this value is less than zero, the character will be moved closer
+    /// together.
+    pub fn value(&self) -> FWord {
+        self.value.get()
+    }
+}
+
+impl FixedSized for KernPair {
+    const RAW_BYTE_LEN: usize = GlyphId::RAW_BYTE_LEN + GlyphId::RAW_BYTE_LEN + FWord::RAW_BYTE_LEN;
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeRecord<'a> for KernPair {
+    fn traverse(self, data: FontData<'a>) -> RecordResolver<'a> {
+        RecordResolver {
+            name: "KernPair",
+            get_field: Box::new(move |idx, _data| match idx {
+                0usize => Some(Field::new("left", self.left())),
+                1usize => Some(Field::new("right", self.right())),
+                2usize => Some(Field::new("value", self.value())),
+                _ => None,
+            }),
+            data,
+        }
+    }
+}
+
+/// A class table used by a [format 2](https://docs.microsoft.com/en-us/typography/opentype/spec/kern#format-2)
+/// kerning subtable.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct Subtable2ClassTableMarker {
+    offsets_byte_len: usize,
+}
+
+impl Subtable2ClassTableMarker {
+    fn first_glyph_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + GlyphId::RAW_BYTE_LEN
+    }
+    fn n_glyphs_byte_range(&self) -> Range<usize> {
+        let start = self.first_glyph_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn offsets_byte_range(&self) -> Range<usize> {
+        let start = self.n_glyphs_byte_range().end;
+        start..start + self.offsets_byte_len
+    }
+}
+
+impl TableInfo for Subtable2ClassTableMarker {
+    #[allow(unused_parens)]
+    fn parse(data: FontData) -> Result<TableRef<Self>, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<GlyphId>();
+        let n_glyphs: u16 = cursor.read()?;
+        let offsets_byte_len = n_glyphs as usize * u16::RAW_BYTE_LEN;
+        cursor.advance_by(offsets_byte_len);
+        cursor.finish(Subtable2ClassTableMarker { offsets_byte_len })
+    }
+}
+
+/// A class table used by a [format 2](https://docs.microsoft.com/en-us/typography/opentype/spec/kern#format-2)
+/// kerning subtable.
+pub type Subtable2ClassTable<'a> = TableRef<'a, Subtable2ClassTableMarker>;
+
+impl<'a> Subtable2ClassTable<'a> {
+    /// First glyph in class range.
+    pub fn first_glyph(&self) -> GlyphId {
+        let range = self.shape.first_glyph_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Number of glyphs in class range.
+    pub fn n_glyphs(&self) -> u16 {
+        let range = self.shape.n_glyphs_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// The offsets array for all glyphs in the range.
+    pub fn offsets(&self) -> &'a [BigEndian<u16>] {
+        let range = self.shape.offsets_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for Subtable2ClassTable<'a> {
+    fn type_name(&self) -> &str {
+        "Subtable2ClassTable"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(Field::new("first_glyph", self.first_glyph())),
+            1usize => Some(Field::new("n_glyphs", self.n_glyphs())),
+            2usize => Some(Field::new("offsets", self.offsets())),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for Subtable2ClassTable<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
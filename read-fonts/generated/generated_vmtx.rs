@@ -0,0 +1,137 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+#[allow(unused_imports)]
+use crate::codegen_prelude::*;
+
+/// The [vmtx (Vertical Metrics)](https://docs.microsoft.com/en-us/typography/opentype/spec/vmtx) table
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct VmtxMarker {
+    v_metrics_byte_len: usize,
+    top_side_bearings_byte_len: usize,
+}
+
+impl VmtxMarker {
+    fn v_metrics_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + self.v_metrics_byte_len
+    }
+    fn top_side_bearings_byte_range(&self) -> Range<usize> {
+        let start = self.v_metrics_byte_range().end;
+        start..start + self.top_side_bearings_byte_len
+    }
+}
+
+impl ReadArgs for VmtxMarker {
+    type Args = (u16, u16);
+}
+
+impl TableInfoWithArgs for VmtxMarker {
+    #[allow(unused_parens)]
+    fn parse_with_args<'a>(
+        data: FontData<'a>,
+        args: &(u16, u16),
+    ) -> Result<TableRef<'a, Self>, ReadError> {
+        let (number_of_long_ver_metrics, num_glyphs) = *args;
+        let mut cursor = data.cursor();
+        let v_metrics_byte_len = number_of_long_ver_metrics as usize * LongVerMetric::RAW_BYTE_LEN;
+        cursor.advance_by(v_metrics_byte_len);
+        let top_side_bearings_byte_len =
+            num_glyphs.saturating_sub(number_of_long_ver_metrics) as usize * i16::RAW_BYTE_LEN;
+        cursor.advance_by(top_side_bearings_byte_len);
+        cursor.finish(VmtxMarker {
+            v_metrics_byte_len,
+            top_side_bearings_byte_len,
+        })
+    }
+}
+
+/// The [vmtx (Vertical Metrics)](https://docs.microsoft.com/en-us/typography/opentype/spec/vmtx) table
+pub type Vmtx<'a> = TableRef<'a, VmtxMarker>;
+
+impl<'a> Vmtx<'a> {
+    /// Paired advance height and top side bearing values for each
+    /// glyph. Records are indexed by glyph ID.
+    pub fn v_metrics(&self) -> &'a [LongVerMetric] {
+        let range = self.shape.v_metrics_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+
+    /// Top side bearings for glyph IDs greater than or equal to
+    /// numberOfLongMetrics.
+    pub fn top_side_bearings(&self) -> &'a [BigEndian<i16>] {
+        let range = self.shape.top_side_bearings_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for Vmtx<'a> {
+    fn type_name(&self) -> &str {
+        "Vmtx"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(Field::new(
+                "v_metrics",
+                traversal::FieldType::array_of_records(
+                    stringify!(LongVerMetric),
+                    self.v_metrics(),
+                    self.offset_data(),
+                ),
+            )),
+            1usize => Some(Field::new("top_side_bearings", self.top_side_bearings())),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for Vmtx<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+#[derive(Clone, Debug)]
+#[repr(C)]
+#[repr(packed)]
+pub struct LongVerMetric {
+    /// Advance height, in font design units.
+    pub advance: BigEndian<u16>,
+    /// Glyph top side bearing, in font design units.
+    pub side_bearing: BigEndian<i16>,
+}
+
+impl LongVerMetric {
+    /// Advance height, in font design units.
+    pub fn advance(&self) -> u16 {
+        self.advance.get()
+    }
+
+    /// Glyph top side bearing, in font design units.
+    pub fn side_bearing(&self) -> i16 {
+        self.side_bearing.get()
+    }
+}
+
+impl FixedSized for LongVerMetric {
+    const RAW_BYTE_LEN: usize = u16::RAW_BYTE_LEN + i16::RAW_BYTE_LEN;
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeRecord<'a> for LongVerMetric {
+    fn traverse(self, data: FontData<'a>) -> RecordResolver<'a> {
+        RecordResolver {
+            name: "LongVerMetric",
+            get_field: Box::new(move |idx, _data| match idx {
+                0usize => Some(Field::new("advance", self.advance())),
+                1usize => Some(Field::new("side_bearing", self.side_bearing())),
+                _ => None,
+            }),
+            data,
+        }
+    }
+}
@@ -1,6 +1,6 @@
 //! Custom array types
 
-use crate::read::{ComputeSize, FontReadWithArgs, ReadArgs};
+use crate::read::{ComputeSize, FontRead, FontReadWithArgs, ReadArgs};
 use crate::{FontData, ReadError};
 
 /// An array whose items size is not known at compile time.
@@ -19,7 +19,7 @@ pub struct ComputedArray<'a, T: ReadArgs> {
 impl<'a, T: ComputeSize> ComputedArray<'a, T> {
     pub fn new(data: FontData<'a>, args: T::Args) -> Self {
         let item_len = T::compute_size(&args);
-        let len = data.len() / item_len;
+        let len = data.len().checked_div(item_len).unwrap_or(0);
         ComputedArray {
             item_len,
             len,
@@ -91,3 +91,78 @@ impl<T: ReadArgs> std::fmt::Debug for ComputedArray<'_, T> {
             .finish()
     }
 }
+
+/// A trait for types whose size is determined by data stored at the start of
+/// each item.
+///
+/// This is used to read arrays of items (such as avar's segment maps) where
+/// each item has a different length.
+pub trait VarSize {
+    /// Return the length, in bytes, of the item starting at `pos` in `data`.
+    fn read_len_at(data: FontData, pos: usize) -> Option<usize>;
+
+    /// Return the total length of `count` consecutive items at the start of `data`.
+    fn total_len_for_count(data: FontData, count: usize) -> Result<usize, ReadError> {
+        let mut pos = 0;
+        for _ in 0..count {
+            pos += Self::read_len_at(data, pos).ok_or(ReadError::OutOfBounds)?;
+        }
+        Ok(pos)
+    }
+}
+
+/// An array of items, each of which may have a different length.
+#[derive(Clone, Copy)]
+pub struct VarLenArray<'a, T> {
+    data: FontData<'a>,
+    phantom: std::marker::PhantomData<*const T>,
+}
+
+impl<'a, T> VarLenArray<'a, T>
+where
+    T: FontRead<'a> + VarSize,
+{
+    /// Iterate over the items in this array.
+    pub fn iter(&self) -> impl Iterator<Item = Result<T, ReadError>> + 'a {
+        let mut data = self.data;
+        std::iter::from_fn(move || {
+            if data.is_empty() {
+                return None;
+            }
+            let item_len = match T::read_len_at(data, 0) {
+                Some(len) => len,
+                None => {
+                    data = FontData::new(&[]);
+                    return Some(Err(ReadError::OutOfBounds));
+                }
+            };
+            let item_data = data.slice(..item_len).ok_or(ReadError::OutOfBounds);
+            data = data.split_off(item_len).unwrap_or_default();
+            Some(item_data.and_then(T::read))
+        })
+    }
+
+    /// Return the item at the provided index.
+    ///
+    /// This requires walking all of the preceding items.
+    pub fn get(&self, idx: usize) -> Option<Result<T, ReadError>> {
+        self.iter().nth(idx)
+    }
+}
+
+impl<'a, T> FontRead<'a> for VarLenArray<'a, T> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        Ok(VarLenArray {
+            data,
+            phantom: std::marker::PhantomData,
+        })
+    }
+}
+
+impl<T> std::fmt::Debug for VarLenArray<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("VarLenArray")
+            .field("bytes", &self.data)
+            .finish()
+    }
+}
@@ -0,0 +1,583 @@
+//! Embedded bitmap glyphs.
+//!
+//! The EBLC/CBLC and EBDT/CBDT tables share their index and image formats,
+//! which are defined here along with a loader that also handles `sbix`.
+
+include!("../generated/generated_bitmap.rs");
+
+use crate::{
+    metrics::optional,
+    tables::{
+        cbdt::Cbdt,
+        cblc::Cblc,
+        ebdt::Ebdt,
+        eblc::Eblc,
+        sbix::{GlyphData, Sbix},
+    },
+    TableProvider,
+};
+
+/// The location of a glyph's image in an EBDT or CBDT table.
+#[derive(Clone, Debug)]
+pub struct BitmapLocation {
+    /// Format of the image data.
+    pub image_format: u16,
+    /// Offset of the image data from the start of the EBDT or CBDT table.
+    pub data_offset: usize,
+    /// Size of the image data in bytes.
+    pub data_size: usize,
+    /// Metrics shared by every glyph in the index subtable, present for
+    /// index formats 2 and 5.
+    pub metrics: Option<BigGlyphMetrics>,
+    /// Bit depth of the strike.
+    pub bit_depth: u8,
+    /// Horizontal pixels per em of the strike.
+    pub ppem_x: u8,
+    /// Vertical pixels per em of the strike.
+    pub ppem_y: u8,
+}
+
+impl BitmapSize {
+    /// Returns the location of the image for the given glyph in this strike,
+    /// or `None` if the strike has no image for it.
+    ///
+    /// `offset_data` is the data of the EBLC or CBLC table containing this
+    /// record.
+    pub fn location(
+        &self,
+        offset_data: FontData,
+        glyph_id: GlyphId,
+    ) -> Result<Option<BitmapLocation>, ReadError> {
+        if glyph_id < self.start_glyph_index() || glyph_id > self.end_glyph_index() {
+            return Ok(None);
+        }
+        let list = self.index_subtable_list(offset_data)?;
+        let record = match list.index_subtable_records().iter().find(|record| {
+            (record.first_glyph_index()..=record.last_glyph_index()).contains(&glyph_id)
+        }) {
+            Some(record) => record,
+            None => return Ok(None),
+        };
+        let mut location = BitmapLocation {
+            image_format: 0,
+            data_offset: 0,
+            data_size: 0,
+            metrics: None,
+            bit_depth: self.bit_depth(),
+            ppem_x: self.ppem_x(),
+            ppem_y: self.ppem_y(),
+        };
+        let ix = (glyph_id.to_u16() - record.first_glyph_index().to_u16()) as usize;
+        // the image range, relative to the subtable's image data offset
+        let range = match record.index_subtable(list.offset_data())? {
+            IndexSubtable::Format1(subtable) => {
+                location.image_format = subtable.image_format();
+                location.data_offset = subtable.image_data_offset() as usize;
+                let offsets = subtable.sbit_offsets();
+                match (offsets.get(ix), offsets.get(ix + 1)) {
+                    (Some(start), Some(end)) => start.get() as usize..end.get() as usize,
+                    _ => return Err(ReadError::OutOfBounds),
+                }
+            }
+            IndexSubtable::Format2(subtable) => {
+                location.image_format = subtable.image_format();
+                location.data_offset = subtable.image_data_offset() as usize;
+                location.metrics = subtable.big_metrics().first().copied();
+                let size = subtable.image_size() as usize;
+                let start = ix * size;
+                start..start + size
+            }
+            IndexSubtable::Format3(subtable) => {
+                location.image_format = subtable.image_format();
+                location.data_offset = subtable.image_data_offset() as usize;
+                let offsets = subtable.sbit_offsets();
+                match (offsets.get(ix), offsets.get(ix + 1)) {
+                    (Some(start), Some(end)) => start.get() as usize..end.get() as usize,
+                    _ => return Err(ReadError::OutOfBounds),
+                }
+            }
+            IndexSubtable::Format4(subtable) => {
+                location.image_format = subtable.image_format();
+                location.data_offset = subtable.image_data_offset() as usize;
+                // the last pair only marks the end of the final glyph's data
+                let pairs = subtable.glyph_array();
+                let glyphs = pairs
+                    .get(..pairs.len().saturating_sub(1))
+                    .unwrap_or_default();
+                let ix = match glyphs.binary_search_by_key(&glyph_id, |pair| pair.glyph_id()) {
+                    Ok(ix) => ix,
+                    Err(_) => return Ok(None),
+                };
+                pairs[ix].sbit_offset() as usize..pairs[ix + 1].sbit_offset() as usize
+            }
+            IndexSubtable::Format5(subtable) => {
+                location.image_format = subtable.image_format();
+                location.data_offset = subtable.image_data_offset() as usize;
+                location.metrics = subtable.big_metrics().first().copied();
+                let ix = match subtable
+                    .glyph_array()
+                    .binary_search_by_key(&glyph_id, |gid| gid.get())
+                {
+                    Ok(ix) => ix,
+                    Err(_) => return Ok(None),
+                };
+                let size = subtable.image_size() as usize;
+                let start = ix * size;
+                start..start + size
+            }
+        };
+        if range.start >= range.end {
+            return Ok(None);
+        }
+        location.data_offset += range.start;
+        location.data_size = range.end - range.start;
+        Ok(Some(location))
+    }
+}
+
+impl BitmapLocation {
+    /// Reads the glyph image at this location.
+    ///
+    /// `data` is the data of the EBDT or CBDT table.
+    pub fn glyph<'a>(&self, data: FontData<'a>) -> Result<BitmapGlyph<'a>, ReadError> {
+        let format = self.image_format;
+        let end = self.data_offset.saturating_add(self.data_size);
+        let data = data
+            .slice(self.data_offset..end)
+            .ok_or(ReadError::OutOfBounds)?;
+        let (metrics, header_len) = match format {
+            1 | 2 | 8 | 17 => (
+                BitmapMetrics::Small(*data.read_ref_at::<SmallGlyphMetrics>(0)?),
+                SmallGlyphMetrics::RAW_BYTE_LEN,
+            ),
+            6 | 7 | 9 | 18 => (
+                BitmapMetrics::Big(*data.read_ref_at::<BigGlyphMetrics>(0)?),
+                BigGlyphMetrics::RAW_BYTE_LEN,
+            ),
+            5 | 19 => {
+                let metrics = self
+                    .metrics
+                    .ok_or(ReadError::MalformedData("missing index subtable metrics"))?;
+                (BitmapMetrics::Big(metrics), 0)
+            }
+            _ => return Err(ReadError::InvalidFormat(format.into())),
+        };
+        let data = data.split_off(header_len).ok_or(ReadError::OutOfBounds)?;
+        let data = match format {
+            1 | 2 | 5 | 6 | 7 => BitmapData::Bitmap {
+                data: data.as_bytes(),
+                bit_depth: self.bit_depth,
+                byte_aligned: matches!(format, 1 | 6),
+            },
+            8 | 9 => {
+                // format 8 has a pad byte after the small metrics
+                let data = data
+                    .split_off((format == 8) as usize)
+                    .ok_or(ReadError::OutOfBounds)?;
+                let num_components = data.read_at::<u16>(0)? as usize;
+                let len = num_components * EbdtComponent::RAW_BYTE_LEN;
+                BitmapData::Composite(data.read_array(2..2 + len)?)
+            }
+            _ => {
+                let len = data.read_at::<u32>(0)? as usize;
+                let png = data
+                    .slice(4..4usize.saturating_add(len))
+                    .ok_or(ReadError::OutOfBounds)?;
+                BitmapData::Png(png.as_bytes())
+            }
+        };
+        Ok(BitmapGlyph {
+            data,
+            metrics,
+            ppem_x: self.ppem_x.into(),
+            ppem_y: self.ppem_y.into(),
+        })
+    }
+}
+
+/// An embedded bitmap glyph.
+#[derive(Clone, Debug)]
+pub struct BitmapGlyph<'a> {
+    /// The image data.
+    pub data: BitmapData<'a>,
+    /// The glyph metrics, in pixels.
+    pub metrics: BitmapMetrics,
+    /// Horizontal pixels per em of the strike containing the glyph.
+    pub ppem_x: u16,
+    /// Vertical pixels per em of the strike containing the glyph.
+    pub ppem_y: u16,
+}
+
+/// Image data of an embedded bitmap glyph.
+#[derive(Clone, Debug)]
+pub enum BitmapData<'a> {
+    /// Uncompressed bitmap data, with rows padded to a byte boundary if
+    /// `byte_aligned` is true and tightly packed otherwise.
+    Bitmap {
+        data: &'a [u8],
+        bit_depth: u8,
+        byte_aligned: bool,
+    },
+    /// A glyph composed of other bitmap glyphs.
+    Composite(&'a [EbdtComponent]),
+    /// PNG image data.
+    Png(&'a [u8]),
+    /// JPEG image data.
+    Jpeg(&'a [u8]),
+    /// TIFF image data.
+    Tiff(&'a [u8]),
+    /// Image data in some other format, identified by its `sbix` graphic
+    /// type.
+    Other { graphic_type: Tag, data: &'a [u8] },
+}
+
+/// Metrics of an embedded bitmap glyph.
+#[derive(Clone, Copy, Debug)]
+pub enum BitmapMetrics {
+    /// Metrics for a single layout direction.
+    Small(SmallGlyphMetrics),
+    /// Metrics for both horizontal and vertical layout.
+    Big(BigGlyphMetrics),
+    /// The position of the bottom left corner of an `sbix` image relative
+    /// to the glyph origin.
+    OriginOffset { x: i16, y: i16 },
+}
+
+/// Loader for embedded bitmap glyphs.
+///
+/// Glyphs are looked up in the `CBLC`/`CBDT`, `sbix` and `EBLC`/`EBDT`
+/// tables, in that order.
+#[derive(Clone)]
+pub struct BitmapGlyphs<'a> {
+    cbdt: Option<(Cblc<'a>, Cbdt<'a>)>,
+    sbix: Option<Sbix<'a>>,
+    ebdt: Option<(Eblc<'a>, Ebdt<'a>)>,
+}
+
+impl<'a> BitmapGlyphs<'a> {
+    /// Creates a new bitmap glyph loader for the given font.
+    ///
+    /// Missing tables are ignored.
+    pub fn new(font: &impl TableProvider<'a>) -> Result<Self, ReadError> {
+        let cbdt = match optional(font.cblc())? {
+            Some(cblc) => optional(font.cbdt())?.map(|cbdt| (cblc, cbdt)),
+            None => None,
+        };
+        let ebdt = match optional(font.eblc())? {
+            Some(eblc) => optional(font.ebdt())?.map(|ebdt| (eblc, ebdt)),
+            None => None,
+        };
+        Ok(Self {
+            cbdt,
+            sbix: optional(font.sbix())?,
+            ebdt,
+        })
+    }
+
+    /// Returns true if the font contains any bitmap tables.
+    pub fn is_empty(&self) -> bool {
+        self.cbdt.is_none() && self.sbix.is_none() && self.ebdt.is_none()
+    }
+
+    /// Returns the bitmap for the given glyph from the strike that best
+    /// matches `ppem`, or `None` if no strike has an image for the glyph.
+    ///
+    /// An exact match is preferred, followed by the smallest larger strike
+    /// and then the largest smaller strike. Only strikes containing the
+    /// glyph are considered.
+    pub fn bitmap_glyph(
+        &self,
+        glyph_id: GlyphId,
+        ppem: u16,
+    ) -> Result<Option<BitmapGlyph<'a>>, ReadError> {
+        if let Some((cblc, cbdt)) = &self.cbdt {
+            let location = best_location(cblc.offset_data(), cblc.bitmap_sizes(), glyph_id, ppem)?;
+            if let Some(location) = location {
+                return location.glyph(cbdt.offset_data()).map(Some);
+            }
+        }
+        if let Some(sbix) = &self.sbix {
+            if let Some(glyph) = sbix_glyph(sbix, glyph_id, ppem)? {
+                return Ok(Some(glyph));
+            }
+        }
+        if let Some((eblc, ebdt)) = &self.ebdt {
+            let location = best_location(eblc.offset_data(), eblc.bitmap_sizes(), glyph_id, ppem)?;
+            if let Some(location) = location {
+                return location.glyph(ebdt.offset_data()).map(Some);
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Returns a sort key for a strike of size `strike_ppem`; lower is better.
+fn strike_score(strike_ppem: u16, ppem: u16) -> (bool, u16) {
+    if strike_ppem >= ppem {
+        (false, strike_ppem - ppem)
+    } else {
+        (true, ppem - strike_ppem)
+    }
+}
+
+fn best_location(
+    offset_data: FontData,
+    sizes: &[BitmapSize],
+    glyph_id: GlyphId,
+    ppem: u16,
+) -> Result<Option<BitmapLocation>, ReadError> {
+    let mut best: Option<BitmapLocation> = None;
+    for size in sizes {
+        let score = strike_score(size.ppem_y().into(), ppem);
+        if matches!(&best, Some(best) if strike_score(best.ppem_y.into(), ppem) <= score) {
+            continue;
+        }
+        if let Some(location) = size.location(offset_data, glyph_id)? {
+            best = Some(location);
+        }
+    }
+    Ok(best)
+}
+
+fn sbix_glyph<'a>(
+    sbix: &Sbix<'a>,
+    glyph_id: GlyphId,
+    ppem: u16,
+) -> Result<Option<BitmapGlyph<'a>>, ReadError> {
+    const DUPE: Tag = Tag::new(b"dupe");
+    let mut best: Option<((bool, u16), u16, GlyphData<'a>)> = None;
+    for strike in sbix.strike() {
+        let strike = strike?;
+        let score = strike_score(strike.ppem(), ppem);
+        if matches!(&best, Some((best_score, ..)) if *best_score <= score) {
+            continue;
+        }
+        let mut glyph = match strike.glyph_data(glyph_id)? {
+            Some(glyph) => glyph,
+            None => continue,
+        };
+        // a 'dupe' glyph refers to the data of another glyph in the strike
+        if glyph.graphic_type() == DUPE {
+            let data = FontData::new(glyph.data());
+            glyph = match strike.glyph_data(GlyphId::new(data.read_at(0)?))? {
+                Some(glyph) if glyph.graphic_type() != DUPE => glyph,
+                _ => continue,
+            };
+        }
+        best = Some((score, strike.ppem(), glyph));
+    }
+    let Some((_, ppem, glyph)) = best else {
+        return Ok(None);
+    };
+    let data = match &glyph.graphic_type().to_be_bytes() {
+        b"png " => BitmapData::Png(glyph.data()),
+        b"jpg " => BitmapData::Jpeg(glyph.data()),
+        b"tiff" => BitmapData::Tiff(glyph.data()),
+        _ => BitmapData::Other {
+            graphic_type: glyph.graphic_type(),
+            data: glyph.data(),
+        },
+    };
+    Ok(Some(BitmapGlyph {
+        data,
+        metrics: BitmapMetrics::OriginOffset {
+            x: glyph.origin_offset_x(),
+            y: glyph.origin_offset_y(),
+        },
+        ppem_x: ppem,
+        ppem_y: ppem,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    /// Pushes a BitmapSize record with zeroed line metrics.
+    fn push_size(
+        buf: &mut BeBuffer,
+        list_offset: u32,
+        num_subtables: u32,
+        glyphs: [u16; 2],
+        ppem: u8,
+    ) {
+        buf.extend([list_offset, 0, num_subtables, 0]);
+        buf.extend([0u8; 24]);
+        buf.extend(glyphs);
+        buf.extend([ppem, ppem, 1, 1]);
+    }
+
+    /// An EBLC table with a 10ppem strike for glyphs 1-3 (glyph 2 has no
+    /// image) and a 20ppem strike for glyphs 1, 2 and 4.
+    fn test_eblc() -> BeBuffer {
+        let mut buf = BeBuffer::new();
+        buf.extend([2u16, 0]);
+        buf.push(2u32);
+        push_size(&mut buf, 104, 1, [1, 3], 10);
+        push_size(&mut buf, 136, 2, [1, 5], 20);
+        assert_eq!(buf.len(), 104);
+        // index subtable format 1, image format 17
+        buf.extend([1u16, 3, 0, 8]);
+        buf.extend([1u16, 17]);
+        buf.extend([4u32, 0, 12, 12, 24]);
+        assert_eq!(buf.len(), 136);
+        buf.extend([1u16, 2, 0, 16]);
+        buf.extend([4u16, 5, 0, 44]);
+        // index subtable format 5, image format 19
+        buf.extend([5u16, 19]);
+        buf.extend([28u32, 8]);
+        buf.extend([4u8, 4, 0, 4, 5, 0, 0, 0]);
+        buf.push(2u32);
+        buf.extend([1u16, 2]);
+        // index subtable format 4, image format 6
+        buf.extend([4u16, 6]);
+        buf.extend([44u32, 1]);
+        buf.extend([4u16, 0, 0, 10]);
+        buf
+    }
+
+    fn test_ebdt() -> BeBuffer {
+        let mut buf = BeBuffer::new();
+        buf.extend([2u16, 0]);
+        // glyphs 1 and 3 at 10ppem: small metrics and png data
+        for png in [[1u8, 2, 3], [4, 5, 6]] {
+            buf.extend([2u8, 3, 0, 2, 3]);
+            buf.push(3u32);
+            buf.extend(png);
+        }
+        // glyphs 1 and 2 at 20ppem: png data with metrics from the index
+        buf.extend([4u32, 0x0708090A, 4, 0x0B0C0D0E]);
+        // glyph 4 at 20ppem: big metrics and a byte aligned bitmap
+        buf.extend([1u8, 9, 0, 1, 10, 0, 0, 0, 0xFF, 0x80]);
+        buf
+    }
+
+    #[test]
+    fn locations() {
+        let buf = test_eblc();
+        let data = buf.font_data();
+        let eblc = crate::tables::eblc::Eblc::read(data).unwrap();
+        let sizes = eblc.bitmap_sizes();
+        let location = sizes[0].location(data, GlyphId::new(1)).unwrap().unwrap();
+        assert_eq!(location.image_format, 17);
+        assert_eq!((location.data_offset, location.data_size), (4, 12));
+        assert!(sizes[0].location(data, GlyphId::new(2)).unwrap().is_none());
+        assert!(sizes[0].location(data, GlyphId::new(4)).unwrap().is_none());
+        let location = sizes[1].location(data, GlyphId::new(2)).unwrap().unwrap();
+        assert_eq!((location.data_offset, location.data_size), (36, 8));
+        assert_eq!(location.metrics.unwrap().width(), 4);
+        let location = sizes[1].location(data, GlyphId::new(4)).unwrap().unwrap();
+        assert_eq!((location.data_offset, location.data_size), (44, 10));
+        // in range of the subtable, but not in its glyph array
+        assert!(sizes[1].location(data, GlyphId::new(5)).unwrap().is_none());
+    }
+
+    #[test]
+    fn strike_selection() {
+        let buf = test_eblc();
+        let eblc = crate::tables::eblc::Eblc::read(buf.font_data()).unwrap();
+        let best = |gid, ppem| {
+            best_location(
+                buf.font_data(),
+                eblc.bitmap_sizes(),
+                GlyphId::new(gid),
+                ppem,
+            )
+            .unwrap()
+            .map(|location| location.ppem_y)
+        };
+        assert_eq!(best(1, 10), Some(10));
+        assert_eq!(best(1, 15), Some(20));
+        assert_eq!(best(1, 30), Some(20));
+        assert_eq!(best(1, 5), Some(10));
+        // only the 10ppem strike has glyph 3
+        assert_eq!(best(3, 20), Some(10));
+        assert_eq!(best(5, 20), None);
+    }
+
+    #[test]
+    fn glyph_images() {
+        let eblc_buf = test_eblc();
+        let ebdt_buf = test_ebdt();
+        let eblc = crate::tables::eblc::Eblc::read(eblc_buf.font_data()).unwrap();
+        let glyph = |gid, ppem| {
+            best_location(
+                eblc_buf.font_data(),
+                eblc.bitmap_sizes(),
+                GlyphId::new(gid),
+                ppem,
+            )
+            .unwrap()
+            .unwrap()
+            .glyph(ebdt_buf.font_data())
+            .unwrap()
+        };
+
+        let glyph1 = glyph(1, 10);
+        assert!(matches!(glyph1.data, BitmapData::Png([1, 2, 3])));
+        let BitmapMetrics::Small(metrics) = glyph1.metrics else {
+            panic!("expected small metrics");
+        };
+        assert_eq!((metrics.width(), metrics.advance()), (3, 3));
+
+        let glyph1 = glyph(1, 20);
+        assert_eq!((glyph1.ppem_x, glyph1.ppem_y), (20, 20));
+        assert!(matches!(glyph1.data, BitmapData::Png([7, 8, 9, 10])));
+        assert!(matches!(glyph1.metrics, BitmapMetrics::Big(m) if m.hori_advance() == 5));
+
+        let glyph4 = glyph(4, 20);
+        assert!(matches!(
+            glyph4.data,
+            BitmapData::Bitmap {
+                data: [0xFF, 0x80],
+                bit_depth: 1,
+                byte_aligned: true
+            }
+        ));
+        assert!(matches!(glyph4.metrics, BitmapMetrics::Big(m) if m.width() == 9));
+    }
+
+    /// An sbix table for three glyphs with a 16ppem strike, where glyph 2 is
+    /// a dupe of glyph 1, and an empty 32ppem strike.
+    fn test_sbix() -> BeBuffer {
+        let mut buf = BeBuffer::new();
+        buf.extend([1u16, 1]);
+        buf.push(2u32);
+        buf.extend([16u32, 57]);
+        buf.extend([16u16, 72]);
+        buf.extend([20u32, 20, 31, 41]);
+        buf.extend([1i16, 2]);
+        buf.push(Tag::new(b"png"));
+        buf.extend([1u8, 2, 3]);
+        buf.extend([0i16, 0]);
+        buf.push(Tag::new(b"dupe"));
+        buf.push(1u16);
+        assert_eq!(buf.len(), 57);
+        buf.extend([32u16, 72]);
+        buf.extend([20u32, 20, 20, 20]);
+        buf
+    }
+
+    #[test]
+    fn sbix_strikes() {
+        let buf = test_sbix();
+        let sbix = Sbix::read_with_args(buf.font_data(), &3).unwrap();
+        let strike = sbix.strike().next().unwrap().unwrap();
+        assert!(strike.glyph_data(GlyphId::new(0)).unwrap().is_none());
+        let data = strike.glyph_data(GlyphId::new(1)).unwrap().unwrap();
+        assert_eq!(data.graphic_type(), Tag::new(b"png"));
+        assert_eq!(data.data(), [1, 2, 3]);
+        assert!(strike.glyph_data(GlyphId::new(3)).is_err());
+
+        // the 32ppem strike is empty, and the dupe resolves to glyph 1
+        let glyph = sbix_glyph(&sbix, GlyphId::new(2), 32).unwrap().unwrap();
+        assert_eq!(glyph.ppem_x, 16);
+        assert!(matches!(glyph.data, BitmapData::Png([1, 2, 3])));
+        assert!(matches!(
+            glyph.metrics,
+            BitmapMetrics::OriginOffset { x: 1, y: 2 }
+        ));
+        assert!(sbix_glyph(&sbix, GlyphId::new(0), 32).unwrap().is_none());
+    }
+}
@@ -0,0 +1,960 @@
+//! Walking the paint graphs of color glyphs.
+
+use core::{f32::consts::PI, ops::Mul, slice};
+
+use font_types::{F2Dot14, FWord, Fixed, GlyphId, Point, UfWord};
+
+use crate::{
+    layout::{DeltaSetIndex, DeltaSetIndexMap, ItemVariationStore},
+    tables::colr::{self, ClipBox, Colr, CompositeMode, Extend, Paint},
+    FontData, ReadError, TableProvider,
+};
+
+/// The maximum depth of nested paint tables.
+///
+/// Deeper nesting is treated as an error.
+pub const PAINT_RECURSION_LIMIT: usize = 64;
+
+/// The variation index base signifying that a paint has no variation data.
+const NO_VARIATION_INDEX: u32 = 0xFFFFFFFF;
+
+/// Interface for receiving the operations of a color glyph's paint graph.
+///
+/// Coordinates are in font units, with the y axis pointing up. Every push
+/// is balanced by the matching pop.
+pub trait PaintVisitor {
+    /// Pushes a transform, which applies to all operations until the
+    /// matching [`pop_transform`](Self::pop_transform).
+    ///
+    /// The transform is relative to any transform that is already active.
+    fn push_transform(&mut self, transform: Transform);
+
+    /// Pops the most recently pushed transform.
+    fn pop_transform(&mut self);
+
+    /// Pushes a clip to the outline of the given glyph.
+    fn push_clip_glyph(&mut self, glyph_id: GlyphId);
+
+    /// Pushes a clip to the given rectangle.
+    fn push_clip_box(&mut self, clip_box: ClipRect);
+
+    /// Pops the most recently pushed clip.
+    fn pop_clip(&mut self);
+
+    /// Fills the current clip region with the given brush.
+    fn fill(&mut self, brush: Brush<'_>);
+
+    /// Pushes a new, empty layer, which is composited onto the layer below
+    /// with the given mode when it is popped.
+    fn push_layer(&mut self, composite_mode: CompositeMode);
+
+    /// Pops the most recently pushed layer.
+    fn pop_layer(&mut self);
+}
+
+/// An affine transform.
+///
+/// A point `(x, y)` is mapped to `(xx * x + xy * y + dx, yx * x + yy * y + dy)`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transform {
+    pub xx: f32,
+    pub yx: f32,
+    pub xy: f32,
+    pub yy: f32,
+    pub dx: f32,
+    pub dy: f32,
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self::scale(1.0, 1.0)
+    }
+}
+
+impl Transform {
+    /// Creates a translation.
+    pub fn translate(dx: f32, dy: f32) -> Self {
+        Self {
+            dx,
+            dy,
+            ..Default::default()
+        }
+    }
+
+    /// Creates a scale.
+    pub fn scale(sx: f32, sy: f32) -> Self {
+        Self {
+            xx: sx,
+            yx: 0.0,
+            xy: 0.0,
+            yy: sy,
+            dx: 0.0,
+            dy: 0.0,
+        }
+    }
+
+    /// Creates a counter-clockwise rotation by the given angle, in radians.
+    pub fn rotate(angle: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self {
+            xx: cos,
+            yx: sin,
+            xy: -sin,
+            yy: cos,
+            ..Default::default()
+        }
+    }
+
+    /// Creates a skew by the given angles, in radians.
+    ///
+    /// Positive angles rotate the y axis (for `x_angle`) and the x axis (for
+    /// `y_angle`) counter-clockwise.
+    pub fn skew(x_angle: f32, y_angle: f32) -> Self {
+        Self {
+            yx: y_angle.tan(),
+            xy: -x_angle.tan(),
+            ..Default::default()
+        }
+    }
+
+    /// Returns this transform applied around the given center point.
+    pub fn around_center(self, center_x: f32, center_y: f32) -> Self {
+        Self::translate(center_x, center_y) * self * Self::translate(-center_x, -center_y)
+    }
+
+    /// Applies the transform to the given point.
+    pub fn transform_point(&self, point: Point<f32>) -> Point<f32> {
+        Point::new(
+            self.xx * point.x + self.xy * point.y + self.dx,
+            self.yx * point.x + self.yy * point.y + self.dy,
+        )
+    }
+}
+
+impl Mul for Transform {
+    type Output = Self;
+
+    /// Returns the transform that applies `other` followed by `self`.
+    fn mul(self, other: Self) -> Self {
+        Self {
+            xx: self.xx * other.xx + self.xy * other.yx,
+            yx: self.yx * other.xx + self.yy * other.yx,
+            xy: self.xx * other.xy + self.xy * other.yy,
+            yy: self.yx * other.xy + self.yy * other.yy,
+            dx: self.xx * other.dx + self.xy * other.dy + self.dx,
+            dy: self.yx * other.dx + self.yy * other.dy + self.dy,
+        }
+    }
+}
+
+/// A rectangular clip region.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ClipRect {
+    pub x_min: f32,
+    pub y_min: f32,
+    pub x_max: f32,
+    pub y_max: f32,
+}
+
+/// The source of color for a fill operation.
+///
+/// Colors are given as indices into the palettes of the `CPAL` table, where
+/// 0xFFFF is the text foreground color. Alpha values scale the alpha of the
+/// palette color.
+#[derive(Clone, Debug)]
+pub enum Brush<'a> {
+    /// A solid color.
+    Solid { palette_index: u16, alpha: f32 },
+    /// A gradient along the line from `p0` to `p1`, rotated to be
+    /// perpendicular to the line from `p0` to `p2`.
+    LinearGradient {
+        p0: Point<f32>,
+        p1: Point<f32>,
+        p2: Point<f32>,
+        color_stops: ColorStops<'a>,
+        extend: Extend,
+    },
+    /// A gradient between two circles.
+    RadialGradient {
+        c0: Point<f32>,
+        r0: f32,
+        c1: Point<f32>,
+        r1: f32,
+        color_stops: ColorStops<'a>,
+        extend: Extend,
+    },
+    /// A gradient around a center point, with angles in counter-clockwise
+    /// degrees.
+    SweepGradient {
+        c0: Point<f32>,
+        start_angle: f32,
+        end_angle: f32,
+        color_stops: ColorStops<'a>,
+        extend: Extend,
+    },
+}
+
+/// A color stop of a gradient, with any variations applied.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ColorStop {
+    /// The position of the stop on the color line.
+    pub offset: f32,
+    pub palette_index: u16,
+    pub alpha: f32,
+}
+
+/// An iterator over the color stops of a gradient.
+///
+/// The stops are yielded in the order they are stored, which is not
+/// necessarily sorted by offset.
+#[derive(Clone)]
+pub struct ColorStops<'a>(Stops<'a>);
+
+#[derive(Clone)]
+enum Stops<'a> {
+    Static(slice::Iter<'a, colr::ColorStop>),
+    Variable(slice::Iter<'a, colr::VarColorStop>, VarContext<'a>),
+}
+
+impl<'a> Iterator for ColorStops<'a> {
+    type Item = Result<ColorStop, ReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.0 {
+            Stops::Static(stops) => {
+                let stop = stops.next()?;
+                Some(Ok(ColorStop {
+                    offset: stop.stop_offset().to_f32(),
+                    palette_index: stop.palette_index(),
+                    alpha: stop.alpha().to_f32(),
+                }))
+            }
+            Stops::Variable(stops, var) => {
+                let stop = stops.next()?;
+                Some(
+                    var.deltas(stop.var_index_base())
+                        .map(|[offset, alpha]| ColorStop {
+                            offset: f2dot14(stop.stop_offset(), offset),
+                            palette_index: stop.palette_index(),
+                            alpha: f2dot14(stop.alpha(), alpha),
+                        }),
+                )
+            }
+        }
+    }
+}
+
+impl core::fmt::Debug for ColorStops<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+/// Walks the paint graphs of the color glyphs in the `COLR` table.
+#[derive(Clone)]
+pub struct ColorGlyphs<'a> {
+    colr: Colr<'a>,
+}
+
+impl<'a> ColorGlyphs<'a> {
+    /// Creates a new color glyph loader for the given font.
+    ///
+    /// The `COLR` table is required.
+    pub fn new(font: &impl TableProvider<'a>) -> Result<Self, ReadError> {
+        Ok(Self::from_table(&font.colr()?))
+    }
+
+    /// Creates a new color glyph loader for the given table.
+    pub fn from_table(colr: &Colr<'a>) -> Self {
+        Self { colr: *colr }
+    }
+
+    /// Walks the paint graph of the given glyph at the given normalized
+    /// variation coordinates, passing each operation to the visitor.
+    ///
+    /// Glyphs in the version 1 base glyph list take precedence over the
+    /// version 0 layer records. Returns `false` if the glyph has no color
+    /// data.
+    ///
+    /// Cycles in the paint graph, and nesting deeper than
+    /// [`PAINT_RECURSION_LIMIT`], are reported as errors.
+    pub fn paint_glyph(
+        &self,
+        glyph_id: GlyphId,
+        coords: &[F2Dot14],
+        visitor: &mut impl PaintVisitor,
+    ) -> Result<bool, ReadError> {
+        if let Some(paint) = self.colr.v1_base_glyph(glyph_id)? {
+            let mut walker = Walker {
+                colr: self.colr,
+                var: VarContext::new(&self.colr, coords)?,
+                active: Vec::new(),
+            };
+            walker.walk_base_glyph(glyph_id, &paint, visitor)?;
+            return Ok(true);
+        }
+        if let Some(layers) = self.colr.v0_base_glyph(glyph_id)? {
+            for index in layers {
+                let (glyph_id, palette_index) = self.colr.v0_layer(index)?;
+                visitor.push_clip_glyph(glyph_id);
+                visitor.fill(Brush::Solid {
+                    palette_index,
+                    alpha: 1.0,
+                });
+                visitor.pop_clip();
+            }
+            return Ok(true);
+        }
+        Ok(false)
+    }
+}
+
+/// The state for walking a single paint graph.
+struct Walker<'a> {
+    colr: Colr<'a>,
+    var: VarContext<'a>,
+    /// The addresses of the layer and glyph reference paints that are
+    /// currently being walked, for detecting cycles.
+    active: Vec<usize>,
+}
+
+impl<'a> Walker<'a> {
+    /// Walks the root paint of a base glyph, clipped to its clip box.
+    fn walk_base_glyph(
+        &mut self,
+        glyph_id: GlyphId,
+        paint: &Paint<'a>,
+        visitor: &mut impl PaintVisitor,
+    ) -> Result<(), ReadError> {
+        match self.colr.v1_clip_box(glyph_id)? {
+            Some(clip_box) => {
+                visitor.push_clip_box(self.clip_rect(&clip_box)?);
+                self.walk(paint, visitor)?;
+                visitor.pop_clip();
+                Ok(())
+            }
+            None => self.walk(paint, visitor),
+        }
+    }
+
+    fn walk(
+        &mut self,
+        paint: &Paint<'a>,
+        visitor: &mut impl PaintVisitor,
+    ) -> Result<(), ReadError> {
+        if self.active.len() >= PAINT_RECURSION_LIMIT {
+            return Err(ReadError::MalformedData("COLR paint graph is too deep"));
+        }
+        // every paint is tracked so that the depth is bounded, but only the
+        // paints that reference other glyphs or layers can form a cycle
+        let address = match paint {
+            Paint::ColrLayers(layers) => Some(address(layers.offset_data())),
+            Paint::ColrGlyph(glyph) => Some(address(glyph.offset_data())),
+            _ => None,
+        };
+        if let Some(address) = address {
+            if self.active.contains(&address) {
+                return Err(ReadError::MalformedData("COLR paint graph has a cycle"));
+            }
+        }
+        self.active.push(address.unwrap_or(0));
+        let result = self.walk_paint(paint, visitor);
+        self.active.pop();
+        result
+    }
+
+    fn walk_paint(
+        &mut self,
+        paint: &Paint<'a>,
+        visitor: &mut impl PaintVisitor,
+    ) -> Result<(), ReadError> {
+        match paint {
+            Paint::ColrLayers(layers) => {
+                let first = layers.first_layer_index();
+                for i in 0..layers.num_layers() as u32 {
+                    let index = first.checked_add(i).ok_or(ReadError::OutOfBounds)?;
+                    self.walk(&self.colr.v1_layer(index)?, visitor)?;
+                }
+            }
+            Paint::Solid(solid) => visitor.fill(Brush::Solid {
+                palette_index: solid.palette_index(),
+                alpha: solid.alpha().to_f32(),
+            }),
+            Paint::VarSolid(solid) => {
+                let [alpha] = self.var.deltas(solid.var_index_base())?;
+                visitor.fill(Brush::Solid {
+                    palette_index: solid.palette_index(),
+                    alpha: f2dot14(solid.alpha(), alpha),
+                })
+            }
+            Paint::LinearGradient(gradient) => {
+                let color_line = gradient.color_line()?;
+                visitor.fill(Brush::LinearGradient {
+                    p0: point(gradient.x0(), gradient.y0(), [0.0; 2]),
+                    p1: point(gradient.x1(), gradient.y1(), [0.0; 2]),
+                    p2: point(gradient.x2(), gradient.y2(), [0.0; 2]),
+                    color_stops: ColorStops(Stops::Static(color_line.color_stops().iter())),
+                    extend: color_line.extend(),
+                })
+            }
+            Paint::VarLinearGradient(gradient) => {
+                let color_line = gradient.color_line()?;
+                let [x0, y0, x1, y1, x2, y2] = self.var.deltas(gradient.var_index_base())?;
+                visitor.fill(Brush::LinearGradient {
+                    p0: point(gradient.x0(), gradient.y0(), [x0, y0]),
+                    p1: point(gradient.x1(), gradient.y1(), [x1, y1]),
+                    p2: point(gradient.x2(), gradient.y2(), [x2, y2]),
+                    color_stops: self.var_color_stops(&color_line),
+                    extend: color_line.extend(),
+                })
+            }
+            Paint::RadialGradient(gradient) => {
+                let color_line = gradient.color_line()?;
+                visitor.fill(Brush::RadialGradient {
+                    c0: point(gradient.x0(), gradient.y0(), [0.0; 2]),
+                    r0: ufword(gradient.radius0(), 0.0),
+                    c1: point(gradient.x1(), gradient.y1(), [0.0; 2]),
+                    r1: ufword(gradient.radius1(), 0.0),
+                    color_stops: ColorStops(Stops::Static(color_line.color_stops().iter())),
+                    extend: color_line.extend(),
+                })
+            }
+            Paint::VarRadialGradient(gradient) => {
+                let color_line = gradient.color_line()?;
+                let [x0, y0, r0, x1, y1, r1] = self.var.deltas(gradient.var_index_base())?;
+                visitor.fill(Brush::RadialGradient {
+                    c0: point(gradient.x0(), gradient.y0(), [x0, y0]),
+                    r0: ufword(gradient.radius0(), r0),
+                    c1: point(gradient.x1(), gradient.y1(), [x1, y1]),
+                    r1: ufword(gradient.radius1(), r1),
+                    color_stops: self.var_color_stops(&color_line),
+                    extend: color_line.extend(),
+                })
+            }
+            Paint::SweepGradient(gradient) => {
+                let color_line = gradient.color_line()?;
+                visitor.fill(Brush::SweepGradient {
+                    c0: point(gradient.center_x(), gradient.center_y(), [0.0; 2]),
+                    start_angle: f2dot14(gradient.start_angle(), 0.0) * 180.0,
+                    end_angle: f2dot14(gradient.end_angle(), 0.0) * 180.0,
+                    color_stops: ColorStops(Stops::Static(color_line.color_stops().iter())),
+                    extend: color_line.extend(),
+                })
+            }
+            Paint::VarSweepGradient(gradient) => {
+                let color_line = gradient.color_line()?;
+                let [x, y, start, end] = self.var.deltas(gradient.var_index_base())?;
+                visitor.fill(Brush::SweepGradient {
+                    c0: point(gradient.center_x(), gradient.center_y(), [x, y]),
+                    start_angle: f2dot14(gradient.start_angle(), start) * 180.0,
+                    end_angle: f2dot14(gradient.end_angle(), end) * 180.0,
+                    color_stops: self.var_color_stops(&color_line),
+                    extend: color_line.extend(),
+                })
+            }
+            Paint::Glyph(glyph) => {
+                visitor.push_clip_glyph(glyph.glyph_id());
+                self.walk(&glyph.paint()?, visitor)?;
+                visitor.pop_clip();
+            }
+            Paint::ColrGlyph(glyph) => {
+                if let Some(paint) = self.colr.v1_base_glyph(glyph.glyph_id())? {
+                    self.walk_base_glyph(glyph.glyph_id(), &paint, visitor)?;
+                }
+            }
+            Paint::Transform(paint) => {
+                let affine = paint.transform()?;
+                let transform = Transform {
+                    xx: fixed(affine.xx(), 0.0),
+                    yx: fixed(affine.yx(), 0.0),
+                    xy: fixed(affine.xy(), 0.0),
+                    yy: fixed(affine.yy(), 0.0),
+                    dx: fixed(affine.dx(), 0.0),
+                    dy: fixed(affine.dy(), 0.0),
+                };
+                self.walk_transformed(transform, &paint.paint()?, visitor)?;
+            }
+            Paint::VarTransform(paint) => {
+                let affine = paint.transform()?;
+                let [xx, yx, xy, yy, dx, dy] = self.var.deltas(affine.var_index_base())?;
+                let transform = Transform {
+                    xx: fixed(affine.xx(), xx),
+                    yx: fixed(affine.yx(), yx),
+                    xy: fixed(affine.xy(), xy),
+                    yy: fixed(affine.yy(), yy),
+                    dx: fixed(affine.dx(), dx),
+                    dy: fixed(affine.dy(), dy),
+                };
+                self.walk_transformed(transform, &paint.paint()?, visitor)?;
+            }
+            Paint::Translate(translate) => {
+                let transform =
+                    Transform::translate(fword(translate.dx(), 0.0), fword(translate.dy(), 0.0));
+                self.walk_transformed(transform, &translate.paint()?, visitor)?;
+            }
+            Paint::VarTranslate(translate) => {
+                let [dx, dy] = self.var.deltas(translate.var_index_base())?;
+                let transform =
+                    Transform::translate(fword(translate.dx(), dx), fword(translate.dy(), dy));
+                self.walk_transformed(transform, &translate.paint()?, visitor)?;
+            }
+            Paint::Scale(scale) => {
+                let transform =
+                    Transform::scale(f2dot14(scale.scale_x(), 0.0), f2dot14(scale.scale_y(), 0.0));
+                self.walk_transformed(transform, &scale.paint()?, visitor)?;
+            }
+            Paint::VarScale(scale) => {
+                let [x, y] = self.var.deltas(scale.var_index_base())?;
+                let transform =
+                    Transform::scale(f2dot14(scale.scale_x(), x), f2dot14(scale.scale_y(), y));
+                self.walk_transformed(transform, &scale.paint()?, visitor)?;
+            }
+            Paint::ScaleAroundCenter(scale) => {
+                let transform =
+                    Transform::scale(f2dot14(scale.scale_x(), 0.0), f2dot14(scale.scale_y(), 0.0))
+                        .around_center(fword(scale.center_x(), 0.0), fword(scale.center_y(), 0.0));
+                self.walk_transformed(transform, &scale.paint()?, visitor)?;
+            }
+            Paint::VarScaleAroundCenter(scale) => {
+                let [x, y, cx, cy] = self.var.deltas(scale.var_index_base())?;
+                let transform =
+                    Transform::scale(f2dot14(scale.scale_x(), x), f2dot14(scale.scale_y(), y))
+                        .around_center(fword(scale.center_x(), cx), fword(scale.center_y(), cy));
+                self.walk_transformed(transform, &scale.paint()?, visitor)?;
+            }
+            Paint::ScaleUniform(scale) => {
+                let s = f2dot14(scale.scale(), 0.0);
+                self.walk_transformed(Transform::scale(s, s), &scale.paint()?, visitor)?;
+            }
+            Paint::VarScaleUniform(scale) => {
+                let [delta] = self.var.deltas(scale.var_index_base())?;
+                let s = f2dot14(scale.scale(), delta);
+                self.walk_transformed(Transform::scale(s, s), &scale.paint()?, visitor)?;
+            }
+            Paint::ScaleUniformAroundCenter(scale) => {
+                let s = f2dot14(scale.scale(), 0.0);
+                let transform = Transform::scale(s, s)
+                    .around_center(fword(scale.center_x(), 0.0), fword(scale.center_y(), 0.0));
+                self.walk_transformed(transform, &scale.paint()?, visitor)?;
+            }
+            Paint::VarScaleUniformAroundCenter(scale) => {
+                let [delta, cx, cy] = self.var.deltas(scale.var_index_base())?;
+                let s = f2dot14(scale.scale(), delta);
+                let transform = Transform::scale(s, s)
+                    .around_center(fword(scale.center_x(), cx), fword(scale.center_y(), cy));
+                self.walk_transformed(transform, &scale.paint()?, visitor)?;
+            }
+            Paint::Rotate(rotate) => {
+                let transform = Transform::rotate(f2dot14(rotate.angle(), 0.0) * PI);
+                self.walk_transformed(transform, &rotate.paint()?, visitor)?;
+            }
+            Paint::VarRotate(rotate) => {
+                let [angle] = self.var.deltas(rotate.var_index_base())?;
+                let transform = Transform::rotate(f2dot14(rotate.angle(), angle) * PI);
+                self.walk_transformed(transform, &rotate.paint()?, visitor)?;
+            }
+            Paint::RotateAroundCenter(rotate) => {
+                let transform = Transform::rotate(f2dot14(rotate.angle(), 0.0) * PI)
+                    .around_center(fword(rotate.center_x(), 0.0), fword(rotate.center_y(), 0.0));
+                self.walk_transformed(transform, &rotate.paint()?, visitor)?;
+            }
+            Paint::VarRotateAroundCenter(rotate) => {
+                let [angle, cx, cy] = self.var.deltas(rotate.var_index_base())?;
+                let transform = Transform::rotate(f2dot14(rotate.angle(), angle) * PI)
+                    .around_center(fword(rotate.center_x(), cx), fword(rotate.center_y(), cy));
+                self.walk_transformed(transform, &rotate.paint()?, visitor)?;
+            }
+            Paint::Skew(skew) => {
+                let transform = Transform::skew(
+                    f2dot14(skew.x_skew_angle(), 0.0) * PI,
+                    f2dot14(skew.y_skew_angle(), 0.0) * PI,
+                );
+                self.walk_transformed(transform, &skew.paint()?, visitor)?;
+            }
+            Paint::VarSkew(skew) => {
+                let [x, y] = self.var.deltas(skew.var_index_base())?;
+                let transform = Transform::skew(
+                    f2dot14(skew.x_skew_angle(), x) * PI,
+                    f2dot14(skew.y_skew_angle(), y) * PI,
+                );
+                self.walk_transformed(transform, &skew.paint()?, visitor)?;
+            }
+            Paint::SkewAroundCenter(skew) => {
+                let transform = Transform::skew(
+                    f2dot14(skew.x_skew_angle(), 0.0) * PI,
+                    f2dot14(skew.y_skew_angle(), 0.0) * PI,
+                )
+                .around_center(fword(skew.center_x(), 0.0), fword(skew.center_y(), 0.0));
+                self.walk_transformed(transform, &skew.paint()?, visitor)?;
+            }
+            Paint::VarSkewAroundCenter(skew) => {
+                let [x, y, cx, cy] = self.var.deltas(skew.var_index_base())?;
+                let transform = Transform::skew(
+                    f2dot14(skew.x_skew_angle(), x) * PI,
+                    f2dot14(skew.y_skew_angle(), y) * PI,
+                )
+                .around_center(fword(skew.center_x(), cx), fword(skew.center_y(), cy));
+                self.walk_transformed(transform, &skew.paint()?, visitor)?;
+            }
+            Paint::Composite(composite) => {
+                // the backdrop and source are isolated in their own layer so
+                // that the source is only composited with the backdrop
+                visitor.push_layer(CompositeMode::SrcOver);
+                self.walk(&composite.backdrop_paint()?, visitor)?;
+                visitor.push_layer(composite.composite_mode());
+                self.walk(&composite.source_paint()?, visitor)?;
+                visitor.pop_layer();
+                visitor.pop_layer();
+            }
+        }
+        Ok(())
+    }
+
+    fn walk_transformed(
+        &mut self,
+        transform: Transform,
+        paint: &Paint<'a>,
+        visitor: &mut impl PaintVisitor,
+    ) -> Result<(), ReadError> {
+        visitor.push_transform(transform);
+        self.walk(paint, visitor)?;
+        visitor.pop_transform();
+        Ok(())
+    }
+
+    fn var_color_stops(&self, color_line: &colr::VarColorLine<'a>) -> ColorStops<'a> {
+        ColorStops(Stops::Variable(
+            color_line.color_stops().iter(),
+            self.var.clone(),
+        ))
+    }
+
+    fn clip_rect(&self, clip_box: &ClipBox) -> Result<ClipRect, ReadError> {
+        Ok(match clip_box {
+            ClipBox::Format1(clip) => ClipRect {
+                x_min: fword(clip.x_min(), 0.0),
+                y_min: fword(clip.y_min(), 0.0),
+                x_max: fword(clip.x_max(), 0.0),
+                y_max: fword(clip.y_max(), 0.0),
+            },
+            ClipBox::Format2(clip) => {
+                let [x_min, y_min, x_max, y_max] = self.var.deltas(clip.var_index_base())?;
+                ClipRect {
+                    x_min: fword(clip.x_min(), x_min),
+                    y_min: fword(clip.y_min(), y_min),
+                    x_max: fword(clip.x_max(), x_max),
+                    y_max: fword(clip.y_max(), y_max),
+                }
+            }
+        })
+    }
+}
+
+/// The variation data for computing the deltas of variable paints.
+#[derive(Clone)]
+struct VarContext<'a> {
+    map: Option<DeltaSetIndexMap<'a>>,
+    /// This is `None` if the font has no variations, or no coordinates were
+    /// given.
+    store: Option<ItemVariationStore<'a>>,
+    coords: &'a [F2Dot14],
+}
+
+impl<'a> VarContext<'a> {
+    fn new(colr: &Colr<'a>, coords: &'a [F2Dot14]) -> Result<Self, ReadError> {
+        let store = if coords.is_empty() {
+            None
+        } else {
+            colr.item_variation_store().transpose()?
+        };
+        Ok(Self {
+            map: colr.var_index_map().transpose()?,
+            store,
+            coords,
+        })
+    }
+
+    /// Returns the deltas for `N` consecutive variation indices, starting at
+    /// the given base, in the units of the stored deltas.
+    fn deltas<const N: usize>(&self, var_index_base: u32) -> Result<[f32; N], ReadError> {
+        let mut deltas = [0.0; N];
+        let store = match &self.store {
+            Some(store) if var_index_base != NO_VARIATION_INDEX => store,
+            _ => return Ok(deltas),
+        };
+        for (i, delta) in deltas.iter_mut().enumerate() {
+            let index = var_index_base
+                .checked_add(i as u32)
+                .ok_or(ReadError::OutOfBounds)?;
+            // without a map, the index is split into the outer and inner
+            // indices directly
+            let DeltaSetIndex { outer, inner } = match &self.map {
+                Some(map) => map.get(index)?,
+                None => DeltaSetIndex {
+                    outer: (index >> 16) as u16,
+                    inner: index as u16,
+                },
+            };
+            if outer == 0xFFFF && inner == 0xFFFF {
+                continue;
+            }
+            *delta = store.compute_delta(outer, inner, self.coords)?.to_f64() as f32;
+        }
+        Ok(deltas)
+    }
+}
+
+/// The address of a table, used to identify it during traversal.
+fn address(data: FontData) -> usize {
+    data.as_bytes().as_ptr() as usize
+}
+
+fn fword(value: FWord, delta: f32) -> f32 {
+    value.to_i16() as f32 + delta
+}
+
+fn ufword(value: UfWord, delta: f32) -> f32 {
+    value.to_u16() as f32 + delta
+}
+
+fn point(x: FWord, y: FWord, delta: [f32; 2]) -> Point<f32> {
+    Point::new(fword(x, delta[0]), fword(y, delta[1]))
+}
+
+/// Applies a delta in units of 1/16384.
+fn f2dot14(value: F2Dot14, delta: f32) -> f32 {
+    value.to_f32() + delta / 16384.0
+}
+
+/// Applies a delta in units of 1/65536.
+fn fixed(value: Fixed, delta: f32) -> f32 {
+    value.to_f64() as f32 + delta / 65536.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_helpers::BeBuffer, FontRead};
+    use font_types::Uint24;
+
+    /// A version 1 table with:
+    ///
+    /// - glyph 1: two layers, a variable solid clipped to glyph 10, and a
+    ///   linear gradient clipped to glyph 11 and rotated around (100, 0)
+    /// - glyph 2: a reference to itself
+    /// - glyph 3: a version 0 glyph with a single layer
+    fn test_colr() -> BeBuffer {
+        let mut buf = BeBuffer::new();
+        buf.extend([1u16, 1]);
+        // base glyph records, layer records, num layer records
+        buf.extend([34u32, 40]);
+        buf.push(1u16);
+        // base glyph list, layer list, clip list, var index map, var store
+        buf.extend([44u32, 69, 143, 0, 164]);
+        assert_eq!(buf.len(), 34);
+        buf.extend([3u16, 0, 1]);
+        buf.extend([12u16, 5]);
+        assert_eq!(buf.len(), 44);
+
+        // base glyph list
+        buf.push(2u32);
+        buf.push(1u16);
+        buf.push(16u32);
+        buf.push(2u16);
+        buf.push(22u32);
+        // PaintColrLayers
+        buf.extend([1u8, 2]);
+        buf.push(0u32);
+        // PaintColrGlyph
+        buf.push(11u8);
+        buf.push(2u16);
+        assert_eq!(buf.len(), 69);
+
+        // layer list
+        buf.push(2u32);
+        buf.extend([12u32, 27]);
+        // PaintGlyph -> PaintVarSolid
+        buf.push(10u8);
+        buf.push(Uint24::new(6));
+        buf.push(10u16);
+        buf.push(3u8);
+        buf.push(2u16);
+        buf.push(F2Dot14::from_f32(1.0));
+        buf.push(0u32);
+        assert_eq!(buf.len(), 96);
+        // PaintRotateAroundCenter -> PaintGlyph -> PaintLinearGradient
+        buf.push(26u8);
+        buf.push(Uint24::new(10));
+        buf.push(F2Dot14::from_f32(0.5));
+        buf.extend([100i16, 0]);
+        buf.push(10u8);
+        buf.push(Uint24::new(6));
+        buf.push(11u16);
+        buf.push(4u8);
+        buf.push(Uint24::new(16));
+        buf.extend([0i16, 0, 100, 0, 0, 100]);
+        // color line
+        buf.push(1u8);
+        buf.push(2u16);
+        buf.push(F2Dot14::from_f32(0.0));
+        buf.push(0u16);
+        buf.push(F2Dot14::from_f32(1.0));
+        buf.push(F2Dot14::from_f32(1.0));
+        buf.push(1u16);
+        buf.push(F2Dot14::from_f32(0.5));
+        assert_eq!(buf.len(), 143);
+
+        // clip list, with a clip box for glyph 1
+        buf.push(1u8);
+        buf.push(1u32);
+        buf.extend([1u16, 1]);
+        buf.push(Uint24::new(12));
+        buf.push(1u8);
+        buf.extend([0i16, 0, 100, 100]);
+        assert_eq!(buf.len(), 164);
+
+        // item variation store with a single region peaking at 1.0, and a
+        // delta of -0.5 for the alpha of the solid
+        buf.push(1u16);
+        buf.push(12u32);
+        buf.push(1u16);
+        buf.push(22u32);
+        buf.extend([1u16, 1]);
+        buf.extend([0u16, 0x4000, 0x4000]);
+        buf.extend([1u16, 1, 1, 0]);
+        buf.push(-8192i16);
+        buf
+    }
+
+    /// Records the visited operations as strings.
+    #[derive(Default)]
+    struct Recorder(Vec<String>);
+
+    /// Rounds away float noise, and negative zeros.
+    fn round(value: f32) -> f32 {
+        (value * 1000.0).round() / 1000.0 + 0.0
+    }
+
+    impl PaintVisitor for Recorder {
+        fn push_transform(&mut self, t: Transform) {
+            let values = [t.xx, t.yx, t.xy, t.yy, t.dx, t.dy].map(round);
+            self.0.push(format!("push_transform {values:?}"));
+        }
+
+        fn pop_transform(&mut self) {
+            self.0.push("pop_transform".into());
+        }
+
+        fn push_clip_glyph(&mut self, glyph_id: GlyphId) {
+            self.0
+                .push(format!("push_clip_glyph {}", glyph_id.to_u16()));
+        }
+
+        fn push_clip_box(&mut self, clip: ClipRect) {
+            let values = [clip.x_min, clip.y_min, clip.x_max, clip.y_max];
+            self.0.push(format!("push_clip_box {values:?}"));
+        }
+
+        fn pop_clip(&mut self) {
+            self.0.push("pop_clip".into());
+        }
+
+        fn fill(&mut self, brush: Brush<'_>) {
+            let op = match brush {
+                Brush::Solid {
+                    palette_index,
+                    alpha,
+                } => format!("solid {palette_index} {alpha}"),
+                Brush::LinearGradient {
+                    p0,
+                    p1,
+                    p2,
+                    color_stops,
+                    extend,
+                } => {
+                    let stops = color_stops
+                        .map(|stop| {
+                            let stop = stop.unwrap();
+                            format!("{}:{}@{}", stop.offset, stop.palette_index, stop.alpha)
+                        })
+                        .collect::<Vec<_>>();
+                    let points = [p0, p1, p2].map(|p| (p.x, p.y));
+                    format!("linear {points:?} {extend:?} {stops:?}")
+                }
+                other => format!("{other:?}"),
+            };
+            self.0.push(format!("fill {op}"));
+        }
+
+        fn push_layer(&mut self, composite_mode: CompositeMode) {
+            self.0.push(format!("push_layer {composite_mode:?}"));
+        }
+
+        fn pop_layer(&mut self) {
+            self.0.push("pop_layer".into());
+        }
+    }
+
+    fn paint(colr: &ColorGlyphs, gid: u16, coords: &[F2Dot14]) -> Result<Vec<String>, ReadError> {
+        let mut recorder = Recorder::default();
+        let found = colr.paint_glyph(GlyphId::new(gid), coords, &mut recorder)?;
+        assert!(found || recorder.0.is_empty());
+        Ok(recorder.0)
+    }
+
+    #[test]
+    fn paint_graph() {
+        let buf = test_colr();
+        let colr = Colr::read(buf.font_data()).unwrap();
+        let glyphs = ColorGlyphs::from_table(&colr);
+        let expected = |alpha: f32| {
+            vec![
+                "push_clip_box [0.0, 0.0, 100.0, 100.0]".to_string(),
+                "push_clip_glyph 10".into(),
+                format!("fill solid 2 {alpha}"),
+                "pop_clip".into(),
+                "push_transform [0.0, 1.0, -1.0, 0.0, 100.0, -100.0]".into(),
+                "push_clip_glyph 11".into(),
+                "fill linear [(0.0, 0.0), (100.0, 0.0), (0.0, 100.0)] Repeat \
+                 [\"0:0@1\", \"1:1@0.5\"]"
+                    .into(),
+                "pop_clip".into(),
+                "pop_transform".into(),
+                "pop_clip".into(),
+            ]
+        };
+        assert_eq!(paint(&glyphs, 1, &[]).unwrap(), expected(1.0));
+        let coords = [F2Dot14::from_f32(1.0)];
+        assert_eq!(paint(&glyphs, 1, &coords).unwrap(), expected(0.5));
+
+        assert_eq!(
+            paint(&glyphs, 3, &[]).unwrap(),
+            ["push_clip_glyph 12", "fill solid 5 1", "pop_clip"]
+        );
+        assert!(paint(&glyphs, 4, &[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn paint_graph_cycle() {
+        let buf = test_colr();
+        let colr = Colr::read(buf.font_data()).unwrap();
+        let glyphs = ColorGlyphs::from_table(&colr);
+        assert!(matches!(
+            paint(&glyphs, 2, &[]),
+            Err(ReadError::MalformedData(_))
+        ));
+    }
+
+    #[test]
+    fn transforms() {
+        let p = Point::new(1.0, 0.0);
+        let rotated = Transform::rotate(PI / 2.0).transform_point(p);
+        assert_eq!((round(rotated.x), round(rotated.y)), (0.0, 1.0));
+        let skewed = Transform::skew(PI / 4.0, 0.0).transform_point(Point::new(0.0, 1.0));
+        assert_eq!((round(skewed.x), round(skewed.y)), (-1.0, 1.0));
+        let scaled = Transform::scale(2.0, 3.0)
+            .around_center(1.0, 1.0)
+            .transform_point(Point::new(2.0, 2.0));
+        assert_eq!(scaled, Point::new(3.0, 4.0));
+    }
+}
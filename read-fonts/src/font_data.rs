@@ -24,6 +24,7 @@ pub struct FontData<'a> {
 /// # Note
 ///
 /// call `finish` when you're done to ensure you're in bounds
+#[derive(Clone)]
 pub struct Cursor<'a> {
     pos: usize,
     data: FontData<'a>,
@@ -210,7 +211,7 @@ impl<'a> Cursor<'a> {
         temp
     }
 
-    /// read a value, validating it with the provided function if successful.
+    // read a value, validating it with the provided function if successful.
     //pub(crate) fn read_validate<T, F>(&mut self, f: F) -> Result<T, ReadError>
     //where
     //T: ReadScalar,
@@ -239,6 +240,11 @@ impl<'a> Cursor<'a> {
         self.data.check_in_bounds(self.pos).map(|_| self.pos)
     }
 
+    // used when computing the length of arrays of variable-length items
+    pub(crate) fn remaining(&self) -> Option<FontData<'a>> {
+        self.data.split_off(self.pos)
+    }
+
     // used when handling fields with an implicit length, which must be at the
     // end of a table.
     pub(crate) fn remaining_bytes(&self) -> usize {
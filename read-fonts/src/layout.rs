@@ -1,8 +1,10 @@
 //! OpenType Layout
 
+pub mod base;
 pub mod gdef;
 pub mod gpos;
 pub mod gsub;
+pub mod jstf;
 
 #[cfg(test)]
 #[path = "./tests/layout.rs"]
@@ -87,6 +89,27 @@ impl CoverageTable<'_> {
             .flatten()
             .chain(iter2.into_iter().flatten())
     }
+
+    /// Returns the coverage index of the given glyph, or `None` if the glyph
+    /// is not covered.
+    pub fn get(&self, glyph_id: GlyphId) -> Option<u16> {
+        match self {
+            CoverageTable::Format1(t) => t
+                .glyph_array()
+                .binary_search_by_key(&glyph_id, |g| g.get())
+                .ok()
+                .map(|ix| ix as u16),
+            CoverageTable::Format2(t) => {
+                let records = t.range_records();
+                let ix = records.partition_point(|rec| rec.end_glyph_id() < glyph_id);
+                let record = records
+                    .get(ix)
+                    .filter(|rec| rec.start_glyph_id() <= glyph_id)?;
+                let delta = glyph_id.to_u16() - record.start_glyph_id().to_u16();
+                Some(record.start_coverage_index().wrapping_add(delta))
+            }
+        }
+    }
 }
 
 impl RangeRecord {
@@ -95,6 +118,8 @@ impl RangeRecord {
     }
 }
 
+// the enum is generated, so we can't derive this
+#[allow(clippy::derivable_impls)]
 impl Default for DeltaFormat {
     fn default() -> Self {
         DeltaFormat::Local2BitDeltas
@@ -114,3 +139,216 @@ fn delta_value_count(start_size: u16, end_size: u16, delta_format: DeltaFormat)
     let extra = (range_len % val_per_word).min(1);
     count + extra
 }
+
+/// The number of bytes in a single delta-set row of an [ItemVariationData]
+/// subtable.
+fn delta_set_row_len(word_delta_count: u16, region_index_count: u16) -> usize {
+    let is_long = word_delta_count & ItemVariationData::LONG_WORDS != 0;
+    let word_count = (word_delta_count & ItemVariationData::WORD_DELTA_COUNT_MASK) as usize;
+    let region_count = region_index_count as usize;
+    let (word_len, short_len) = if is_long { (4, 2) } else { (2, 1) };
+    word_count * word_len + region_count.saturating_sub(word_count) * short_len
+}
+
+fn delta_sets_byte_len(item_count: u16, word_delta_count: u16, region_index_count: u16) -> usize {
+    item_count as usize * delta_set_row_len(word_delta_count, region_index_count)
+}
+
+fn map_data_byte_len(entry_format: EntryFormat, map_count: impl Into<u32>) -> usize {
+    entry_format.entry_size() as usize * map_count.into() as usize
+}
+
+impl EntryFormat {
+    /// The size, in bytes, of each entry in the map data.
+    pub fn entry_size(self) -> u8 {
+        ((self.bits() & Self::MAP_ENTRY_SIZE_MASK.bits()) >> 4) + 1
+    }
+
+    /// The number of bits in each entry used for the inner-level index.
+    pub fn bit_count(self) -> u8 {
+        (self.bits() & Self::INNER_INDEX_BIT_COUNT_MASK.bits()) + 1
+    }
+}
+
+/// An outer/inner index pair for a delta set in an [ItemVariationStore].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DeltaSetIndex {
+    /// Index into the item variation data subtables.
+    pub outer: u16,
+    /// Index of a delta-set row within an item variation data subtable.
+    pub inner: u16,
+}
+
+impl<'a> DeltaSetIndexMap<'a> {
+    /// Returns the delta-set index for the item at the given index.
+    ///
+    /// If the index is greater than the number of entries in the map, the
+    /// last entry is used. An empty map has no mapping for any index, and
+    /// returns an error.
+    pub fn get(&self, index: u32) -> Result<DeltaSetIndex, ReadError> {
+        let (entry_format, map_count, data) = match self {
+            Self::Format0(map) => (map.entry_format(), map.map_count() as u32, map.map_data()),
+            Self::Format1(map) => (map.entry_format(), map.map_count(), map.map_data()),
+        };
+        let last = map_count.checked_sub(1).ok_or(ReadError::OutOfBounds)?;
+        let entry_size = entry_format.entry_size() as usize;
+        let start = index.min(last) as usize * entry_size;
+        let bytes = data
+            .get(start..start + entry_size)
+            .ok_or(ReadError::OutOfBounds)?;
+        let entry = bytes
+            .iter()
+            .fold(0u32, |entry, byte| (entry << 8) | *byte as u32);
+        let bit_count = entry_format.bit_count();
+        Ok(DeltaSetIndex {
+            outer: (entry >> bit_count) as u16,
+            inner: (entry & ((1 << bit_count) - 1)) as u16,
+        })
+    }
+}
+
+impl<'a> ItemVariationStore<'a> {
+    /// Computes the delta for the delta-set at the given outer and inner
+    /// indices, at the given normalized coordinates.
+    ///
+    /// The result is in the units of the stored deltas; missing coordinates
+    /// are treated as zero.
+    pub fn compute_delta(
+        &self,
+        outer: u16,
+        inner: u16,
+        coords: &[F2Dot14],
+    ) -> Result<Fixed, ReadError> {
+        let data = self
+            .item_variation_data()
+            .nth(outer as usize)
+            .flatten()
+            .ok_or(ReadError::OutOfBounds)??;
+        let regions = self.variation_region_list()?.variation_regions();
+        let mut accumulator = 0i64;
+        for (region_index, delta) in data.region_indexes().iter().zip(data.delta_set(inner)?) {
+            if delta == 0 {
+                continue;
+            }
+            let region = regions.get(region_index.get() as usize)?;
+            let scalar = region.compute_scalar(coords);
+            accumulator += delta as i64 * scalar.to_bits() as i64;
+        }
+        let bits = accumulator.clamp(i32::MIN as i64, i32::MAX as i64) as i32;
+        Ok(Fixed::from_bits(bits))
+    }
+
+    /// Computes the delta for the item at `index`, first mapping it through
+    /// the given delta-set index map.
+    ///
+    /// Without a map, the index is used directly as the inner index into
+    /// the first item variation data subtable.
+    pub(crate) fn compute_mapped_delta(
+        &self,
+        map: Option<&DeltaSetIndexMap>,
+        index: u32,
+        coords: &[F2Dot14],
+    ) -> Result<Fixed, ReadError> {
+        let DeltaSetIndex { outer, inner } = match map {
+            Some(map) => map.get(index)?,
+            None => DeltaSetIndex {
+                outer: 0,
+                inner: index as u16,
+            },
+        };
+        self.compute_delta(outer, inner, coords)
+    }
+}
+
+impl<'a> ItemVariationData<'a> {
+    /// Flag indicating that “word” deltas are long (int32)
+    pub const LONG_WORDS: u16 = 0x8000;
+    /// Count of “word” deltas
+    pub const WORD_DELTA_COUNT_MASK: u16 = 0x7FFF;
+
+    /// Returns the deltas for the delta-set row at the given index, one per
+    /// referenced region.
+    pub fn delta_set(&self, inner: u16) -> Result<impl Iterator<Item = i32> + 'a, ReadError> {
+        let word_delta_count = self.word_delta_count();
+        let region_count = self.region_index_count();
+        let row_len = delta_set_row_len(word_delta_count, region_count);
+        let start = inner as usize * row_len;
+        let row = self
+            .delta_sets()
+            .get(start..start + row_len)
+            .ok_or(ReadError::OutOfBounds)?;
+        let mut cursor = FontData::new(row).cursor();
+        let is_long = word_delta_count & Self::LONG_WORDS != 0;
+        let word_count = word_delta_count & Self::WORD_DELTA_COUNT_MASK;
+        Ok((0..region_count).map(move |i| {
+            let delta = match (is_long, i < word_count) {
+                (true, true) => cursor.read::<i32>(),
+                (true, false) => cursor.read::<i16>().map(|x| x as i32),
+                (false, true) => cursor.read::<i16>().map(|x| x as i32),
+                (false, false) => cursor.read::<i8>().map(|x| x as i32),
+            };
+            // we checked the row length above
+            delta.unwrap_or_default()
+        }))
+    }
+}
+
+impl<'a> VariationRegion<'a> {
+    /// Computes the scalar for this region at the given normalized
+    /// coordinates.
+    ///
+    /// Missing coordinates are treated as zero.
+    pub fn compute_scalar(&self, coords: &[F2Dot14]) -> Fixed {
+        let mut scalar = Fixed::ONE;
+        for (i, axis) in self.region_axes().iter().enumerate() {
+            let coord = coords.get(i).copied().unwrap_or_default();
+            let factor = region_axis_scalar(
+                axis.start_coord(),
+                axis.peak_coord(),
+                axis.end_coord(),
+                coord,
+            );
+            if factor == Fixed::ZERO {
+                return Fixed::ZERO;
+            }
+            scalar = scalar * factor;
+        }
+        scalar
+    }
+}
+
+/// Computes the contribution of a single axis to the scalar of a variation
+/// region.
+///
+/// This is shared between the item variation store and tuple variation
+/// store formats; axes where the region is malformed or does not constrain
+/// the coordinate contribute a factor of one.
+pub(crate) fn region_axis_scalar(
+    start: F2Dot14,
+    peak: F2Dot14,
+    end: F2Dot14,
+    coord: F2Dot14,
+) -> Fixed {
+    let (start, peak, end, coord) = (
+        start.to_fixed(),
+        peak.to_fixed(),
+        end.to_fixed(),
+        coord.to_fixed(),
+    );
+    if peak == Fixed::ZERO
+        || start > peak
+        || peak > end
+        || (start < Fixed::ZERO && end > Fixed::ZERO)
+        || coord == peak
+    {
+        return Fixed::ONE;
+    }
+    if coord <= start || coord >= end {
+        return Fixed::ZERO;
+    }
+    if coord < peak {
+        (coord - start) / (peak - start)
+    } else {
+        (end - coord) / (end - peak)
+    }
+}
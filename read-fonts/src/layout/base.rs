@@ -0,0 +1,70 @@
+//! the [BASE] table
+//!
+//! [BASE]: https://learn.microsoft.com/en-us/typography/opentype/spec/base
+
+pub use super::{Device, ItemVariationStore};
+use font_types::Tag;
+
+#[cfg(test)]
+#[path = "../tests/test_base.rs"]
+mod tests;
+
+/// 'BASE'
+pub const TAG: Tag = Tag::new(b"BASE");
+
+include!("../../generated/generated_base.rs");
+
+/// The script used when a script has no BaseScript record of its own.
+const DEFAULT_SCRIPT: Tag = Tag::new(b"DFLT");
+
+impl<'a> Axis<'a> {
+    /// Returns the BaseScript table for the given script, if it has one.
+    pub fn base_script(&self, script: Tag) -> Result<Option<BaseScript<'a>>, ReadError> {
+        let list = self.base_script_list()?;
+        let records = list.base_script_records();
+        match records.binary_search_by_key(&script, |rec| rec.base_script_tag()) {
+            Ok(ix) => records[ix].base_script(list.offset_data()).map(Some),
+            Err(_) => Ok(None),
+        }
+    }
+
+    /// Returns the coordinate of the given baseline for the given script.
+    ///
+    /// If the script has no BaseScript record, the `DFLT` script is used.
+    pub fn baseline(&self, script: Tag, baseline: Tag) -> Result<Option<BaseCoord<'a>>, ReadError> {
+        let tags = match self.base_tag_list().transpose()? {
+            Some(list) => list.baseline_tags(),
+            None => return Ok(None),
+        };
+        let index = match tags.iter().position(|tag| tag.get() == baseline) {
+            Some(index) => index,
+            None => return Ok(None),
+        };
+        let base_script = match self.base_script(script)? {
+            Some(base_script) => base_script,
+            None => match self.base_script(DEFAULT_SCRIPT)? {
+                Some(base_script) => base_script,
+                None => return Ok(None),
+            },
+        };
+        let values = match base_script.base_values().transpose()? {
+            Some(values) => values,
+            None => return Ok(None),
+        };
+        match values.base_coord_offsets().get(index) {
+            Some(offset) => offset.get().resolve(values.offset_data()).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+impl BaseCoord<'_> {
+    /// The X or Y value, in design units.
+    pub fn coordinate(&self) -> i16 {
+        match self {
+            BaseCoord::Format1(coord) => coord.coordinate(),
+            BaseCoord::Format2(coord) => coord.coordinate(),
+            BaseCoord::Format3(coord) => coord.coordinate(),
+        }
+    }
+}
@@ -0,0 +1,104 @@
+//! the [JSTF] table
+//!
+//! [JSTF]: https://learn.microsoft.com/en-us/typography/opentype/spec/jstf
+
+pub use super::gpos::PositionLookupList;
+use font_types::Tag;
+
+/// 'JSTF'
+pub const TAG: Tag = Tag::new(b"JSTF");
+
+include!("../../generated/generated_jstf.rs");
+
+impl<'a> Jstf<'a> {
+    /// Returns the JstfScript table for the given script, if it has one.
+    pub fn jstf_script(&self, script: Tag) -> Result<Option<JstfScript<'a>>, ReadError> {
+        let records = self.jstf_script_records();
+        match records.binary_search_by_key(&script, |rec| rec.jstf_script_tag()) {
+            Ok(ix) => records[ix].jstf_script(self.offset_data()).map(Some),
+            Err(_) => Ok(None),
+        }
+    }
+}
+
+impl<'a> JstfScript<'a> {
+    /// Returns the JstfLangSys table for the given language system.
+    ///
+    /// If the language system has no record, the default JstfLangSys is used.
+    pub fn jstf_lang_sys(&self, lang_sys: Tag) -> Result<Option<JstfLangSys<'a>>, ReadError> {
+        let records = self.jstf_lang_sys_records();
+        match records.binary_search_by_key(&lang_sys, |rec| rec.jstf_lang_sys_tag()) {
+            Ok(ix) => records[ix].jstf_lang_sys(self.offset_data()).map(Some),
+            Err(_) => self.def_jstf_lang_sys().transpose(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::gpos::PositionLookup;
+    use crate::test_helpers::BeBuffer;
+
+    /// A table with a single `arab` script that has extender glyphs, a
+    /// default JstfLangSys and one for `URD `, each with a single priority.
+    fn test_jstf() -> BeBuffer {
+        let mut buf = BeBuffer::new();
+        buf.push(MajorMinor::VERSION_1_0);
+        buf.push(1u16);
+        buf.push(Tag::new(b"arab"));
+        buf.push(12u16);
+        assert_eq!(buf.len(), 12);
+        // JstfScript
+        buf.extend([12u16, 18, 1]);
+        buf.push(Tag::new(b"URD"));
+        buf.push(22u16);
+        assert_eq!(buf.len(), 24);
+        // ExtenderGlyph
+        buf.extend([2u16, 7, 9]);
+        // default JstfLangSys, and the one for URD
+        buf.extend([1u16, 8]);
+        buf.extend([1u16, 4]);
+        assert_eq!(buf.len(), 38);
+        // JstfPriority with a GSUB shrinkage disable list and an extension JstfMax
+        buf.extend([0u16, 20, 0, 0, 0, 0, 0, 0, 0, 24]);
+        // JstfModList
+        buf.extend([1u16, 3]);
+        // JstfMax with a single lookup
+        buf.extend([1u16, 4]);
+        // a GPOS lookup with no subtables, and a mark filtering set
+        buf.extend([1u16, 0, 0, 0]);
+        buf
+    }
+
+    #[test]
+    fn priorities() {
+        let buf = test_jstf();
+        let jstf = Jstf::read(buf.font_data()).unwrap();
+        assert_eq!(jstf.version(), MajorMinor::VERSION_1_0);
+        assert!(jstf.jstf_script(Tag::new(b"latn")).unwrap().is_none());
+        let script = jstf.jstf_script(Tag::new(b"arab")).unwrap().unwrap();
+        let extenders = script.extender_glyph().unwrap().unwrap();
+        assert_eq!(
+            extenders.extender_glyphs(),
+            [GlyphId::new(7), GlyphId::new(9)]
+        );
+
+        let urdu = script.jstf_lang_sys(Tag::new(b"URD")).unwrap().unwrap();
+        let default = script.jstf_lang_sys(Tag::new(b"FAR")).unwrap().unwrap();
+        assert_eq!(urdu.jstf_priority_count(), 1);
+        assert_eq!(default.jstf_priority_count(), 1);
+        let priority = urdu.jstf_priority().next().unwrap().unwrap();
+        assert!(priority.gsub_shrinkage_enable().is_none());
+        let disable = priority.gsub_shrinkage_disable().unwrap().unwrap();
+        assert_eq!(disable.lookup_indices(), [3]);
+        assert!(priority.shrinkage_jstf_max().is_none());
+        let max = priority.extension_jstf_max().unwrap().unwrap();
+        assert_eq!(max.lookup_count(), 1);
+        let lookup = max.lookup().next().unwrap().unwrap();
+        let PositionLookup::Single(lookup) = lookup else {
+            panic!("expected a single pos lookup");
+        };
+        assert_eq!(lookup.sub_table_count(), 0);
+    }
+}
@@ -12,15 +12,24 @@ extern crate std;
 extern crate core as std;
 
 mod array;
+pub mod bitmap;
+#[cfg(feature = "std")]
+pub mod color;
 mod font_data;
 pub mod layout;
+pub mod metrics;
 mod offset;
+#[cfg(feature = "std")]
+pub mod outline;
+pub mod pen;
+pub mod postscript;
 mod read;
 mod table_provider;
 mod table_ref;
 pub mod tables;
 #[cfg(feature = "traversal")]
 pub mod traversal;
+pub mod truetype;
 
 #[cfg(any(test, feature = "test_data"))]
 #[path = "tests/test_data.rs"]
@@ -29,6 +38,7 @@ pub mod test_data;
 #[path = "tests/test_helpers.rs"]
 pub mod test_helpers;
 
+pub use crate::array::{ComputedArray, VarLenArray};
 pub use crate::offset::{Offset, ResolveNullableOffset, ResolveOffset};
 pub use font_data::FontData;
 pub use read::{ComputeSize, FontRead, FontReadWithArgs, ReadArgs, ReadError};
@@ -38,7 +48,8 @@ pub use table_ref::TableRef;
 /// All the types that may be referenced in auto-generated code.
 #[doc(hidden)]
 pub(crate) mod codegen_prelude {
-    pub use crate::array::ComputedArray;
+    #[allow(unused_imports)]
+    pub use crate::array::{ComputedArray, VarLenArray, VarSize};
     pub use crate::font_data::{Cursor, FontData};
     pub use crate::offset::{Offset, ResolveNullableOffset, ResolveOffset};
     pub use crate::read::{ComputeSize, FontRead, FontReadWithArgs, Format, ReadArgs, ReadError};
@@ -0,0 +1,507 @@
+//! Font-wide and glyph metrics, with support for variations.
+
+use font_types::{F2Dot14, Fixed, GlyphId, Point};
+
+use crate::{
+    tables::{
+        glyf::Glyf, glyf::Glyph, gvar::Gvar, hmtx::Hmtx, hvar::Hvar, loca::Loca, mvar::tags,
+        os2::SelectionFlags,
+    },
+    ReadError, TableProvider,
+};
+
+/// Metrics for a text decoration, such as an underline or strikeout.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Decoration {
+    /// Offset of the decoration from the baseline; positive values are
+    /// above the baseline.
+    pub offset: i32,
+    /// Thickness of the decoration stroke.
+    pub thickness: i32,
+}
+
+/// Font-wide metrics, in font units.
+///
+/// Values are collected from the `head`, `hhea`, `OS/2` and `post` tables
+/// and, for variable fonts, adjusted by the deltas in `MVAR`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct FontMetrics {
+    /// Number of font units per em.
+    pub units_per_em: u16,
+    /// Distance from the baseline to the top of the line.
+    pub ascent: i32,
+    /// Distance from the baseline to the bottom of the line; usually
+    /// negative.
+    pub descent: i32,
+    /// Additional spacing between lines.
+    pub leading: i32,
+    /// Height of lowercase letters such as 'x', if present in `OS/2`.
+    pub x_height: Option<i32>,
+    /// Height of uppercase letters, if present in `OS/2`.
+    pub cap_height: Option<i32>,
+    /// Underline metrics from `post`.
+    pub underline: Option<Decoration>,
+    /// Strikeout metrics from `OS/2`.
+    pub strikeout: Option<Decoration>,
+}
+
+impl FontMetrics {
+    /// Collects the metrics of the given font at the given normalized
+    /// coordinates.
+    ///
+    /// The `head` table is required; other tables are optional.
+    ///
+    /// Line metrics come from the typographic values in `OS/2` if its
+    /// `USE_TYPO_METRICS` flag is set, or otherwise from `hhea`.
+    pub fn new<'a>(font: &impl TableProvider<'a>, coords: &[F2Dot14]) -> Result<Self, ReadError> {
+        let head = font.head()?;
+        let hhea = optional(font.hhea())?;
+        let os2 = optional(font.os2())?;
+        let post = optional(font.post())?;
+        let mvar = if has_variations(coords) {
+            optional(font.mvar())?
+        } else {
+            None
+        };
+        let adjust = |value: i16, tag| -> Result<i32, ReadError> {
+            let delta = match &mvar {
+                Some(mvar) => mvar.metric_delta(tag, coords)?,
+                None => Fixed::ZERO,
+            };
+            Ok(apply_delta(value as i32, delta))
+        };
+
+        let use_typo_metrics = os2.as_ref().is_some_and(|os2| {
+            hhea.is_none()
+                || os2
+                    .fs_selection()
+                    .contains(SelectionFlags::USE_TYPO_METRICS)
+        });
+        let (ascent, descent, leading) = match (&os2, &hhea) {
+            (Some(os2), _) if use_typo_metrics => (
+                os2.s_typo_ascender(),
+                os2.s_typo_descender(),
+                os2.s_typo_line_gap(),
+            ),
+            (_, Some(hhea)) => (
+                hhea.ascender().to_i16(),
+                hhea.descender().to_i16(),
+                hhea.line_gap().to_i16(),
+            ),
+            _ => (0, 0, 0),
+        };
+        let mut metrics = FontMetrics {
+            units_per_em: head.units_per_em(),
+            ascent: adjust(ascent, tags::HASC)?,
+            descent: adjust(descent, tags::HDSC)?,
+            leading: adjust(leading, tags::HLGP)?,
+            ..Default::default()
+        };
+        if let Some(os2) = &os2 {
+            if let Some(x_height) = os2.sx_height() {
+                metrics.x_height = Some(adjust(x_height, tags::XHGT)?);
+            }
+            if let Some(cap_height) = os2.s_cap_height() {
+                metrics.cap_height = Some(adjust(cap_height, tags::CPHT)?);
+            }
+            metrics.strikeout = Some(Decoration {
+                offset: adjust(os2.y_strikeout_position(), tags::STRO)?,
+                thickness: adjust(os2.y_strikeout_size(), tags::STRS)?,
+            });
+        }
+        if let Some(post) = &post {
+            metrics.underline = Some(Decoration {
+                offset: adjust(post.underline_position().to_i16(), tags::UNDO)?,
+                thickness: adjust(post.underline_thickness().to_i16(), tags::UNDS)?,
+            });
+        }
+        Ok(metrics)
+    }
+}
+
+/// Horizontal metrics for the glyphs in a font.
+///
+/// Variations are applied using the `HVAR` table when present. Otherwise,
+/// for fonts with TrueType outlines, the deltas for the horizontal phantom
+/// points in `gvar` are used.
+#[derive(Clone)]
+pub struct GlyphMetrics<'a> {
+    hmtx: Hmtx<'a>,
+    hvar: Option<Hvar<'a>>,
+    gvar: Option<GvarMetrics<'a>>,
+}
+
+#[derive(Clone)]
+struct GvarMetrics<'a> {
+    loca: Loca<'a>,
+    glyf: Glyf<'a>,
+    gvar: Gvar<'a>,
+}
+
+impl<'a> GlyphMetrics<'a> {
+    /// Creates a new metrics collection from the tables of the given font.
+    ///
+    /// The `hmtx` table (and `hhea` and `maxp`, which are required to parse
+    /// it) must be present.
+    pub fn new(font: &impl TableProvider<'a>) -> Result<Self, ReadError> {
+        let hmtx = font.hmtx()?;
+        let hvar = optional(font.hvar())?;
+        let gvar = match optional(font.gvar())? {
+            Some(gvar) => Some(GvarMetrics {
+                loca: font.loca(None)?,
+                glyf: font.glyf()?,
+                gvar,
+            }),
+            None => None,
+        };
+        Ok(Self { hmtx, hvar, gvar })
+    }
+
+    /// Returns the advance width of the given glyph at the given normalized
+    /// coordinates, in font units.
+    ///
+    /// The result is rounded to the nearest integer.
+    pub fn advance_width(&self, gid: GlyphId, coords: &[F2Dot14]) -> Result<i32, ReadError> {
+        let advance = self.hmtx.advance(gid).ok_or(ReadError::OutOfBounds)? as i32;
+        if !has_variations(coords) {
+            return Ok(advance);
+        }
+        let delta = if let Some(hvar) = &self.hvar {
+            hvar.advance_width_delta(gid, coords)?
+        } else if let Some(gvar) = &self.gvar {
+            let [left, right, ..] = gvar.phantom_point_deltas(gid, coords)?;
+            right.x.wrapping_sub(left.x)
+        } else {
+            Fixed::ZERO
+        };
+        Ok(apply_delta(advance, delta))
+    }
+
+    /// Returns the left side bearing of the given glyph at the given
+    /// normalized coordinates, in font units.
+    ///
+    /// The result is rounded to the nearest integer.
+    ///
+    /// When `HVAR` does not provide left side bearing variations, the
+    /// bearing is adjusted by the movement of the left phantom point in
+    /// `gvar`; changes in the extent of the outline itself are not taken
+    /// into account.
+    pub fn left_side_bearing(&self, gid: GlyphId, coords: &[F2Dot14]) -> Result<i32, ReadError> {
+        let lsb = self.hmtx.side_bearing(gid).ok_or(ReadError::OutOfBounds)? as i32;
+        if !has_variations(coords) {
+            return Ok(lsb);
+        }
+        let delta = match self
+            .hvar
+            .as_ref()
+            .and_then(|hvar| hvar.lsb_delta(gid, coords))
+        {
+            Some(delta) => delta?,
+            None => match &self.gvar {
+                Some(gvar) => -gvar.phantom_point_deltas(gid, coords)?[0].x,
+                None => Fixed::ZERO,
+            },
+        };
+        Ok(apply_delta(lsb, delta))
+    }
+}
+
+impl<'a> GvarMetrics<'a> {
+    fn phantom_point_deltas(
+        &self,
+        gid: GlyphId,
+        coords: &[F2Dot14],
+    ) -> Result<[Point<Fixed>; 4], ReadError> {
+        let idx = gid.to_u16() as usize;
+        let start = self.loca.get_raw(idx).ok_or(ReadError::OutOfBounds)?;
+        let end = self.loca.get_raw(idx + 1).ok_or(ReadError::OutOfBounds)?;
+        // glyphs without outlines have only the phantom points
+        let point_count = if start == end {
+            0
+        } else {
+            match self.loca.get_glyf(gid, &self.glyf)? {
+                Glyph::Simple(glyph) => glyph.num_points(),
+                Glyph::Composite(glyph) => glyph.iter_components().count(),
+            }
+        };
+        let point_count = u16::try_from(point_count).map_err(|_| ReadError::OutOfBounds)?;
+        self.gvar.phantom_point_deltas(gid, coords, point_count)
+    }
+}
+
+/// Adds a delta to a value in font units, rounding the delta to the nearest
+/// integer.
+///
+/// Values such as advance widths may exceed the range of [`Fixed`], so the
+/// sum is computed on integers.
+fn apply_delta(value: i32, delta: Fixed) -> i32 {
+    let delta = (delta.to_bits() as i64 + 0x8000) >> 16;
+    (value as i64 + delta).clamp(i32::MIN as i64, i32::MAX as i64) as i32
+}
+
+pub(crate) fn has_variations(coords: &[F2Dot14]) -> bool {
+    coords.iter().any(|coord| *coord != F2Dot14::ZERO)
+}
+
+/// Converts a missing table into `None`, propagating other errors.
+pub(crate) fn optional<T>(table: Result<T, ReadError>) -> Result<Option<T>, ReadError> {
+    match table {
+        Ok(table) => Ok(Some(table)),
+        Err(ReadError::TableIsMissing(_)) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use font_types::Tag;
+
+    use super::*;
+    use crate::{tables, test_helpers::BeBuffer, FontData};
+
+    struct TestFont(Vec<(Tag, BeBuffer)>);
+
+    impl<'a> TableProvider<'a> for &'a TestFont {
+        fn data_for_tag(&self, tag: Tag) -> Option<FontData<'a>> {
+            self.0
+                .iter()
+                .find(|(t, _)| *t == tag)
+                .map(|(_, buf)| buf.font_data())
+        }
+    }
+
+    /// Two glyphs with advances of 500, and side bearings of 50 and 20.
+    fn metrics_tables() -> Vec<(Tag, BeBuffer)> {
+        let mut maxp = BeBuffer::new();
+        maxp.push(0x00005000u32);
+        maxp.push(2u16);
+        let mut hhea = BeBuffer::new();
+        hhea.extend([0u16; 17]);
+        hhea.push(1u16); // number of h metrics
+        let mut hmtx = BeBuffer::new();
+        hmtx.extend([500i16, 50, 20]);
+        vec![
+            (tables::maxp::TAG, maxp),
+            (tables::hhea::TAG, hhea),
+            (tables::hmtx::TAG, hmtx),
+        ]
+    }
+
+    /// head, hhea, OS/2 (version 2) and post tables for testing font-wide
+    /// metrics.
+    fn font_metrics_tables(fs_selection: u16) -> Vec<(Tag, BeBuffer)> {
+        let mut head = BeBuffer::new();
+        head.extend([0u16; 9]);
+        head.push(1000u16); // units per em
+        head.extend([0u16; 17]);
+        let mut hhea = BeBuffer::new();
+        hhea.extend([1u16, 0]);
+        hhea.extend([800i16, -200, 100]); // ascender, descender, line gap
+        hhea.extend([0u16; 13]);
+        let mut os2 = BeBuffer::new();
+        os2.push(2u16); // version
+        os2.extend([0u16; 12]);
+        os2.extend([50i16, 250]); // strikeout size, position
+        os2.extend([0u16; 16]);
+        os2.push(fs_selection);
+        os2.extend([0u16; 2]);
+        os2.extend([900i16, -300, 0]); // typo ascender, descender, line gap
+        os2.extend([0u16; 6]);
+        os2.extend([500i16, 700]); // x height, cap height
+        os2.extend([0u16; 3]);
+        let mut post = BeBuffer::new();
+        post.push(0x00030000u32);
+        post.extend([0u16; 2]);
+        post.extend([-100i16, 40]); // underline position, thickness
+        post.extend([0u32; 5]);
+        vec![
+            (tables::head::TAG, head),
+            (tables::hhea::TAG, hhea),
+            (tables::os2::TAG, os2),
+            (tables::post::TAG, post),
+        ]
+    }
+
+    #[test]
+    fn font_metrics() {
+        let font = TestFont(font_metrics_tables(0));
+        let metrics = FontMetrics::new(&&font, &[]).unwrap();
+        assert_eq!(
+            metrics,
+            FontMetrics {
+                units_per_em: 1000,
+                ascent: 800,
+                descent: -200,
+                leading: 100,
+                x_height: Some(500),
+                cap_height: Some(700),
+                underline: Some(Decoration {
+                    offset: -100,
+                    thickness: 40
+                }),
+                strikeout: Some(Decoration {
+                    offset: 250,
+                    thickness: 50
+                }),
+            }
+        );
+        let font = TestFont(font_metrics_tables(SelectionFlags::USE_TYPO_METRICS.bits()));
+        let metrics = FontMetrics::new(&&font, &[]).unwrap();
+        assert_eq!(
+            (metrics.ascent, metrics.descent, metrics.leading),
+            (900, -300, 0)
+        );
+    }
+
+    #[test]
+    fn mvar_font_metrics() {
+        let mut mvar = BeBuffer::new();
+        mvar.extend([1u16, 0, 0, 8, 3]);
+        mvar.push(36u16); // item variation store offset
+        mvar.push(tags::HASC);
+        mvar.extend([0u16, 0]);
+        mvar.push(tags::UNDS);
+        mvar.extend([0u16, 1]);
+        mvar.push(tags::XHGT);
+        mvar.extend([0u16, 1]);
+        mvar.push(1u16);
+        mvar.push(12u32); // region list offset
+        mvar.push(1u16);
+        mvar.push(22u32); // item variation data offset
+        mvar.extend([1u16, 1]);
+        mvar.extend([F2Dot14::ZERO, F2Dot14::ONE, F2Dot14::ONE]);
+        mvar.extend([2u16, 0, 1, 0]);
+        mvar.extend([100i8, 20]);
+
+        let mut tables = font_metrics_tables(0);
+        tables.push((tables::mvar::TAG, mvar));
+        let font = TestFont(tables);
+        let default = FontMetrics::new(&&font, &[F2Dot14::ZERO]).unwrap();
+        assert_eq!(default.ascent, 800);
+        let metrics = FontMetrics::new(&&font, &[F2Dot14::from_f32(0.5)]).unwrap();
+        assert_eq!(metrics.ascent, 850);
+        assert_eq!(metrics.x_height, Some(510));
+        assert_eq!(metrics.underline.unwrap().thickness, 50);
+        assert_eq!(metrics.underline.unwrap().offset, -100);
+        assert_eq!(metrics.cap_height, Some(700));
+    }
+
+    #[test]
+    fn mvar_large_delta() {
+        let mut mvar = BeBuffer::new();
+        mvar.extend([1u16, 0, 0, 8, 1]);
+        mvar.push(20u16); // item variation store offset
+        mvar.push(tags::HASC);
+        mvar.extend([0u16, 0]);
+        mvar.push(1u16);
+        mvar.push(12u32); // region list offset
+        mvar.push(1u16);
+        mvar.push(22u32); // item variation data offset
+        mvar.extend([1u16, 1]);
+        mvar.extend([F2Dot14::ZERO, F2Dot14::ONE, F2Dot14::ONE]);
+        mvar.extend([1u16, 1, 1, 0]);
+        mvar.push(i16::MAX);
+
+        let mut tables = font_metrics_tables(0);
+        tables.push((tables::mvar::TAG, mvar));
+        let font = TestFont(tables);
+        let metrics = FontMetrics::new(&&font, &[F2Dot14::ONE]).unwrap();
+        // beyond the range of Fixed
+        assert_eq!(metrics.ascent, 800 + i16::MAX as i32);
+    }
+
+    #[test]
+    fn default_metrics() {
+        let font = TestFont(metrics_tables());
+        let metrics = GlyphMetrics::new(&&font).unwrap();
+        let coords = [F2Dot14::ONE];
+        for (gid, lsb) in [(0, 50), (1, 20)] {
+            let gid = GlyphId::new(gid);
+            assert_eq!(metrics.advance_width(gid, &[]).unwrap(), 500);
+            assert_eq!(metrics.advance_width(gid, &coords).unwrap(), 500);
+            assert_eq!(metrics.left_side_bearing(gid, &coords).unwrap(), lsb);
+        }
+        assert!(metrics.left_side_bearing(GlyphId::new(2), &[]).is_err());
+    }
+
+    #[test]
+    fn large_advance() {
+        let mut tables = metrics_tables();
+        let mut hmtx = BeBuffer::new();
+        hmtx.push(40000u16);
+        hmtx.extend([50i16, 20]);
+        tables[2].1 = hmtx;
+        let font = TestFont(tables);
+        let metrics = GlyphMetrics::new(&&font).unwrap();
+        let gid = GlyphId::new(0);
+        assert_eq!(metrics.advance_width(gid, &[]).unwrap(), 40000);
+        assert_eq!(metrics.advance_width(gid, &[F2Dot14::ONE]).unwrap(), 40000);
+    }
+
+    #[test]
+    fn hvar_metrics() {
+        let mut hvar = BeBuffer::new();
+        hvar.extend([1u16, 0]);
+        hvar.push(20u32); // item variation store offset
+        hvar.extend([0u32, 0, 0]); // no mappings
+        hvar.push(1u16); // format
+        hvar.push(12u32); // region list offset
+        hvar.push(1u16); // item variation data count
+        hvar.push(22u32); // item variation data offset
+        hvar.extend([1u16, 1]); // axis count, region count
+        hvar.extend([F2Dot14::ZERO, F2Dot14::ONE, F2Dot14::ONE]);
+        hvar.extend([2u16, 0, 1, 0]); // item count, word delta count, region count, region index
+        hvar.extend([11i8, -20]);
+
+        let mut tables = metrics_tables();
+        tables.push((tables::hvar::TAG, hvar));
+        let font = TestFont(tables);
+        let metrics = GlyphMetrics::new(&&font).unwrap();
+        let coords = [F2Dot14::from_f32(0.5)];
+        let advance = |gid| metrics.advance_width(GlyphId::new(gid), &coords).unwrap();
+        // 500 + 5.5 rounds up
+        assert_eq!(advance(0), 506);
+        assert_eq!(advance(1), 490);
+        // no lsb mapping, and no gvar
+        let lsb = metrics.left_side_bearing(GlyphId::new(0), &coords);
+        assert_eq!(lsb.unwrap(), 50);
+    }
+
+    #[test]
+    fn gvar_phantom_metrics() {
+        let mut head = BeBuffer::new();
+        head.extend([0u16; 27]); // short loca offsets
+        let mut loca = BeBuffer::new();
+        loca.extend([0u16, 0, 0]); // both glyphs are empty
+        let mut gvar = BeBuffer::new();
+        gvar.extend([1u16, 0, 1, 0]); // version, axis count, shared tuple count
+        gvar.push(26u32); // shared tuples offset
+        gvar.extend([2u16, 0]); // glyph count, flags
+        gvar.push(26u32); // glyph variation data array offset
+        gvar.extend([0u16, 9, 9]); // offsets (divided by 2)
+        gvar.extend([0x8001u16, 10]); // count with shared points, data offset
+        gvar.extend([6u16, 0x8000]); // tuple header: embedded peak
+        gvar.push(F2Dot14::ONE);
+        gvar.push(0u8); // shared point numbers: all points
+        gvar.push(3u8); // x deltas for the four phantom points
+        gvar.extend([-10i8, 30, 0, 0]);
+        gvar.push(0x83u8); // y deltas are all zero
+        gvar.push(0u8); // padding
+
+        let mut tables = metrics_tables();
+        tables.push((tables::head::TAG, head));
+        tables.push((tables::loca::TAG, loca));
+        tables.push((tables::glyf::TAG, BeBuffer::new()));
+        tables.push((tables::gvar::TAG, gvar));
+        let font = TestFont(tables);
+        let metrics = GlyphMetrics::new(&&font).unwrap();
+        let coords = [F2Dot14::from_f32(0.5)];
+        let gid = GlyphId::new(0);
+        assert_eq!(metrics.advance_width(gid, &coords).unwrap(), 520);
+        assert_eq!(metrics.left_side_bearing(gid, &coords).unwrap(), 55);
+        // glyph 1 has no variations
+        let gid = GlyphId::new(1);
+        assert_eq!(metrics.advance_width(gid, &coords).unwrap(), 500);
+        assert_eq!(metrics.left_side_bearing(gid, &coords).unwrap(), 20);
+    }
+}
@@ -0,0 +1,958 @@
+//! Loading and drawing glyph outlines.
+
+use font_types::{F2Dot14, GlyphId, Point};
+
+use crate::{
+    metrics::{has_variations, optional},
+    pen::OutlinePen,
+    tables::{
+        glyf::{
+            ComponentPos, CompositeGlyph, CompositeGlyphFlags, Glyf, Glyph, SimpleGlyph,
+            SimpleGlyphFlags, Transform,
+        },
+        gvar::Gvar,
+        hmtx::Hmtx,
+        loca::Loca,
+        vmtx::Vmtx,
+    },
+    ReadError, TableProvider,
+};
+
+mod cff;
+mod cff2;
+mod hint;
+
+pub use cff::CffOutlines;
+pub use cff2::Cff2Outlines;
+
+/// The maximum depth of nested composite glyphs.
+///
+/// Deeper nesting (including cycles) is treated as an error.
+pub const GLYF_COMPOSITE_RECURSION_LIMIT: usize = 32;
+
+/// A glyph outline in font units.
+///
+/// Composite glyphs are flattened into a single set of contours.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Outline {
+    /// The points of all contours.
+    pub points: Vec<Point<f32>>,
+    /// Whether each point is on the curve.
+    pub on_curve: Vec<bool>,
+    /// The index of the last point of each contour.
+    pub contours: Vec<usize>,
+    /// The four phantom points: horizontal origin, advance, top origin and
+    /// bottom advance, in that order.
+    ///
+    /// These carry the (possibly varied) metrics of the glyph.
+    pub phantom_points: [Point<f32>; 4],
+}
+
+impl Outline {
+    /// Creates a new, empty outline.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Removes all points and contours, keeping the allocated memory.
+    pub fn clear(&mut self) {
+        self.points.clear();
+        self.on_curve.clear();
+        self.contours.clear();
+        self.phantom_points = Default::default();
+    }
+
+    /// Returns the horizontal advance, as given by the phantom points.
+    pub fn advance_width(&self) -> f32 {
+        self.phantom_points[1].x - self.phantom_points[0].x
+    }
+
+    /// Returns the vertical advance, as given by the phantom points.
+    pub fn advance_height(&self) -> f32 {
+        self.phantom_points[2].y - self.phantom_points[3].y
+    }
+
+    /// Returns the left side bearing: the distance from the horizontal
+    /// origin to the leftmost point of the outline.
+    pub fn left_side_bearing(&self) -> f32 {
+        self.x_min() - self.phantom_points[0].x
+    }
+
+    /// Returns the top side bearing: the distance from the topmost point of
+    /// the outline to the top origin.
+    pub fn top_side_bearing(&self) -> f32 {
+        self.phantom_points[2].y - self.y_max()
+    }
+
+    fn x_min(&self) -> f32 {
+        let min = self.points.iter().map(|point| point.x).reduce(f32::min);
+        min.unwrap_or_default()
+    }
+
+    fn y_max(&self) -> f32 {
+        let max = self.points.iter().map(|point| point.y).reduce(f32::max);
+        max.unwrap_or_default()
+    }
+
+    /// Draws this outline to the given pen.
+    ///
+    /// Quadratic contours are converted to pen commands, inserting the
+    /// implied on-curve points between consecutive off-curve points.
+    pub fn draw(&self, pen: &mut impl OutlinePen) {
+        let mut start = 0;
+        for &end in &self.contours {
+            // skip malformed contours rather than panicking
+            if end < start || end >= self.points.len() || end >= self.on_curve.len() {
+                break;
+            }
+            draw_contour(&self.points[start..=end], &self.on_curve[start..=end], pen);
+            start = end + 1;
+        }
+    }
+}
+
+fn draw_contour(points: &[Point<f32>], on_curve: &[bool], pen: &mut impl OutlinePen) {
+    let Some((&first, &last)) = points.first().zip(points.last()) else {
+        return;
+    };
+    let n_points = points.len();
+    // find a starting point that is on the curve, synthesizing one if
+    // every point is off the curve.
+    let (start, range) = if on_curve[0] {
+        (first, 1..n_points)
+    } else if on_curve[n_points - 1] {
+        (last, 0..n_points - 1)
+    } else {
+        (midpoint(last, first), 0..n_points)
+    };
+    pen.move_to(start.x, start.y);
+    let mut pending_control: Option<Point<f32>> = None;
+    let mut current = start;
+    for idx in range {
+        let point = points[idx];
+        match (on_curve[idx], pending_control.take()) {
+            (true, Some(control)) => {
+                pen.quad_to(control.x, control.y, point.x, point.y);
+                current = point;
+            }
+            (true, None) => {
+                pen.line_to(point.x, point.y);
+                current = point;
+            }
+            (false, Some(control)) => {
+                let implied = midpoint(control, point);
+                pen.quad_to(control.x, control.y, implied.x, implied.y);
+                current = implied;
+                pending_control = Some(point);
+            }
+            (false, None) => pending_control = Some(point),
+        }
+    }
+    match pending_control {
+        Some(control) => pen.quad_to(control.x, control.y, start.x, start.y),
+        None if current != start => pen.line_to(start.x, start.y),
+        None => (),
+    }
+    pen.close();
+}
+
+fn midpoint(a: Point<f32>, b: Point<f32>) -> Point<f32> {
+    Point::new((a.x + b.x) * 0.5, (a.y + b.y) * 0.5)
+}
+
+/// Loads outlines from the `glyf` and `loca` tables.
+///
+/// If the font has a `gvar` table, outlines can be loaded at any position in
+/// the font's variation space.
+#[derive(Clone)]
+pub struct GlyfOutlines<'a> {
+    loca: Loca<'a>,
+    glyf: Glyf<'a>,
+    gvar: Option<Gvar<'a>>,
+    hmtx: Option<Hmtx<'a>>,
+    vmtx: Option<Vmtx<'a>>,
+    /// The ascender and descender, used for the vertical phantom points
+    /// when there is no `vmtx` table.
+    ascender: i16,
+    descender: i16,
+}
+
+impl<'a> GlyfOutlines<'a> {
+    /// Creates a new outline loader for the given font.
+    ///
+    /// The `head`, `loca` and `glyf` tables are required. The `gvar`,
+    /// `hmtx` and `vmtx` tables are used if present.
+    pub fn new(font: &impl TableProvider<'a>) -> Result<Self, ReadError> {
+        let (ascender, descender) = optional(font.hhea())?
+            .map(|hhea| (hhea.ascender().to_i16(), hhea.descender().to_i16()))
+            .unwrap_or_default();
+        Ok(Self {
+            loca: font.loca(None)?,
+            glyf: font.glyf()?,
+            gvar: optional(font.gvar())?,
+            hmtx: optional(font.hmtx())?,
+            vmtx: optional(font.vmtx())?,
+            ascender,
+            descender,
+        })
+    }
+
+    /// Returns the outline of the given glyph.
+    pub fn outline(&self, gid: GlyphId) -> Result<Outline, ReadError> {
+        self.outline_with_coords(gid, &[])
+    }
+
+    /// Returns the outline of the given glyph at the given normalized
+    /// variation coordinates.
+    ///
+    /// The phantom points of the returned outline are varied along with the
+    /// outline, so the advances and side bearings reflect the location.
+    pub fn outline_with_coords(
+        &self,
+        gid: GlyphId,
+        coords: &[F2Dot14],
+    ) -> Result<Outline, ReadError> {
+        let mut outline = Outline::new();
+        outline.phantom_points = self.load(gid, coords, &mut outline, 0)?;
+        Ok(outline)
+    }
+
+    /// Draws the outline of the given glyph to the given pen.
+    pub fn draw_glyph(&self, gid: GlyphId, pen: &mut impl OutlinePen) -> Result<(), ReadError> {
+        self.outline(gid)?.draw(pen);
+        Ok(())
+    }
+
+    /// Draws the outline of the given glyph at the given normalized
+    /// variation coordinates to the given pen.
+    pub fn draw_glyph_with_coords(
+        &self,
+        gid: GlyphId,
+        coords: &[F2Dot14],
+        pen: &mut impl OutlinePen,
+    ) -> Result<(), ReadError> {
+        self.outline_with_coords(gid, coords)?.draw(pen);
+        Ok(())
+    }
+
+    /// Appends the outline of the given glyph to `outline`, returning its
+    /// phantom points.
+    fn load(
+        &self,
+        gid: GlyphId,
+        coords: &[F2Dot14],
+        outline: &mut Outline,
+        depth: usize,
+    ) -> Result<[Point<f32>; 4], ReadError> {
+        if depth > GLYF_COMPOSITE_RECURSION_LIMIT {
+            return Err(ReadError::MalformedData(
+                "composite glyph recursion limit exceeded",
+            ));
+        }
+        let glyph = self.loca.get_glyph(gid, &self.glyf)?;
+        let (x_min, y_max) = glyph
+            .as_ref()
+            .map(|glyph| (glyph.x_min(), glyph.y_max()))
+            .unwrap_or_default();
+        let mut phantom = self.phantom_points(gid, x_min, y_max);
+        let gvar = self.gvar.as_ref().filter(|_| has_variations(coords));
+        match glyph {
+            None => {
+                if let Some(gvar) = gvar {
+                    let deltas = gvar.phantom_point_deltas(gid, coords, 0)?;
+                    for (point, delta) in phantom.iter_mut().zip(deltas) {
+                        *point += delta.map(|coord| coord.to_f64() as f32);
+                    }
+                }
+            }
+            Some(Glyph::Simple(glyph)) => {
+                let start = outline.points.len();
+                let contours_start = outline.contours.len();
+                load_simple(&glyph, outline)?;
+                if let Some(gvar) = gvar {
+                    let mut points = outline.points[start..].to_vec();
+                    points.extend_from_slice(&phantom);
+                    let contours = outline.contours[contours_start..]
+                        .iter()
+                        .map(|end| end - start)
+                        .collect::<Vec<_>>();
+                    let deltas = glyph_deltas(gvar, gid, coords, &points, &contours)?;
+                    let points = outline.points[start..].iter_mut().chain(&mut phantom);
+                    for (point, delta) in points.zip(deltas) {
+                        *point += delta;
+                    }
+                }
+            }
+            Some(Glyph::Composite(glyph)) => {
+                let deltas = self.composite_deltas(&glyph, gid, coords, &mut phantom)?;
+                if let Some(metrics) =
+                    self.load_composite(&glyph, coords, &deltas, outline, depth)?
+                {
+                    phantom = metrics;
+                }
+            }
+        }
+        Ok(phantom)
+    }
+
+    /// Computes the variation deltas for the component offsets of a
+    /// composite glyph, applying the deltas for the phantom points.
+    ///
+    /// Returns an empty list if the glyph is not varied.
+    fn composite_deltas(
+        &self,
+        glyph: &CompositeGlyph,
+        gid: GlyphId,
+        coords: &[F2Dot14],
+        phantom: &mut [Point<f32>; 4],
+    ) -> Result<Vec<Point<f32>>, ReadError> {
+        let Some(gvar) = self.gvar.as_ref().filter(|_| has_variations(coords)) else {
+            return Ok(Vec::new());
+        };
+        // each component offset is varied like a point, and the
+        // components are not contours so nothing is inferred
+        let mut points = vec![Point::default(); glyph.iter_components().count()];
+        points.extend_from_slice(phantom);
+        let deltas = glyph_deltas(gvar, gid, coords, &points, &[])?;
+        let phantom_deltas = &deltas[deltas.len() - 4..];
+        for (point, delta) in phantom.iter_mut().zip(phantom_deltas) {
+            *point += *delta;
+        }
+        Ok(deltas)
+    }
+
+    /// Appends the components of a composite glyph to `outline`.
+    ///
+    /// `deltas` contains the variation delta for each component offset, or
+    /// is empty if the glyph is not varied. Returns the phantom points of
+    /// the component flagged with `USE_MY_METRICS`, if any.
+    fn load_composite(
+        &self,
+        glyph: &CompositeGlyph,
+        coords: &[F2Dot14],
+        deltas: &[Point<f32>],
+        outline: &mut Outline,
+        depth: usize,
+    ) -> Result<Option<[Point<f32>; 4]>, ReadError> {
+        let glyph_start = outline.points.len();
+        let mut metrics = None;
+        for (idx, component) in glyph.iter_components().enumerate() {
+            let start = outline.points.len();
+            let phantom = self.load(component.glyph, coords, outline, depth + 1)?;
+            if component
+                .flags
+                .contains(CompositeGlyphFlags::USE_MY_METRICS)
+            {
+                metrics = Some(phantom);
+            }
+            let transform = component.transform;
+            let has_transform = transform != Transform::default();
+            if has_transform {
+                for point in &mut outline.points[start..] {
+                    *point = apply_transform(&transform, *point);
+                }
+            }
+            let offset = match component.pos {
+                ComponentPos::Offset { x, y } => {
+                    let mut offset = Point::new(x as f32, y as f32);
+                    if let Some(delta) = deltas.get(idx) {
+                        offset += *delta;
+                    }
+                    if has_transform && has_scaled_offset(component.flags) {
+                        let offset = apply_transform(&transform, offset);
+                        if component
+                            .flags
+                            .contains(CompositeGlyphFlags::ROUND_XY_TO_GRID)
+                        {
+                            offset.map(f32::round)
+                        } else {
+                            offset
+                        }
+                    } else {
+                        offset
+                    }
+                }
+                ComponentPos::Point { base, component } => {
+                    // the base point must come from a previous component
+                    let base_point = outline.points[glyph_start..start]
+                        .get(base as usize)
+                        .copied();
+                    let component_point = outline.points[start..].get(component as usize).copied();
+                    match base_point.zip(component_point) {
+                        Some((base, component)) => base - component,
+                        None => return Err(ReadError::MalformedData("invalid anchor point")),
+                    }
+                }
+            };
+            if offset != Point::default() {
+                for point in &mut outline.points[start..] {
+                    *point += offset;
+                }
+            }
+        }
+        Ok(metrics)
+    }
+
+    /// Computes the unvaried phantom points for a glyph with the given
+    /// bounding box values.
+    fn phantom_points(&self, gid: GlyphId, x_min: i16, y_max: i16) -> [Point<f32>; 4] {
+        let (advance, lsb) = self
+            .hmtx
+            .as_ref()
+            .map(|hmtx| {
+                (
+                    hmtx.advance(gid).unwrap_or_default() as i32,
+                    hmtx.side_bearing(gid).unwrap_or_default() as i32,
+                )
+            })
+            .unwrap_or_default();
+        let (v_advance, tsb) = match self.vmtx.as_ref() {
+            Some(vmtx) => (
+                vmtx.advance(gid).unwrap_or_default() as i32,
+                vmtx.side_bearing(gid).unwrap_or_default() as i32,
+            ),
+            None => (
+                self.ascender as i32 - self.descender as i32,
+                self.ascender as i32 - y_max as i32,
+            ),
+        };
+        let h_origin = x_min as i32 - lsb;
+        let v_origin = y_max as i32 + tsb;
+        [
+            Point::new(h_origin, 0),
+            Point::new(h_origin + advance, 0),
+            Point::new(0, v_origin),
+            Point::new(0, v_origin - v_advance),
+        ]
+        .map(|point| point.map(|coord| coord as f32))
+    }
+}
+
+/// Computes the variation deltas for a glyph at the given coordinates.
+///
+/// `points` are the unvaried points of the glyph, followed by the four
+/// phantom points, and `contours` holds the index of the last point of each
+/// contour. For tuple variations with sparse deltas, the deltas of untouched
+/// points in each contour are inferred by interpolation.
+fn glyph_deltas(
+    gvar: &Gvar,
+    gid: GlyphId,
+    coords: &[F2Dot14],
+    points: &[Point<f32>],
+    contours: &[usize],
+) -> Result<Vec<Point<f32>>, ReadError> {
+    let mut deltas = vec![Point::default(); points.len()];
+    let Some(var_data) = gvar.glyph_variation_data(gid)? else {
+        return Ok(deltas);
+    };
+    let mut tuple_deltas = Vec::new();
+    let mut touched = Vec::new();
+    for (scalar, tuple) in var_data.active_tuples(coords) {
+        let scalar = scalar.to_f64() as f32;
+        if tuple.has_deltas_for_all_points() {
+            for delta in tuple.deltas() {
+                if let Some(target) = deltas.get_mut(delta.position as usize) {
+                    *target += Point::new(delta.x_delta as f32, delta.y_delta as f32) * scalar;
+                }
+            }
+            continue;
+        }
+        tuple_deltas.clear();
+        tuple_deltas.resize(points.len(), Point::default());
+        touched.clear();
+        touched.resize(points.len(), false);
+        for delta in tuple.deltas() {
+            let idx = delta.position as usize;
+            if idx < points.len() {
+                tuple_deltas[idx] = Point::new(delta.x_delta as f32, delta.y_delta as f32);
+                touched[idx] = true;
+            }
+        }
+        interpolate_untouched(points, contours, &touched, &mut tuple_deltas);
+        for (target, delta) in deltas.iter_mut().zip(&tuple_deltas) {
+            *target += *delta * scalar;
+        }
+    }
+    Ok(deltas)
+}
+
+/// Infers deltas for the untouched points of each contour.
+///
+/// See <https://learn.microsoft.com/en-us/typography/opentype/spec/gvar#inferred-deltas-for-un-referenced-point-numbers>
+fn interpolate_untouched(
+    points: &[Point<f32>],
+    contours: &[usize],
+    touched: &[bool],
+    deltas: &mut [Point<f32>],
+) {
+    let mut start = 0;
+    for &end in contours {
+        if end < start || end >= points.len() {
+            break;
+        }
+        let range = start..end + 1;
+        interpolate_contour(
+            &points[range.clone()],
+            &touched[range.clone()],
+            &mut deltas[range],
+        );
+        start = end + 1;
+    }
+}
+
+fn interpolate_contour(points: &[Point<f32>], touched: &[bool], deltas: &mut [Point<f32>]) {
+    // contours without any touched points are left unchanged
+    let Some(first) = touched.iter().position(|touched| *touched) else {
+        return;
+    };
+    let n_points = points.len();
+    let mut prev = first;
+    loop {
+        let mut next = (prev + 1) % n_points;
+        while !touched[next] {
+            next = (next + 1) % n_points;
+        }
+        let mut idx = (prev + 1) % n_points;
+        while idx != next {
+            let (p1, p2) = (points[prev], points[next]);
+            let (d1, d2) = (deltas[prev], deltas[next]);
+            let point = points[idx];
+            deltas[idx] = Point::new(
+                interpolate(point.x, p1.x, p2.x, d1.x, d2.x),
+                interpolate(point.y, p1.y, p2.y, d1.y, d2.y),
+            );
+            idx = (idx + 1) % n_points;
+        }
+        if next == first {
+            break;
+        }
+        prev = next;
+    }
+}
+
+/// Infers the delta for a single coordinate from the two adjacent touched
+/// points.
+fn interpolate(coord: f32, c1: f32, c2: f32, d1: f32, d2: f32) -> f32 {
+    if c1 == c2 {
+        return if d1 == d2 { d1 } else { 0.0 };
+    }
+    let ((c1, d1), (c2, d2)) = if c1 < c2 {
+        ((c1, d1), (c2, d2))
+    } else {
+        ((c2, d2), (c1, d1))
+    };
+    if coord <= c1 {
+        d1
+    } else if coord >= c2 {
+        d2
+    } else {
+        d1 + (coord - c1) * (d2 - d1) / (c2 - c1)
+    }
+}
+
+fn load_simple(glyph: &SimpleGlyph, outline: &mut Outline) -> Result<(), ReadError> {
+    let n_points = glyph.num_points();
+    let mut points = vec![Point::default(); n_points];
+    let mut flags = vec![SimpleGlyphFlags::empty(); n_points];
+    glyph.read_points(&mut points, &mut flags)?;
+    let base = outline.points.len();
+    outline
+        .points
+        .extend(points.iter().map(|point| point.map(|coord| coord as f32)));
+    outline.on_curve.extend(
+        flags
+            .iter()
+            .map(|flags| flags.contains(SimpleGlyphFlags::ON_CURVE_POINT)),
+    );
+    outline.contours.extend(
+        glyph
+            .end_pts_of_contours()
+            .iter()
+            .map(|end| base + end.get() as usize),
+    );
+    Ok(())
+}
+
+fn apply_transform(transform: &Transform, point: Point<f32>) -> Point<f32> {
+    Point::new(
+        transform.xx.to_f32() * point.x + transform.xy.to_f32() * point.y,
+        transform.yx.to_f32() * point.x + transform.yy.to_f32() * point.y,
+    )
+}
+
+/// Component offsets are not transformed unless explicitly requested.
+fn has_scaled_offset(flags: CompositeGlyphFlags) -> bool {
+    flags.contains(CompositeGlyphFlags::SCALED_COMPONENT_OFFSET)
+        && !flags.contains(CompositeGlyphFlags::UNSCALED_COMPONENT_OFFSET)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        pen::SvgPen,
+        test_helpers::BeBuffer,
+        truetype::{HintingInstance, InterpreterVersion},
+        FontData, FontRead, FontReadWithArgs,
+    };
+
+    /// A simple glyph with a single contour where every coordinate is
+    /// stored as a 16-bit delta.
+    fn simple_glyph(points: &[(i16, i16, bool)]) -> BeBuffer {
+        instructed_glyph(points, &[])
+    }
+
+    /// A simple glyph like [`simple_glyph`], with the given instructions.
+    fn instructed_glyph(points: &[(i16, i16, bool)], instructions: &[u8]) -> BeBuffer {
+        let mut buf = BeBuffer::new();
+        buf.extend([1i16, 0, 0, 0, 0]);
+        buf.push(points.len() as u16 - 1);
+        buf.push(instructions.len() as u16);
+        buf.extend(instructions.iter().copied());
+        buf.extend(points.iter().map(|(_, _, on)| *on as u8));
+        let mut prev = 0;
+        for (x, _, _) in points {
+            buf.push(x - prev);
+            prev = *x;
+        }
+        prev = 0;
+        for (_, y, _) in points {
+            buf.push(y - prev);
+            prev = *y;
+        }
+        buf
+    }
+
+    struct TestGlyphs {
+        loca: BeBuffer,
+        glyf: Vec<u8>,
+    }
+
+    impl TestGlyphs {
+        fn new(glyphs: &[BeBuffer]) -> Self {
+            let mut loca = BeBuffer::new();
+            let mut glyf = Vec::new();
+            for glyph in glyphs {
+                loca.push(glyf.len() as u32);
+                glyf.extend_from_slice(glyph);
+            }
+            loca.push(glyf.len() as u32);
+            Self { loca, glyf }
+        }
+
+        fn outlines(&self) -> GlyfOutlines<'_> {
+            GlyfOutlines {
+                loca: Loca::read_with_args(self.loca.font_data(), &true).unwrap(),
+                glyf: Glyf::read(FontData::new(&self.glyf)).unwrap(),
+                gvar: None,
+                hmtx: None,
+                vmtx: None,
+                ascender: 0,
+                descender: 0,
+            }
+        }
+    }
+
+    fn draw(outlines: &GlyfOutlines, gid: u16) -> String {
+        let mut pen = SvgPen::new();
+        outlines.draw_glyph(GlyphId::new(gid), &mut pen).unwrap();
+        pen.into_string()
+    }
+
+    fn test_glyphs() -> TestGlyphs {
+        // 0: a square with one off-curve point
+        let glyph0 = simple_glyph(&[
+            (0, 0, true),
+            (100, 0, true),
+            (100, 100, false),
+            (0, 100, true),
+        ]);
+        // 1: a square with only off-curve points
+        let glyph1 = simple_glyph(&[
+            (0, 0, false),
+            (100, 0, false),
+            (100, 100, false),
+            (0, 100, false),
+        ]);
+        // 2: glyph 0 scaled by 0.5 and offset, with glyph 1 anchored to its
+        // second point
+        let mut glyph2 = BeBuffer::new();
+        glyph2.extend([-1i16, 0, 0, 0, 0]);
+        // ARGS_ARE_XY_VALUES | WE_HAVE_A_SCALE | MORE_COMPONENTS
+        glyph2.extend([0x002Au16, 0]);
+        glyph2.extend([10i8, 20]);
+        glyph2.push(0x2000u16);
+        glyph2.extend([0u16, 1]);
+        glyph2.extend([1u8, 0]);
+        // 3: a composite that references itself
+        let mut glyph3 = BeBuffer::new();
+        glyph3.extend([-1i16, 0, 0, 0, 0]);
+        glyph3.extend([0x0002u16, 3]);
+        glyph3.extend([0i8, 0]);
+        // 4: empty
+        let glyph4 = BeBuffer::new();
+        // 5: glyph 0 with a 2x2 transform (rotate 90 degrees) and a
+        // negative offset
+        let mut glyph5 = BeBuffer::new();
+        glyph5.extend([-1i16, 0, 0, 0, 0]);
+        // ARGS_ARE_XY_VALUES | WE_HAVE_A_TWO_BY_TWO
+        glyph5.extend([0x0082u16, 0]);
+        glyph5.extend([-5i8, 0]);
+        glyph5.extend([0u16, 0x4000, 0xC000, 0]);
+        TestGlyphs::new(&[glyph0, glyph1, glyph2, glyph3, glyph4, glyph5])
+    }
+
+    #[test]
+    fn simple_outlines() {
+        let glyphs = test_glyphs();
+        let outlines = glyphs.outlines();
+        assert_eq!(draw(&outlines, 0), "M0,0 L100,0 Q100,100 0,100 L0,0 Z");
+        assert_eq!(
+            draw(&outlines, 1),
+            "M0,50 Q0,0 50,0 Q100,0 100,50 Q100,100 50,100 Q0,100 0,50 Z"
+        );
+        assert_eq!(draw(&outlines, 4), "");
+    }
+
+    #[test]
+    fn composite_outlines() {
+        let glyphs = test_glyphs();
+        let outlines = glyphs.outlines();
+        let outline = outlines.outline(GlyphId::new(2)).unwrap();
+        assert_eq!(outline.contours, [3, 7]);
+        let points = outline
+            .points
+            .iter()
+            .map(|point| (point.x, point.y))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            points,
+            [
+                (10.0, 20.0),
+                (60.0, 20.0),
+                (60.0, 70.0),
+                (10.0, 70.0),
+                (60.0, 20.0),
+                (160.0, 20.0),
+                (160.0, 120.0),
+                (60.0, 120.0)
+            ]
+        );
+        assert_eq!(draw(&outlines, 5), "M-5,0 L-5,100 Q-105,100 -105,0 L-5,0 Z");
+    }
+
+    #[test]
+    fn recursion_limit() {
+        let glyphs = test_glyphs();
+        let outlines = glyphs.outlines();
+        assert!(matches!(
+            outlines.outline(GlyphId::new(3)),
+            Err(ReadError::MalformedData(_))
+        ));
+    }
+
+    /// A simple glyph with sparse deltas and a composite of it, along with
+    /// their `gvar` and `hmtx` data.
+    fn variable_glyphs() -> (TestGlyphs, BeBuffer, BeBuffer) {
+        let glyph0 = simple_glyph(&[
+            (0, 0, true),
+            (50, 0, true),
+            (100, 0, true),
+            (100, 100, true),
+            (0, 100, true),
+        ]);
+        let mut glyph1 = BeBuffer::new();
+        glyph1.extend([-1i16, 0, 0, 0, 0]);
+        // ARGS_ARE_XY_VALUES
+        glyph1.extend([0x0002u16, 0]);
+        glyph1.extend([10i8, 20]);
+        let glyphs = TestGlyphs::new(&[glyph0, glyph1]);
+
+        let mut gvar = BeBuffer::new();
+        gvar.extend([1u16, 0, 1, 0]); // version, axis count, shared tuple count
+        gvar.push(20u32); // shared tuples offset
+        gvar.extend([2u16, 0]); // glyph count, flags
+        gvar.push(26u32); // glyph variation data array offset
+        gvar.extend([0u16, 12, 22]); // offsets (divided by 2)
+
+        // glyph 0: one tuple with deltas for points 0, 2 and the advance
+        gvar.extend([1u16, 10]); // tuple count, data offset
+        gvar.extend([13u16, 0xA000]); // data size, embedded peak, private points
+        gvar.push(F2Dot14::from_f32(1.0));
+        gvar.extend([3u8, 2, 0, 2, 4]); // points 0, 2 and 6
+        gvar.extend([2u8, 0, 20, 30]); // x deltas
+        gvar.extend([2u8, 0, 10, 0]); // y deltas
+        gvar.push(0u8); // padding
+
+        // glyph 1: one tuple with deltas for the component offset and advance
+        gvar.extend([1u16, 10]);
+        gvar.extend([10u16, 0xA000]);
+        gvar.push(F2Dot14::from_f32(1.0));
+        gvar.extend([2u8, 1, 0, 2]); // points 0 and 2
+        gvar.extend([1u8, 5, 10]); // x deltas
+        gvar.extend([1u8, 0xFB, 0]); // y deltas
+
+        let mut hmtx = BeBuffer::new();
+        hmtx.extend([500u16, 0, 500, 10]);
+        (glyphs, gvar, hmtx)
+    }
+
+    fn variable_outlines<'a>(
+        glyphs: &'a TestGlyphs,
+        gvar: &'a BeBuffer,
+        hmtx: &'a BeBuffer,
+    ) -> GlyfOutlines<'a> {
+        GlyfOutlines {
+            gvar: Some(Gvar::read(gvar.font_data()).unwrap()),
+            hmtx: Some(Hmtx::read_with_args(hmtx.font_data(), &(2, 2)).unwrap()),
+            ..glyphs.outlines()
+        }
+    }
+
+    fn point_list(outline: &Outline) -> Vec<(f32, f32)> {
+        outline
+            .points
+            .iter()
+            .map(|point| (point.x, point.y))
+            .collect()
+    }
+
+    #[test]
+    fn varied_simple_outline() {
+        let (glyphs, gvar, hmtx) = variable_glyphs();
+        let outlines = variable_outlines(&glyphs, &gvar, &hmtx);
+        let gid = GlyphId::new(0);
+        let default = outlines.outline(gid).unwrap();
+        assert_eq!(default.advance_width(), 500.0);
+        let coords = [F2Dot14::from_f32(0.5)];
+        let outline = outlines.outline_with_coords(gid, &coords).unwrap();
+        // points 1, 3 and 4 have inferred deltas
+        assert_eq!(
+            point_list(&outline),
+            [
+                (0.0, 0.0),
+                (55.0, 0.0),
+                (110.0, 5.0),
+                (110.0, 100.0),
+                (0.0, 100.0)
+            ]
+        );
+        assert_eq!(outline.advance_width(), 515.0);
+        assert_eq!(outline.left_side_bearing(), 0.0);
+    }
+
+    #[test]
+    fn varied_composite_outline() {
+        let (glyphs, gvar, hmtx) = variable_glyphs();
+        let outlines = variable_outlines(&glyphs, &gvar, &hmtx);
+        let gid = GlyphId::new(1);
+        let default = outlines.outline(gid).unwrap();
+        assert_eq!(default.points[0], Point::new(10.0, 20.0));
+        assert_eq!(default.left_side_bearing(), 20.0);
+        let coords = [F2Dot14::from_f32(1.0)];
+        let outline = outlines.outline_with_coords(gid, &coords).unwrap();
+        // the component is varied and then moved by its varied offset
+        assert_eq!(
+            point_list(&outline),
+            [
+                (15.0, 15.0),
+                (75.0, 15.0),
+                (135.0, 25.0),
+                (135.0, 115.0),
+                (15.0, 115.0)
+            ]
+        );
+        assert_eq!(outline.phantom_points[0], Point::new(-10.0, 0.0));
+        assert_eq!(outline.advance_width(), 510.0);
+        assert_eq!(outline.left_side_bearing(), 25.0);
+    }
+
+    /// The `head` and `maxp` tables needed to create a hinting instance,
+    /// with 1000 units per em.
+    struct HintingFont {
+        head: BeBuffer,
+        maxp: BeBuffer,
+    }
+
+    impl HintingFont {
+        fn new() -> Self {
+            let mut head = BeBuffer::new();
+            head.extend([0u16; 9]);
+            head.push(1000u16); // units per em
+            head.extend([0u16; 17]);
+            let mut maxp = BeBuffer::new();
+            maxp.push(0x00010000u32);
+            maxp.push(3u16); // number of glyphs
+            maxp.extend([0u16; 9]);
+            maxp.push(16u16); // max stack elements
+            maxp.extend([0u16; 3]);
+            Self { head, maxp }
+        }
+    }
+
+    impl<'a> TableProvider<'a> for &'a HintingFont {
+        fn data_for_tag(&self, tag: font_types::Tag) -> Option<FontData<'a>> {
+            match tag {
+                crate::tables::head::TAG => Some(self.head.font_data()),
+                crate::tables::maxp::TAG => Some(self.maxp.font_data()),
+                _ => None,
+            }
+        }
+    }
+
+    #[test]
+    fn hinted_outlines() {
+        let instructions = [
+            // SVTCA[y], then PUSHB 0 2, MDAP[rnd] twice: round points 0 and 2
+            0x00, 0xB1, 0, 2, 0x2F, 0x2F, // IUP[y]
+            0x30,
+        ];
+        let glyph0 = instructed_glyph(
+            &[
+                (0, 0, true),
+                (130, 0, true),
+                (130, 130, true),
+                (0, 130, true),
+            ],
+            &instructions,
+        );
+        // 1: glyph 0 with an offset that is rounded to the grid
+        let mut glyph1 = BeBuffer::new();
+        glyph1.extend([-1i16, 0, 0, 0, 0]);
+        // ARGS_ARE_XY_VALUES | ROUND_XY_TO_GRID
+        glyph1.extend([0x0006u16, 0]);
+        glyph1.extend([10i8, 20]);
+        // 2: glyph 1 nested in another composite
+        let mut glyph2 = BeBuffer::new();
+        glyph2.extend([-1i16, 0, 0, 0, 0]);
+        glyph2.extend([0x0002u16, 1]);
+        glyph2.extend([0i8, 0]);
+        let glyphs = TestGlyphs::new(&[glyph0, glyph1, glyph2]);
+        let outlines = glyphs.outlines();
+        let font = HintingFont::new();
+        // 10 ppem at 1000 units per em: 130 units is 83/64 pixels
+        let instance =
+            HintingInstance::new(&&font, 10, &[], InterpreterVersion::default()).unwrap();
+        let outline = outlines.hinted_outline(GlyphId::new(0), &instance).unwrap();
+        let x = 83.0 / 64.0;
+        // point 3 is moved along with point 2 by IUP
+        assert_eq!(
+            point_list(&outline),
+            [(0.0, 0.0), (x, 0.0), (x, 1.0), (0.0, 1.0)]
+        );
+        assert_eq!(outline.contours, [3]);
+        // the offset of (6, 13) in 26.6 is only rounded in y, as there is
+        // no horizontal hinting in backward compatibility mode
+        let dx = 6.0 / 64.0;
+        let expected = [(dx, 0.0), (x + dx, 0.0), (x + dx, 1.0), (dx, 1.0)];
+        for gid in [1, 2] {
+            let outline = outlines
+                .hinted_outline(GlyphId::new(gid), &instance)
+                .unwrap();
+            assert_eq!(point_list(&outline), expected);
+        }
+        let instance = HintingInstance::new(&&font, 10, &[], InterpreterVersion::V35).unwrap();
+        let outline = outlines.hinted_outline(GlyphId::new(1), &instance).unwrap();
+        assert_eq!(outline.points[1], Point::new(x, 0.0));
+    }
+}
@@ -0,0 +1,370 @@
+//! Loading outlines from the `CFF ` table.
+
+use std::ops::Range;
+
+use font_types::{Fixed, GlyphId};
+
+use crate::{
+    layout::ItemVariationStore,
+    pen::OutlinePen,
+    postscript::{
+        charset::Charset,
+        charstring::{self, Subroutines},
+        dict::{self, Entry},
+        encoding::Encoding,
+        fd_select::FdSelect,
+        Index,
+    },
+    tables::cff::Cff,
+    FontData, ReadError, TableProvider,
+};
+
+/// Loads outlines from the `CFF ` table.
+///
+/// Only the first font in the table is used, as required for OpenType.
+#[derive(Clone)]
+pub struct CffOutlines<'a> {
+    data: FontData<'a>,
+    global_subrs: Index<'a>,
+    charstrings: Index<'a>,
+    charset: Charset<'a>,
+    /// The Private DICT of a font that is not CID-keyed.
+    private: PrivateDict<'a>,
+    /// The font DICTs and selector of a CID-keyed font.
+    fd_array: Option<(Index<'a>, FdSelect<'a>)>,
+}
+
+impl<'a> CffOutlines<'a> {
+    /// Creates a new outline loader for the given font.
+    ///
+    /// The `CFF ` table is required.
+    pub fn new(font: &impl TableProvider<'a>) -> Result<Self, ReadError> {
+        Self::from_table(&font.cff()?)
+    }
+
+    /// Creates a new outline loader for the first font in the given table.
+    pub fn from_table(cff: &Cff<'a>) -> Result<Self, ReadError> {
+        let data = cff.offset_data();
+        let top_dict = cff.top_dicts()?.get(0)?;
+        let mut charstrings_offset = None;
+        let mut charset_offset = 0;
+        let mut private_range = None;
+        let mut fd_array_offset = None;
+        let mut fd_select_offset = None;
+        for entry in dict::entries(top_dict) {
+            match entry? {
+                Entry::CharstringsOffset(offset) => charstrings_offset = Some(offset),
+                Entry::Charset(offset) => charset_offset = offset,
+                Entry::PrivateDictRange(range) => private_range = Some(range),
+                Entry::FdArrayOffset(offset) => fd_array_offset = Some(offset),
+                Entry::FdSelectOffset(offset) => fd_select_offset = Some(offset),
+                Entry::CharstringType(kind) if kind != 2 => {
+                    return Err(ReadError::InvalidFormat(kind as _))
+                }
+                _ => (),
+            }
+        }
+        let charstrings_offset =
+            charstrings_offset.ok_or(ReadError::MalformedData("missing CharStrings"))?;
+        let charstrings = Index::read_cff(split_off(data, charstrings_offset)?)?;
+        let charset = Charset::new(data, charset_offset, charstrings.count())?;
+        let private = private_range
+            .map(|range| PrivateDict::new(data, range, false, None))
+            .transpose()?
+            .unwrap_or_default();
+        let fd_array = match (fd_array_offset, fd_select_offset) {
+            (Some(fd_array_offset), Some(fd_select_offset)) => Some((
+                Index::read_cff(split_off(data, fd_array_offset)?)?,
+                FdSelect::new(split_off(data, fd_select_offset)?)?,
+            )),
+            _ => None,
+        };
+        Ok(Self {
+            data,
+            global_subrs: cff.global_subrs()?,
+            charstrings,
+            charset,
+            private,
+            fd_array,
+        })
+    }
+
+    /// The number of glyphs in the font.
+    pub fn glyph_count(&self) -> u32 {
+        self.charstrings.count()
+    }
+
+    /// Returns true if this is a CID-keyed font.
+    pub fn is_cid(&self) -> bool {
+        self.fd_array.is_some()
+    }
+
+    /// Draws the outline of the given glyph to the given pen, returning its
+    /// advance width.
+    pub fn draw_glyph(&self, gid: GlyphId, pen: &mut impl OutlinePen) -> Result<f32, ReadError> {
+        let width = self.draw(gid, pen, true)?;
+        Ok(width.to_f64() as f32)
+    }
+
+    fn draw(
+        &self,
+        gid: GlyphId,
+        pen: &mut impl OutlinePen,
+        allow_seac: bool,
+    ) -> Result<Fixed, ReadError> {
+        let private = self.private_dict(gid)?;
+        let charstring = self.charstrings.get(gid.to_u16() as usize)?;
+        let subrs = Subroutines {
+            global: self.global_subrs,
+            local: private.subrs,
+        };
+        let info = charstring::evaluate(charstring, subrs, pen)?;
+        if let Some(seac) = info.seac {
+            if !allow_seac {
+                return Err(ReadError::MalformedData("nested seac"));
+            }
+            // components are selected by their code in the Standard encoding
+            let component = |code| {
+                Encoding::standard()
+                    .map(code, &self.charset)
+                    .ok_or(ReadError::MalformedData("invalid seac component"))
+            };
+            self.draw(component(seac.base_code)?, pen, false)?;
+            let (dx, dy) = seac.accent_offset;
+            let mut accent_pen = OffsetPen {
+                pen,
+                dx: dx.to_f64() as f32,
+                dy: dy.to_f64() as f32,
+            };
+            self.draw(component(seac.accent_code)?, &mut accent_pen, false)?;
+        }
+        Ok(match info.width {
+            Some(width) => private.nominal_width_x.wrapping_add(width),
+            None => private.default_width_x,
+        })
+    }
+
+    /// Returns the Private DICT for the given glyph, which is selected by
+    /// the FDSelect in CID-keyed fonts.
+    fn private_dict(&self, gid: GlyphId) -> Result<PrivateDict<'a>, ReadError> {
+        let Some((fd_array, fd_select)) = &self.fd_array else {
+            return Ok(self.private);
+        };
+        let fd = fd_select
+            .font_index(gid)
+            .ok_or(ReadError::MalformedData("glyph is missing from FDSelect"))?;
+        let font_dict = fd_array.get(fd as usize)?;
+        for entry in dict::entries(font_dict) {
+            if let Entry::PrivateDictRange(range) = entry? {
+                return PrivateDict::new(self.data, range, false, None);
+            }
+        }
+        Ok(PrivateDict::default())
+    }
+}
+
+/// The parts of a Private DICT needed to evaluate charstrings.
+#[derive(Clone, Copy, Default)]
+pub(super) struct PrivateDict<'a> {
+    pub(super) subrs: Option<Index<'a>>,
+    default_width_x: Fixed,
+    nominal_width_x: Fixed,
+    /// The default item variation data index, in CFF2.
+    pub(super) vsindex: u16,
+}
+
+impl<'a> PrivateDict<'a> {
+    /// Parses the Private DICT at the given range of the table data.
+    ///
+    /// The variation store of a CFF2 table resolves any blended operands.
+    pub(super) fn new(
+        data: FontData<'a>,
+        range: Range<usize>,
+        is_cff2: bool,
+        var_store: Option<ItemVariationStore<'a>>,
+    ) -> Result<Self, ReadError> {
+        let dict_data = data
+            .slice(range.clone())
+            .ok_or(ReadError::OutOfBounds)?
+            .as_bytes();
+        let mut dict = Self::default();
+        let entries = match var_store {
+            Some(var_store) => dict::Entries::with_var_store(dict_data, var_store),
+            None => dict::entries(dict_data),
+        };
+        for entry in entries {
+            match entry? {
+                // the subroutine offset is relative to the Private DICT
+                Entry::SubrsOffset(offset) => {
+                    let subrs_data = split_off(data, range.start.saturating_add(offset))?;
+                    dict.subrs = Some(if is_cff2 {
+                        Index::read_cff2(subrs_data)?
+                    } else {
+                        Index::read_cff(subrs_data)?
+                    });
+                }
+                Entry::VariationStoreIndex(vsindex) => dict.vsindex = vsindex,
+                Entry::DefaultWidthX(width) => dict.default_width_x = width,
+                Entry::NominalWidthX(width) => dict.nominal_width_x = width,
+                _ => (),
+            }
+        }
+        Ok(dict)
+    }
+}
+
+pub(super) fn split_off(data: FontData, offset: usize) -> Result<FontData, ReadError> {
+    data.split_off(offset).ok_or(ReadError::OutOfBounds)
+}
+
+/// A pen that translates everything drawn to it.
+struct OffsetPen<'p> {
+    pen: &'p mut dyn OutlinePen,
+    dx: f32,
+    dy: f32,
+}
+
+impl OutlinePen for OffsetPen<'_> {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.pen.move_to(x + self.dx, y + self.dy);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.pen.line_to(x + self.dx, y + self.dy);
+    }
+
+    fn quad_to(&mut self, cx0: f32, cy0: f32, x: f32, y: f32) {
+        self.pen
+            .quad_to(cx0 + self.dx, cy0 + self.dy, x + self.dx, y + self.dy);
+    }
+
+    fn curve_to(&mut self, cx0: f32, cy0: f32, cx1: f32, cy1: f32, x: f32, y: f32) {
+        let (dx, dy) = (self.dx, self.dy);
+        self.pen
+            .curve_to(cx0 + dx, cy0 + dy, cx1 + dx, cy1 + dy, x + dx, y + dy);
+    }
+
+    fn close(&mut self) {
+        self.pen.close();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        pen::SvgPen,
+        test_helpers::{cff_index, charstring_num, dict_int},
+        FontRead,
+    };
+
+    /// A font with a glyph that uses a local subroutine, an accent glyph
+    /// and a seac glyph composed from the two.
+    fn test_cff() -> Vec<u8> {
+        const ENDCHAR: u8 = 14;
+        let notdef = [ENDCHAR];
+        // width 50, then a square drawn in a local subroutine
+        let glyph1 = [
+            charstring_num(50),
+            charstring_num(10),
+            charstring_num(20),
+            21,
+            charstring_num(-107),
+            10,
+            ENDCHAR,
+        ];
+        let glyph2 = [
+            charstring_num(0),
+            charstring_num(0),
+            21,
+            charstring_num(10),
+            charstring_num(10),
+            5,
+            ENDCHAR,
+        ];
+        // base 'A' (65) with an acute accent (194)
+        let glyph3 = [
+            charstring_num(100),
+            charstring_num(5),
+            charstring_num(6),
+            charstring_num(65),
+            247,
+            86,
+            ENDCHAR,
+        ];
+        let charstrings = cff_index(&[&notdef, &glyph1, &glyph2, &glyph3]);
+        let local_subr = [
+            charstring_num(100),
+            charstring_num(100),
+            charstring_num(-100),
+            6,
+            11,
+        ];
+
+        let mut private = Vec::new();
+        private.extend(dict_int(500));
+        private.push(20);
+        private.extend(dict_int(400));
+        private.push(21);
+        private.extend(dict_int(18));
+        private.push(19);
+        let subrs = cff_index(&[&local_subr]);
+        // format 0: A, acute and a custom string
+        let charset = [0u8, 0, 34, 0, 125, 1, 135];
+
+        let top_dict_len = 23;
+        let charstrings_offset = 4 + 6 + (5 + top_dict_len) + 2 + 2;
+        let private_offset = charstrings_offset + charstrings.len();
+        let charset_offset = private_offset + private.len() + subrs.len();
+        let mut top_dict = Vec::new();
+        top_dict.extend(dict_int(charstrings_offset as i32));
+        top_dict.push(17);
+        top_dict.extend(dict_int(private.len() as i32));
+        top_dict.extend(dict_int(private_offset as i32));
+        top_dict.push(18);
+        top_dict.extend(dict_int(charset_offset as i32));
+        top_dict.push(15);
+        assert_eq!(top_dict.len(), top_dict_len);
+
+        let mut cff = vec![1, 0, 4, 1];
+        cff.extend(cff_index(&[b"A"]));
+        cff.extend(cff_index(&[&top_dict]));
+        cff.extend(cff_index(&[]));
+        cff.extend(cff_index(&[]));
+        assert_eq!(cff.len(), charstrings_offset);
+        cff.extend(charstrings);
+        cff.extend(private);
+        cff.extend(subrs);
+        cff.extend(charset);
+        cff
+    }
+
+    fn draw(outlines: &CffOutlines, gid: u16) -> (String, f32) {
+        let mut pen = SvgPen::new();
+        let width = outlines.draw_glyph(GlyphId::new(gid), &mut pen).unwrap();
+        (pen.into_string(), width)
+    }
+
+    #[test]
+    fn draw_glyphs() {
+        let data = test_cff();
+        let cff = Cff::read(FontData::new(&data)).unwrap();
+        assert_eq!(cff.names().unwrap().get(0).unwrap(), b"A");
+        let outlines = CffOutlines::from_table(&cff).unwrap();
+        assert_eq!(outlines.glyph_count(), 4);
+        assert!(!outlines.is_cid());
+        assert_eq!(draw(&outlines, 0), (String::new(), 500.0));
+        assert_eq!(
+            draw(&outlines, 1),
+            ("M10,20 L110,20 L110,120 L10,120 Z".into(), 450.0)
+        );
+        assert_eq!(draw(&outlines, 2), ("M0,0 L10,10 Z".into(), 500.0));
+        assert_eq!(
+            draw(&outlines, 3),
+            (
+                "M10,20 L110,20 L110,120 L10,120 Z M5,6 L15,16 Z".into(),
+                500.0
+            )
+        );
+    }
+}
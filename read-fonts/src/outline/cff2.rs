@@ -0,0 +1,233 @@
+//! Loading outlines from the `CFF2` table.
+
+use font_types::{F2Dot14, GlyphId};
+
+use super::cff::{split_off, PrivateDict};
+use crate::{
+    layout::ItemVariationStore,
+    pen::OutlinePen,
+    postscript::{
+        charstring::{self, BlendState, Subroutines},
+        dict::{self, Entry},
+        fd_select::FdSelect,
+        Index,
+    },
+    tables::cff2::Cff2,
+    FontData, FontRead, ReadError, TableProvider,
+};
+
+/// Loads outlines from the `CFF2` table.
+#[derive(Clone)]
+pub struct Cff2Outlines<'a> {
+    data: FontData<'a>,
+    global_subrs: Index<'a>,
+    charstrings: Index<'a>,
+    fd_array: Index<'a>,
+    /// Selects the font DICT for each glyph, if there is more than one.
+    fd_select: Option<FdSelect<'a>>,
+    var_store: Option<ItemVariationStore<'a>>,
+}
+
+impl<'a> Cff2Outlines<'a> {
+    /// Creates a new outline loader for the given font.
+    ///
+    /// The `CFF2` table is required.
+    pub fn new(font: &impl TableProvider<'a>) -> Result<Self, ReadError> {
+        Self::from_table(&font.cff2()?)
+    }
+
+    /// Creates a new outline loader for the given table.
+    pub fn from_table(cff2: &Cff2<'a>) -> Result<Self, ReadError> {
+        let data = cff2.offset_data();
+        let mut charstrings_offset = None;
+        let mut fd_array_offset = None;
+        let mut fd_select_offset = None;
+        let mut var_store_offset = None;
+        for entry in dict::entries(cff2.top_dict_data()) {
+            match entry? {
+                Entry::CharstringsOffset(offset) => charstrings_offset = Some(offset),
+                Entry::FdArrayOffset(offset) => fd_array_offset = Some(offset),
+                Entry::FdSelectOffset(offset) => fd_select_offset = Some(offset),
+                Entry::VariationStoreOffset(offset) => var_store_offset = Some(offset),
+                _ => (),
+            }
+        }
+        let charstrings_offset =
+            charstrings_offset.ok_or(ReadError::MalformedData("missing CharStrings"))?;
+        let fd_array_offset = fd_array_offset.ok_or(ReadError::MalformedData("missing FDArray"))?;
+        let fd_select = fd_select_offset
+            .map(|offset| FdSelect::new(split_off(data, offset)?))
+            .transpose()?;
+        // the variation store is preceded by its length
+        let var_store = var_store_offset
+            .map(|offset| ItemVariationStore::read(split_off(data, offset.saturating_add(2))?))
+            .transpose()?;
+        Ok(Self {
+            data,
+            global_subrs: cff2.global_subrs()?,
+            charstrings: Index::read_cff2(split_off(data, charstrings_offset)?)?,
+            fd_array: Index::read_cff2(split_off(data, fd_array_offset)?)?,
+            fd_select,
+            var_store,
+        })
+    }
+
+    /// The number of glyphs in the font.
+    pub fn glyph_count(&self) -> u32 {
+        self.charstrings.count()
+    }
+
+    /// Draws the outline of the given glyph to the given pen.
+    pub fn draw_glyph(&self, gid: GlyphId, pen: &mut impl OutlinePen) -> Result<(), ReadError> {
+        self.draw_glyph_with_coords(gid, &[], pen)
+    }
+
+    /// Draws the outline of the given glyph at the given normalized
+    /// variation coordinates to the given pen.
+    pub fn draw_glyph_with_coords(
+        &self,
+        gid: GlyphId,
+        coords: &[F2Dot14],
+        pen: &mut impl OutlinePen,
+    ) -> Result<(), ReadError> {
+        let private = self.private_dict(gid)?;
+        let charstring = self.charstrings.get(gid.to_u16() as usize)?;
+        let subrs = Subroutines {
+            global: self.global_subrs,
+            local: private.subrs,
+        };
+        let blend = self
+            .var_store
+            .map(|store| BlendState::new(store, private.vsindex, coords));
+        charstring::evaluate_cff2(charstring, subrs, blend, pen)
+    }
+
+    /// Returns the Private DICT for the given glyph, from the font DICT
+    /// selected by the FDSelect.
+    fn private_dict(&self, gid: GlyphId) -> Result<PrivateDict<'a>, ReadError> {
+        let fd = match &self.fd_select {
+            Some(fd_select) => fd_select
+                .font_index(gid)
+                .ok_or(ReadError::MalformedData("glyph is missing from FDSelect"))?,
+            None => 0,
+        };
+        let font_dict = self.fd_array.get(fd as usize)?;
+        for entry in dict::entries(font_dict) {
+            if let Entry::PrivateDictRange(range) = entry? {
+                return PrivateDict::new(self.data, range, true, self.var_store);
+            }
+        }
+        Ok(PrivateDict::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        pen::SvgPen,
+        test_helpers::{cff2_index, charstring_num, dict_int, BeBuffer},
+    };
+
+    /// A variation store with a single region on a single axis, peaking at
+    /// the maximum.
+    fn var_store() -> Vec<u8> {
+        let mut buf = BeBuffer::new();
+        buf.push(1u16);
+        buf.push(12u32);
+        buf.push(1u16);
+        buf.push(22u32);
+        // the region list
+        buf.extend([1u16, 1]);
+        buf.extend([0u16, 0x4000, 0x4000]);
+        // item variation data with no items and a single region
+        buf.extend([0u16, 0, 1, 0]);
+        buf.to_vec()
+    }
+
+    /// A font with a glyph that draws a line with a blended length in a
+    /// local subroutine.
+    fn test_cff2() -> Vec<u8> {
+        const CALLSUBR: u8 = 10;
+        const VSINDEX: u8 = 15;
+        const BLEND: u8 = 16;
+        const RMOVETO: u8 = 21;
+        const RLINETO: u8 = 5;
+        let glyph1 = [
+            charstring_num(0),
+            VSINDEX,
+            charstring_num(10),
+            charstring_num(20),
+            RMOVETO,
+            charstring_num(-107),
+            CALLSUBR,
+        ];
+        let local_subr = [
+            charstring_num(100),
+            charstring_num(50),
+            charstring_num(1),
+            BLEND,
+            charstring_num(0),
+            RLINETO,
+        ];
+        let charstrings = cff2_index(&[&[], &glyph1]);
+
+        let var_store = var_store();
+        let top_dict_len = 19;
+        let var_store_offset = 5 + top_dict_len + 4;
+        let charstrings_offset = var_store_offset + 2 + var_store.len();
+        let fd_array_offset = charstrings_offset + charstrings.len();
+        // the font DICT INDEX has a single 11 byte DICT
+        let private_offset = fd_array_offset + 4 + 1 + 2 + 11;
+        let mut private = dict_int(6);
+        private.push(19);
+        let mut font_dict = dict_int(private.len() as i32);
+        font_dict.extend(dict_int(private_offset as i32));
+        font_dict.push(18);
+        let mut top_dict = dict_int(charstrings_offset as i32);
+        top_dict.push(17);
+        top_dict.extend(dict_int(fd_array_offset as i32));
+        top_dict.extend([12, 36]);
+        top_dict.extend(dict_int(var_store_offset as i32));
+        top_dict.push(24);
+        assert_eq!(top_dict.len(), top_dict_len);
+
+        let mut cff2 = vec![2, 0, 5];
+        cff2.extend((top_dict_len as u16).to_be_bytes());
+        cff2.extend(top_dict);
+        cff2.extend(cff2_index(&[]));
+        cff2.extend((var_store.len() as u16).to_be_bytes());
+        cff2.extend(var_store);
+        cff2.extend(charstrings);
+        cff2.extend(cff2_index(&[&font_dict]));
+        assert_eq!(cff2.len(), private_offset);
+        cff2.extend(private);
+        cff2.extend(cff2_index(&[&local_subr]));
+        cff2
+    }
+
+    #[test]
+    fn draw_blended_glyphs() {
+        let data = test_cff2();
+        let cff2 = Cff2::read(FontData::new(&data)).unwrap();
+        assert_eq!(cff2.major_version(), 2);
+        let outlines = Cff2Outlines::from_table(&cff2).unwrap();
+        assert_eq!(outlines.glyph_count(), 2);
+        let draw = |coord: f32| {
+            let mut pen = SvgPen::new();
+            let coords = [F2Dot14::from_f32(coord)];
+            outlines
+                .draw_glyph_with_coords(GlyphId::new(1), &coords, &mut pen)
+                .unwrap();
+            pen.into_string()
+        };
+        assert_eq!(draw(0.0), "M10,20 L110,20 Z");
+        assert_eq!(draw(0.5), "M10,20 L135,20 Z");
+        assert_eq!(draw(1.0), "M10,20 L160,20 Z");
+        assert_eq!(draw(-1.0), "M10,20 L110,20 Z");
+
+        let mut pen = SvgPen::new();
+        outlines.draw_glyph(GlyphId::new(0), &mut pen).unwrap();
+        assert!(pen.into_string().is_empty());
+    }
+}
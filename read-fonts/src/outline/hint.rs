@@ -0,0 +1,229 @@
+//! Loading of grid-fitted outlines from the `glyf` table.
+
+use font_types::{GlyphId, Point};
+
+use super::{
+    apply_transform, has_scaled_offset, GlyfOutlines, Outline, GLYF_COMPOSITE_RECURSION_LIMIT,
+};
+use crate::{
+    tables::glyf::{ComponentPos, CompositeGlyph, CompositeGlyphFlags, Glyph, Transform},
+    truetype::{
+        hint::{mul_fix, round, PointFlags, Zone},
+        HintError, HintingInstance,
+    },
+    ReadError,
+};
+
+/// The accumulated points of a glyph as 26.6 values.
+#[derive(Default)]
+struct HintedGlyph {
+    points: Vec<Point<i32>>,
+    on_curve: Vec<bool>,
+    contours: Vec<usize>,
+}
+
+impl HintedGlyph {
+    /// Appends the points of a hinted zone, returning its phantom points.
+    fn append(&mut self, zone: Zone) -> [Point<i32>; 4] {
+        let len = zone.len() - 4;
+        let base = self.points.len();
+        self.points.extend_from_slice(&zone.points[..len]);
+        self.on_curve.extend(
+            zone.flags[..len]
+                .iter()
+                .map(|flags| flags.contains(PointFlags::ON_CURVE)),
+        );
+        self.contours
+            .extend(zone.contours.iter().map(|end| base + end));
+        [0, 1, 2, 3].map(|idx| zone.points[len + idx])
+    }
+}
+
+impl<'a> GlyfOutlines<'a> {
+    /// Returns the outline of the given glyph, grid-fitted by its TrueType
+    /// instructions at the size and location of the given instance.
+    ///
+    /// Unlike [`outline`](Self::outline), the points and phantom points of
+    /// the returned outline are in pixels rather than font units.
+    pub fn hinted_outline(
+        &self,
+        gid: GlyphId,
+        instance: &HintingInstance,
+    ) -> Result<Outline, HintError> {
+        let mut glyph = HintedGlyph::default();
+        let phantom = self.load_hinted(gid, instance, &mut glyph, 0)?;
+        let to_pixels = |point: Point<i32>| point.map(|coord| coord as f32 / 64.0);
+        Ok(Outline {
+            points: glyph.points.into_iter().map(to_pixels).collect(),
+            on_curve: glyph.on_curve,
+            contours: glyph.contours,
+            phantom_points: phantom.map(to_pixels),
+        })
+    }
+
+    /// Appends the hinted points of the given glyph to `glyph`, returning
+    /// its phantom points.
+    fn load_hinted(
+        &self,
+        gid: GlyphId,
+        instance: &HintingInstance,
+        glyph: &mut HintedGlyph,
+        depth: usize,
+    ) -> Result<[Point<i32>; 4], HintError> {
+        if depth > GLYF_COMPOSITE_RECURSION_LIMIT {
+            return Err(
+                ReadError::MalformedData("composite glyph recursion limit exceeded").into(),
+            );
+        }
+        let instructions = match self.loca.get_glyph(gid, &self.glyf)? {
+            Some(Glyph::Composite(composite)) => {
+                return self.load_hinted_composite(gid, &composite, instance, glyph, depth)
+            }
+            Some(Glyph::Simple(simple)) => simple.instructions(),
+            None => &[],
+        };
+        // the unhinted loader applies any variations
+        let mut outline = Outline::new();
+        let phantom = self.load(gid, instance.coords(), &mut outline, depth)?;
+        let scale = instance.scale();
+        let mut zone = Zone {
+            unscaled: outline
+                .points
+                .iter()
+                .chain(&phantom)
+                .map(|point| point.map(|coord| coord.round() as i32))
+                .collect(),
+            contours: outline.contours,
+            ..Default::default()
+        };
+        zone.points = zone
+            .unscaled
+            .iter()
+            .map(|point| point.map(|coord| mul_fix(coord, scale)))
+            .collect();
+        zone.original.clone_from(&zone.points);
+        zone.flags = outline
+            .on_curve
+            .iter()
+            .chain(&[false; 4])
+            .map(|on_curve| point_flags(*on_curve))
+            .collect();
+        instance.hint_glyph(&mut zone, instructions, false)?;
+        Ok(glyph.append(zone))
+    }
+
+    /// Appends the hinted components of a composite glyph to `glyph` and
+    /// runs the instructions of the composite over them.
+    fn load_hinted_composite(
+        &self,
+        gid: GlyphId,
+        composite: &CompositeGlyph,
+        instance: &HintingInstance,
+        glyph: &mut HintedGlyph,
+        depth: usize,
+    ) -> Result<[Point<i32>; 4], HintError> {
+        let scale = instance.scale();
+        let mut phantom = self.phantom_points(gid, composite.x_min(), composite.y_max());
+        let deltas = self.composite_deltas(composite, gid, instance.coords(), &mut phantom)?;
+        let glyph_start = glyph.points.len();
+        let contours_start = glyph.contours.len();
+        let mut metrics = None;
+        for (idx, component) in composite.iter_components().enumerate() {
+            let start = glyph.points.len();
+            let component_phantom =
+                self.load_hinted(component.glyph, instance, glyph, depth + 1)?;
+            if component
+                .flags
+                .contains(CompositeGlyphFlags::USE_MY_METRICS)
+            {
+                metrics = Some(component_phantom);
+            }
+            let transform = component.transform;
+            let has_transform = transform != Transform::default();
+            if has_transform {
+                for point in &mut glyph.points[start..] {
+                    let transformed = apply_transform(&transform, point.map(|coord| coord as f32));
+                    *point = transformed.map(|coord| coord.round() as i32);
+                }
+            }
+            let offset = match component.pos {
+                ComponentPos::Offset { x, y } => {
+                    let mut offset = Point::new(x as f32, y as f32);
+                    if let Some(delta) = deltas.get(idx) {
+                        offset += *delta;
+                    }
+                    if has_transform && has_scaled_offset(component.flags) {
+                        offset = apply_transform(&transform, offset);
+                    }
+                    let offset = offset.map(|coord| mul_fix(coord.round() as i32, scale));
+                    if instance.is_enabled()
+                        && component
+                            .flags
+                            .contains(CompositeGlyphFlags::ROUND_XY_TO_GRID)
+                    {
+                        let x = if instance.rounds_x_offsets() {
+                            round(offset.x)
+                        } else {
+                            offset.x
+                        };
+                        Point::new(x, round(offset.y))
+                    } else {
+                        offset
+                    }
+                }
+                ComponentPos::Point { base, component } => {
+                    // the base point must come from a previous component
+                    let base_point = glyph.points[glyph_start..start].get(base as usize).copied();
+                    let component_point = glyph.points[start..].get(component as usize).copied();
+                    match base_point.zip(component_point) {
+                        Some((base, component)) => base - component,
+                        None => return Err(ReadError::MalformedData("invalid anchor point").into()),
+                    }
+                }
+            };
+            if offset != Point::default() {
+                for point in &mut glyph.points[start..] {
+                    *point += offset;
+                }
+            }
+        }
+        let phantom = metrics.unwrap_or_else(|| {
+            phantom.map(|point| point.map(|coord| mul_fix(coord.round() as i32, scale)))
+        });
+        // the instructions of the composite operate on the already hinted
+        // components, which serve as both the original and unscaled points
+        let points = glyph.points[glyph_start..]
+            .iter()
+            .chain(&phantom)
+            .copied()
+            .collect::<Vec<_>>();
+        let mut zone = Zone {
+            unscaled: points.clone(),
+            original: points.clone(),
+            points,
+            flags: glyph.on_curve[glyph_start..]
+                .iter()
+                .chain(&[false; 4])
+                .map(|on_curve| point_flags(*on_curve))
+                .collect(),
+            contours: glyph.contours[contours_start..]
+                .iter()
+                .map(|end| end - glyph_start)
+                .collect(),
+        };
+        let instructions = composite.instructions().unwrap_or_default();
+        instance.hint_glyph(&mut zone, instructions, true)?;
+        glyph.points.truncate(glyph_start);
+        glyph.on_curve.truncate(glyph_start);
+        glyph.contours.truncate(contours_start);
+        Ok(glyph.append(zone))
+    }
+}
+
+fn point_flags(on_curve: bool) -> PointFlags {
+    if on_curve {
+        PointFlags::ON_CURVE
+    } else {
+        PointFlags::default()
+    }
+}
@@ -0,0 +1,92 @@
+//! Drawing glyph outlines.
+
+/// Interface for accepting a sequence of path commands.
+///
+/// Coordinates are in font units, with the y axis pointing up.
+pub trait OutlinePen {
+    /// Starts a new contour at the given point.
+    fn move_to(&mut self, x: f32, y: f32);
+
+    /// Adds a line segment from the current point to the given point.
+    fn line_to(&mut self, x: f32, y: f32);
+
+    /// Adds a quadratic bezier segment from the current point, with the
+    /// given control point and end point.
+    fn quad_to(&mut self, cx0: f32, cy0: f32, x: f32, y: f32);
+
+    /// Adds a cubic bezier segment from the current point, with the given
+    /// control points and end point.
+    fn curve_to(&mut self, cx0: f32, cy0: f32, cx1: f32, cy1: f32, x: f32, y: f32);
+
+    /// Closes the current contour.
+    fn close(&mut self);
+}
+
+/// A pen that builds the data for an SVG `path` element.
+///
+/// Coordinates are written as-is, so the y axis is not flipped.
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, Default)]
+pub struct SvgPen(String);
+
+#[cfg(feature = "std")]
+impl SvgPen {
+    /// Creates a new, empty pen.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the path data drawn so far.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Consumes the pen, returning the path data.
+    pub fn into_string(self) -> String {
+        self.0
+    }
+
+    fn command(&mut self, command: char, coords: &[f32]) {
+        use std::fmt::Write;
+        if !self.0.is_empty() {
+            self.0.push(' ');
+        }
+        self.0.push(command);
+        for (i, coord) in coords.iter().enumerate() {
+            if i > 0 {
+                self.0.push(if i % 2 == 0 { ' ' } else { ',' });
+            }
+            let _ = write!(self.0, "{coord}");
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl OutlinePen for SvgPen {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.command('M', &[x, y]);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.command('L', &[x, y]);
+    }
+
+    fn quad_to(&mut self, cx0: f32, cy0: f32, x: f32, y: f32) {
+        self.command('Q', &[cx0, cy0, x, y]);
+    }
+
+    fn curve_to(&mut self, cx0: f32, cy0: f32, cx1: f32, cy1: f32, x: f32, y: f32) {
+        self.command('C', &[cx0, cy0, cx1, cy1, x, y]);
+    }
+
+    fn close(&mut self) {
+        self.command('Z', &[]);
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::fmt::Display for SvgPen {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
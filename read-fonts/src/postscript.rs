@@ -0,0 +1,39 @@
+//! Support for the PostScript based outline formats: CFF and CFF2.
+
+pub mod charset;
+pub mod charstring;
+pub mod dict;
+pub mod encoding;
+pub mod fd_select;
+mod index;
+
+pub use index::Index;
+
+/// An identifier for a string in a CFF font.
+///
+/// Identifiers below [`STANDARD_STRING_COUNT`](Self::STANDARD_STRING_COUNT)
+/// refer to the predefined standard strings; the remainder index the String
+/// INDEX.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StringId(u16);
+
+impl StringId {
+    /// The number of predefined standard strings.
+    pub const STANDARD_STRING_COUNT: u16 = 391;
+
+    pub const fn new(raw: u16) -> Self {
+        Self(raw)
+    }
+
+    pub const fn to_u16(self) -> u16 {
+        self.0
+    }
+
+    /// Returns the index of this string in the String INDEX, or `None` if
+    /// it is a standard string.
+    pub fn string_index(self) -> Option<usize> {
+        self.0
+            .checked_sub(Self::STANDARD_STRING_COUNT)
+            .map(|idx| idx as usize)
+    }
+}
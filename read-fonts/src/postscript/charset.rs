@@ -0,0 +1,267 @@
+//! Parsing for CFF charsets.
+
+use font_types::GlyphId;
+
+use super::StringId;
+use crate::{FontData, ReadError};
+
+/// A mapping from glyph identifiers to string identifiers.
+///
+/// For CID-keyed fonts, the charset maps glyph identifiers to CIDs rather
+/// than string identifiers.
+///
+/// See "Charsets" in the [CFF spec](https://adobe-type-tools.github.io/font-tech-notes/pdfs/5176.CFF.pdf).
+#[derive(Clone)]
+pub struct Charset<'a> {
+    kind: CharsetKind<'a>,
+    num_glyphs: u32,
+}
+
+#[derive(Clone)]
+enum CharsetKind<'a> {
+    IsoAdobe,
+    Expert,
+    ExpertSubset,
+    Format0(&'a [u8]),
+    Format1(&'a [u8]),
+    Format2(&'a [u8]),
+}
+
+impl<'a> Charset<'a> {
+    /// Creates a charset from the `charset` offset in a Top DICT.
+    ///
+    /// `data` is the data for the entire CFF table. Offsets 0, 1 and 2
+    /// select the predefined ISOAdobe, Expert and ExpertSubset charsets.
+    pub fn new(data: FontData<'a>, offset: usize, num_glyphs: u32) -> Result<Self, ReadError> {
+        let kind = match offset {
+            0 => CharsetKind::IsoAdobe,
+            1 => CharsetKind::Expert,
+            2 => CharsetKind::ExpertSubset,
+            _ => {
+                let data = data.split_off(offset).ok_or(ReadError::OutOfBounds)?;
+                let format = data.read_at::<u8>(0)?;
+                let data = data.as_bytes().get(1..).unwrap_or_default();
+                match format {
+                    0 => CharsetKind::Format0(data),
+                    1 => CharsetKind::Format1(data),
+                    2 => CharsetKind::Format2(data),
+                    _ => return Err(ReadError::InvalidFormat(format as _)),
+                }
+            }
+        };
+        Ok(Self { kind, num_glyphs })
+    }
+
+    /// Returns the string identifier (or CID) for the given glyph.
+    pub fn string_id(&self, gid: GlyphId) -> Option<StringId> {
+        let gid = gid.to_u16() as u32;
+        if gid >= self.num_glyphs {
+            return None;
+        }
+        match &self.kind {
+            CharsetKind::IsoAdobe => (gid <= ISO_ADOBE_LAST_SID as u32).then_some(gid as u16),
+            CharsetKind::Expert => EXPERT_CHARSET.get(gid as usize).copied(),
+            CharsetKind::ExpertSubset => EXPERT_SUBSET_CHARSET.get(gid as usize).copied(),
+            CharsetKind::Format0(data) => {
+                if gid == 0 {
+                    return Some(StringId::new(0));
+                }
+                let idx = (gid as usize - 1) * 2;
+                data.get(idx..idx + 2)
+                    .map(|bytes| u16::from_be_bytes([bytes[0], bytes[1]]))
+            }
+            CharsetKind::Format1(_) | CharsetKind::Format2(_) => self
+                .iter()
+                .find(|(candidate, _)| candidate.to_u16() as u32 == gid)
+                .map(|(_, sid)| sid.to_u16()),
+        }
+        .map(StringId::new)
+    }
+
+    /// Returns the glyph identifier for the given string identifier (or
+    /// CID).
+    pub fn glyph_id(&self, sid: StringId) -> Option<GlyphId> {
+        match &self.kind {
+            CharsetKind::IsoAdobe => (sid.to_u16() <= ISO_ADOBE_LAST_SID
+                && (sid.to_u16() as u32) < self.num_glyphs)
+                .then_some(GlyphId::new(sid.to_u16())),
+            _ => self
+                .iter()
+                .find(|(_, candidate)| *candidate == sid)
+                .map(|(gid, _)| gid),
+        }
+    }
+
+    /// Iterate over the glyph identifiers and their string identifiers (or
+    /// CIDs), in glyph order.
+    pub fn iter(&self) -> impl Iterator<Item = (GlyphId, StringId)> + 'a {
+        let mut iter = CharsetIter {
+            kind: self.kind.clone(),
+            num_glyphs: self.num_glyphs,
+            gid: 0,
+            pos: 0,
+            range: None,
+        };
+        std::iter::from_fn(move || iter.next_mapping())
+    }
+}
+
+struct CharsetIter<'a> {
+    kind: CharsetKind<'a>,
+    num_glyphs: u32,
+    gid: u32,
+    /// The position of the next range, for formats 1 and 2.
+    pos: usize,
+    /// The next string identifier and the number left in the current
+    /// range.
+    range: Option<(u16, u16)>,
+}
+
+impl<'a> CharsetIter<'a> {
+    fn next_mapping(&mut self) -> Option<(GlyphId, StringId)> {
+        if self.gid >= self.num_glyphs || self.gid > u16::MAX as u32 {
+            return None;
+        }
+        let gid = self.gid;
+        // .notdef is always the first glyph, and is not included in the data
+        let sid = if gid == 0 {
+            0
+        } else {
+            match &self.kind {
+                CharsetKind::IsoAdobe => {
+                    (gid <= ISO_ADOBE_LAST_SID as u32).then_some(gid as u16)?
+                }
+                CharsetKind::Expert => *EXPERT_CHARSET.get(gid as usize)?,
+                CharsetKind::ExpertSubset => *EXPERT_SUBSET_CHARSET.get(gid as usize)?,
+                CharsetKind::Format0(data) => {
+                    let idx = (gid as usize - 1) * 2;
+                    let bytes = data.get(idx..idx + 2)?;
+                    u16::from_be_bytes([bytes[0], bytes[1]])
+                }
+                CharsetKind::Format1(data) | CharsetKind::Format2(data) => {
+                    let (sid, n_left) = match self.range {
+                        Some(range) => range,
+                        None => self.read_range(data)?,
+                    };
+                    self.range = n_left
+                        .checked_sub(1)
+                        .map(|n_left| (sid.wrapping_add(1), n_left));
+                    sid
+                }
+            }
+        };
+        self.gid += 1;
+        Some((GlyphId::new(gid as u16), StringId::new(sid)))
+    }
+
+    fn read_range(&mut self, data: &[u8]) -> Option<(u16, u16)> {
+        let is_format1 = matches!(self.kind, CharsetKind::Format1(_));
+        let range_len = if is_format1 { 3 } else { 4 };
+        let bytes = data.get(self.pos..self.pos + range_len)?;
+        self.pos += range_len;
+        let first = u16::from_be_bytes([bytes[0], bytes[1]]);
+        let n_left = if is_format1 {
+            bytes[2] as u16
+        } else {
+            u16::from_be_bytes([bytes[2], bytes[3]])
+        };
+        Some((first, n_left))
+    }
+}
+
+/// The ISOAdobe charset maps each glyph to the string with the same
+/// identifier, up to and including this one.
+const ISO_ADOBE_LAST_SID: u16 = 228;
+
+#[rustfmt::skip]
+const EXPERT_CHARSET: [u16; 166] = [
+    0, 1, 229, 230, 231, 232, 233, 234, 235, 236, 237, 238, 13, 14, 15, 99,
+    239, 240, 241, 242, 243, 244, 245, 246, 247, 248, 27, 28, 249, 250, 251, 252,
+    253, 254, 255, 256, 257, 258, 259, 260, 261, 262, 263, 264, 265, 266, 109, 110,
+    267, 268, 269, 270, 271, 272, 273, 274, 275, 276, 277, 278, 279, 280, 281, 282,
+    283, 284, 285, 286, 287, 288, 289, 290, 291, 292, 293, 294, 295, 296, 297, 298,
+    299, 300, 301, 302, 303, 304, 305, 306, 307, 308, 309, 310, 311, 312, 313, 314,
+    315, 316, 317, 318, 158, 155, 163, 319, 320, 321, 322, 323, 324, 325, 326, 150,
+    164, 169, 327, 328, 329, 330, 331, 332, 333, 334, 335, 336, 337, 338, 339, 340,
+    341, 342, 343, 344, 345, 346, 347, 348, 349, 350, 351, 352, 353, 354, 355, 356,
+    357, 358, 359, 360, 361, 362, 363, 364, 365, 366, 367, 368, 369, 370, 371, 372,
+    373, 374, 375, 376, 377, 378,
+];
+
+#[rustfmt::skip]
+const EXPERT_SUBSET_CHARSET: [u16; 87] = [
+    0, 1, 231, 232, 235, 236, 237, 238, 13, 14, 15, 99, 239, 240, 241, 242,
+    243, 244, 245, 246, 247, 248, 27, 28, 249, 250, 251, 253, 254, 255, 256, 257,
+    258, 259, 260, 261, 262, 263, 264, 265, 266, 109, 110, 267, 268, 269, 270, 272,
+    300, 301, 302, 305, 314, 315, 158, 155, 163, 320, 321, 322, 323, 324, 325, 326,
+    150, 164, 169, 327, 328, 329, 330, 331, 332, 333, 334, 335, 336, 337, 338, 339,
+    340, 341, 342, 343, 344, 345, 346,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    fn sids(charset: &Charset) -> Vec<u16> {
+        charset.iter().map(|(_, sid)| sid.to_u16()).collect()
+    }
+
+    /// Returns a buffer with padding for the reserved charset offsets
+    /// followed by the given charset format.
+    fn charset_data(format: u8) -> BeBuffer {
+        let mut buf = BeBuffer::new();
+        buf.extend([0u8, 0, 0, format]);
+        buf
+    }
+
+    #[test]
+    fn custom_charsets() {
+        let mut format0 = charset_data(0);
+        format0.extend([391u16, 5, 392]);
+        let charset = Charset::new(format0.font_data(), 3, 4).unwrap();
+        assert_eq!(sids(&charset), [0, 391, 5, 392]);
+        assert_eq!(charset.string_id(GlyphId::new(2)), Some(StringId::new(5)));
+        assert_eq!(charset.string_id(GlyphId::new(4)), None);
+        assert_eq!(charset.glyph_id(StringId::new(392)), Some(GlyphId::new(3)));
+
+        let mut format1 = charset_data(1);
+        format1.push(100u16);
+        format1.push(2u8);
+        format1.push(500u16);
+        format1.push(0u8);
+        let charset = Charset::new(format1.font_data(), 3, 5).unwrap();
+        assert_eq!(sids(&charset), [0, 100, 101, 102, 500]);
+        assert_eq!(charset.string_id(GlyphId::new(3)), Some(StringId::new(102)));
+        assert_eq!(charset.glyph_id(StringId::new(500)), Some(GlyphId::new(4)));
+        assert_eq!(charset.glyph_id(StringId::new(103)), None);
+
+        let mut format2 = charset_data(2);
+        format2.extend([1000u16, 299]);
+        let charset = Charset::new(format2.font_data(), 3, 301).unwrap();
+        assert_eq!(
+            charset.string_id(GlyphId::new(300)),
+            Some(StringId::new(1299))
+        );
+        assert_eq!(charset.string_id(GlyphId::new(301)), None);
+        assert_eq!(charset.glyph_id(StringId::new(1000)), Some(GlyphId::new(1)));
+
+        assert!(Charset::new(format2.font_data(), 100, 2).is_err());
+    }
+
+    #[test]
+    fn predefined_charsets() {
+        let data = FontData::new(&[]);
+        let iso_adobe = Charset::new(data, 0, 300).unwrap();
+        assert_eq!(iso_adobe.iter().count(), 229);
+        assert_eq!(
+            iso_adobe.glyph_id(StringId::new(100)),
+            Some(GlyphId::new(100))
+        );
+        let expert = Charset::new(data, 1, 300).unwrap();
+        assert_eq!(expert.iter().count(), 166);
+        assert_eq!(expert.glyph_id(StringId::new(378)), Some(GlyphId::new(165)));
+        let subset = Charset::new(data, 2, 10).unwrap();
+        assert_eq!(subset.string_id(GlyphId::new(2)), Some(StringId::new(231)));
+    }
+}
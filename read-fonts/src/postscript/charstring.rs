@@ -0,0 +1,814 @@
+//! Evaluation of Type 2 charstrings.
+//!
+//! See the [Type 2 Charstring Format](https://adobe-type-tools.github.io/font-tech-notes/pdfs/5177.Type2.pdf)
+//! and the [CFF2 charstring format](https://learn.microsoft.com/en-us/typography/opentype/spec/cff2charstr).
+
+use font_types::{F2Dot14, Fixed};
+
+use super::Index;
+use crate::{
+    layout::{ItemVariationData, ItemVariationStore},
+    pen::OutlinePen,
+    ReadError,
+};
+
+/// The maximum nesting depth of subroutine calls.
+const MAX_SUBR_DEPTH: usize = 10;
+
+/// The maximum size of the operand stack in CFF charstrings.
+const MAX_CFF_STACK: usize = 48;
+
+/// The maximum size of the operand stack in CFF2 charstrings.
+const MAX_CFF2_STACK: usize = 513;
+
+// one byte operators
+const HSTEM: u8 = 1;
+const VSTEM: u8 = 3;
+const VMOVETO: u8 = 4;
+const RLINETO: u8 = 5;
+const HLINETO: u8 = 6;
+const VLINETO: u8 = 7;
+const RRCURVETO: u8 = 8;
+const CALLSUBR: u8 = 10;
+const RETURN: u8 = 11;
+const ESCAPE: u8 = 12;
+const ENDCHAR: u8 = 14;
+const VSINDEX: u8 = 15;
+const BLEND: u8 = 16;
+const HSTEMHM: u8 = 18;
+const HINTMASK: u8 = 19;
+const CNTRMASK: u8 = 20;
+const RMOVETO: u8 = 21;
+const HMOVETO: u8 = 22;
+const VSTEMHM: u8 = 23;
+const RCURVELINE: u8 = 24;
+const RLINECURVE: u8 = 25;
+const VVCURVETO: u8 = 26;
+const HHCURVETO: u8 = 27;
+const SHORTINT: u8 = 28;
+const CALLGSUBR: u8 = 29;
+const VHCURVETO: u8 = 30;
+const HVCURVETO: u8 = 31;
+const FIXED: u8 = 255;
+
+// two byte operators, following ESCAPE
+const DOTSECTION: u8 = 0;
+const HFLEX: u8 = 34;
+const FLEX: u8 = 35;
+const HFLEX1: u8 = 36;
+const FLEX1: u8 = 37;
+
+/// The subroutines available to a charstring.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Subroutines<'a> {
+    /// The global subroutines, shared by all glyphs.
+    pub global: Index<'a>,
+    /// The local subroutines from the Private DICT for the glyph, if any.
+    pub local: Option<Index<'a>>,
+}
+
+/// The variation data used to evaluate `blend` operators in CFF2
+/// charstrings.
+#[derive(Clone)]
+pub struct BlendState<'a> {
+    store: ItemVariationStore<'a>,
+    vsindex: u16,
+    coords: &'a [F2Dot14],
+}
+
+impl<'a> BlendState<'a> {
+    /// Creates a blend state for the given variation store and normalized
+    /// coordinates.
+    ///
+    /// `vsindex` selects the item variation data used by default, and comes
+    /// from the Private DICT. Charstrings may select another with the
+    /// `vsindex` operator.
+    pub fn new(store: ItemVariationStore<'a>, vsindex: u16, coords: &'a [F2Dot14]) -> Self {
+        Self {
+            store,
+            vsindex,
+            coords,
+        }
+    }
+
+    fn variation_data(&self) -> Result<ItemVariationData<'a>, ReadError> {
+        self.store
+            .item_variation_data()
+            .nth(self.vsindex as usize)
+            .flatten()
+            .ok_or(ReadError::MalformedData("invalid vsindex"))?
+    }
+}
+
+/// Accented character components, from the deprecated `seac` form of the
+/// `endchar` operator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Seac {
+    /// The offset of the accent relative to the base character.
+    pub accent_offset: (Fixed, Fixed),
+    /// The Standard Encoding code of the base character.
+    pub base_code: u8,
+    /// The Standard Encoding code of the accent character.
+    pub accent_code: u8,
+}
+
+/// Information about an evaluated charstring.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CharstringInfo {
+    /// The width, relative to the nominal width, if the charstring
+    /// specified one.
+    ///
+    /// If this is `None`, the glyph has the default width.
+    pub width: Option<Fixed>,
+    /// The components of an accented character, if the charstring ended
+    /// with the `seac` form of `endchar`.
+    pub seac: Option<Seac>,
+}
+
+/// Evaluates a CFF charstring, sending the outline to the given pen.
+///
+/// Hinting operators are parsed but otherwise ignored.
+pub fn evaluate(
+    charstring: &[u8],
+    subrs: Subroutines,
+    pen: &mut impl OutlinePen,
+) -> Result<CharstringInfo, ReadError> {
+    let mut evaluator = Evaluator::new(subrs, None, false, pen);
+    evaluator.evaluate(charstring, 0)?;
+    evaluator.path.close();
+    Ok(CharstringInfo {
+        width: evaluator.width,
+        seac: evaluator.seac,
+    })
+}
+
+/// Evaluates a CFF2 charstring, sending the outline to the given pen.
+///
+/// Charstrings that use the `blend` or `vsindex` operators require a blend
+/// state. CFF2 charstrings do not specify widths.
+pub fn evaluate_cff2(
+    charstring: &[u8],
+    subrs: Subroutines,
+    blend: Option<BlendState>,
+    pen: &mut impl OutlinePen,
+) -> Result<(), ReadError> {
+    let mut evaluator = Evaluator::new(subrs, blend, true, pen);
+    evaluator.evaluate(charstring, 0)?;
+    evaluator.path.close();
+    Ok(())
+}
+
+/// Returns the bias applied to subroutine numbers for a subroutine INDEX
+/// with the given count.
+pub fn subr_bias(count: u32) -> i32 {
+    if count < 1240 {
+        107
+    } else if count < 33900 {
+        1131
+    } else {
+        32768
+    }
+}
+
+struct Evaluator<'a, 'p, P> {
+    subrs: Subroutines<'a>,
+    /// The variation state, for CFF2 charstrings.
+    blend: Option<BlendState<'a>>,
+    is_cff2: bool,
+    path: PathBuilder<'p, P>,
+    stack: Stack,
+    stem_count: usize,
+    /// True once the first stack clearing operator has been seen, after
+    /// which a width can no longer be specified.
+    seen_width: bool,
+    width: Option<Fixed>,
+    seac: Option<Seac>,
+}
+
+impl<'a, 'p, P: OutlinePen> Evaluator<'a, 'p, P> {
+    fn new(
+        subrs: Subroutines<'a>,
+        blend: Option<BlendState<'a>>,
+        is_cff2: bool,
+        pen: &'p mut P,
+    ) -> Self {
+        let stack_limit = if is_cff2 {
+            MAX_CFF2_STACK
+        } else {
+            MAX_CFF_STACK
+        };
+        Self {
+            subrs,
+            blend,
+            is_cff2,
+            path: PathBuilder::new(pen),
+            stack: Stack::new(stack_limit),
+            stem_count: 0,
+            // CFF2 charstrings never have a width
+            seen_width: is_cff2,
+            width: None,
+            seac: None,
+        }
+    }
+
+    /// Evaluates a charstring or subroutine, returning `true` if evaluation
+    /// should stop because `endchar` was reached.
+    fn evaluate(&mut self, charstring: &[u8], depth: usize) -> Result<bool, ReadError> {
+        if depth > MAX_SUBR_DEPTH {
+            return Err(ReadError::MalformedData(
+                "charstring recursion limit exceeded",
+            ));
+        }
+        let mut cursor = Cursor::new(charstring);
+        while let Some(b0) = cursor.next_byte() {
+            match b0 {
+                32..=246 => self.stack.push(Fixed::from_i32(b0 as i32 - 139))?,
+                247..=250 => {
+                    let b1 = cursor.read_byte()? as i32;
+                    self.stack
+                        .push(Fixed::from_i32((b0 as i32 - 247) * 256 + b1 + 108))?;
+                }
+                251..=254 => {
+                    let b1 = cursor.read_byte()? as i32;
+                    self.stack
+                        .push(Fixed::from_i32(-(b0 as i32 - 251) * 256 - b1 - 108))?;
+                }
+                SHORTINT => {
+                    let value = i16::from_be_bytes([cursor.read_byte()?, cursor.read_byte()?]);
+                    self.stack.push(Fixed::from_i32(value as i32))?;
+                }
+                FIXED => {
+                    let mut bytes = [0; 4];
+                    for byte in &mut bytes {
+                        *byte = cursor.read_byte()?;
+                    }
+                    self.stack
+                        .push(Fixed::from_bits(i32::from_be_bytes(bytes)))?;
+                }
+                CALLSUBR | CALLGSUBR => {
+                    let subrs = if b0 == CALLSUBR {
+                        self.subrs
+                            .local
+                            .ok_or(ReadError::MalformedData("missing local subroutines"))?
+                    } else {
+                        self.subrs.global
+                    };
+                    let number = self.stack.pop()?.to_i32() + subr_bias(subrs.count());
+                    let subr = usize::try_from(number)
+                        .map_err(|_| ReadError::MalformedData("invalid subroutine number"))
+                        .and_then(|number| subrs.get(number))?;
+                    if self.evaluate(subr, depth + 1)? {
+                        return Ok(true);
+                    }
+                }
+                // return and endchar were removed in CFF2
+                RETURN if !self.is_cff2 => return Ok(false),
+                ENDCHAR if !self.is_cff2 => {
+                    self.read_width(self.stack.len() == 1 || self.stack.len() == 5);
+                    if self.stack.len() == 4 {
+                        let args = self.stack.values();
+                        self.seac = Some(Seac {
+                            accent_offset: (args[0], args[1]),
+                            base_code: to_code(args[2])?,
+                            accent_code: to_code(args[3])?,
+                        });
+                    }
+                    self.stack.clear();
+                    return Ok(true);
+                }
+                VSINDEX if self.is_cff2 => {
+                    let vsindex = u16::try_from(self.stack.pop()?.to_i32())
+                        .map_err(|_| ReadError::MalformedData("invalid vsindex"))?;
+                    self.blend_state()?.vsindex = vsindex;
+                    self.stack.clear();
+                }
+                BLEND if self.is_cff2 => self.blend()?,
+                ESCAPE => {
+                    let b1 = cursor.read_byte()?;
+                    self.evaluate_escaped(b1)?;
+                    self.stack.clear();
+                }
+                HINTMASK | CNTRMASK => {
+                    // any remaining operands are an implied vstem
+                    self.read_width(self.stack.len() % 2 == 1);
+                    self.stem_count += self.stack.len() / 2;
+                    self.stack.clear();
+                    cursor.skip(self.stem_count.div_ceil(8))?;
+                }
+                _ => {
+                    self.evaluate_path_operator(b0)?;
+                    self.stack.clear();
+                }
+            }
+        }
+        Ok(false)
+    }
+
+    fn blend_state(&mut self) -> Result<&mut BlendState<'a>, ReadError> {
+        self.blend
+            .as_mut()
+            .ok_or(ReadError::MalformedData("missing variation store"))
+    }
+
+    /// Replaces the operands of a `blend` operator with the blended values
+    /// at the current coordinates.
+    ///
+    /// The operands are `n` default values, `n` deltas for each region and
+    /// finally `n` itself. Unlike other operators, the stack is not cleared.
+    fn blend(&mut self) -> Result<(), ReadError> {
+        let n = usize::try_from(self.stack.pop()?.to_i32())
+            .map_err(|_| ReadError::MalformedData("invalid blend count"))?;
+        let blend = self.blend_state()?.clone();
+        let data = blend.variation_data()?;
+        let regions = blend.store.variation_region_list()?.variation_regions();
+        let region_indexes = data.region_indexes();
+        let region_count = region_indexes.len();
+        let start = n
+            .checked_mul(region_count + 1)
+            .and_then(|operand_count| self.stack.len().checked_sub(operand_count))
+            .ok_or(ReadError::MalformedData("charstring stack underflow"))?;
+        let (defaults, deltas) = self.stack.values_mut()[start..].split_at_mut(n);
+        for (region, region_index) in region_indexes.iter().enumerate() {
+            let scalar = regions
+                .get(region_index.get() as usize)?
+                .compute_scalar(blend.coords);
+            if scalar == Fixed::ZERO {
+                continue;
+            }
+            // deltas are grouped by value, with one for each region
+            for (value, deltas) in defaults.iter_mut().zip(deltas.chunks_exact(region_count)) {
+                *value = value.wrapping_add(deltas[region] * scalar);
+            }
+        }
+        self.stack.truncate(start + n);
+        Ok(())
+    }
+
+    /// Consumes the width operand, if present, from the bottom of the
+    /// stack at the first stack clearing operator.
+    fn read_width(&mut self, has_width: bool) {
+        if !self.seen_width {
+            self.seen_width = true;
+            if has_width {
+                self.width = Some(self.stack.values()[0]);
+                self.stack.remove_first();
+            }
+        }
+    }
+
+    fn evaluate_path_operator(&mut self, op: u8) -> Result<(), ReadError> {
+        let len = self.stack.len();
+        match op {
+            HSTEM | VSTEM | HSTEMHM | VSTEMHM => {
+                self.read_width(len % 2 == 1);
+                self.stem_count += self.stack.len() / 2;
+                return Ok(());
+            }
+            RMOVETO => self.read_width(len > 2),
+            HMOVETO | VMOVETO => self.read_width(len > 1),
+            _ => self.seen_width = true,
+        }
+        let args = self.stack.values();
+        let path = &mut self.path;
+        match op {
+            RMOVETO => {
+                let [dx, dy] = last_args(args)?;
+                path.move_to(dx, dy);
+            }
+            HMOVETO => path.move_to(last_args::<1>(args)?[0], Fixed::ZERO),
+            VMOVETO => path.move_to(Fixed::ZERO, last_args::<1>(args)?[0]),
+            RLINETO => {
+                for pair in args.chunks_exact(2) {
+                    path.line_to(pair[0], pair[1]);
+                }
+            }
+            HLINETO | VLINETO => {
+                let mut is_horizontal = op == HLINETO;
+                for &arg in args {
+                    if is_horizontal {
+                        path.line_to(arg, Fixed::ZERO);
+                    } else {
+                        path.line_to(Fixed::ZERO, arg);
+                    }
+                    is_horizontal = !is_horizontal;
+                }
+            }
+            RRCURVETO => {
+                for args in args.chunks_exact(6) {
+                    path.curve_to(args[0], args[1], args[2], args[3], args[4], args[5]);
+                }
+            }
+            RCURVELINE => {
+                let (curves, line) = args.split_at(len.saturating_sub(2));
+                for args in curves.chunks_exact(6) {
+                    path.curve_to(args[0], args[1], args[2], args[3], args[4], args[5]);
+                }
+                if let [dx, dy] = line {
+                    path.line_to(*dx, *dy);
+                }
+            }
+            RLINECURVE => {
+                let (lines, curve) = args.split_at(len.saturating_sub(6));
+                for pair in lines.chunks_exact(2) {
+                    path.line_to(pair[0], pair[1]);
+                }
+                if let [dx1, dy1, dx2, dy2, dx3, dy3] = curve {
+                    path.curve_to(*dx1, *dy1, *dx2, *dy2, *dx3, *dy3);
+                }
+            }
+            VVCURVETO => {
+                // an odd argument count means the first curve has a dx1
+                let (mut dx1, args) = match len % 2 {
+                    1 => (args[0], &args[1..]),
+                    _ => (Fixed::ZERO, args),
+                };
+                for args in args.chunks_exact(4) {
+                    path.curve_to(dx1, args[0], args[1], args[2], Fixed::ZERO, args[3]);
+                    dx1 = Fixed::ZERO;
+                }
+            }
+            HHCURVETO => {
+                // an odd argument count means the first curve has a dy1
+                let (mut dy1, args) = match len % 2 {
+                    1 => (args[0], &args[1..]),
+                    _ => (Fixed::ZERO, args),
+                };
+                for args in args.chunks_exact(4) {
+                    path.curve_to(args[0], dy1, args[1], args[2], args[3], Fixed::ZERO);
+                    dy1 = Fixed::ZERO;
+                }
+            }
+            VHCURVETO | HVCURVETO => {
+                let mut is_horizontal = op == HVCURVETO;
+                let mut remaining = args;
+                while remaining.len() >= 4 {
+                    // the final curve may have an extra argument
+                    let last = if remaining.len() == 5 {
+                        remaining[4]
+                    } else {
+                        Fixed::ZERO
+                    };
+                    let [a, b, c, d] = [remaining[0], remaining[1], remaining[2], remaining[3]];
+                    if is_horizontal {
+                        path.curve_to(a, Fixed::ZERO, b, c, last, d);
+                    } else {
+                        path.curve_to(Fixed::ZERO, a, b, c, d, last);
+                    }
+                    is_horizontal = !is_horizontal;
+                    remaining = &remaining[4..];
+                }
+            }
+            _ => return Err(ReadError::MalformedData("unsupported charstring operator")),
+        }
+        Ok(())
+    }
+
+    fn evaluate_escaped(&mut self, op: u8) -> Result<(), ReadError> {
+        self.seen_width = true;
+        let args = self.stack.values();
+        let path = &mut self.path;
+        match op {
+            DOTSECTION => (),
+            FLEX => {
+                let [dx1, dy1, dx2, dy2, dx3, dy3, dx4, dy4, dx5, dy5, dx6, dy6, _fd] =
+                    last_args(args)?;
+                path.curve_to(dx1, dy1, dx2, dy2, dx3, dy3);
+                path.curve_to(dx4, dy4, dx5, dy5, dx6, dy6);
+            }
+            HFLEX => {
+                let [dx1, dx2, dy2, dx3, dx4, dx5, dx6] = last_args(args)?;
+                let zero = Fixed::ZERO;
+                path.curve_to(dx1, zero, dx2, dy2, dx3, zero);
+                path.curve_to(dx4, zero, dx5, -dy2, dx6, zero);
+            }
+            HFLEX1 => {
+                let [dx1, dy1, dx2, dy2, dx3, dx4, dx5, dy5, dx6] = last_args(args)?;
+                let zero = Fixed::ZERO;
+                // the final point returns to the starting y coordinate
+                let dy6 = -(dy1.wrapping_add(dy2).wrapping_add(dy5));
+                path.curve_to(dx1, dy1, dx2, dy2, dx3, zero);
+                path.curve_to(dx4, zero, dx5, dy5, dx6, dy6);
+            }
+            FLEX1 => {
+                let [dx1, dy1, dx2, dy2, dx3, dy3, dx4, dy4, dx5, dy5, d6] = last_args(args)?;
+                let sum = |values: [Fixed; 5]| {
+                    values
+                        .into_iter()
+                        .fold(Fixed::ZERO, |sum, value| sum.wrapping_add(value))
+                };
+                let dx = sum([dx1, dx2, dx3, dx4, dx5]);
+                let dy = sum([dy1, dy2, dy3, dy4, dy5]);
+                // the last point moves along the dominant axis; the sums
+                // may be MIN, which has no absolute value as a Fixed
+                let (dx6, dy6) = if dx.to_bits().unsigned_abs() > dy.to_bits().unsigned_abs() {
+                    (d6, -dy)
+                } else {
+                    (-dx, d6)
+                };
+                path.curve_to(dx1, dy1, dx2, dy2, dx3, dy3);
+                path.curve_to(dx4, dy4, dx5, dy5, dx6, dy6);
+            }
+            _ => return Err(ReadError::MalformedData("unsupported charstring operator")),
+        }
+        Ok(())
+    }
+}
+
+/// Returns the last `N` operands on the stack.
+fn last_args<const N: usize>(args: &[Fixed]) -> Result<[Fixed; N], ReadError> {
+    args.get(args.len().saturating_sub(N)..)
+        .and_then(|args| args.try_into().ok())
+        .ok_or(ReadError::MalformedData("charstring stack underflow"))
+}
+
+fn to_code(value: Fixed) -> Result<u8, ReadError> {
+    u8::try_from(value.to_i32()).map_err(|_| ReadError::MalformedData("invalid seac code"))
+}
+
+/// The charstring operand stack.
+struct Stack {
+    values: [Fixed; MAX_CFF2_STACK],
+    len: usize,
+    limit: usize,
+}
+
+impl Stack {
+    fn new(limit: usize) -> Self {
+        Self {
+            values: [Fixed::ZERO; MAX_CFF2_STACK],
+            len: 0,
+            limit: limit.min(MAX_CFF2_STACK),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn values(&self) -> &[Fixed] {
+        &self.values[..self.len]
+    }
+
+    fn values_mut(&mut self) -> &mut [Fixed] {
+        &mut self.values[..self.len]
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.len = self.len.min(len);
+    }
+
+    fn push(&mut self, value: Fixed) -> Result<(), ReadError> {
+        if self.len == self.limit {
+            return Err(ReadError::MalformedData("charstring stack overflow"));
+        }
+        self.values[self.len] = value;
+        self.len += 1;
+        Ok(())
+    }
+
+    fn pop(&mut self) -> Result<Fixed, ReadError> {
+        self.len = self
+            .len
+            .checked_sub(1)
+            .ok_or(ReadError::MalformedData("charstring stack underflow"))?;
+        Ok(self.values[self.len])
+    }
+
+    fn remove_first(&mut self) {
+        if self.len > 0 {
+            self.values.copy_within(1..self.len, 0);
+            self.len -= 1;
+        }
+    }
+
+    fn clear(&mut self) {
+        self.len = 0;
+    }
+}
+
+/// Tracks the current point and sends absolute coordinates to a pen.
+struct PathBuilder<'p, P> {
+    pen: &'p mut P,
+    x: Fixed,
+    y: Fixed,
+    is_open: bool,
+}
+
+impl<'p, P: OutlinePen> PathBuilder<'p, P> {
+    fn new(pen: &'p mut P) -> Self {
+        Self {
+            pen,
+            x: Fixed::ZERO,
+            y: Fixed::ZERO,
+            is_open: false,
+        }
+    }
+
+    fn advance(&mut self, dx: Fixed, dy: Fixed) -> (f32, f32) {
+        self.x = self.x.wrapping_add(dx);
+        self.y = self.y.wrapping_add(dy);
+        (self.x.to_f64() as f32, self.y.to_f64() as f32)
+    }
+
+    fn move_to(&mut self, dx: Fixed, dy: Fixed) {
+        self.close();
+        let (x, y) = self.advance(dx, dy);
+        self.pen.move_to(x, y);
+        self.is_open = true;
+    }
+
+    /// Starts a contour at the current point if drawing began without a
+    /// moveto.
+    fn ensure_open(&mut self) {
+        if !self.is_open {
+            self.move_to(Fixed::ZERO, Fixed::ZERO);
+        }
+    }
+
+    fn line_to(&mut self, dx: Fixed, dy: Fixed) {
+        self.ensure_open();
+        let (x, y) = self.advance(dx, dy);
+        self.pen.line_to(x, y);
+    }
+
+    fn curve_to(&mut self, dx1: Fixed, dy1: Fixed, dx2: Fixed, dy2: Fixed, dx3: Fixed, dy3: Fixed) {
+        self.ensure_open();
+        let (x1, y1) = self.advance(dx1, dy1);
+        let (x2, y2) = self.advance(dx2, dy2);
+        let (x3, y3) = self.advance(dx3, dy3);
+        self.pen.curve_to(x1, y1, x2, y2, x3, y3);
+    }
+
+    fn close(&mut self) {
+        if self.is_open {
+            self.pen.close();
+            self.is_open = false;
+        }
+    }
+}
+
+struct Cursor<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    fn next_byte(&mut self) -> Option<u8> {
+        let byte = *self.data.get(self.pos)?;
+        self.pos += 1;
+        Some(byte)
+    }
+
+    fn read_byte(&mut self) -> Result<u8, ReadError> {
+        self.next_byte().ok_or(ReadError::OutOfBounds)
+    }
+
+    fn skip(&mut self, n_bytes: usize) -> Result<(), ReadError> {
+        if self.pos + n_bytes > self.data.len() {
+            return Err(ReadError::OutOfBounds);
+        }
+        self.pos += n_bytes;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        pen::SvgPen,
+        test_helpers::{charstring_num, BeBuffer},
+    };
+
+    fn draw(charstring: &[u8], subrs: Subroutines) -> (String, CharstringInfo) {
+        let mut pen = SvgPen::new();
+        let info = evaluate(charstring, subrs, &mut pen).unwrap();
+        (pen.into_string(), info)
+    }
+
+    #[test]
+    fn lines_and_width() {
+        let charstring = [
+            charstring_num(50),
+            charstring_num(10),
+            charstring_num(20),
+            RMOVETO,
+            charstring_num(100),
+            charstring_num(100),
+            charstring_num(-100),
+            HLINETO,
+            ENDCHAR,
+        ];
+        let (path, info) = draw(&charstring, Subroutines::default());
+        assert_eq!(path, "M10,20 L110,20 L110,120 L10,120 Z");
+        assert_eq!(info.width, Some(Fixed::from_i32(50)));
+        assert_eq!(info.seac, None);
+    }
+
+    #[test]
+    fn hints_and_curves() {
+        let charstring = [
+            charstring_num(1),
+            charstring_num(2),
+            charstring_num(3),
+            charstring_num(4),
+            HSTEMHM,
+            // an implied vstem makes three stems, so a one byte mask
+            charstring_num(5),
+            charstring_num(6),
+            HINTMASK,
+            0xE0,
+            charstring_num(0),
+            HMOVETO,
+            charstring_num(10),
+            charstring_num(20),
+            charstring_num(30),
+            charstring_num(40),
+            charstring_num(5),
+            HVCURVETO,
+            charstring_num(1),
+            charstring_num(2),
+            charstring_num(3),
+            charstring_num(4),
+            charstring_num(5),
+            charstring_num(6),
+            charstring_num(7),
+            ESCAPE,
+            HFLEX,
+        ];
+        let (path, info) = draw(&charstring, Subroutines::default());
+        assert_eq!(
+            path,
+            "M0,0 C10,0 30,30 35,70 C36,70 38,73 42,73 C47,73 53,70 60,70 Z"
+        );
+        assert_eq!(info.width, None);
+    }
+
+    #[test]
+    fn flex_with_min_operand() {
+        // a 16.16 operand of 0x80000000 has no positive counterpart
+        let charstring = [
+            139, 139, 255, 0x80, 0, 0, 0, 139, 139, 139, 139, ESCAPE, HFLEX,
+        ];
+        let (path, _) = draw(&charstring, Subroutines::default());
+        assert_eq!(path, "M0,0 C0,0 0,-32768 0,-32768 C0,-32768 0,0 0,0 Z");
+        let mut charstring = vec![139, 139, 255, 0x80, 0, 0, 0];
+        charstring.extend([139; 8]);
+        charstring.extend([ESCAPE, FLEX1]);
+        draw(&charstring, Subroutines::default());
+    }
+
+    #[test]
+    fn subroutines_and_seac() {
+        // a single subroutine that draws a line and returns
+        let mut global = BeBuffer::new();
+        global.push(1u16);
+        global.push(1u8);
+        global.extend([1u8, 5]);
+        global.extend([charstring_num(10), charstring_num(10), RLINETO, RETURN]);
+        let subrs = Subroutines {
+            global: Index::read_cff(global.font_data()).unwrap(),
+            local: None,
+        };
+        let charstring = [
+            charstring_num(0),
+            charstring_num(0),
+            RMOVETO,
+            charstring_num(-107),
+            CALLGSUBR,
+            ENDCHAR,
+        ];
+        let (path, _) = draw(&charstring, subrs);
+        assert_eq!(path, "M0,0 L10,10 Z");
+
+        // local subroutines are missing
+        let mut pen = SvgPen::new();
+        assert!(evaluate(&[charstring_num(-107), CALLSUBR], subrs, &mut pen).is_err());
+
+        // 'A' with an acute accent (code 194)
+        let seac = [
+            charstring_num(100),
+            charstring_num(10),
+            charstring_num(20),
+            charstring_num(65),
+            247,
+            86,
+            ENDCHAR,
+        ];
+        let (path, info) = draw(&seac, subrs);
+        assert!(path.is_empty());
+        assert_eq!(info.width, Some(Fixed::from_i32(100)));
+        assert_eq!(
+            info.seac,
+            Some(Seac {
+                accent_offset: (Fixed::from_i32(10), Fixed::from_i32(20)),
+                base_code: 65,
+                accent_code: 194,
+            })
+        );
+    }
+}
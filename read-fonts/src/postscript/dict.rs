@@ -0,0 +1,820 @@
+//! Parsing for PostScript DICTs.
+
+use std::ops::Range;
+
+use font_types::Fixed;
+
+use super::StringId;
+use crate::{layout::ItemVariationStore, ReadError};
+
+/// The maximum number of operands in a DICT.
+///
+/// This is the CFF2 limit, which is much larger than the CFF limit of 48.
+const MAX_OPERANDS: usize = 513;
+
+/// A numeric operand in a DICT.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Number {
+    Integer(i32),
+    Real(Fixed),
+}
+
+impl Number {
+    /// Returns the value as a fixed point number, saturating integers that
+    /// are out of range.
+    pub fn to_fixed(self) -> Fixed {
+        match self {
+            Self::Integer(value) => Fixed::from_i32(value.clamp(i16::MIN as i32, i16::MAX as i32)),
+            Self::Real(value) => value,
+        }
+    }
+
+    /// Returns the value as an integer, truncating real numbers.
+    pub fn to_i32(self) -> i32 {
+        match self {
+            Self::Integer(value) => value,
+            Self::Real(value) => value.to_i32(),
+        }
+    }
+}
+
+impl Default for Number {
+    fn default() -> Self {
+        Self::Integer(0)
+    }
+}
+
+/// DICT operators, for both the CFF and CFF2 tables.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operator {
+    Version,
+    Notice,
+    FullName,
+    FamilyName,
+    Weight,
+    FontBbox,
+    BlueValues,
+    OtherBlues,
+    FamilyBlues,
+    FamilyOtherBlues,
+    StdHw,
+    StdVw,
+    UniqueId,
+    Xuid,
+    Charset,
+    Encoding,
+    CharstringsOffset,
+    PrivateDictRange,
+    SubrsOffset,
+    DefaultWidthX,
+    NominalWidthX,
+    VariationStoreIndex,
+    Blend,
+    VariationStoreOffset,
+    MaxStack,
+    Copyright,
+    IsFixedPitch,
+    ItalicAngle,
+    UnderlinePosition,
+    UnderlineThickness,
+    PaintType,
+    CharstringType,
+    FontMatrix,
+    StrokeWidth,
+    BlueScale,
+    BlueShift,
+    BlueFuzz,
+    StemSnapH,
+    StemSnapV,
+    ForceBold,
+    LanguageGroup,
+    ExpansionFactor,
+    InitialRandomSeed,
+    SyntheticBase,
+    PostScript,
+    BaseFontName,
+    BaseFontBlend,
+    Ros,
+    CidFontVersion,
+    CidFontRevision,
+    CidFontType,
+    CidCount,
+    UidBase,
+    FdArrayOffset,
+    FdSelectOffset,
+    FontName,
+}
+
+impl Operator {
+    fn from_opcode(opcode: u8) -> Option<Self> {
+        use Operator::*;
+        Some(match opcode {
+            0 => Version,
+            1 => Notice,
+            2 => FullName,
+            3 => FamilyName,
+            4 => Weight,
+            5 => FontBbox,
+            6 => BlueValues,
+            7 => OtherBlues,
+            8 => FamilyBlues,
+            9 => FamilyOtherBlues,
+            10 => StdHw,
+            11 => StdVw,
+            13 => UniqueId,
+            14 => Xuid,
+            15 => Charset,
+            16 => Encoding,
+            17 => CharstringsOffset,
+            18 => PrivateDictRange,
+            19 => SubrsOffset,
+            20 => DefaultWidthX,
+            21 => NominalWidthX,
+            22 => VariationStoreIndex,
+            23 => Blend,
+            24 => VariationStoreOffset,
+            25 => MaxStack,
+            _ => return None,
+        })
+    }
+
+    fn from_escaped_opcode(opcode: u8) -> Option<Self> {
+        use Operator::*;
+        Some(match opcode {
+            0 => Copyright,
+            1 => IsFixedPitch,
+            2 => ItalicAngle,
+            3 => UnderlinePosition,
+            4 => UnderlineThickness,
+            5 => PaintType,
+            6 => CharstringType,
+            7 => FontMatrix,
+            8 => StrokeWidth,
+            9 => BlueScale,
+            10 => BlueShift,
+            11 => BlueFuzz,
+            12 => StemSnapH,
+            13 => StemSnapV,
+            14 => ForceBold,
+            17 => LanguageGroup,
+            18 => ExpansionFactor,
+            19 => InitialRandomSeed,
+            20 => SyntheticBase,
+            21 => PostScript,
+            22 => BaseFontName,
+            23 => BaseFontBlend,
+            30 => Ros,
+            31 => CidFontVersion,
+            32 => CidFontRevision,
+            33 => CidFontType,
+            34 => CidCount,
+            35 => UidBase,
+            36 => FdArrayOffset,
+            37 => FdSelectOffset,
+            38 => FontName,
+            _ => return None,
+        })
+    }
+}
+
+/// A single token in a DICT.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Token {
+    Operand(Number),
+    /// An operator, or `None` for an unknown or reserved operator.
+    Operator(Option<Operator>),
+}
+
+/// Iterator over the tokens in a DICT.
+#[derive(Clone)]
+pub struct Tokens<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Tokens<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    fn read_byte(&mut self) -> Result<u8, ReadError> {
+        let byte = *self.data.get(self.pos).ok_or(ReadError::OutOfBounds)?;
+        self.pos += 1;
+        Ok(byte)
+    }
+
+    fn read_token(&mut self, b0: u8) -> Result<Token, ReadError> {
+        let token = match b0 {
+            12 => Token::Operator(Operator::from_escaped_opcode(self.read_byte()?)),
+            0..=27 => Token::Operator(Operator::from_opcode(b0)),
+            28 => {
+                let bytes = [self.read_byte()?, self.read_byte()?];
+                Token::Operand(Number::Integer(i16::from_be_bytes(bytes) as i32))
+            }
+            29 => {
+                let mut bytes = [0; 4];
+                for byte in &mut bytes {
+                    *byte = self.read_byte()?;
+                }
+                Token::Operand(Number::Integer(i32::from_be_bytes(bytes)))
+            }
+            30 => Token::Operand(Number::Real(self.read_real()?)),
+            32..=246 => Token::Operand(Number::Integer(b0 as i32 - 139)),
+            247..=250 => {
+                let b1 = self.read_byte()? as i32;
+                Token::Operand(Number::Integer((b0 as i32 - 247) * 256 + b1 + 108))
+            }
+            251..=254 => {
+                let b1 = self.read_byte()? as i32;
+                Token::Operand(Number::Integer(-(b0 as i32 - 251) * 256 - b1 - 108))
+            }
+            _ => return Err(ReadError::MalformedData("invalid DICT operand")),
+        };
+        Ok(token)
+    }
+
+    /// Reads a real number, encoded as a sequence of BCD nibbles.
+    fn read_real(&mut self) -> Result<Fixed, ReadError> {
+        // the mantissa is accumulated as an integer with a decimal exponent
+        let mut mantissa: i64 = 0;
+        let mut exponent: i32 = 0;
+        let mut exponent_value: i32 = 0;
+        let mut exponent_sign = 1;
+        let mut negative = false;
+        let mut in_fraction = false;
+        let mut in_exponent = false;
+        'outer: loop {
+            let byte = self.read_byte()?;
+            for nibble in [byte >> 4, byte & 0xF] {
+                match nibble {
+                    0..=9 if in_exponent => {
+                        exponent_value = (exponent_value * 10 + nibble as i32).min(1000);
+                    }
+                    0..=9 => {
+                        // ignore digits beyond the precision we can store
+                        if mantissa < i64::MAX / 100 {
+                            mantissa = mantissa * 10 + nibble as i64;
+                            if in_fraction {
+                                exponent -= 1;
+                            }
+                        } else if !in_fraction {
+                            exponent += 1;
+                        }
+                    }
+                    0xA => in_fraction = true,
+                    0xB => in_exponent = true,
+                    0xC => {
+                        in_exponent = true;
+                        exponent_sign = -1;
+                    }
+                    0xE => negative = true,
+                    0xF => break 'outer,
+                    _ => return Err(ReadError::MalformedData("invalid real number")),
+                }
+            }
+        }
+        let exponent = exponent + exponent_sign * exponent_value;
+        let mut bits = (mantissa as i128) << 16;
+        if exponent >= 0 {
+            for _ in 0..exponent {
+                bits = bits.saturating_mul(10);
+                if bits > i32::MAX as i128 {
+                    break;
+                }
+            }
+        } else {
+            let mut divisor: i128 = 1;
+            for _ in 0..-exponent {
+                divisor = divisor.saturating_mul(10);
+                // any further division would round to zero
+                if divisor > bits * 2 {
+                    break;
+                }
+            }
+            bits = (bits + divisor / 2) / divisor;
+        }
+        let bits = bits.min(i32::MAX as i128) as i32;
+        Ok(Fixed::from_bits(if negative { -bits } else { bits }))
+    }
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = Result<Token, ReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let b0 = *self.data.get(self.pos)?;
+        self.pos += 1;
+        let token = self.read_token(b0);
+        if token.is_err() {
+            // stop after the first error
+            self.pos = self.data.len();
+        }
+        Some(token)
+    }
+}
+
+/// Up to seven pairs of blue zone edges, as used by the `BlueValues`,
+/// `OtherBlues`, `FamilyBlues` and `FamilyOtherBlues` operators.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Blues {
+    values: [(Fixed, Fixed); 7],
+    len: usize,
+}
+
+impl Blues {
+    /// The blue zones, as (bottom, top) pairs.
+    pub fn values(&self) -> &[(Fixed, Fixed)] {
+        &self.values[..self.len]
+    }
+}
+
+/// Up to twelve stem widths, as used by the `StemSnapH` and `StemSnapV`
+/// operators.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StemSnaps {
+    values: [Fixed; 12],
+    len: usize,
+}
+
+impl StemSnaps {
+    /// The stem widths.
+    pub fn values(&self) -> &[Fixed] {
+        &self.values[..self.len]
+    }
+}
+
+/// A parsed DICT entry: an operator along with its operands.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Entry {
+    Version(StringId),
+    Notice(StringId),
+    FullName(StringId),
+    FamilyName(StringId),
+    Weight(StringId),
+    FontBbox([Fixed; 4]),
+    BlueValues(Blues),
+    OtherBlues(Blues),
+    FamilyBlues(Blues),
+    FamilyOtherBlues(Blues),
+    StdHw(Fixed),
+    StdVw(Fixed),
+    UniqueId(i32),
+    Charset(usize),
+    Encoding(usize),
+    CharstringsOffset(usize),
+    PrivateDictRange(Range<usize>),
+    SubrsOffset(usize),
+    DefaultWidthX(Fixed),
+    NominalWidthX(Fixed),
+    VariationStoreIndex(u16),
+    VariationStoreOffset(usize),
+    MaxStack(u32),
+    Copyright(StringId),
+    IsFixedPitch(bool),
+    ItalicAngle(Fixed),
+    UnderlinePosition(Fixed),
+    UnderlineThickness(Fixed),
+    PaintType(i32),
+    CharstringType(i32),
+    FontMatrix([Fixed; 6]),
+    StrokeWidth(Fixed),
+    BlueScale(Fixed),
+    BlueShift(Fixed),
+    BlueFuzz(Fixed),
+    StemSnapH(StemSnaps),
+    StemSnapV(StemSnaps),
+    ForceBold(bool),
+    LanguageGroup(i32),
+    ExpansionFactor(Fixed),
+    InitialRandomSeed(i32),
+    SyntheticBase(i32),
+    PostScript(StringId),
+    BaseFontName(StringId),
+    Ros {
+        registry: StringId,
+        ordering: StringId,
+        supplement: Fixed,
+    },
+    CidFontVersion(Fixed),
+    CidFontRevision(Fixed),
+    CidFontType(i32),
+    CidCount(u32),
+    UidBase(i32),
+    FdArrayOffset(usize),
+    FdSelectOffset(usize),
+    FontName(StringId),
+}
+
+/// Iterator over the entries in a DICT.
+///
+/// Unknown operators, and operators whose operands are not meaningful on
+/// their own (such as `XUID` and `BaseFontBlend`), are skipped. Blended
+/// operands in CFF2 DICTs take their default values.
+#[derive(Clone)]
+pub struct Entries<'a> {
+    tokens: Tokens<'a>,
+    stack: [Number; MAX_OPERANDS],
+    len: usize,
+    var_store: Option<ItemVariationStore<'a>>,
+    vsindex: u16,
+}
+
+impl<'a> Entries<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self {
+            tokens: Tokens::new(data),
+            stack: [Number::default(); MAX_OPERANDS],
+            len: 0,
+            var_store: None,
+            vsindex: 0,
+        }
+    }
+
+    /// Creates an iterator over a CFF2 DICT that may contain blended
+    /// operands, using the given variation store to determine the number
+    /// of regions.
+    pub fn with_var_store(data: &'a [u8], var_store: ItemVariationStore<'a>) -> Self {
+        Self {
+            var_store: Some(var_store),
+            ..Self::new(data)
+        }
+    }
+
+    fn operands(&self) -> &[Number] {
+        &self.stack[..self.len]
+    }
+
+    fn parse_entry(&self, op: Operator) -> Result<Option<Entry>, ReadError> {
+        use Operator::*;
+        let entry = match op {
+            Version => Entry::Version(self.string_id()?),
+            Notice => Entry::Notice(self.string_id()?),
+            FullName => Entry::FullName(self.string_id()?),
+            FamilyName => Entry::FamilyName(self.string_id()?),
+            Weight => Entry::Weight(self.string_id()?),
+            FontBbox => Entry::FontBbox(self.fixed_array()?),
+            BlueValues => Entry::BlueValues(self.blues()?),
+            OtherBlues => Entry::OtherBlues(self.blues()?),
+            FamilyBlues => Entry::FamilyBlues(self.blues()?),
+            FamilyOtherBlues => Entry::FamilyOtherBlues(self.blues()?),
+            StdHw => Entry::StdHw(self.fixed()?),
+            StdVw => Entry::StdVw(self.fixed()?),
+            UniqueId => Entry::UniqueId(self.int()?),
+            Charset => Entry::Charset(self.offset()?),
+            Encoding => Entry::Encoding(self.offset()?),
+            CharstringsOffset => Entry::CharstringsOffset(self.offset()?),
+            PrivateDictRange => {
+                let [size, offset] = self.array()?;
+                let (size, offset) = (to_usize(size)?, to_usize(offset)?);
+                Entry::PrivateDictRange(offset..offset + size)
+            }
+            SubrsOffset => Entry::SubrsOffset(self.offset()?),
+            DefaultWidthX => Entry::DefaultWidthX(self.fixed()?),
+            NominalWidthX => Entry::NominalWidthX(self.fixed()?),
+            VariationStoreIndex => {
+                let index = u16::try_from(self.int()?)
+                    .map_err(|_| ReadError::MalformedData("invalid vsindex"))?;
+                Entry::VariationStoreIndex(index)
+            }
+            VariationStoreOffset => Entry::VariationStoreOffset(self.offset()?),
+            MaxStack => Entry::MaxStack(self.offset()? as u32),
+            Copyright => Entry::Copyright(self.string_id()?),
+            IsFixedPitch => Entry::IsFixedPitch(self.int()? != 0),
+            ItalicAngle => Entry::ItalicAngle(self.fixed()?),
+            UnderlinePosition => Entry::UnderlinePosition(self.fixed()?),
+            UnderlineThickness => Entry::UnderlineThickness(self.fixed()?),
+            PaintType => Entry::PaintType(self.int()?),
+            CharstringType => Entry::CharstringType(self.int()?),
+            FontMatrix => Entry::FontMatrix(self.fixed_array()?),
+            StrokeWidth => Entry::StrokeWidth(self.fixed()?),
+            BlueScale => Entry::BlueScale(self.fixed()?),
+            BlueShift => Entry::BlueShift(self.fixed()?),
+            BlueFuzz => Entry::BlueFuzz(self.fixed()?),
+            StemSnapH => Entry::StemSnapH(self.stem_snaps()?),
+            StemSnapV => Entry::StemSnapV(self.stem_snaps()?),
+            ForceBold => Entry::ForceBold(self.int()? != 0),
+            LanguageGroup => Entry::LanguageGroup(self.int()?),
+            ExpansionFactor => Entry::ExpansionFactor(self.fixed()?),
+            InitialRandomSeed => Entry::InitialRandomSeed(self.int()?),
+            SyntheticBase => Entry::SyntheticBase(self.int()?),
+            PostScript => Entry::PostScript(self.string_id()?),
+            BaseFontName => Entry::BaseFontName(self.string_id()?),
+            Ros => {
+                let [registry, ordering, supplement] = self.array()?;
+                Entry::Ros {
+                    registry: to_string_id(registry)?,
+                    ordering: to_string_id(ordering)?,
+                    supplement: supplement.to_fixed(),
+                }
+            }
+            CidFontVersion => Entry::CidFontVersion(self.fixed()?),
+            CidFontRevision => Entry::CidFontRevision(self.fixed()?),
+            CidFontType => Entry::CidFontType(self.int()?),
+            CidCount => Entry::CidCount(self.offset()? as u32),
+            UidBase => Entry::UidBase(self.int()?),
+            FdArrayOffset => Entry::FdArrayOffset(self.offset()?),
+            FdSelectOffset => Entry::FdSelectOffset(self.offset()?),
+            FontName => Entry::FontName(self.string_id()?),
+            Xuid | BaseFontBlend | Blend => return Ok(None),
+        };
+        Ok(Some(entry))
+    }
+
+    /// Replaces the operands of a `blend` operator with their default
+    /// values, leaving them on the stack for the following operator.
+    ///
+    /// The operands are `n` default values, `n` deltas for each region of
+    /// the active item variation data and finally `n` itself. Any operands
+    /// below them are left untouched.
+    fn blend(&mut self) -> Result<(), ReadError> {
+        let region_count = self
+            .var_store
+            .as_ref()
+            .ok_or(ReadError::MalformedData(
+                "DICT blend without variation store",
+            ))?
+            .item_variation_data()
+            .nth(self.vsindex as usize)
+            .flatten()
+            .ok_or(ReadError::MalformedData("invalid vsindex"))??
+            .region_index_count() as usize;
+        let last = self
+            .len
+            .checked_sub(1)
+            .ok_or(ReadError::MalformedData("invalid DICT blend"))?;
+        let n = to_usize(self.stack[last])?;
+        let start = n
+            .checked_mul(region_count + 1)
+            .and_then(|operand_count| last.checked_sub(operand_count))
+            .ok_or(ReadError::MalformedData("invalid DICT blend"))?;
+        // the default values are already in place
+        self.len = start + n;
+        Ok(())
+    }
+
+    /// Returns the last `N` operands.
+    ///
+    /// Operators with a fixed number of operands ignore any extras.
+    fn array<const N: usize>(&self) -> Result<[Number; N], ReadError> {
+        let operands = self.operands();
+        operands
+            .get(operands.len().saturating_sub(N)..)
+            .filter(|operands| operands.len() == N)
+            .and_then(|operands| operands.try_into().ok())
+            .ok_or(ReadError::MalformedData("missing DICT operands"))
+    }
+
+    fn fixed_array<const N: usize>(&self) -> Result<[Fixed; N], ReadError> {
+        Ok(self.array::<N>()?.map(Number::to_fixed))
+    }
+
+    fn int(&self) -> Result<i32, ReadError> {
+        Ok(self.array::<1>()?[0].to_i32())
+    }
+
+    fn fixed(&self) -> Result<Fixed, ReadError> {
+        Ok(self.array::<1>()?[0].to_fixed())
+    }
+
+    fn offset(&self) -> Result<usize, ReadError> {
+        to_usize(self.array::<1>()?[0])
+    }
+
+    fn string_id(&self) -> Result<StringId, ReadError> {
+        to_string_id(self.array::<1>()?[0])
+    }
+
+    /// Reads a delta encoded array of blue zone pairs.
+    fn blues(&self) -> Result<Blues, ReadError> {
+        let mut blues = Blues::default();
+        let mut prev = Fixed::ZERO;
+        for pair in self.operands().chunks_exact(2).take(blues.values.len()) {
+            let bottom = prev.wrapping_add(pair[0].to_fixed());
+            let top = bottom.wrapping_add(pair[1].to_fixed());
+            blues.values[blues.len] = (bottom, top);
+            blues.len += 1;
+            prev = top;
+        }
+        Ok(blues)
+    }
+
+    /// Reads a delta encoded array of stem widths.
+    fn stem_snaps(&self) -> Result<StemSnaps, ReadError> {
+        let mut snaps = StemSnaps::default();
+        let mut prev = Fixed::ZERO;
+        for operand in self.operands().iter().take(snaps.values.len()) {
+            prev = prev.wrapping_add(operand.to_fixed());
+            snaps.values[snaps.len] = prev;
+            snaps.len += 1;
+        }
+        Ok(snaps)
+    }
+}
+
+impl<'a> Iterator for Entries<'a> {
+    type Item = Result<Entry, ReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.tokens.next()? {
+                Ok(Token::Operand(number)) => {
+                    if self.len == MAX_OPERANDS {
+                        return Some(Err(ReadError::MalformedData("DICT stack overflow")));
+                    }
+                    self.stack[self.len] = number;
+                    self.len += 1;
+                }
+                Ok(Token::Operator(Some(Operator::Blend))) => {
+                    if let Err(e) = self.blend() {
+                        return Some(Err(e));
+                    }
+                }
+                Ok(Token::Operator(op)) => {
+                    let entry = op.map(|op| self.parse_entry(op)).transpose();
+                    self.len = 0;
+                    match entry {
+                        Ok(Some(Some(entry))) => {
+                            // subsequent blends use the selected item variation data
+                            if let Entry::VariationStoreIndex(vsindex) = entry {
+                                self.vsindex = vsindex;
+                            }
+                            return Some(Ok(entry));
+                        }
+                        Ok(_) => (),
+                        Err(e) => return Some(Err(e)),
+                    }
+                }
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+/// Iterate over the entries in the DICT contained in `data`.
+pub fn entries(data: &[u8]) -> Entries<'_> {
+    Entries::new(data)
+}
+
+fn to_usize(number: Number) -> Result<usize, ReadError> {
+    usize::try_from(number.to_i32()).map_err(|_| ReadError::MalformedData("negative DICT offset"))
+}
+
+fn to_string_id(number: Number) -> Result<StringId, ReadError> {
+    u16::try_from(number.to_i32())
+        .map(StringId::new)
+        .map_err(|_| ReadError::MalformedData("invalid string id"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_helpers::BeBuffer, FontData, FontRead};
+
+    #[test]
+    fn operands() {
+        let data = [
+            0x8B, // 0
+            0xEF, // 100
+            0x27, // -100
+            0xFA, 0x7C, // 1000
+            0xFE, 0x7C, // -1000
+            0x1C, 0x27, 0x10, // 10000
+            0x1C, 0xD8, 0xF0, // -10000
+            0x1D, 0x00, 0x01, 0x86, 0xA0, // 100000
+            0x1E, 0xE2, 0xA2, 0x5F, // -2.25
+            0x1E, 0x1C, 0x4F, // 1E-4
+        ];
+        let numbers = Tokens::new(&data)
+            .map(|token| match token.unwrap() {
+                Token::Operand(number) => number,
+                _ => panic!("expected operand"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            numbers,
+            [
+                Number::Integer(0),
+                Number::Integer(100),
+                Number::Integer(-100),
+                Number::Integer(1000),
+                Number::Integer(-1000),
+                Number::Integer(10000),
+                Number::Integer(-10000),
+                Number::Integer(100000),
+                Number::Real(Fixed::from_f64(-2.25)),
+                Number::Real(Fixed::from_bits(7)),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_entries() {
+        let data = [
+            0x8B, 0x8B, 0x8B, 0x8B, 0x8B, 0x8B, 0x8B, 0x0C, 0x08, // StrokeWidth
+            0x1E, 0x0A, 0x00, 0x1F, 0x8B, 0x8B, 0x1E, 0x0A, 0x00, 0x1F, 0x8B, 0x8B, 0x0C,
+            0x07, // FontMatrix
+            0xF7, 0x00, 0x1D, 0x00, 0x00, 0x10, 0x00, 0x12, // Private
+            0x0C, 0x06, // CharstringType with no operands
+            0x1C, 0xFF, 0xF6, 0x9B, 0x06, // BlueValues: [-10, 6]
+        ];
+        let entries = entries(&data).collect::<Vec<_>>();
+        let fixed = Fixed::from_f64;
+        assert_eq!(
+            entries[0].as_ref().unwrap(),
+            &Entry::StrokeWidth(Fixed::ZERO),
+            "StrokeWidth uses the last operand"
+        );
+        assert_eq!(
+            entries[1].as_ref().unwrap(),
+            &Entry::FontMatrix([
+                fixed(0.001),
+                Fixed::ZERO,
+                Fixed::ZERO,
+                fixed(0.001),
+                Fixed::ZERO,
+                Fixed::ZERO
+            ])
+        );
+        assert_eq!(
+            entries[2].as_ref().unwrap(),
+            &Entry::PrivateDictRange(4096..4096 + 108)
+        );
+        assert!(entries[3].is_err());
+        let Ok(Entry::BlueValues(blues)) = &entries[4] else {
+            panic!("expected blue values");
+        };
+        assert_eq!(blues.values(), [(Fixed::from_i32(-10), Fixed::from_i32(6))]);
+    }
+
+    /// A variation store on a single axis with two regions, where the first
+    /// item variation data references one region and the second references
+    /// both.
+    fn var_store() -> Vec<u8> {
+        let mut buf = BeBuffer::new();
+        buf.push(1u16);
+        buf.push(16u32);
+        buf.push(2u16);
+        buf.extend([32u32, 40]);
+        // the region list
+        buf.extend([1u16, 2]);
+        buf.extend([0u16, 0x4000, 0x4000]);
+        buf.extend([0xC000u16, 0xC000, 0]);
+        // item variation data with no items
+        buf.extend([0u16, 0, 1, 0]);
+        buf.extend([0u16, 0, 2, 0, 1]);
+        buf.to_vec()
+    }
+
+    fn blended_entries(data: &[u8]) -> Vec<Result<Entry, ReadError>> {
+        let store = var_store();
+        let store = ItemVariationStore::read(FontData::new(&store)).unwrap();
+        Entries::with_var_store(data, store).collect()
+    }
+
+    #[test]
+    fn blended_operands() {
+        let data = [
+            0xBD, 0x95, 0x8C, 0x17, 0x0A, // StdHw: blend([50, 10], 1)
+            0x8D, 0x17, // invalid blend of two values
+        ];
+        let entries = blended_entries(&data);
+        assert_eq!(
+            entries[0].as_ref().unwrap(),
+            &Entry::StdHw(Fixed::from_i32(50))
+        );
+        assert!(entries[1].is_err());
+        // blends require a variation store
+        assert!(super::entries(&data).next().unwrap().is_err());
+    }
+
+    #[test]
+    fn blend_after_plain_operand() {
+        let data = [
+            0x81, 0x91, 0x8E, 0x8C, 0x17, 0x06, // BlueValues: [-10, blend([6, 3], 1)]
+        ];
+        let entries = blended_entries(&data);
+        let Ok(Entry::BlueValues(blues)) = &entries[0] else {
+            panic!("expected blue values");
+        };
+        assert_eq!(
+            blues.values(),
+            [(Fixed::from_i32(-10), Fixed::from_i32(-4))]
+        );
+    }
+
+    #[test]
+    fn multiple_blends() {
+        let data = [
+            0x8C, 0x16, // vsindex 1, with two regions
+            0x95, 0x9F, 0x8C, 0x8D, 0x8E, 0x8F, 0x8D, 0x17, // blend([10, 20], 4 deltas, 2)
+            0xA9, 0x8C, 0x8D, 0x8C, 0x17, // blend([30], 2 deltas, 1)
+            0x0C, 0x0C, // StemSnapH
+        ];
+        let entries = blended_entries(&data);
+        assert_eq!(entries[0].as_ref().unwrap(), &Entry::VariationStoreIndex(1));
+        let Ok(Entry::StemSnapH(snaps)) = &entries[1] else {
+            panic!("expected stem snaps");
+        };
+        assert_eq!(snaps.values(), [10, 30, 60].map(Fixed::from_i32));
+    }
+}
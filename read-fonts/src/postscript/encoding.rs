@@ -0,0 +1,220 @@
+//! Parsing for CFF encodings.
+
+use font_types::GlyphId;
+
+use super::{charset::Charset, StringId};
+use crate::{FontData, ReadError};
+
+/// A mapping from character codes to glyphs.
+///
+/// Custom encodings map codes directly to glyphs, while the predefined
+/// encodings map codes to string identifiers, which are resolved to glyphs
+/// through the font's charset.
+///
+/// See "Encodings" in the [CFF spec](https://adobe-type-tools.github.io/font-tech-notes/pdfs/5176.CFF.pdf).
+#[derive(Clone)]
+pub struct Encoding<'a> {
+    kind: EncodingKind<'a>,
+}
+
+#[derive(Clone)]
+enum EncodingKind<'a> {
+    Standard,
+    Expert,
+    Custom {
+        format: u8,
+        data: &'a [u8],
+        supplements: &'a [u8],
+    },
+}
+
+impl<'a> Encoding<'a> {
+    /// Creates an encoding from the `Encoding` offset in a Top DICT.
+    ///
+    /// `data` is the data for the entire CFF table. Offsets 0 and 1 select
+    /// the predefined Standard and Expert encodings.
+    pub fn new(data: FontData<'a>, offset: usize) -> Result<Self, ReadError> {
+        let kind = match offset {
+            0 => EncodingKind::Standard,
+            1 => EncodingKind::Expert,
+            _ => {
+                let data = data
+                    .split_off(offset)
+                    .ok_or(ReadError::OutOfBounds)?
+                    .as_bytes();
+                let (&raw_format, data) = data.split_first().ok_or(ReadError::OutOfBounds)?;
+                let (&count, data) = data.split_first().ok_or(ReadError::OutOfBounds)?;
+                let format = raw_format & 0x7F;
+                let len = match format {
+                    0 => count as usize,
+                    1 => count as usize * 2,
+                    _ => return Err(ReadError::InvalidFormat(format as _)),
+                };
+                let (data, rest) = (data.get(..len).ok_or(ReadError::OutOfBounds)?, &data[len..]);
+                // the high bit of the format indicates supplemental codes
+                let supplements = if raw_format & 0x80 != 0 {
+                    let (&n_sups, rest) = rest.split_first().ok_or(ReadError::OutOfBounds)?;
+                    rest.get(..n_sups as usize * 3)
+                        .ok_or(ReadError::OutOfBounds)?
+                } else {
+                    &[]
+                };
+                EncodingKind::Custom {
+                    format,
+                    data,
+                    supplements,
+                }
+            }
+        };
+        Ok(Self { kind })
+    }
+
+    /// Creates the predefined Standard encoding.
+    pub fn standard() -> Self {
+        Self {
+            kind: EncodingKind::Standard,
+        }
+    }
+
+    /// Returns the glyph for the given character code.
+    pub fn map(&self, code: u8, charset: &Charset) -> Option<GlyphId> {
+        let sid = match &self.kind {
+            EncodingKind::Standard => STANDARD_ENCODING[code as usize],
+            EncodingKind::Expert => EXPERT_ENCODING[code as usize],
+            EncodingKind::Custom {
+                format,
+                data,
+                supplements,
+            } => {
+                if let Some(gid) = map_custom(*format, data, code) {
+                    return Some(gid);
+                }
+                supplements
+                    .chunks_exact(3)
+                    .find(|sup| sup[0] == code)
+                    .map(|sup| u16::from_be_bytes([sup[1], sup[2]]))?
+            }
+        };
+        // code 0 is always unmapped
+        if sid == 0 {
+            return None;
+        }
+        charset.glyph_id(StringId::new(sid))
+    }
+}
+
+/// Maps a code with a custom encoding, where codes are assigned to glyphs
+/// in order starting from glyph 1.
+fn map_custom(format: u8, data: &[u8], code: u8) -> Option<GlyphId> {
+    let idx = if format == 0 {
+        data.iter().position(|candidate| *candidate == code)?
+    } else {
+        let mut first_gid = 0;
+        let mut found = None;
+        for range in data.chunks_exact(2) {
+            let (first, n_left) = (range[0], range[1]);
+            if (first..=first.saturating_add(n_left)).contains(&code) {
+                found = Some(first_gid + (code - first) as usize);
+                break;
+            }
+            first_gid += n_left as usize + 1;
+        }
+        found?
+    };
+    u16::try_from(idx + 1).ok().map(GlyphId::new)
+}
+
+/// The predefined Standard encoding, mapping codes to string identifiers.
+#[rustfmt::skip]
+pub(crate) const STANDARD_ENCODING: [u16; 256] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+    17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32,
+    33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48,
+    49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64,
+    65, 66, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80,
+    81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 92, 93, 94, 95, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 96, 97, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 109, 110,
+    0, 111, 112, 113, 114, 0, 115, 116, 117, 118, 119, 120, 121, 122, 0, 123,
+    0, 124, 125, 126, 127, 128, 129, 130, 131, 0, 132, 133, 0, 134, 135, 136,
+    137, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 138, 0, 139, 0, 0, 0, 0, 140, 141, 142, 143, 0, 0, 0, 0,
+    0, 144, 0, 0, 0, 145, 0, 0, 146, 147, 148, 149, 0, 0, 0, 0,
+];
+
+/// The predefined Expert encoding, mapping codes to string identifiers.
+#[rustfmt::skip]
+const EXPERT_ENCODING: [u16; 256] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    1, 229, 230, 0, 231, 232, 233, 234, 235, 236, 237, 238, 13, 14, 15, 99,
+    239, 240, 241, 242, 243, 244, 245, 246, 247, 248, 27, 28, 249, 250, 251, 252,
+    0, 253, 254, 255, 256, 257, 0, 0, 0, 258, 0, 0, 259, 260, 261, 262,
+    0, 0, 263, 264, 265, 0, 266, 109, 110, 267, 268, 269, 0, 270, 271, 272,
+    273, 274, 275, 276, 277, 278, 279, 280, 281, 282, 283, 284, 285, 286, 287, 288,
+    289, 290, 291, 292, 293, 294, 295, 296, 297, 298, 299, 300, 301, 302, 303, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 304, 305, 306, 0, 0, 307, 308, 309, 310, 311, 0, 312, 0, 0, 313,
+    0, 0, 314, 315, 0, 0, 316, 317, 318, 0, 0, 0, 158, 155, 163, 319,
+    320, 321, 322, 323, 324, 325, 0, 0, 326, 150, 164, 169, 327, 328, 329, 330,
+    331, 332, 333, 334, 335, 336, 337, 338, 339, 340, 341, 342, 343, 344, 345, 346,
+    347, 348, 349, 350, 351, 352, 353, 354, 355, 356, 357, 358, 359, 360, 361, 362,
+    363, 364, 365, 366, 367, 368, 369, 370, 371, 372, 373, 374, 375, 376, 377, 378,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    #[test]
+    fn standard_encoding() {
+        let data = FontData::new(&[]);
+        let charset = Charset::new(data, 0, 229).unwrap();
+        let encoding = Encoding::new(data, 0).unwrap();
+        // 'A' is string 34
+        assert_eq!(encoding.map(b'A', &charset), Some(GlyphId::new(34)));
+        // germandbls
+        assert_eq!(encoding.map(0xFB, &charset), Some(GlyphId::new(149)));
+        assert_eq!(encoding.map(0x80, &charset), None);
+    }
+
+    #[test]
+    fn expert_encoding_matches_charset() {
+        let data = FontData::new(&[]);
+        let charset = Charset::new(data, 1, 166).unwrap();
+        let encoding = Encoding::new(data, 1).unwrap();
+        let mut mapped = (0..=255u8)
+            .filter_map(|code| encoding.map(code, &charset))
+            .collect::<Vec<_>>();
+        mapped.sort();
+        let expected = (1..166).map(GlyphId::new).collect::<Vec<_>>();
+        assert_eq!(mapped, expected);
+    }
+
+    #[test]
+    fn custom_encodings() {
+        let data = FontData::new(&[]);
+        let charset = Charset::new(data, 0, 229).unwrap();
+        let mut format0 = BeBuffer::new();
+        format0.extend([0u8, 0, 0x80, 2, b'a', b'b', 1, b'z']);
+        format0.push(5u16);
+        let encoding = Encoding::new(format0.font_data(), 2).unwrap();
+        assert_eq!(encoding.map(b'a', &charset), Some(GlyphId::new(1)));
+        assert_eq!(encoding.map(b'b', &charset), Some(GlyphId::new(2)));
+        // supplements map to a string identifier
+        assert_eq!(encoding.map(b'z', &charset), Some(GlyphId::new(5)));
+        assert_eq!(encoding.map(b'c', &charset), None);
+
+        let mut format1 = BeBuffer::new();
+        format1.extend([0u8, 0, 1, 2, b'a', 2, b'x', 0]);
+        let encoding = Encoding::new(format1.font_data(), 2).unwrap();
+        assert_eq!(encoding.map(b'c', &charset), Some(GlyphId::new(3)));
+        assert_eq!(encoding.map(b'x', &charset), Some(GlyphId::new(4)));
+        assert_eq!(encoding.map(b'y', &charset), None);
+    }
+}
@@ -0,0 +1,129 @@
+//! Parsing for the FDSelect structure of CID-keyed fonts.
+
+use font_types::GlyphId;
+
+use crate::{FontData, ReadError};
+
+/// A mapping from glyph identifiers to indices in the FDArray.
+///
+/// Formats 0 and 3 are used in CFF, and formats 0, 3 and 4 in CFF2.
+///
+/// See "FDSelect" in the [CFF spec](https://adobe-type-tools.github.io/font-tech-notes/pdfs/5176.CFF.pdf)
+/// and the [CFF2 spec](https://learn.microsoft.com/en-us/typography/opentype/spec/cff2#fdselect).
+#[derive(Clone)]
+pub struct FdSelect<'a> {
+    format: u8,
+    data: FontData<'a>,
+}
+
+impl<'a> FdSelect<'a> {
+    /// Creates an FDSelect from the data at its offset.
+    pub fn new(data: FontData<'a>) -> Result<Self, ReadError> {
+        let format = data.read_at::<u8>(0)?;
+        if !matches!(format, 0 | 3 | 4) {
+            return Err(ReadError::InvalidFormat(format as _));
+        }
+        let data = data.split_off(1).ok_or(ReadError::OutOfBounds)?;
+        Ok(Self { format, data })
+    }
+
+    /// The format of this FDSelect.
+    pub fn format(&self) -> u8 {
+        self.format
+    }
+
+    /// Returns the index of the font DICT for the given glyph.
+    pub fn font_index(&self, gid: GlyphId) -> Option<u16> {
+        let gid = gid.to_u16() as u32;
+        match self.format {
+            0 => self
+                .data
+                .read_at::<u8>(gid as usize)
+                .ok()
+                .map(|fd| fd as u16),
+            // ranges are (first: u16, fd: u8), followed by a sentinel
+            3 => self.find_range(gid, 2, 1),
+            // ranges are (first: u32, fd: u16), followed by a sentinel
+            4 => self.find_range(gid, 4, 2),
+            _ => None,
+        }
+    }
+
+    /// Binary searches the ranges of a format 3 or 4 FDSelect.
+    ///
+    /// The range count has the same size as the first glyph of each range.
+    fn find_range(&self, gid: u32, first_size: usize, fd_size: usize) -> Option<u16> {
+        let range_len = first_size + fd_size;
+        let n_ranges = read_uint(self.data, 0, first_size)? as usize;
+        let first = |idx: usize| read_uint(self.data, first_size + idx * range_len, first_size);
+        // the sentinel is the first glyph after the last range
+        let sentinel = first(n_ranges)?;
+        if gid >= sentinel {
+            return None;
+        }
+        let (mut lo, mut hi) = (0, n_ranges);
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if gid < first(mid)? {
+                hi = mid;
+            } else if gid >= first(mid + 1)? {
+                lo = mid + 1;
+            } else {
+                let fd_pos = first_size + mid * range_len + first_size;
+                return read_uint(self.data, fd_pos, fd_size).map(|fd| fd as u16);
+            }
+        }
+        None
+    }
+}
+
+/// Reads a big-endian unsigned integer of one to four bytes.
+fn read_uint(data: FontData, pos: usize, size: usize) -> Option<u32> {
+    let bytes = data.slice(pos..pos + size)?.as_bytes();
+    Some(bytes.iter().fold(0, |acc, byte| (acc << 8) | *byte as u32))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    #[test]
+    fn formats() {
+        let mut format0 = BeBuffer::new();
+        format0.extend([0u8, 1, 1, 0, 2]);
+        let fd_select = FdSelect::new(format0.font_data()).unwrap();
+        let fd = |fd_select: &FdSelect, gid| fd_select.font_index(GlyphId::new(gid));
+        assert_eq!(fd(&fd_select, 0), Some(1));
+        assert_eq!(fd(&fd_select, 3), Some(2));
+        assert_eq!(fd(&fd_select, 4), None);
+
+        let mut format3 = BeBuffer::new();
+        format3.push(3u8);
+        format3.push(3u16);
+        for (first, fd) in [(0u16, 2u8), (10, 0), (12, 1)] {
+            format3.push(first);
+            format3.push(fd);
+        }
+        format3.push(20u16);
+        let fd_select = FdSelect::new(format3.font_data()).unwrap();
+        assert_eq!(fd(&fd_select, 9), Some(2));
+        assert_eq!(fd(&fd_select, 10), Some(0));
+        assert_eq!(fd(&fd_select, 11), Some(0));
+        assert_eq!(fd(&fd_select, 19), Some(1));
+        assert_eq!(fd(&fd_select, 20), None);
+
+        let mut format4 = BeBuffer::new();
+        format4.push(4u8);
+        format4.push(2u32);
+        format4.extend([0u32]);
+        format4.push(300u16);
+        format4.push(5u32);
+        format4.push(7u16);
+        format4.push(8u32);
+        let fd_select = FdSelect::new(format4.font_data()).unwrap();
+        assert_eq!(fd(&fd_select, 4), Some(300));
+        assert_eq!(fd(&fd_select, 7), Some(7));
+        assert_eq!(fd(&fd_select, 8), None);
+    }
+}
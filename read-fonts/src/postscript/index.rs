@@ -0,0 +1,163 @@
+//! Parsing for PostScript INDEX objects.
+
+use crate::{FontData, ReadError};
+
+/// An array of variable-sized objects, addressed by index.
+///
+/// CFF uses a 16-bit count, while CFF2 uses a 32-bit count; the format is
+/// otherwise the same.
+///
+/// See "INDEX Data" in the [CFF spec](https://adobe-type-tools.github.io/font-tech-notes/pdfs/5176.CFF.pdf)
+/// and the [CFF2 spec](https://learn.microsoft.com/en-us/typography/opentype/spec/cff2#5-index-data).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Index<'a> {
+    count_size: usize,
+    count: u32,
+    off_size: u8,
+    offsets: &'a [u8],
+    data: &'a [u8],
+}
+
+impl<'a> Index<'a> {
+    /// Reads a CFF INDEX from the start of `data`.
+    pub fn read_cff(data: FontData<'a>) -> Result<Self, ReadError> {
+        let count = data.read_at::<u16>(0)? as u32;
+        Self::read_with_count(data, count, 2)
+    }
+
+    /// Reads a CFF2 INDEX from the start of `data`.
+    pub fn read_cff2(data: FontData<'a>) -> Result<Self, ReadError> {
+        let count = data.read_at::<u32>(0)?;
+        Self::read_with_count(data, count, 4)
+    }
+
+    fn read_with_count(
+        data: FontData<'a>,
+        count: u32,
+        count_size: usize,
+    ) -> Result<Self, ReadError> {
+        if count == 0 {
+            // an empty INDEX consists of only the count
+            return Ok(Self {
+                count_size,
+                ..Default::default()
+            });
+        }
+        let off_size = data.read_at::<u8>(count_size)?;
+        if !(1..=4).contains(&off_size) {
+            return Err(ReadError::MalformedData("invalid INDEX offset size"));
+        }
+        let offsets_start = count_size + 1;
+        let offsets_len = (count as usize + 1) * off_size as usize;
+        let offsets = data
+            .slice(offsets_start..offsets_start + offsets_len)
+            .ok_or(ReadError::OutOfBounds)?
+            .as_bytes();
+        // offsets are relative to the byte preceding the object data
+        let data_len = read_offset(offsets, off_size, count as usize)
+            .checked_sub(1)
+            .ok_or(ReadError::MalformedData("invalid INDEX offset"))?;
+        let data_start = offsets_start + offsets_len;
+        let data = data
+            .slice(data_start..data_start + data_len)
+            .ok_or(ReadError::OutOfBounds)?
+            .as_bytes();
+        Ok(Self {
+            count_size,
+            count,
+            off_size,
+            offsets,
+            data,
+        })
+    }
+
+    /// The number of objects in this INDEX.
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// The total size of this INDEX, in bytes.
+    ///
+    /// This is the offset of whatever data immediately follows the INDEX.
+    pub fn size_in_bytes(&self) -> usize {
+        if self.count == 0 {
+            self.count_size
+        } else {
+            self.count_size + 1 + self.offsets.len() + self.data.len()
+        }
+    }
+
+    /// Returns the data for the object at the given index.
+    pub fn get(&self, idx: usize) -> Result<&'a [u8], ReadError> {
+        if idx >= self.count as usize {
+            return Err(ReadError::OutOfBounds);
+        }
+        let start = read_offset(self.offsets, self.off_size, idx);
+        let end = read_offset(self.offsets, self.off_size, idx + 1);
+        // offsets are one-based
+        start
+            .checked_sub(1)
+            .zip(end.checked_sub(1))
+            .and_then(|(start, end)| self.data.get(start..end))
+            .ok_or(ReadError::OutOfBounds)
+    }
+
+    /// Iterate over the objects in this INDEX.
+    pub fn iter(&self) -> impl Iterator<Item = Result<&'a [u8], ReadError>> + 'a {
+        let index = *self;
+        (0..self.count as usize).map(move |idx| index.get(idx))
+    }
+}
+
+/// Reads the offset at `idx` from an offset array with the given size.
+///
+/// The offset array must have been bounds checked.
+fn read_offset(offsets: &[u8], off_size: u8, idx: usize) -> usize {
+    let off_size = off_size as usize;
+    let start = idx * off_size;
+    offsets[start..start + off_size]
+        .iter()
+        .fold(0, |acc, byte| (acc << 8) | *byte as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    #[test]
+    fn cff_index() {
+        let mut buf = BeBuffer::new();
+        buf.push(3u16);
+        buf.push(1u8);
+        buf.extend([1u8, 3, 3, 6]);
+        buf.extend([b'a', b'b', b'c', b'd', b'e']);
+        buf.push(0xFFu8);
+        let index = Index::read_cff(buf.font_data()).unwrap();
+        assert_eq!(index.count(), 3);
+        assert_eq!(index.size_in_bytes(), 12);
+        assert_eq!(index.get(0).unwrap(), b"ab");
+        assert_eq!(index.get(1).unwrap(), b"");
+        assert_eq!(index.get(2).unwrap(), b"cde");
+        assert!(index.get(3).is_err());
+    }
+
+    #[test]
+    fn cff2_index() {
+        let mut buf = BeBuffer::new();
+        buf.push(2u32);
+        buf.push(2u8);
+        buf.extend([1u16, 2, 4]);
+        buf.extend([7u8, 8, 9]);
+        let index = Index::read_cff2(buf.font_data()).unwrap();
+        assert_eq!(index.size_in_bytes(), 14);
+        let objects = index.iter().collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(objects, [&[7u8][..], &[8, 9]]);
+        let mut empty = BeBuffer::new();
+        empty.push(0u32);
+        assert_eq!(
+            Index::read_cff2(empty.font_data()).unwrap().size_in_bytes(),
+            4
+        );
+    }
+}
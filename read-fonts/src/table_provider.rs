@@ -33,23 +33,54 @@ pub trait TableProvider<'a> {
         let number_of_h_metrics = self.hhea().map(|hhea| hhea.number_of_h_metrics())?;
         self.expect_data_for_tag(tables::hmtx::TAG)
             .and_then(|data| {
-                FontReadWithArgs::read_with_args(data, &(num_glyphs, number_of_h_metrics))
+                FontReadWithArgs::read_with_args(data, &(number_of_h_metrics, num_glyphs))
             })
     }
 
+    fn vhea(&self) -> Result<tables::vhea::Vhea<'a>, ReadError> {
+        self.expect_data_for_tag(tables::vhea::TAG)
+            .and_then(FontRead::read)
+    }
+
+    fn vmtx(&self) -> Result<tables::vmtx::Vmtx<'a>, ReadError> {
+        let num_glyphs = self.maxp().map(|maxp| maxp.num_glyphs())?;
+        let number_of_long_ver_metrics =
+            self.vhea().map(|vhea| vhea.number_of_long_ver_metrics())?;
+        self.expect_data_for_tag(tables::vmtx::TAG)
+            .and_then(|data| {
+                FontReadWithArgs::read_with_args(data, &(number_of_long_ver_metrics, num_glyphs))
+            })
+    }
+
+    fn vorg(&self) -> Result<tables::vorg::Vorg<'a>, ReadError> {
+        self.expect_data_for_tag(tables::vorg::TAG)
+            .and_then(FontRead::read)
+    }
+
     fn maxp(&self) -> Result<tables::maxp::Maxp<'a>, ReadError> {
         self.expect_data_for_tag(tables::maxp::TAG)
             .and_then(FontRead::read)
     }
 
+    fn os2(&self) -> Result<tables::os2::Os2<'a>, ReadError> {
+        self.expect_data_for_tag(tables::os2::TAG)
+            .and_then(FontRead::read)
+    }
+
     fn post(&self) -> Result<tables::post::Post<'a>, ReadError> {
         self.expect_data_for_tag(tables::post::TAG)
             .and_then(FontRead::read)
     }
 
-    //fn stat(&self) -> Option<stat::Stat> {
-    //self.data_for_tag(stat::TAG).and_then(stat::Stat::read)
-    //}
+    fn stat(&self) -> Result<tables::stat::Stat<'a>, ReadError> {
+        self.expect_data_for_tag(tables::stat::TAG)
+            .and_then(FontRead::read)
+    }
+
+    fn kern(&self) -> Result<tables::kern::Kern<'a>, ReadError> {
+        self.expect_data_for_tag(tables::kern::TAG)
+            .and_then(FontRead::read)
+    }
 
     /// is_long can be optionally provided, if known, otherwise we look it up in head.
     fn loca(&self, is_long: impl Into<Option<bool>>) -> Result<tables::loca::Loca<'a>, ReadError> {
@@ -66,11 +97,127 @@ pub trait TableProvider<'a> {
             .and_then(FontRead::read)
     }
 
+    fn cvt(&self) -> Result<tables::cvt::Cvt<'a>, ReadError> {
+        self.expect_data_for_tag(tables::cvt::TAG)
+            .and_then(FontRead::read)
+    }
+
+    fn fpgm(&self) -> Result<tables::fpgm::Fpgm<'a>, ReadError> {
+        self.expect_data_for_tag(tables::fpgm::TAG)
+            .and_then(FontRead::read)
+    }
+
+    fn prep(&self) -> Result<tables::prep::Prep<'a>, ReadError> {
+        self.expect_data_for_tag(tables::prep::TAG)
+            .and_then(FontRead::read)
+    }
+
+    fn gasp(&self) -> Result<tables::gasp::Gasp<'a>, ReadError> {
+        self.expect_data_for_tag(tables::gasp::TAG)
+            .and_then(FontRead::read)
+    }
+
+    fn cff(&self) -> Result<tables::cff::Cff<'a>, ReadError> {
+        self.expect_data_for_tag(tables::cff::TAG)
+            .and_then(FontRead::read)
+    }
+
+    fn cff2(&self) -> Result<tables::cff2::Cff2<'a>, ReadError> {
+        self.expect_data_for_tag(tables::cff2::TAG)
+            .and_then(FontRead::read)
+    }
+
     fn cmap(&self) -> Result<tables::cmap::Cmap<'a>, ReadError> {
         self.expect_data_for_tag(tables::cmap::TAG)
             .and_then(FontRead::read)
     }
 
+    fn colr(&self) -> Result<tables::colr::Colr<'a>, ReadError> {
+        self.expect_data_for_tag(tables::colr::TAG)
+            .and_then(FontRead::read)
+    }
+
+    fn cpal(&self) -> Result<tables::cpal::Cpal<'a>, ReadError> {
+        self.expect_data_for_tag(tables::cpal::TAG)
+            .and_then(FontRead::read)
+    }
+
+    fn cblc(&self) -> Result<tables::cblc::Cblc<'a>, ReadError> {
+        self.expect_data_for_tag(tables::cblc::TAG)
+            .and_then(FontRead::read)
+    }
+
+    fn cbdt(&self) -> Result<tables::cbdt::Cbdt<'a>, ReadError> {
+        self.expect_data_for_tag(tables::cbdt::TAG)
+            .and_then(FontRead::read)
+    }
+
+    fn eblc(&self) -> Result<tables::eblc::Eblc<'a>, ReadError> {
+        self.expect_data_for_tag(tables::eblc::TAG)
+            .and_then(FontRead::read)
+    }
+
+    fn ebdt(&self) -> Result<tables::ebdt::Ebdt<'a>, ReadError> {
+        self.expect_data_for_tag(tables::ebdt::TAG)
+            .and_then(FontRead::read)
+    }
+
+    fn sbix(&self) -> Result<tables::sbix::Sbix<'a>, ReadError> {
+        let num_glyphs = self.maxp().map(|maxp| maxp.num_glyphs())?;
+        self.expect_data_for_tag(tables::sbix::TAG)
+            .and_then(|data| FontReadWithArgs::read_with_args(data, &num_glyphs))
+    }
+
+    fn svg(&self) -> Result<tables::svg::Svg<'a>, ReadError> {
+        self.expect_data_for_tag(tables::svg::TAG)
+            .and_then(FontRead::read)
+    }
+
+    fn fvar(&self) -> Result<tables::fvar::Fvar<'a>, ReadError> {
+        self.expect_data_for_tag(tables::fvar::TAG)
+            .and_then(FontRead::read)
+    }
+
+    fn avar(&self) -> Result<tables::avar::Avar<'a>, ReadError> {
+        self.expect_data_for_tag(tables::avar::TAG)
+            .and_then(FontRead::read)
+    }
+
+    fn gvar(&self) -> Result<tables::gvar::Gvar<'a>, ReadError> {
+        self.expect_data_for_tag(tables::gvar::TAG)
+            .and_then(FontRead::read)
+    }
+
+    fn hvar(&self) -> Result<tables::hvar::Hvar<'a>, ReadError> {
+        self.expect_data_for_tag(tables::hvar::TAG)
+            .and_then(FontRead::read)
+    }
+
+    fn vvar(&self) -> Result<tables::vvar::Vvar<'a>, ReadError> {
+        self.expect_data_for_tag(tables::vvar::TAG)
+            .and_then(FontRead::read)
+    }
+
+    fn mvar(&self) -> Result<tables::mvar::Mvar<'a>, ReadError> {
+        self.expect_data_for_tag(tables::mvar::TAG)
+            .and_then(FontRead::read)
+    }
+
+    fn jstf(&self) -> Result<tables::jstf::Jstf<'a>, ReadError> {
+        self.expect_data_for_tag(tables::jstf::TAG)
+            .and_then(FontRead::read)
+    }
+
+    fn math(&self) -> Result<tables::math::Math<'a>, ReadError> {
+        self.expect_data_for_tag(tables::math::TAG)
+            .and_then(FontRead::read)
+    }
+
+    fn base(&self) -> Result<tables::base::Base<'a>, ReadError> {
+        self.expect_data_for_tag(tables::base::TAG)
+            .and_then(FontRead::read)
+    }
+
     fn gdef(&self) -> Result<tables::gdef::Gdef<'a>, ReadError> {
         self.expect_data_for_tag(tables::gdef::TAG)
             .and_then(FontRead::read)
@@ -8,6 +8,7 @@ use crate::{
 };
 
 /// Typed access to raw table data.
+#[derive(Clone, Copy)]
 pub struct TableRef<'a, T> {
     pub(crate) shape: T,
     pub(crate) data: FontData<'a>,
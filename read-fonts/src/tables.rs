@@ -1,12 +1,39 @@
 //! The various font tables
 
-pub use super::layout::{gdef, gpos, gsub};
+pub use super::layout::{base, gdef, gpos, gsub, jstf};
+pub mod avar;
+pub mod cbdt;
+pub mod cblc;
+pub mod cff;
+pub mod cff2;
 pub mod cmap;
+pub mod colr;
+pub mod cpal;
+pub mod cvt;
+pub mod ebdt;
+pub mod eblc;
+pub mod fpgm;
+pub mod fvar;
+pub mod gasp;
 pub mod glyf;
+pub mod gvar;
 pub mod head;
 pub mod hhea;
 pub mod hmtx;
+pub mod hvar;
+pub mod kern;
 pub mod loca;
+pub mod math;
 pub mod maxp;
+pub mod mvar;
 pub mod name;
+pub mod os2;
 pub mod post;
+pub mod prep;
+pub mod sbix;
+pub mod stat;
+pub mod svg;
+pub mod vhea;
+pub mod vmtx;
+pub mod vorg;
+pub mod vvar;
@@ -0,0 +1,144 @@
+//! The [avar (Axis Variations)](https://docs.microsoft.com/en-us/typography/opentype/spec/avar) table
+
+/// 'avar'
+pub const TAG: Tag = Tag::new(b"avar");
+
+include!("../../generated/generated_avar.rs");
+
+use crate::layout::{DeltaSetIndexMap, ItemVariationStore};
+
+impl VarSize for SegmentMaps<'_> {
+    fn read_len_at(data: FontData, pos: usize) -> Option<usize> {
+        let count: u16 = data.read_at(pos).ok()?;
+        Some(u16::RAW_BYTE_LEN + count as usize * AxisValueMap::RAW_BYTE_LEN)
+    }
+}
+
+impl<'a> SegmentMaps<'a> {
+    /// Applies the piecewise linear mapping to the given normalized
+    /// coordinate.
+    ///
+    /// Values outside of the range of the map are shifted by the offset of
+    /// the nearest endpoint.
+    pub fn apply(&self, coord: Fixed) -> Fixed {
+        let maps = self.axis_value_maps();
+        let (Some(first), Some(last)) = (maps.first(), maps.last()) else {
+            return coord;
+        };
+        let (first_from, first_to) = first.coordinates();
+        if coord <= first_from {
+            return coord - first_from + first_to;
+        }
+        let (last_from, last_to) = last.coordinates();
+        if coord >= last_from {
+            return coord - last_from + last_to;
+        }
+        // we know that first_from < coord < last_from, so there is
+        // a segment containing the coordinate.
+        let idx = maps
+            .iter()
+            .position(|map| map.from_coordinate().to_fixed() >= coord)
+            .unwrap_or(maps.len() - 1);
+        let (from, to) = maps[idx].coordinates();
+        if from == coord {
+            return to;
+        }
+        let (prev_from, prev_to) = maps[idx - 1].coordinates();
+        prev_to + (to - prev_to) * (coord - prev_from) / (from - prev_from)
+    }
+}
+
+impl AxisValueMap {
+    fn coordinates(&self) -> (Fixed, Fixed) {
+        (
+            self.from_coordinate().to_fixed(),
+            self.to_coordinate().to_fixed(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    fn make_avar(maps: &[&[(f32, f32)]]) -> BeBuffer {
+        let mut buf = BeBuffer::new();
+        buf.extend([1u16, 0, 0, maps.len() as u16]);
+        for map in maps {
+            buf.push(map.len() as u16);
+            for (from, to) in map.iter() {
+                buf.extend([*from, *to].map(F2Dot14::from_f32));
+            }
+        }
+        buf
+    }
+
+    fn map_coord(maps: &SegmentMaps, coord: f32) -> f32 {
+        maps.apply(F2Dot14::from_f32(coord).to_fixed())
+            .to_f2dot14()
+            .to_f32()
+    }
+
+    #[test]
+    fn read_segment_maps() {
+        let buf = make_avar(&[
+            &[(-1.0, -1.0), (0.0, 0.0), (1.0, 1.0)],
+            &[(-1.0, -1.0), (0.0, 0.0), (0.5, 0.25), (1.0, 1.0)],
+        ]);
+        let avar = Avar::read(buf.font_data()).unwrap();
+        assert_eq!(avar.axis_count(), 2);
+        assert!(avar.axis_index_map_offset().is_none());
+        let maps = avar
+            .axis_segment_maps()
+            .iter()
+            .map(|maps| maps.unwrap().position_map_count())
+            .collect::<Vec<_>>();
+        assert_eq!(maps, [3, 4]);
+        let second = avar.axis_segment_maps().get(1).unwrap().unwrap();
+        assert_eq!(
+            second.axis_value_maps()[2].to_coordinate(),
+            F2Dot14::from_f32(0.25)
+        );
+    }
+
+    #[test]
+    fn apply_segment_maps() {
+        let buf = make_avar(&[&[
+            (-1.0, -1.0),
+            (-0.5, -0.75),
+            (0.0, 0.0),
+            (0.5, 0.25),
+            (1.0, 1.0),
+        ]]);
+        let avar = Avar::read(buf.font_data()).unwrap();
+        let maps = avar.axis_segment_maps().get(0).unwrap().unwrap();
+        assert_eq!(map_coord(&maps, -1.0), -1.0);
+        assert_eq!(map_coord(&maps, -0.75), -0.875);
+        assert_eq!(map_coord(&maps, -0.5), -0.75);
+        assert_eq!(map_coord(&maps, 0.0), 0.0);
+        assert_eq!(map_coord(&maps, 0.25), 0.125);
+        assert_eq!(map_coord(&maps, 0.75), 0.625);
+        assert_eq!(map_coord(&maps, 1.0), 1.0);
+    }
+
+    #[test]
+    fn empty_segment_map_is_identity() {
+        let buf = make_avar(&[&[]]);
+        let avar = Avar::read(buf.font_data()).unwrap();
+        let maps = avar.axis_segment_maps().get(0).unwrap().unwrap();
+        assert_eq!(map_coord(&maps, 0.3), F2Dot14::from_f32(0.3).to_f32());
+    }
+
+    #[test]
+    fn read_version_2() {
+        let mut buf = BeBuffer::new();
+        buf.extend([2u16, 0, 0, 1]);
+        buf.push(0u16);
+        buf.extend([0u32, 42]);
+        let avar = Avar::read(buf.font_data()).unwrap();
+        assert_eq!(avar.version(), MajorMinor::VERSION_2_0);
+        assert!(avar.axis_index_map_offset().unwrap().is_null());
+        assert_eq!(avar.var_store_offset().unwrap().offset().to_u32(), 42);
+    }
+}
@@ -0,0 +1,6 @@
+//! The [CBDT](https://learn.microsoft.com/en-us/typography/opentype/spec/cbdt) table
+
+/// 'CBDT'
+pub const TAG: Tag = Tag::new(b"CBDT");
+
+include!("../../generated/generated_cbdt.rs");
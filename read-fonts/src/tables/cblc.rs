@@ -0,0 +1,8 @@
+//! The [CBLC](https://learn.microsoft.com/en-us/typography/opentype/spec/cblc) table
+
+/// 'CBLC'
+pub const TAG: Tag = Tag::new(b"CBLC");
+
+include!("../../generated/generated_cblc.rs");
+
+use crate::bitmap::BitmapSize;
@@ -0,0 +1,46 @@
+//! The [CFF](https://learn.microsoft.com/en-us/typography/opentype/spec/cff) table
+
+use crate::postscript::Index;
+
+/// 'CFF '
+pub const TAG: Tag = Tag::new(b"CFF");
+
+include!("../../generated/generated_cff.rs");
+
+impl<'a> Cff<'a> {
+    /// The Name INDEX, containing the PostScript name of each font in this
+    /// table.
+    pub fn names(&self) -> Result<Index<'a>, ReadError> {
+        self.index(0)
+    }
+
+    /// The Top DICT INDEX, containing the Top DICT of each font in this
+    /// table.
+    pub fn top_dicts(&self) -> Result<Index<'a>, ReadError> {
+        self.index(1)
+    }
+
+    /// The String INDEX, containing the strings that are not among the
+    /// predefined standard strings.
+    pub fn strings(&self) -> Result<Index<'a>, ReadError> {
+        self.index(2)
+    }
+
+    /// The global subroutine INDEX.
+    pub fn global_subrs(&self) -> Result<Index<'a>, ReadError> {
+        self.index(3)
+    }
+
+    /// Returns the INDEX at the given position in the sequence that
+    /// follows the header.
+    fn index(&self, position: usize) -> Result<Index<'a>, ReadError> {
+        let mut data = FontData::new(self.trailing_data());
+        for _ in 0..position {
+            let index = Index::read_cff(data)?;
+            data = data
+                .split_off(index.size_in_bytes())
+                .ok_or(ReadError::OutOfBounds)?;
+        }
+        Index::read_cff(data)
+    }
+}
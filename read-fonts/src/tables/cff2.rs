@@ -0,0 +1,15 @@
+//! The [CFF2](https://learn.microsoft.com/en-us/typography/opentype/spec/cff2) table
+
+use crate::postscript::Index;
+
+/// 'CFF2'
+pub const TAG: Tag = Tag::new(b"CFF2");
+
+include!("../../generated/generated_cff2.rs");
+
+impl<'a> Cff2<'a> {
+    /// The global subroutine INDEX, which follows the Top DICT.
+    pub fn global_subrs(&self) -> Result<Index<'a>, ReadError> {
+        Index::read_cff2(FontData::new(self.trailing_data()))
+    }
+}
@@ -6,3 +6,848 @@ use font_types::Tag;
 pub const TAG: Tag = Tag::new(b"cmap");
 
 include!("../../generated/generated_cmap.rs");
+
+/// Encoding records to use for codepoint lookup, in order of preference.
+///
+/// Full unicode subtables are preferred over BMP-only subtables, and
+/// Windows subtables are preferred over their Unicode platform
+/// equivalents. The Windows symbol encoding is used as a last resort.
+const PREFERRED_ENCODINGS: &[(u16, u16)] = &[
+    (3, 10),
+    (0, 6),
+    (0, 4),
+    (3, 1),
+    (0, 3),
+    (0, 2),
+    (0, 1),
+    (0, 0),
+    (3, 0),
+];
+
+/// Symbol fonts conventionally map their glyphs into this private use range.
+const SYMBOL_PUA_START: u32 = 0xF000;
+
+impl<'a> Cmap<'a> {
+    /// Returns the preferred encoding record and subtable for mapping
+    /// codepoints to glyphs, if one exists.
+    ///
+    /// Format 14 subtables are never selected; see [`Cmap14`] for variation
+    /// sequences.
+    pub fn best_subtable(&self) -> Option<(&'a EncodingRecord, CmapSubtable<'a>)> {
+        let data = self.offset_data();
+        PREFERRED_ENCODINGS.iter().find_map(|(platform, encoding)| {
+            self.encoding_records()
+                .iter()
+                .filter(|record| {
+                    record.platform_id() == *platform && record.encoding_id() == *encoding
+                })
+                .filter_map(|record| Some((record, record.subtable(data).ok()?)))
+                .find(|(_, subtable)| !matches!(subtable, CmapSubtable::Format14(_)))
+        })
+    }
+
+    /// Maps a codepoint to a nominal glyph identifier, using the preferred
+    /// subtable.
+    ///
+    /// Returns `None` if the codepoint is not mapped, or maps to `.notdef`.
+    pub fn map_codepoint(&self, codepoint: impl Into<u32>) -> Option<GlyphId> {
+        let codepoint = codepoint.into();
+        let (record, subtable) = self.best_subtable()?;
+        subtable.map_codepoint(codepoint).or_else(|| {
+            // symbol fonts generally map their glyphs into the PUA range
+            let is_symbol = record.platform_id() == 3 && record.encoding_id() == 0;
+            (is_symbol && codepoint <= 0xFF)
+                .then(|| subtable.map_codepoint(SYMBOL_PUA_START + codepoint))
+                .flatten()
+        })
+    }
+
+    /// Returns the format 14 subtable, if one exists.
+    pub fn variation_subtable(&self) -> Option<Cmap14<'a>> {
+        let data = self.offset_data();
+        self.encoding_records()
+            .iter()
+            .filter(|record| record.platform_id() == 0 && record.encoding_id() == 5)
+            .find_map(|record| match record.subtable(data) {
+                Ok(CmapSubtable::Format14(subtable)) => Some(subtable),
+                _ => None,
+            })
+    }
+
+    /// Maps a variation sequence to a glyph, using the format 14 subtable.
+    ///
+    /// See [`Cmap14::map_variant`] for details.
+    pub fn map_variant(
+        &self,
+        codepoint: impl Into<u32>,
+        selector: impl Into<u32>,
+    ) -> Option<MapVariant> {
+        self.variation_subtable()?.map_variant(codepoint, selector)
+    }
+
+    /// Returns an iterator over all (codepoint, glyph) mappings in the
+    /// preferred subtable.
+    ///
+    /// Codepoints are yielded in the order they appear in the subtable,
+    /// which is ascending for well-formed fonts. Codepoints mapped to
+    /// `.notdef` are skipped.
+    pub fn iter_mappings(&self) -> impl Iterator<Item = (u32, GlyphId)> + 'a {
+        self.best_subtable()
+            .into_iter()
+            .flat_map(|(_, subtable)| subtable.iter_mappings())
+    }
+
+    /// Builds an index from each mapped glyph to the set of codepoints that
+    /// map to it, using the preferred subtable.
+    #[cfg(feature = "std")]
+    pub fn reverse_map(
+        &self,
+    ) -> std::collections::BTreeMap<GlyphId, std::collections::BTreeSet<u32>> {
+        let mut result = std::collections::BTreeMap::<_, std::collections::BTreeSet<_>>::new();
+        for (codepoint, gid) in self.iter_mappings() {
+            result.entry(gid).or_default().insert(codepoint);
+        }
+        result
+    }
+}
+
+impl<'a> CmapSubtable<'a> {
+    /// Maps a codepoint to a nominal glyph identifier.
+    ///
+    /// Format 14 subtables only contain variation sequences, so this
+    /// always returns `None` for them.
+    pub fn map_codepoint(&self, codepoint: impl Into<u32>) -> Option<GlyphId> {
+        let codepoint = codepoint.into();
+        match self {
+            Self::Format0(item) => item.map_codepoint(codepoint),
+            Self::Format2(item) => item.map_codepoint(codepoint),
+            Self::Format4(item) => item.map_codepoint(codepoint),
+            Self::Format6(item) => item.map_codepoint(codepoint),
+            Self::Format8(item) => item.map_codepoint(codepoint),
+            Self::Format10(item) => item.map_codepoint(codepoint),
+            Self::Format12(item) => item.map_codepoint(codepoint),
+            Self::Format13(item) => item.map_codepoint(codepoint),
+            Self::Format14(_) => None,
+        }
+    }
+
+    /// Returns an iterator over all (codepoint, glyph) mappings in this
+    /// subtable.
+    ///
+    /// Codepoints mapped to `.notdef` are skipped. Format 14 subtables yield
+    /// no mappings.
+    pub fn iter_mappings(&self) -> Mappings<'a> {
+        Mappings {
+            subtable: self.clone(),
+            range_idx: 0,
+            next: 0,
+            end: 0,
+            in_range: false,
+        }
+    }
+
+    /// Returns the inclusive range of codepoints covered by the range
+    /// (segment, group, etc) at the given index, or `None` if there are no
+    /// more ranges.
+    ///
+    /// Empty ranges have a start greater than their end.
+    fn mapping_range(&self, idx: usize) -> Option<(u32, u32)> {
+        match self {
+            Self::Format0(_) => (idx == 0).then_some((0, 255)),
+            Self::Format2(item) => {
+                if idx == 0 {
+                    return Some((0, 255));
+                }
+                let key = item.sub_header_keys().get(idx)?.get();
+                let range = (key != 0)
+                    .then(|| item.sub_header(key))
+                    .flatten()
+                    .and_then(|header| {
+                        let start = ((idx as u32) << 8) + header.first_code() as u32;
+                        inclusive_range(start, header.entry_count() as u32)
+                    });
+                Some(range.unwrap_or(EMPTY_RANGE))
+            }
+            Self::Format4(item) => Some((
+                item.start_code().get(idx)?.get() as u32,
+                item.end_code().get(idx)?.get() as u32,
+            )),
+            Self::Format6(item) => (idx == 0).then(|| {
+                inclusive_range(item.first_code() as u32, item.entry_count() as u32)
+                    .unwrap_or(EMPTY_RANGE)
+            }),
+            Self::Format8(item) => item
+                .groups()
+                .get(idx)
+                .map(|group| (group.start_char_code(), group.end_char_code())),
+            Self::Format10(item) => (idx == 0).then(|| {
+                inclusive_range(item.start_char_code(), item.num_chars()).unwrap_or(EMPTY_RANGE)
+            }),
+            Self::Format12(item) => item
+                .groups()
+                .get(idx)
+                .map(|group| (group.start_char_code(), group.end_char_code())),
+            Self::Format13(item) => item
+                .groups()
+                .get(idx)
+                .map(|group| (group.start_char_code(), group.end_char_code())),
+            Self::Format14(_) => None,
+        }
+    }
+
+    /// Maps a codepoint that is known to be in the range at the given index.
+    fn range_glyph(&self, idx: usize, codepoint: u32) -> Option<GlyphId> {
+        match self {
+            Self::Format4(item) => item.segment_glyph(idx, codepoint as u16),
+            Self::Format8(item) => sequential_group_glyph(item.groups().get(idx)?, codepoint),
+            Self::Format12(item) => sequential_group_glyph(item.groups().get(idx)?, codepoint),
+            Self::Format13(item) => nonzero_glyph(item.groups().get(idx)?.glyph_id()),
+            // the remaining formats have constant time lookup
+            _ => self.map_codepoint(codepoint),
+        }
+    }
+}
+
+/// The largest valid unicode codepoint.
+const MAX_CODEPOINT: u32 = 0x10FFFF;
+
+const EMPTY_RANGE: (u32, u32) = (1, 0);
+
+fn inclusive_range(start: u32, count: u32) -> Option<(u32, u32)> {
+    Some((start, start.checked_add(count.checked_sub(1)?)?))
+}
+
+/// An iterator over the (codepoint, glyph) mappings in a cmap subtable.
+///
+/// This is returned by [`CmapSubtable::iter_mappings`].
+#[derive(Clone)]
+pub struct Mappings<'a> {
+    subtable: CmapSubtable<'a>,
+    range_idx: usize,
+    next: u32,
+    end: u32,
+    in_range: bool,
+}
+
+impl<'a> Iterator for Mappings<'a> {
+    type Item = (u32, GlyphId);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.in_range {
+                while self.next <= self.end {
+                    let codepoint = self.next;
+                    self.next += 1;
+                    if let Some(gid) = self.subtable.range_glyph(self.range_idx, codepoint) {
+                        return Some((codepoint, gid));
+                    }
+                }
+                self.in_range = false;
+                self.range_idx += 1;
+            }
+            let (start, end) = self.subtable.mapping_range(self.range_idx)?;
+            self.next = start;
+            // guard against malformed ranges that would take forever to walk
+            self.end = end.min(MAX_CODEPOINT);
+            self.in_range = true;
+        }
+    }
+}
+
+/// The result of mapping a variation sequence with [`Cmap14::map_variant`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MapVariant {
+    /// The sequence is supported, and should use the glyph the base
+    /// codepoint maps to in the font's unicode subtable.
+    UseDefault,
+    /// The sequence is supported, and maps to this glyph.
+    Variant(GlyphId),
+}
+
+impl<'a> Cmap14<'a> {
+    /// Returns the variation selector record for the given selector.
+    pub fn selector_record(&self, selector: impl Into<u32>) -> Option<&'a VariationSelector> {
+        let selector = selector.into();
+        let records = self.var_selector();
+        records
+            .binary_search_by_key(&selector, |rec| rec.var_selector().into())
+            .ok()
+            .map(|idx| &records[idx])
+    }
+
+    /// Maps a variation sequence, consisting of a base codepoint and a
+    /// variation selector, to a glyph.
+    ///
+    /// Returns `None` if the sequence is not supported by this subtable.
+    pub fn map_variant(
+        &self,
+        codepoint: impl Into<u32>,
+        selector: impl Into<u32>,
+    ) -> Option<MapVariant> {
+        let codepoint = codepoint.into();
+        let record = self.selector_record(selector)?;
+        let data = self.offset_data();
+        if let Some(Ok(default_uvs)) = record.default_uvs(data) {
+            let ranges = default_uvs.ranges();
+            let found = find_group(ranges, codepoint, |range| {
+                let start: u32 = range.start_unicode_value().into();
+                (start, start + range.additional_count() as u32)
+            });
+            if found.is_some() {
+                return Some(MapVariant::UseDefault);
+            }
+        }
+        let non_default_uvs = record.non_default_uvs(data)?.ok()?;
+        let mappings = non_default_uvs.uvs_mapping();
+        let idx = mappings
+            .binary_search_by_key(&codepoint, |mapping| mapping.unicode_value().into())
+            .ok()?;
+        Some(MapVariant::Variant(GlyphId::new(mappings[idx].glyph_id())))
+    }
+
+    /// Returns an iterator over the variation selectors supported by this
+    /// subtable, in ascending order.
+    pub fn selectors(&self) -> impl Iterator<Item = u32> + 'a {
+        self.var_selector()
+            .iter()
+            .map(|record| record.var_selector().into())
+    }
+
+    /// Returns an iterator over the base codepoints of the variation
+    /// sequences supported for the given selector, along with their
+    /// mapping.
+    ///
+    /// Sequences using the default glyph are yielded first, followed by
+    /// those mapped to a specific glyph.
+    pub fn sequences(
+        &self,
+        selector: impl Into<u32>,
+    ) -> impl Iterator<Item = (u32, MapVariant)> + 'a {
+        let data = self.offset_data();
+        let record = self.selector_record(selector);
+        let default_ranges = record
+            .and_then(|record| record.default_uvs(data)?.ok())
+            .map(|table| table.ranges())
+            .unwrap_or_default();
+        let non_default_mappings = record
+            .and_then(|record| record.non_default_uvs(data)?.ok())
+            .map(|table| table.uvs_mapping())
+            .unwrap_or_default();
+        default_ranges
+            .iter()
+            .flat_map(|range| {
+                let start: u32 = range.start_unicode_value().into();
+                (start..=start + range.additional_count() as u32)
+                    .map(|codepoint| (codepoint, MapVariant::UseDefault))
+            })
+            .chain(non_default_mappings.iter().map(|mapping| {
+                (
+                    mapping.unicode_value().into(),
+                    MapVariant::Variant(GlyphId::new(mapping.glyph_id())),
+                )
+            }))
+    }
+
+    /// Returns an iterator over all (codepoint, selector, mapping) variation
+    /// sequences supported by this subtable.
+    pub fn iter_sequences(&self) -> impl Iterator<Item = (u32, u32, MapVariant)> + 'a {
+        let subtable = *self;
+        self.selectors().flat_map(move |selector| {
+            subtable
+                .sequences(selector)
+                .map(move |(codepoint, variant)| (codepoint, selector, variant))
+        })
+    }
+}
+
+/// Converts a raw glyph index to a `GlyphId`, treating `.notdef` as unmapped.
+fn nonzero_glyph(gid: u32) -> Option<GlyphId> {
+    let gid = u16::try_from(gid).ok()?;
+    (gid != 0).then(|| GlyphId::new(gid))
+}
+
+impl<'a> Cmap0<'a> {
+    /// Maps a codepoint to a nominal glyph identifier.
+    pub fn map_codepoint(&self, codepoint: impl Into<u32>) -> Option<GlyphId> {
+        let codepoint = codepoint.into();
+        let gid = self.glyph_id_array().get(codepoint as usize)?.get();
+        nonzero_glyph(gid as u32)
+    }
+}
+
+impl<'a> Cmap2<'a> {
+    /// The byte offset of the sub-header array from the start of the table.
+    const SUB_HEADERS_START: usize = 6 + 256 * 2;
+
+    /// Returns the sub-header with the given key.
+    ///
+    /// Keys are byte offsets from the start of the sub-header array, as found
+    /// in the [`sub_header_keys`](Self::sub_header_keys) array.
+    pub fn sub_header(&self, key: u16) -> Option<&'a SubHeader> {
+        let start = Self::SUB_HEADERS_START + key as usize;
+        self.offset_data()
+            .read_array::<SubHeader>(start..start + SubHeader::RAW_BYTE_LEN)
+            .ok()?
+            .first()
+    }
+
+    /// Maps a codepoint to a nominal glyph identifier.
+    pub fn map_codepoint(&self, codepoint: impl Into<u32>) -> Option<GlyphId> {
+        let codepoint = codepoint.into();
+        if codepoint > 0xFFFF {
+            return None;
+        }
+        let keys = self.sub_header_keys();
+        let high = (codepoint >> 8) as usize;
+        let (key, low) = if high == 0 {
+            // single byte codes always use sub-header 0; if the key is not
+            // zero then this is the first byte of a two byte code.
+            let key = keys.get(codepoint as usize)?.get();
+            if key != 0 {
+                return None;
+            }
+            (key, codepoint as u16)
+        } else {
+            let key = keys.get(high)?.get();
+            if key == 0 {
+                return None;
+            }
+            (key, (codepoint & 0xFF) as u16)
+        };
+        let sub_header = self.sub_header(key)?;
+        let idx = low.checked_sub(sub_header.first_code())?;
+        if idx >= sub_header.entry_count() {
+            return None;
+        }
+        // the id range offset is relative to the location of the
+        // idRangeOffset field itself, which is the last in the record.
+        let range_offset_pos = Self::SUB_HEADERS_START + key as usize + 6;
+        let glyph_pos = range_offset_pos + sub_header.id_range_offset() as usize + idx as usize * 2;
+        let gid = self.offset_data().read_at::<u16>(glyph_pos).ok()?;
+        if gid == 0 {
+            return None;
+        }
+        nonzero_glyph((gid as i32 + sub_header.id_delta() as i32) as u32 & 0xFFFF)
+    }
+}
+
+impl<'a> Cmap4<'a> {
+    /// Maps a codepoint to a nominal glyph identifier.
+    pub fn map_codepoint(&self, codepoint: impl Into<u32>) -> Option<GlyphId> {
+        let codepoint = u16::try_from(codepoint.into()).ok()?;
+        let end_codes = self.end_code();
+        // find the first segment whose end code is >= the codepoint
+        let seg_idx = match end_codes.binary_search_by_key(&codepoint, |end| end.get()) {
+            Ok(idx) | Err(idx) => idx,
+        };
+        self.segment_glyph(seg_idx, codepoint)
+    }
+
+    /// Maps a codepoint to a glyph using the segment at the given index.
+    fn segment_glyph(&self, seg_idx: usize, codepoint: u16) -> Option<GlyphId> {
+        let start = self.start_code().get(seg_idx)?.get();
+        if codepoint < start || codepoint > self.end_code().get(seg_idx)?.get() {
+            return None;
+        }
+        let delta = self.id_delta().get(seg_idx)?.get() as i32;
+        let range_offset = self.id_range_offsets().get(seg_idx)?.get();
+        if range_offset == 0 {
+            return nonzero_glyph((codepoint as i32 + delta) as u32 & 0xFFFF);
+        }
+        // The offset is relative to the position of this segment's
+        // idRangeOffset entry; convert it to an index into the glyph array,
+        // which immediately follows the idRangeOffset array.
+        let seg_count = self.end_code().len();
+        let glyph_idx = (range_offset as usize / 2 + (codepoint - start) as usize + seg_idx)
+            .checked_sub(seg_count)?;
+        let gid = self.glyph_id_array().get(glyph_idx)?.get();
+        if gid == 0 {
+            return None;
+        }
+        nonzero_glyph((gid as i32 + delta) as u32 & 0xFFFF)
+    }
+}
+
+impl<'a> Cmap6<'a> {
+    /// Maps a codepoint to a nominal glyph identifier.
+    pub fn map_codepoint(&self, codepoint: impl Into<u32>) -> Option<GlyphId> {
+        let idx = codepoint.into().checked_sub(self.first_code() as u32)?;
+        let gid = self.glyph_id_array().get(idx as usize)?.get();
+        nonzero_glyph(gid as u32)
+    }
+}
+
+impl<'a> Cmap8<'a> {
+    /// Maps a codepoint to a nominal glyph identifier.
+    pub fn map_codepoint(&self, codepoint: impl Into<u32>) -> Option<GlyphId> {
+        map_sequential_groups(self.groups(), codepoint.into())
+    }
+}
+
+impl<'a> Cmap10<'a> {
+    /// Maps a codepoint to a nominal glyph identifier.
+    pub fn map_codepoint(&self, codepoint: impl Into<u32>) -> Option<GlyphId> {
+        let idx = codepoint.into().checked_sub(self.start_char_code())?;
+        if idx >= self.num_chars() {
+            return None;
+        }
+        let gid = self.glyph_id_array().get(idx as usize)?.get();
+        nonzero_glyph(gid as u32)
+    }
+}
+
+impl<'a> Cmap12<'a> {
+    /// Maps a codepoint to a nominal glyph identifier.
+    pub fn map_codepoint(&self, codepoint: impl Into<u32>) -> Option<GlyphId> {
+        map_sequential_groups(self.groups(), codepoint.into())
+    }
+}
+
+impl<'a> Cmap13<'a> {
+    /// Maps a codepoint to a nominal glyph identifier.
+    pub fn map_codepoint(&self, codepoint: impl Into<u32>) -> Option<GlyphId> {
+        let codepoint = codepoint.into();
+        let groups = self.groups();
+        let idx = find_group(groups, codepoint, |group| {
+            (group.start_char_code(), group.end_char_code())
+        })?;
+        nonzero_glyph(groups[idx].glyph_id())
+    }
+}
+
+/// Lookup for format 8 and format 12 subtables.
+fn map_sequential_groups(groups: &[SequentialMapGroup], codepoint: u32) -> Option<GlyphId> {
+    let idx = find_group(groups, codepoint, |group| {
+        (group.start_char_code(), group.end_char_code())
+    })?;
+    sequential_group_glyph(&groups[idx], codepoint)
+}
+
+fn sequential_group_glyph(group: &SequentialMapGroup, codepoint: u32) -> Option<GlyphId> {
+    let idx = codepoint.checked_sub(group.start_char_code())?;
+    nonzero_glyph(group.start_glyph_id().checked_add(idx)?)
+}
+
+/// Binary search for the group containing `codepoint` in an array of groups
+/// sorted by (non-overlapping) character code ranges.
+fn find_group<T>(groups: &[T], codepoint: u32, range: impl Fn(&T) -> (u32, u32)) -> Option<usize> {
+    groups
+        .binary_search_by(|group| {
+            let (start, end) = range(group);
+            if codepoint < start {
+                core::cmp::Ordering::Greater
+            } else if codepoint > end {
+                core::cmp::Ordering::Less
+            } else {
+                core::cmp::Ordering::Equal
+            }
+        })
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    /// A format 4 subtable mapping:
+    /// - 'A'..='C' to 10..=12 via delta,
+    /// - 'x'..='z' to 40, 0, 42 via the glyph id array,
+    /// - the final 0xFFFF segment
+    fn format4() -> BeBuffer {
+        let mut buf = BeBuffer::new();
+        let seg_count = 3u16;
+        buf.extend([4u16, 0, 0, seg_count * 2, 4, 1, 2]);
+        // end codes, reserved pad, start codes
+        buf.extend([b'C' as u16, b'z' as u16, 0xFFFF, 0]);
+        buf.extend([b'A' as u16, b'x' as u16, 0xFFFF]);
+        // id deltas
+        buf.extend([10i16 - b'A' as i16, 0, 1]);
+        // id range offsets; the second points at the start of the glyph
+        // array, which is two entries away
+        buf.extend([0u16, 4, 0]);
+        buf.extend([40u16, 0, 42]);
+        buf
+    }
+
+    #[test]
+    fn map_format4() {
+        let buf = format4();
+        let subtable = Cmap4::read(buf.font_data()).unwrap();
+        let map = |c: char| subtable.map_codepoint(c).map(|gid| gid.to_u16());
+        assert_eq!(map('A'), Some(10));
+        assert_eq!(map('C'), Some(12));
+        assert_eq!(map('D'), None);
+        assert_eq!(map('x'), Some(40));
+        assert_eq!(map('y'), None);
+        assert_eq!(map('z'), Some(42));
+        assert_eq!(map('\u{FFFF}'), None);
+        assert_eq!(map('\u{10000}'), None);
+    }
+
+    #[test]
+    fn map_format12_and_13() {
+        let mut buf = BeBuffer::new();
+        buf.extend([12u16, 0]);
+        buf.extend([0u32, 0, 2]);
+        buf.extend([0x20u32, 0x7E, 1]);
+        buf.extend([0x1F600u32, 0x1F64F, 200]);
+        let subtable = Cmap12::read(buf.font_data()).unwrap();
+        assert_eq!(subtable.map_codepoint('!'), Some(GlyphId::new(2)));
+        assert_eq!(subtable.map_codepoint('\u{1F601}'), Some(GlyphId::new(201)));
+        assert_eq!(subtable.map_codepoint('\u{1F650}'), None);
+        assert_eq!(subtable.map_codepoint('\u{1F}'), None);
+
+        let mut buf = BeBuffer::new();
+        buf.extend([13u16, 0]);
+        buf.extend([0u32, 0, 1]);
+        buf.extend([0x100u32, 0x1FF, 7]);
+        let subtable = Cmap13::read(buf.font_data()).unwrap();
+        assert_eq!(subtable.map_codepoint(0x100u32), Some(GlyphId::new(7)));
+        assert_eq!(subtable.map_codepoint(0x1FFu32), Some(GlyphId::new(7)));
+        assert_eq!(subtable.map_codepoint(0x200u32), None);
+    }
+
+    #[test]
+    fn map_format2() {
+        let mut buf = BeBuffer::new();
+        buf.extend([2u16, 0, 0]);
+        // sub-header keys: 0x81 is a lead byte using sub-header 1
+        let mut keys = [0u16; 256];
+        keys[0x81] = 8;
+        buf.extend(keys);
+        // sub-header 0: single byte codes 0x20..0x22, glyph array at index 0
+        // (the idRangeOffset field is at 518 + 6, glyphs start at 534)
+        buf.extend([0x20u16, 3]);
+        buf.push(0i16);
+        buf.push(534u16 - (518 + 6));
+        // sub-header 1: low bytes 0x40..0x41, glyph array at index 3
+        buf.extend([0x40u16, 2]);
+        buf.push(100i16);
+        buf.push(540u16 - (526 + 6));
+        // glyph array
+        buf.extend([1u16, 2, 3, 5, 0]);
+        let subtable = Cmap2::read(buf.font_data()).unwrap();
+        let map = |c: u32| subtable.map_codepoint(c).map(|gid| gid.to_u16());
+        assert_eq!(map(0x20), Some(1));
+        assert_eq!(map(0x22), Some(3));
+        assert_eq!(map(0x23), None);
+        // lead bytes are not characters
+        assert_eq!(map(0x81), None);
+        assert_eq!(map(0x8140), Some(105));
+        assert_eq!(map(0x8141), None);
+        assert_eq!(map(0x8240), None);
+    }
+
+    #[test]
+    fn map_simple_formats() {
+        let mut buf = BeBuffer::new();
+        buf.extend([0u16, 262, 0]);
+        let mut glyphs = [0u8; 256];
+        glyphs[b'a' as usize] = 3;
+        buf.extend(glyphs);
+        let subtable = Cmap0::read(buf.font_data()).unwrap();
+        assert_eq!(subtable.map_codepoint('a'), Some(GlyphId::new(3)));
+        assert_eq!(subtable.map_codepoint('b'), None);
+        assert_eq!(subtable.map_codepoint('\u{100}'), None);
+
+        let mut buf = BeBuffer::new();
+        buf.extend([6u16, 0, 0, 0x30, 2, 7, 8]);
+        let subtable = Cmap6::read(buf.font_data()).unwrap();
+        assert_eq!(subtable.map_codepoint('1'), Some(GlyphId::new(8)));
+        assert_eq!(subtable.map_codepoint('2'), None);
+        assert_eq!(subtable.map_codepoint('/'), None);
+
+        let mut buf = BeBuffer::new();
+        buf.extend([10u16, 0]);
+        buf.extend([0u32, 0, 0x10000, 2]);
+        buf.extend([9u16, 10]);
+        let subtable = Cmap10::read(buf.font_data()).unwrap();
+        assert_eq!(subtable.map_codepoint(0x10001u32), Some(GlyphId::new(10)));
+        assert_eq!(subtable.map_codepoint(0x10002u32), None);
+    }
+
+    #[test]
+    fn best_subtable() {
+        let format4 = format4();
+        let mut buf = BeBuffer::new();
+        // a mac roman subtable should be ignored in favour of (3, 1)
+        buf.extend([0u16, 2]);
+        buf.extend([1u16, 0]);
+        buf.push(20u32);
+        buf.extend([3u16, 1]);
+        buf.push(20u32 + 262);
+        let mut glyphs = [0u8; 256];
+        glyphs[b'A' as usize] = 99;
+        buf.extend([0u16, 262, 0]);
+        buf.extend(glyphs);
+        buf.extend(format4.iter().copied());
+
+        let cmap = Cmap::read(buf.font_data()).unwrap();
+        let (record, _) = cmap.best_subtable().unwrap();
+        assert_eq!((record.platform_id(), record.encoding_id()), (3, 1));
+        assert_eq!(cmap.map_codepoint('A'), Some(GlyphId::new(10)));
+        assert_eq!(cmap.map_codepoint('z'), Some(GlyphId::new(42)));
+        assert_eq!(cmap.map_codepoint('@'), None);
+    }
+
+    #[test]
+    fn iter_format4() {
+        let buf = format4();
+        let subtable = CmapSubtable::read(buf.font_data()).unwrap();
+        let mappings = subtable
+            .iter_mappings()
+            .map(|(cp, gid)| (char::from_u32(cp).unwrap(), gid.to_u16()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            mappings,
+            [('A', 10), ('B', 11), ('C', 12), ('x', 40), ('z', 42)]
+        );
+    }
+
+    #[test]
+    fn iter_groups() {
+        let mut buf = BeBuffer::new();
+        buf.extend([12u16, 0]);
+        buf.extend([0u32, 0, 2]);
+        buf.extend([0x20u32, 0x21, 1]);
+        buf.extend([0x1F600u32, 0x1F601, 200]);
+        let subtable = CmapSubtable::read(buf.font_data()).unwrap();
+        let mappings = subtable
+            .iter_mappings()
+            .map(|(cp, gid)| (cp, gid.to_u16()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            mappings,
+            [(0x20, 1), (0x21, 2), (0x1F600, 200), (0x1F601, 201)]
+        );
+
+        let mut buf = BeBuffer::new();
+        buf.extend([13u16, 0]);
+        buf.extend([0u32, 0, 2]);
+        buf.extend([0x30u32, 0x32, 5]);
+        buf.extend([0x40u32, 0x40, 0]);
+        let subtable = CmapSubtable::read(buf.font_data()).unwrap();
+        let mappings = subtable
+            .iter_mappings()
+            .map(|(cp, gid)| (cp, gid.to_u16()))
+            .collect::<Vec<_>>();
+        assert_eq!(mappings, [(0x30, 5), (0x31, 5), (0x32, 5)]);
+    }
+
+    #[test]
+    fn iter_format2() {
+        let mut buf = BeBuffer::new();
+        buf.extend([2u16, 0, 0]);
+        let mut keys = [0u16; 256];
+        keys[0x81] = 8;
+        buf.extend(keys);
+        buf.extend([0x20u16, 2]);
+        buf.push(0i16);
+        buf.push(534u16 - (518 + 6));
+        buf.extend([0x40u16, 2]);
+        buf.push(100i16);
+        buf.push(538u16 - (526 + 6));
+        buf.extend([1u16, 2, 5, 6]);
+        let subtable = CmapSubtable::read(buf.font_data()).unwrap();
+        let mappings = subtable
+            .iter_mappings()
+            .map(|(cp, gid)| (cp, gid.to_u16()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            mappings,
+            [(0x20, 1), (0x21, 2), (0x8140, 105), (0x8141, 106)]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn reverse_map() {
+        let mut buf = BeBuffer::new();
+        buf.extend([0u16, 1]);
+        buf.extend([3u16, 10]);
+        buf.push(12u32);
+        buf.extend([13u16, 0]);
+        buf.extend([0u32, 0, 2]);
+        buf.extend([0x30u32, 0x31, 5]);
+        buf.extend([0x41u32, 0x41, 6]);
+        let cmap = Cmap::read(buf.font_data()).unwrap();
+        assert_eq!(cmap.iter_mappings().count(), 3);
+        let reverse = cmap.reverse_map();
+        assert_eq!(reverse.len(), 2);
+        assert_eq!(
+            reverse[&GlyphId::new(5)]
+                .iter()
+                .copied()
+                .collect::<Vec<_>>(),
+            [0x30, 0x31]
+        );
+        assert_eq!(
+            reverse[&GlyphId::new(6)]
+                .iter()
+                .copied()
+                .collect::<Vec<_>>(),
+            [0x41]
+        );
+    }
+
+    fn format14() -> BeBuffer {
+        let mut buf = BeBuffer::new();
+        buf.push(14u16);
+        buf.extend([0u32, 2]);
+        // selector records: U+FE00 with both tables, U+FE0F default only
+        buf.push(Uint24::new(0xFE00));
+        buf.extend([32u32, 40]);
+        buf.push(Uint24::new(0xFE0F));
+        buf.extend([32u32, 0]);
+        // default uvs at 32: 0x4E00..=0x4E02
+        buf.push(1u32);
+        buf.push(Uint24::new(0x4E00));
+        buf.push(2u8);
+        // non-default uvs at 40
+        buf.push(2u32);
+        buf.push(Uint24::new(0x4E08));
+        buf.push(17u16);
+        buf.push(Uint24::new(0x4E10));
+        buf.push(18u16);
+        buf
+    }
+
+    #[test]
+    fn map_variant() {
+        let buf = format14();
+        let subtable = Cmap14::read(buf.font_data()).unwrap();
+        let map = |c: u32, vs: u32| subtable.map_variant(c, vs);
+        assert_eq!(map(0x4E01, 0xFE00), Some(MapVariant::UseDefault));
+        assert_eq!(map(0x4E02, 0xFE0F), Some(MapVariant::UseDefault));
+        assert_eq!(
+            map(0x4E08, 0xFE00),
+            Some(MapVariant::Variant(GlyphId::new(17)))
+        );
+        assert_eq!(map(0x4E08, 0xFE0F), None);
+        assert_eq!(map(0x4E03, 0xFE00), None);
+        assert_eq!(map(0x4E00, 0xFE01), None);
+    }
+
+    #[test]
+    fn iter_variants() {
+        let buf = format14();
+        let subtable = Cmap14::read(buf.font_data()).unwrap();
+        assert_eq!(subtable.selectors().collect::<Vec<_>>(), [0xFE00, 0xFE0F]);
+        let sequences = subtable.sequences(0xFE00u32).collect::<Vec<_>>();
+        assert_eq!(
+            sequences,
+            [
+                (0x4E00, MapVariant::UseDefault),
+                (0x4E01, MapVariant::UseDefault),
+                (0x4E02, MapVariant::UseDefault),
+                (0x4E08, MapVariant::Variant(GlyphId::new(17))),
+                (0x4E10, MapVariant::Variant(GlyphId::new(18))),
+            ]
+        );
+        assert_eq!(subtable.sequences(0xFE01u32).count(), 0);
+        let all = subtable.iter_sequences().collect::<Vec<_>>();
+        assert_eq!(all.len(), 8);
+        assert_eq!(all[5], (0x4E00, 0xFE0F, MapVariant::UseDefault));
+    }
+}
@@ -0,0 +1,155 @@
+//! The [COLR](https://learn.microsoft.com/en-us/typography/opentype/spec/colr) table
+
+/// 'COLR'
+pub const TAG: Tag = Tag::new(b"COLR");
+
+include!("../../generated/generated_colr.rs");
+
+use crate::layout::{DeltaSetIndexMap, ItemVariationStore};
+
+impl<'a> Colr<'a> {
+    /// Returns the range of layer record indices for the given glyph in the
+    /// version 0 base glyph records, or `None` if the glyph has no entry.
+    pub fn v0_base_glyph(&self, glyph_id: GlyphId) -> Result<Option<Range<usize>>, ReadError> {
+        let records = match self.base_glyph_records().transpose()? {
+            Some(records) => records.base_glyph_records(),
+            None => return Ok(None),
+        };
+        let record = match records.binary_search_by_key(&glyph_id, |rec| rec.glyph_id()) {
+            Ok(ix) => &records[ix],
+            Err(_) => return Ok(None),
+        };
+        let start = record.first_layer_index() as usize;
+        Ok(Some(start..start + record.num_layers() as usize))
+    }
+
+    /// Returns the glyph id and palette index of the version 0 layer record
+    /// at the given index.
+    pub fn v0_layer(&self, index: usize) -> Result<(GlyphId, u16), ReadError> {
+        let layers = self
+            .layer_records()
+            .ok_or(ReadError::NullOffset)??
+            .layer_records();
+        let layer = layers.get(index).ok_or(ReadError::OutOfBounds)?;
+        Ok((layer.glyph_id(), layer.palette_index()))
+    }
+
+    /// Returns the root paint of the given glyph in the base glyph list, or
+    /// `None` if the table has no paint for the glyph.
+    pub fn v1_base_glyph(&self, glyph_id: GlyphId) -> Result<Option<Paint<'a>>, ReadError> {
+        let list = match self.base_glyph_list().transpose()? {
+            Some(list) => list,
+            None => return Ok(None),
+        };
+        let records = list.base_glyph_paint_records();
+        match records.binary_search_by_key(&glyph_id, |rec| rec.glyph_id()) {
+            Ok(ix) => records[ix].paint(list.offset_data()).map(Some),
+            Err(_) => Ok(None),
+        }
+    }
+
+    /// Returns the paint at the given index in the layer list.
+    pub fn v1_layer(&self, index: u32) -> Result<Paint<'a>, ReadError> {
+        let list = self.layer_list().ok_or(ReadError::NullOffset)??;
+        let offset = list
+            .paint_offsets()
+            .get(index as usize)
+            .ok_or(ReadError::OutOfBounds)?;
+        offset.get().resolve(list.offset_data())
+    }
+
+    /// Returns the clip box for the given glyph, or `None` if the glyph is
+    /// not covered by the clip list.
+    pub fn v1_clip_box(&self, glyph_id: GlyphId) -> Result<Option<ClipBox<'a>>, ReadError> {
+        let list = match self.clip_list().transpose()? {
+            Some(list) => list,
+            None => return Ok(None),
+        };
+        let clips = list.clips();
+        let ix = clips.partition_point(|clip| clip.end_glyph_id() < glyph_id);
+        match clips.get(ix) {
+            Some(clip) if clip.start_glyph_id() <= glyph_id => {
+                clip.clip_box(list.offset_data()).map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    /// A version 1 table with one v0 glyph, and one v1 glyph with a clip box
+    /// whose paint is a PaintColrLayers with a single PaintSolid layer.
+    fn test_colr() -> BeBuffer {
+        let mut buf = BeBuffer::new();
+        buf.extend([1u16, 1]);
+        // base glyph records, layer records, num layer records
+        buf.extend([34u32, 40]);
+        buf.push(2u16);
+        // base glyph list, layer list, clip list, var index map, var store
+        buf.extend([48u32, 85, 64, 0, 0]);
+        assert_eq!(buf.len(), 34);
+        // base glyph record: glyph 2 with two layers starting at 0
+        buf.extend([2u16, 0, 2]);
+        // layer records
+        buf.extend([3u16, 0, 4, 1]);
+        assert_eq!(buf.len(), 48);
+        // base glyph list, with glyph 5 pointing at a PaintColrLayers
+        buf.push(1u32);
+        buf.push(5u16);
+        buf.push(10u32);
+        buf.push(1u8);
+        buf.push(1u8);
+        buf.push(0u32);
+        assert_eq!(buf.len(), 64);
+        // clip list, covering glyphs 4 through 6
+        buf.push(1u8);
+        buf.push(1u32);
+        buf.extend([4u16, 6]);
+        buf.push(Uint24::new(12));
+        buf.push(1u8);
+        buf.extend([-10i16, -20, 30, 40]);
+        assert_eq!(buf.len(), 85);
+        // layer list with a single PaintSolid
+        buf.push(1u32);
+        buf.push(8u32);
+        buf.push(2u8);
+        buf.push(3u16);
+        buf.push(F2Dot14::from_f32(0.5));
+        buf
+    }
+
+    #[test]
+    fn lookups() {
+        let buf = test_colr();
+        let colr = Colr::read(buf.font_data()).unwrap();
+        assert_eq!(colr.v0_base_glyph(GlyphId::new(2)).unwrap(), Some(0..2));
+        assert_eq!(colr.v0_base_glyph(GlyphId::new(5)).unwrap(), None);
+        assert_eq!(colr.v0_layer(1).unwrap(), (GlyphId::new(4), 1));
+        assert!(colr.v0_layer(2).is_err());
+
+        let Some(Paint::ColrLayers(layers)) = colr.v1_base_glyph(GlyphId::new(5)).unwrap() else {
+            panic!("expected PaintColrLayers");
+        };
+        assert_eq!(layers.num_layers(), 1);
+        assert_eq!(layers.first_layer_index(), 0);
+        assert!(colr.v1_base_glyph(GlyphId::new(2)).unwrap().is_none());
+        let Paint::Solid(solid) = colr.v1_layer(0).unwrap() else {
+            panic!("expected PaintSolid");
+        };
+        assert_eq!(solid.palette_index(), 3);
+        assert_eq!(solid.alpha(), F2Dot14::from_f32(0.5));
+
+        for gid in [4, 5, 6] {
+            let Some(ClipBox::Format1(clip)) = colr.v1_clip_box(GlyphId::new(gid)).unwrap() else {
+                panic!("expected a clip box for glyph {gid}");
+            };
+            assert_eq!(clip.x_max(), FWord::new(30));
+        }
+        assert!(colr.v1_clip_box(GlyphId::new(3)).unwrap().is_none());
+        assert!(colr.v1_clip_box(GlyphId::new(7)).unwrap().is_none());
+    }
+}
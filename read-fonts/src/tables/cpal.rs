@@ -0,0 +1,115 @@
+//! The [CPAL](https://learn.microsoft.com/en-us/typography/opentype/spec/cpal) table
+
+/// 'CPAL'
+pub const TAG: Tag = Tag::new(b"CPAL");
+
+include!("../../generated/generated_cpal.rs");
+
+impl<'a> Cpal<'a> {
+    /// Returns the color records of the palette at the given index.
+    pub fn palette(&self, index: u16) -> Result<&'a [ColorRecord], ReadError> {
+        let first = self
+            .color_record_indices()
+            .get(index as usize)
+            .ok_or(ReadError::OutOfBounds)?
+            .get() as usize;
+        let records = self.color_records_array()?.color_records();
+        records
+            .get(first..first + self.num_palette_entries() as usize)
+            .ok_or(ReadError::OutOfBounds)
+    }
+
+    /// Returns the type flags of the palette at the given index.
+    ///
+    /// This is `None` if the table has no palette types array.
+    pub fn palette_type(&self, index: u16) -> Option<Result<PaletteType, ReadError>> {
+        let types = match self.palette_types_array()? {
+            Ok(types) => types.palette_types(),
+            Err(e) => return Some(Err(e)),
+        };
+        Some(
+            types
+                .get(index as usize)
+                .map(|ty| ty.get())
+                .ok_or(ReadError::OutOfBounds),
+        )
+    }
+
+    /// Returns the 'name' table ID for the label of the palette at the given
+    /// index, or `None` if no label is provided.
+    pub fn palette_label(&self, index: u16) -> Result<Option<u16>, ReadError> {
+        let labels = match self.palette_labels_array().transpose()? {
+            Some(labels) => labels.palette_labels(),
+            None => return Ok(None),
+        };
+        name_id(labels, index)
+    }
+
+    /// Returns the 'name' table ID for the label of the palette entry at the
+    /// given index, or `None` if no label is provided.
+    pub fn palette_entry_label(&self, index: u16) -> Result<Option<u16>, ReadError> {
+        let labels = match self.palette_entry_labels_array().transpose()? {
+            Some(labels) => labels.palette_entry_labels(),
+            None => return Ok(None),
+        };
+        name_id(labels, index)
+    }
+}
+
+/// Reads a name ID from a label array, where 0xFFFF means no label.
+fn name_id(labels: &[BigEndian<u16>], index: u16) -> Result<Option<u16>, ReadError> {
+    let id = labels
+        .get(index as usize)
+        .ok_or(ReadError::OutOfBounds)?
+        .get();
+    Ok((id != 0xFFFF).then_some(id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    /// A version 1 table with two palettes of two entries each.
+    fn test_cpal() -> BeBuffer {
+        let mut buf = BeBuffer::new();
+        buf.extend([1u16, 2, 2, 3]);
+        buf.push(28u32);
+        // the second palette shares its first entry with the first palette
+        buf.extend([0u16, 1]);
+        // palette types, palette labels, entry labels
+        buf.extend([40u32, 48, 0]);
+        assert_eq!(buf.len(), 28);
+        buf.extend([0xFF0000FFu32, 0x00FF00FF, 0x0000FF80]);
+        buf.extend([
+            PaletteType::USABLE_WITH_LIGHT_BACKGROUND,
+            PaletteType::USABLE_WITH_DARK_BACKGROUND,
+        ]);
+        buf.extend([256u16, 0xFFFF]);
+        buf
+    }
+
+    #[test]
+    fn palettes() {
+        let buf = test_cpal();
+        let cpal = Cpal::read(buf.font_data()).unwrap();
+        let colors = |index| {
+            cpal.palette(index)
+                .unwrap()
+                .iter()
+                .map(|rec| (rec.red(), rec.green(), rec.blue(), rec.alpha()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(colors(0), [(0, 0, 255, 255), (0, 255, 0, 255)]);
+        assert_eq!(colors(1), [(0, 255, 0, 255), (255, 0, 0, 128)]);
+        assert!(cpal.palette(2).is_err());
+
+        assert_eq!(
+            cpal.palette_type(1).unwrap().unwrap(),
+            PaletteType::USABLE_WITH_DARK_BACKGROUND
+        );
+        assert_eq!(cpal.palette_label(0).unwrap(), Some(256));
+        assert_eq!(cpal.palette_label(1).unwrap(), None);
+        assert_eq!(cpal.palette_entry_label(0).unwrap(), None);
+    }
+}
@@ -0,0 +1,22 @@
+//! The [cvt (Control Value Table)](https://learn.microsoft.com/en-us/typography/opentype/spec/cvt) table
+
+/// 'cvt '
+pub const TAG: Tag = Tag::new(b"cvt");
+
+include!("../../generated/generated_cvt.rs");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    #[test]
+    fn values() {
+        let mut buf = BeBuffer::new();
+        buf.extend([FWord::new(0), FWord::new(-12), FWord::new(1400)]);
+        let cvt = Cvt::read(buf.font_data()).unwrap();
+        let values = cvt.values().iter().map(|v| v.get()).collect::<Vec<_>>();
+        assert_eq!(values, [FWord::new(0), FWord::new(-12), FWord::new(1400)]);
+        assert_eq!(TAG.to_raw(), *b"cvt ");
+    }
+}
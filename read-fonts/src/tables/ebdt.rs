@@ -0,0 +1,6 @@
+//! The [EBDT](https://learn.microsoft.com/en-us/typography/opentype/spec/ebdt) table
+
+/// 'EBDT'
+pub const TAG: Tag = Tag::new(b"EBDT");
+
+include!("../../generated/generated_ebdt.rs");
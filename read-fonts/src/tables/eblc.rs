@@ -0,0 +1,8 @@
+//! The [EBLC](https://learn.microsoft.com/en-us/typography/opentype/spec/eblc) table
+
+/// 'EBLC'
+pub const TAG: Tag = Tag::new(b"EBLC");
+
+include!("../../generated/generated_eblc.rs");
+
+use crate::bitmap::BitmapSize;
@@ -0,0 +1,6 @@
+//! The [fpgm (Font Program)](https://learn.microsoft.com/en-us/typography/opentype/spec/fpgm) table
+
+/// 'fpgm'
+pub const TAG: Tag = Tag::new(b"fpgm");
+
+include!("../../generated/generated_fpgm.rs");
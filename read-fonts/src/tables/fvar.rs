@@ -0,0 +1,382 @@
+//! The [fvar (Font Variations)](https://docs.microsoft.com/en-us/typography/opentype/spec/fvar) table
+
+/// 'fvar'
+pub const TAG: Tag = Tag::new(b"fvar");
+
+include!("../../generated/generated_fvar.rs");
+
+use super::avar::Avar;
+use crate::layout::{DeltaSetIndex, DeltaSetIndexMap, ItemVariationStore};
+
+impl<'a> Fvar<'a> {
+    /// Returns the array of variation axis records.
+    pub fn axes(&self) -> Result<&'a [VariationAxisRecord], ReadError> {
+        Ok(self.axis_instance_arrays()?.axes())
+    }
+
+    /// Returns the array of instance records.
+    pub fn instances(&self) -> Result<ComputedArray<'a, InstanceRecord<'a>>, ReadError> {
+        Ok(self.axis_instance_arrays()?.instances())
+    }
+
+    /// Converts user space coordinates to normalized coordinates.
+    ///
+    /// Each item in `user_coords` is an axis tag and a value in user space.
+    /// Axes that are not specified are set to their default value, and
+    /// values for unknown axes are ignored.
+    ///
+    /// The resulting coordinates are written to `normalized` in the order of
+    /// the axes in this table; this should have a length of
+    /// [`axis_count`](Self::axis_count). If an `avar` table is provided, its
+    /// segment maps are applied after default normalization, followed by
+    /// the variation deltas of an `avar` version 2 table, if present.
+    ///
+    /// Applying `avar` version 2 deltas is supported for fonts with up to
+    /// 64 axes; an error is returned for fonts with more axes.
+    pub fn user_to_normalized(
+        &self,
+        avar: Option<&Avar>,
+        user_coords: impl IntoIterator<Item = (Tag, Fixed)>,
+        normalized: &mut [F2Dot14],
+    ) -> Result<(), ReadError> {
+        let axes = self.axes()?;
+        normalized.fill(F2Dot14::ZERO);
+        for (tag, value) in user_coords {
+            for (axis, coord) in axes.iter().zip(normalized.iter_mut()) {
+                if axis.axis_tag() == tag {
+                    *coord = axis.normalize(value).to_f2dot14();
+                }
+            }
+        }
+        if let Some(avar) = avar {
+            for (maps, coord) in avar.axis_segment_maps().iter().zip(normalized.iter_mut()) {
+                *coord = maps?.apply(coord.to_fixed()).to_f2dot14();
+            }
+            if let Some(var_store) = avar.var_store().transpose()? {
+                let index_map = avar.axis_index_map().transpose()?;
+                apply_avar2_deltas(&var_store, index_map.as_ref(), normalized)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The maximum number of axes for which we apply avar version 2 deltas.
+///
+/// The deltas are computed from the coordinates before any of them are
+/// modified, so we need a copy; this lets us avoid allocating.
+const MAX_AVAR2_AXES: usize = 64;
+
+fn apply_avar2_deltas(
+    var_store: &ItemVariationStore,
+    index_map: Option<&DeltaSetIndexMap>,
+    coords: &mut [F2Dot14],
+) -> Result<(), ReadError> {
+    let mut original = [F2Dot14::ZERO; MAX_AVAR2_AXES];
+    let original = original
+        .get_mut(..coords.len())
+        .ok_or(ReadError::OutOfBounds)?;
+    original.copy_from_slice(coords);
+    for (i, coord) in coords.iter_mut().enumerate() {
+        let index = match index_map {
+            Some(map) => map.get(i as u32)?,
+            None => DeltaSetIndex {
+                outer: 0,
+                inner: i as u16,
+            },
+        };
+        let delta = var_store.compute_delta(index.outer, index.inner, original)?;
+        // the delta is in F2Dot14 units
+        let delta_bits = delta.round().to_bits() >> 16;
+        let bits = (coord.to_bits() as i32 + delta_bits).clamp(-(1 << 14), 1 << 14);
+        *coord = F2Dot14::from_bits(bits as i16);
+    }
+    Ok(())
+}
+
+impl VariationAxisRecord {
+    /// If set, this axis should not be exposed directly in user interfaces.
+    pub const HIDDEN_AXIS: u16 = 0x0001;
+
+    /// Returns `true` if the `HIDDEN_AXIS` flag is set.
+    pub fn is_hidden(&self) -> bool {
+        self.flags() & Self::HIDDEN_AXIS != 0
+    }
+
+    /// Returns the normalized value of the given user space coordinate,
+    /// using the default normalization.
+    ///
+    /// The value is clamped to the range of the axis, and the result is in
+    /// the range [-1, 1].
+    pub fn normalize(&self, coord: Fixed) -> Fixed {
+        let default = self.default_value();
+        // be defensive against axes where the default is out of range
+        let min = self.min_value().min(default);
+        let max = self.max_value().max(default);
+        let coord = coord.clamp(min, max);
+        match coord.cmp(&default) {
+            std::cmp::Ordering::Less => {
+                -(default.saturating_sub(coord) / default.saturating_sub(min))
+            }
+            std::cmp::Ordering::Greater => {
+                coord.saturating_sub(default) / max.saturating_sub(default)
+            }
+            std::cmp::Ordering::Equal => Fixed::ZERO,
+        }
+    }
+}
+
+/// The [InstanceRecord](https://docs.microsoft.com/en-us/typography/opentype/spec/fvar#instancerecord)
+///
+/// NOTE: this is handwritten, because the size of the coordinate array depends
+/// on the axis count, and the postScriptNameID field is only present if it
+/// fits in the instance size.
+#[derive(Clone, Debug)]
+pub struct InstanceRecord<'a> {
+    /// The name ID for entries in the 'name' table that provide subfamily
+    /// names for this instance.
+    pub subfamily_name_id: BigEndian<u16>,
+    /// Reserved for future use — set to 0.
+    pub flags: BigEndian<u16>,
+    /// The coordinates array for this instance.
+    pub coordinates: &'a [BigEndian<Fixed>],
+    /// Optional. The name ID for entries in the 'name' table that provide
+    /// PostScript names for this instance.
+    pub post_script_name_id: Option<BigEndian<u16>>,
+}
+
+impl<'a> InstanceRecord<'a> {
+    /// The name ID for entries in the 'name' table that provide subfamily
+    /// names for this instance.
+    pub fn subfamily_name_id(&self) -> u16 {
+        self.subfamily_name_id.get()
+    }
+
+    /// Reserved for future use — set to 0.
+    pub fn flags(&self) -> u16 {
+        self.flags.get()
+    }
+
+    /// The coordinates array for this instance, in user space.
+    pub fn coordinates(&self) -> &'a [BigEndian<Fixed>] {
+        self.coordinates
+    }
+
+    /// The name ID for entries in the 'name' table that provide PostScript
+    /// names for this instance, if present.
+    pub fn post_script_name_id(&self) -> Option<u16> {
+        self.post_script_name_id.map(|id| id.get())
+    }
+}
+
+impl ReadArgs for InstanceRecord<'_> {
+    type Args = (u16, u16);
+}
+
+impl ComputeSize for InstanceRecord<'_> {
+    fn compute_size(args: &(u16, u16)) -> usize {
+        let (_axis_count, instance_size) = *args;
+        instance_size as usize
+    }
+}
+
+impl<'a> FontReadWithArgs<'a> for InstanceRecord<'a> {
+    fn read_with_args(data: FontData<'a>, args: &(u16, u16)) -> Result<Self, ReadError> {
+        let (axis_count, instance_size) = *args;
+        let mut cursor = data.cursor();
+        let subfamily_name_id = cursor.read()?;
+        let flags = cursor.read()?;
+        let coordinates = cursor.read_array(axis_count as usize)?;
+        // the optional field is present if the record has room for it
+        let has_post_script_name_id = instance_size as usize
+            == axis_count as usize * Fixed::RAW_BYTE_LEN + 3 * u16::RAW_BYTE_LEN;
+        let post_script_name_id = has_post_script_name_id.then(|| cursor.read()).transpose()?;
+        Ok(InstanceRecord {
+            subfamily_name_id,
+            flags,
+            coordinates,
+            post_script_name_id,
+        })
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeRecord<'a> for InstanceRecord<'a> {
+    fn traverse(self, data: FontData<'a>) -> RecordResolver<'a> {
+        RecordResolver {
+            name: "InstanceRecord",
+            get_field: Box::new(move |idx, _data| match idx {
+                0usize => Some(Field::new("subfamily_name_id", self.subfamily_name_id())),
+                1usize => Some(Field::new("flags", self.flags())),
+                2usize => Some(Field::new("coordinates", self.coordinates())),
+                3usize if self.post_script_name_id.is_some() => Some(Field::new(
+                    "post_script_name_id",
+                    self.post_script_name_id().unwrap(),
+                )),
+                _ => None,
+            }),
+            data,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    fn make_fvar(instance_size: u16, with_ps_names: bool) -> BeBuffer {
+        let mut buf = BeBuffer::new();
+        buf.extend([1u16, 0]); // version
+        buf.extend([16u16, 2]); // axis array offset, reserved
+        buf.extend([2u16, 20, 2, instance_size]);
+        // axes
+        buf.push(Tag::new(b"wght"));
+        buf.extend([100.0, 400.0, 900.0].map(Fixed::from_f64));
+        buf.extend([0u16, 256]);
+        buf.push(Tag::new(b"wdth"));
+        buf.extend([50.0, 100.0, 200.0].map(Fixed::from_f64));
+        buf.extend([1u16, 257]);
+        // instances
+        for (name_id, wght, wdth) in [(258u16, 400.0, 100.0), (259, 700.0, 75.0)] {
+            buf.extend([name_id, 0]);
+            buf.extend([wght, wdth].map(Fixed::from_f64));
+            if with_ps_names {
+                buf.push(name_id + 10);
+            }
+        }
+        buf
+    }
+
+    #[test]
+    fn read_axes() {
+        let buf = make_fvar(12, false);
+        let fvar = Fvar::read(buf.font_data()).unwrap();
+        assert_eq!(fvar.version(), MajorMinor::VERSION_1_0);
+        assert_eq!(fvar.axis_count(), 2);
+        let axes = fvar.axes().unwrap();
+        assert_eq!(axes.len(), 2);
+        assert_eq!(axes[0].axis_tag(), Tag::new(b"wght"));
+        assert_eq!(axes[0].min_value(), Fixed::from_f64(100.0));
+        assert_eq!(axes[0].default_value(), Fixed::from_f64(400.0));
+        assert_eq!(axes[0].max_value(), Fixed::from_f64(900.0));
+        assert_eq!(axes[0].axis_name_id(), 256);
+        assert!(!axes[0].is_hidden());
+        assert_eq!(axes[1].axis_tag(), Tag::new(b"wdth"));
+        assert!(axes[1].is_hidden());
+    }
+
+    #[test]
+    fn read_instances() {
+        let buf = make_fvar(12, false);
+        let fvar = Fvar::read(buf.font_data()).unwrap();
+        let instances = fvar.instances().unwrap();
+        assert_eq!(instances.len(), 2);
+        let bold = instances.get(1).unwrap();
+        assert_eq!(bold.subfamily_name_id(), 259);
+        let coords = bold
+            .coordinates()
+            .iter()
+            .map(|coord| coord.get())
+            .collect::<Vec<_>>();
+        assert_eq!(coords, [Fixed::from_f64(700.0), Fixed::from_f64(75.0)]);
+        assert_eq!(bold.post_script_name_id(), None);
+    }
+
+    #[test]
+    fn read_instances_with_ps_names() {
+        let buf = make_fvar(14, true);
+        let fvar = Fvar::read(buf.font_data()).unwrap();
+        let ps_names = fvar
+            .instances()
+            .unwrap()
+            .iter()
+            .map(|inst| inst.unwrap().post_script_name_id())
+            .collect::<Vec<_>>();
+        assert_eq!(ps_names, [Some(268), Some(269)]);
+    }
+
+    #[test]
+    fn normalize_axis() {
+        let buf = make_fvar(12, false);
+        let fvar = Fvar::read(buf.font_data()).unwrap();
+        let wght = &fvar.axes().unwrap()[0];
+        let normalize = |val: f64| wght.normalize(Fixed::from_f64(val)).to_f64();
+        assert_eq!(normalize(100.0), -1.0);
+        assert_eq!(normalize(250.0), -0.5);
+        assert_eq!(normalize(400.0), 0.0);
+        assert_eq!(normalize(650.0), 0.5);
+        assert_eq!(normalize(900.0), 1.0);
+        // clamped
+        assert_eq!(normalize(0.0), -1.0);
+        assert_eq!(normalize(1000.0), 1.0);
+    }
+
+    #[test]
+    fn user_to_normalized() {
+        let buf = make_fvar(12, false);
+        let fvar = Fvar::read(buf.font_data()).unwrap();
+        let mut coords = [F2Dot14::from_f32(0.5); 2];
+        fvar.user_to_normalized(
+            None,
+            [(Tag::new(b"wdth"), Fixed::from_f64(150.0))],
+            &mut coords,
+        )
+        .unwrap();
+        assert_eq!(coords, [F2Dot14::ZERO, F2Dot14::from_f32(0.5)]);
+    }
+
+    #[test]
+    fn user_to_normalized_with_avar() {
+        let buf = make_fvar(12, false);
+        let fvar = Fvar::read(buf.font_data()).unwrap();
+        let mut avar_buf = BeBuffer::new();
+        avar_buf.extend([1u16, 0, 0, 2]);
+        avar_buf.push(3u16);
+        avar_buf.extend([-1.0, -1.0, 0.0, 0.0, 1.0, 1.0].map(F2Dot14::from_f32));
+        avar_buf.push(4u16);
+        avar_buf.extend([-1.0, -1.0, 0.0, 0.0, 0.5, 0.25, 1.0, 1.0].map(F2Dot14::from_f32));
+        let avar = Avar::read(avar_buf.font_data()).unwrap();
+        let mut coords = [F2Dot14::ZERO; 2];
+        fvar.user_to_normalized(
+            Some(&avar),
+            [
+                (Tag::new(b"wght"), Fixed::from_f64(650.0)),
+                (Tag::new(b"wdth"), Fixed::from_f64(150.0)),
+            ],
+            &mut coords,
+        )
+        .unwrap();
+        assert_eq!(coords, [F2Dot14::from_f32(0.5), F2Dot14::from_f32(0.25)]);
+    }
+
+    #[test]
+    fn user_to_normalized_with_avar2() {
+        let buf = make_fvar(12, false);
+        let fvar = Fvar::read(buf.font_data()).unwrap();
+        let mut avar_buf = BeBuffer::new();
+        avar_buf.extend([2u16, 0, 0, 2]);
+        // empty segment maps
+        avar_buf.extend([0u16, 0]);
+        // no axis index map, var store at offset 20
+        avar_buf.extend([0u32, 20]);
+        // item variation store with a single region, active when wght > 0
+        avar_buf.push(1u16);
+        avar_buf.push(12u32);
+        avar_buf.push(1u16);
+        avar_buf.push(28u32);
+        avar_buf.extend([2u16, 1]);
+        avar_buf.extend([0.0, 1.0, 1.0, 0.0, 0.0, 0.0].map(F2Dot14::from_f32));
+        avar_buf.extend([2u16, 0, 1, 0]);
+        avar_buf.extend([0i8, 64]);
+        let avar = Avar::read(avar_buf.font_data()).unwrap();
+        let mut coords = [F2Dot14::ZERO; 2];
+        fvar.user_to_normalized(
+            Some(&avar),
+            [(Tag::new(b"wght"), Fixed::from_f64(650.0))],
+            &mut coords,
+        )
+        .unwrap();
+        assert_eq!(coords, [F2Dot14::from_f32(0.5), F2Dot14::from_bits(32)]);
+    }
+}
@@ -0,0 +1,44 @@
+//! The [gasp (Grid-fitting and Scan-conversion Procedure)](https://learn.microsoft.com/en-us/typography/opentype/spec/gasp) table
+
+/// 'gasp'
+pub const TAG: Tag = Tag::new(b"gasp");
+
+include!("../../generated/generated_gasp.rs");
+
+impl<'a> Gasp<'a> {
+    /// Returns the rasterizer behavior requested for the given size.
+    ///
+    /// This is the behavior of the first range whose maximum is at least
+    /// `ppem`; sizes beyond the last range have no flags set.
+    pub fn gasp_behavior(&self, ppem: u16) -> GaspRangeBehavior {
+        self.gasp_ranges()
+            .iter()
+            .find(|range| range.range_max_ppem() >= ppem)
+            .map(|range| range.range_gasp_behavior())
+            .unwrap_or_else(GaspRangeBehavior::empty)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    #[test]
+    fn behavior() {
+        let mut buf = BeBuffer::new();
+        buf.extend([1u16, 3]);
+        buf.extend([8u16, 0x2]);
+        buf.extend([16u16, 0x5]);
+        buf.extend([20u16, 0xF]);
+        let gasp = Gasp::read(buf.font_data()).unwrap();
+        assert_eq!(gasp.gasp_behavior(0), GaspRangeBehavior::GASP_DOGRAY);
+        assert_eq!(gasp.gasp_behavior(8), GaspRangeBehavior::GASP_DOGRAY);
+        assert_eq!(
+            gasp.gasp_behavior(9),
+            GaspRangeBehavior::GASP_GRIDFIT | GaspRangeBehavior::GASP_SYMMETRIC_GRIDFIT
+        );
+        assert_eq!(gasp.gasp_behavior(20), GaspRangeBehavior::all());
+        assert_eq!(gasp.gasp_behavior(21), GaspRangeBehavior::empty());
+    }
+}
@@ -33,6 +33,40 @@ impl<'a> Glyph<'a> {
 // the interim?
 
 impl<'a> SimpleGlyph<'a> {
+    /// The total number of points in this glyph's outline.
+    pub fn num_points(&self) -> usize {
+        self.end_pts_of_contours()
+            .last()
+            .map(|last| last.get() as usize + 1)
+            .unwrap_or(0)
+    }
+
+    /// Reads the points and flags of this glyph's outline into the
+    /// provided buffers.
+    ///
+    /// Both buffers must have a length equal to [`num_points`](Self::num_points).
+    pub fn read_points(
+        &self,
+        points: &mut [font_types::Point<i32>],
+        flags: &mut [SimpleGlyphFlags],
+    ) -> Result<(), ReadError> {
+        let n_points = self.num_points();
+        if points.len() != n_points || flags.len() != n_points {
+            return Err(ReadError::InvalidArrayLen);
+        }
+        if n_points == 0 {
+            return Ok(());
+        }
+        let mut iter = self.iter_points_impl().ok_or(ReadError::OutOfBounds)?;
+        for (point, flag) in points.iter_mut().zip(flags) {
+            iter.advance_flags();
+            iter.advance_points();
+            *point = font_types::Point::new(iter.cur_x as i32, iter.cur_y as i32);
+            *flag = iter.cur_flags;
+        }
+        Ok(())
+    }
+
     pub fn iter_points(&self) -> PointIter<'_> {
         self.iter_points_impl()
             .unwrap_or_else(|| PointIter::new(&[], &[], &[], &[]))
@@ -74,7 +108,7 @@ pub struct PointIter<'a> {
     flags: OldCursor<'a>,
     x_coords: OldCursor<'a>,
     y_coords: OldCursor<'a>,
-    flag_repeats: u8,
+    flag_repeats: u16,
     cur_flags: SimpleGlyphFlags,
     cur_x: i16,
     cur_y: i16,
@@ -131,11 +165,13 @@ impl<'a> PointIter<'a> {
         if self.flag_repeats == 0 {
             self.cur_flags =
                 SimpleGlyphFlags::from_bits_truncate(self.flags.bump().unwrap_or_default());
+            // the repeat count is the number of *additional* uses of the flag
             self.flag_repeats = self
                 .cur_flags
                 .contains(SimpleGlyphFlags::REPEAT_FLAG)
-                .then(|| self.flags.bump())
+                .then(|| self.flags.bump::<u8>())
                 .flatten()
+                .map(|repeats| repeats as u16 + 1)
                 .unwrap_or(1);
         }
         self.flag_repeats -= 1;
@@ -221,10 +257,10 @@ fn resolve_coords_len(data: &[u8], points_total: u16) -> Option<FieldLengths> {
         let y_long = SimpleGlyphFlags::Y_SHORT_VECTOR
             | SimpleGlyphFlags::Y_IS_SAME_OR_POSITIVE_Y_SHORT_VECTOR;
         x_coords_len += ((flags & x_short).bits() != 0) as u32 * repeats;
-        x_coords_len += ((flags & x_long).bits() == 0) as u32 * repeats;
+        x_coords_len += ((flags & x_long).bits() == 0) as u32 * repeats * 2;
 
         y_coords_len += ((flags & y_short).bits() != 0) as u32 * repeats;
-        y_coords_len += ((flags & y_long).bits() == 0) as u32 * repeats;
+        y_coords_len += ((flags & y_long).bits() == 0) as u32 * repeats * 2;
 
         flags_left -= repeats;
     }
@@ -256,7 +292,7 @@ impl<'a> OldCursor<'a> {
 
     /// Attempt to read `T` at the current location, advancing if successful.
     fn bump<T: ReadScalar>(&mut self) -> Option<T> {
-        let r = T::read(self.data.get(self.pos..)?);
+        let r = T::read(self.data.get(self.pos..self.pos + T::RAW_BYTE_LEN)?);
         self.pos += T::RAW_BYTE_LEN;
         r
     }
@@ -267,15 +303,41 @@ pub struct Component {
     pub flags: CompositeGlyphFlags,
     pub glyph: GlyphId,
     pub pos: ComponentPos,
-    //TODO: transforms
+    pub transform: Transform,
 }
 
 #[derive(Debug, Clone, Copy)]
 pub enum ComponentPos {
+    /// An offset to add to the component's points.
     Offset { x: i16, y: i16 },
+    /// Anchor points used to align the component: the point numbered `base`
+    /// in the composite glyph (so far) is aligned with the point numbered
+    /// `component` in the component glyph.
     Point { base: u16, component: u16 },
 }
 
+/// A 2x2 transformation matrix applied to the points of a component.
+///
+/// A point `(x, y)` is transformed to `(xx * x + xy * y, yx * x + yy * y)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Transform {
+    pub xx: F2Dot14,
+    pub yx: F2Dot14,
+    pub xy: F2Dot14,
+    pub yy: F2Dot14,
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self {
+            xx: F2Dot14::ONE,
+            yx: F2Dot14::ZERO,
+            xy: F2Dot14::ZERO,
+            yy: F2Dot14::ONE,
+        }
+    }
+}
+
 impl<'a> CompositeGlyph<'a> {
     pub fn iter_components(&self) -> ComponentIter<'a> {
         ComponentIter {
@@ -283,6 +345,23 @@ impl<'a> CompositeGlyph<'a> {
             cursor: FontData::new(self.component_data()).cursor(),
         }
     }
+
+    /// Returns the TrueType instructions that follow the last component,
+    /// or `None` if the glyph has no instructions.
+    pub fn instructions(&self) -> Option<&'a [u8]> {
+        let mut components = self.iter_components();
+        let mut have_instructions = false;
+        for component in components.by_ref() {
+            have_instructions |= component
+                .flags
+                .contains(CompositeGlyphFlags::WE_HAVE_INSTRUCTIONS);
+        }
+        if !components.done || !have_instructions {
+            return None;
+        }
+        let len = components.cursor.read::<u16>().ok()?;
+        components.cursor.read_array(len as usize).ok()
+    }
 }
 
 pub struct ComponentIter<'a> {
@@ -300,15 +379,15 @@ impl Iterator for ComponentIter<'_> {
         let flags: CompositeGlyphFlags = self.cursor.read().ok()?;
         let glyph = self.cursor.read::<GlyphId>().ok()?;
         let args_are_word = flags.contains(CompositeGlyphFlags::ARG_1_AND_2_ARE_WORDS);
-        let are_signed = flags.contains(CompositeGlyphFlags::ARG_1_AND_2_ARE_WORDS);
+        let are_signed = flags.contains(CompositeGlyphFlags::ARGS_ARE_XY_VALUES);
         let pos = match (are_signed, args_are_word) {
             (true, true) => ComponentPos::Offset {
                 x: self.cursor.read().ok()?,
                 y: self.cursor.read().ok()?,
             },
             (true, false) => ComponentPos::Offset {
-                x: self.cursor.read::<u8>().ok()? as _,
-                y: self.cursor.read::<u8>().ok()? as _,
+                x: self.cursor.read::<i8>().ok()? as _,
+                y: self.cursor.read::<i8>().ok()? as _,
             },
             (false, true) => ComponentPos::Point {
                 base: self.cursor.read().ok()?,
@@ -320,19 +399,27 @@ impl Iterator for ComponentIter<'_> {
             },
         };
 
-        let bytes_to_skip = if flags.contains(CompositeGlyphFlags::WE_HAVE_A_SCALE) {
-            2
+        let mut transform = Transform::default();
+        if flags.contains(CompositeGlyphFlags::WE_HAVE_A_SCALE) {
+            transform.xx = self.cursor.read().ok()?;
+            transform.yy = transform.xx;
         } else if flags.contains(CompositeGlyphFlags::WE_HAVE_AN_X_AND_Y_SCALE) {
-            4
+            transform.xx = self.cursor.read().ok()?;
+            transform.yy = self.cursor.read().ok()?;
         } else if flags.contains(CompositeGlyphFlags::WE_HAVE_A_TWO_BY_TWO) {
-            8
-        } else {
-            0
-        };
-        self.cursor.advance_by(bytes_to_skip);
+            transform.xx = self.cursor.read().ok()?;
+            transform.yx = self.cursor.read().ok()?;
+            transform.xy = self.cursor.read().ok()?;
+            transform.yy = self.cursor.read().ok()?;
+        }
         self.done = !flags.contains(CompositeGlyphFlags::MORE_COMPONENTS);
 
-        Some(Component { flags, glyph, pos })
+        Some(Component {
+            flags,
+            glyph,
+            pos,
+            transform,
+        })
     }
 }
 
@@ -354,6 +441,10 @@ impl<'a> SomeTable<'a> for Component {
                 ComponentPos::Point { component, .. } => Some(Field::new("component", component)),
                 ComponentPos::Offset { y, .. } => Some(Field::new("y", y)),
             },
+            4 => Some(Field::new("xx", self.transform.xx)),
+            5 => Some(Field::new("yx", self.transform.yx)),
+            6 => Some(Field::new("xy", self.transform.xy)),
+            7 => Some(Field::new("yy", self.transform.yy)),
             _ => None,
         }
     }
@@ -372,3 +463,89 @@ impl<'a> SomeTable<'a> for Component {
 //}),
 //))
 //}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    #[test]
+    fn read_points_with_repeats_and_short_vectors() {
+        let mut buf = BeBuffer::new();
+        buf.extend([2i16, 0, 0, 0, 0]);
+        buf.extend([2u16, 3]);
+        // instruction length
+        buf.push(0u16);
+        // point 0: on curve, x short positive, y long
+        // points 1 & 2: off curve, x same, y short negative (repeated)
+        // point 3: on curve, x long, y same
+        buf.extend([0x13u8, 0x18 | 0x04, 1, 0x21]);
+        // x coordinates
+        buf.push(10u8);
+        buf.push(-300i16);
+        // y coordinates
+        buf.push(500i16);
+        buf.extend([5u8, 7]);
+
+        let glyph = SimpleGlyph::read(buf.font_data()).unwrap();
+        assert_eq!(glyph.num_points(), 4);
+        let mut points = [font_types::Point::default(); 4];
+        let mut flags = [SimpleGlyphFlags::empty(); 4];
+        glyph.read_points(&mut points, &mut flags).unwrap();
+        let points = points.map(|point| (point.x, point.y));
+        assert_eq!(points, [(10, 500), (10, 495), (10, 488), (-290, 488)]);
+        let on_curve = flags.map(|flags| flags.contains(SimpleGlyphFlags::ON_CURVE_POINT));
+        assert_eq!(on_curve, [true, false, false, true]);
+        assert_eq!(glyph.iter_points().count(), 4);
+    }
+
+    #[test]
+    fn component_transforms() {
+        let mut buf = BeBuffer::new();
+        buf.extend([-1i16, 0, 0, 0, 0]);
+        // ARG_1_AND_2_ARE_WORDS | ARGS_ARE_XY_VALUES | WE_HAVE_AN_X_AND_Y_SCALE
+        // | MORE_COMPONENTS
+        buf.extend([0x0063u16, 4]);
+        buf.extend([-1000i16, 20]);
+        buf.extend([0x2000u16, 0x6000]);
+        // point numbers as bytes
+        buf.extend([0u16, 5]);
+        buf.extend([3u8, 200]);
+
+        let glyph = CompositeGlyph::read(buf.font_data()).unwrap();
+        let components = glyph.iter_components().collect::<Vec<_>>();
+        assert_eq!(components.len(), 2);
+        assert!(matches!(
+            components[0].pos,
+            ComponentPos::Offset { x: -1000, y: 20 }
+        ));
+        assert_eq!(components[0].transform.xx, F2Dot14::from_f32(0.5));
+        assert_eq!(components[0].transform.yy, F2Dot14::from_f32(1.5));
+        assert_eq!(components[0].transform.xy, F2Dot14::ZERO);
+        assert_eq!(components[1].glyph, GlyphId::new(5));
+        assert!(matches!(
+            components[1].pos,
+            ComponentPos::Point {
+                base: 3,
+                component: 200
+            }
+        ));
+        assert_eq!(components[1].transform, Transform::default());
+        assert!(glyph.instructions().is_none());
+    }
+
+    #[test]
+    fn composite_instructions() {
+        let mut buf = BeBuffer::new();
+        buf.extend([-1i16, 0, 0, 0, 0]);
+        // ARGS_ARE_XY_VALUES | WE_HAVE_INSTRUCTIONS
+        buf.extend([0x0102u16, 4]);
+        buf.extend([1u8, 2]);
+        buf.push(3u16);
+        buf.extend([0xB0u8, 5, 0x2B]);
+
+        let glyph = CompositeGlyph::read(buf.font_data()).unwrap();
+        assert_eq!(glyph.iter_components().count(), 1);
+        assert_eq!(glyph.instructions(), Some([0xB0, 5, 0x2B].as_slice()));
+    }
+}
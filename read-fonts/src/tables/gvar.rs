@@ -0,0 +1,821 @@
+//! The [gvar (Glyph Variations)](https://learn.microsoft.com/en-us/typography/opentype/spec/gvar) table
+
+/// 'gvar'
+pub const TAG: Tag = Tag::new(b"gvar");
+
+include!("../../generated/generated_gvar.rs");
+
+use crate::layout::region_axis_scalar;
+
+fn offsets_byte_len(glyph_count: u16, flags: GvarFlags) -> usize {
+    let offset_len = if flags.contains(GvarFlags::LONG_OFFSETS) {
+        u32::RAW_BYTE_LEN
+    } else {
+        u16::RAW_BYTE_LEN
+    };
+    (glyph_count as usize + 1) * offset_len
+}
+
+impl<'a> Gvar<'a> {
+    /// Returns the byte offset of the glyph variation data for the glyph at
+    /// `idx`, relative to the start of the glyph variation data array.
+    fn glyph_variation_data_offset(&self, idx: usize) -> Result<usize, ReadError> {
+        let data = FontData::new(self.glyph_variation_data_offsets());
+        if self.flags().contains(GvarFlags::LONG_OFFSETS) {
+            data.read_at::<u32>(idx * u32::RAW_BYTE_LEN)
+                .map(|off| off as usize)
+        } else {
+            data.read_at::<u16>(idx * u16::RAW_BYTE_LEN)
+                .map(|off| off as usize * 2)
+        }
+    }
+
+    /// Returns the variation data for the given glyph, or `None` if the
+    /// glyph has no variations.
+    pub fn glyph_variation_data(
+        &self,
+        gid: GlyphId,
+    ) -> Result<Option<GlyphVariationData<'a>>, ReadError> {
+        let idx = gid.to_u16() as usize;
+        let start = self.glyph_variation_data_offset(idx)?;
+        let end = self.glyph_variation_data_offset(idx + 1)?;
+        if start >= end {
+            return Ok(None);
+        }
+        let base = self.glyph_variation_data_array_offset() as usize;
+        let data = self
+            .data
+            .slice(base + start..base + end)
+            .ok_or(ReadError::OutOfBounds)?;
+        GlyphVariationData::new(data, self.axis_count(), self.shared_tuples()?).map(Some)
+    }
+
+    /// Computes the variation deltas for each point of the given glyph at
+    /// the given normalized coordinates, adding them to `deltas`.
+    ///
+    /// The `deltas` slice should contain one element for each point in the
+    /// glyph outline, followed by the four phantom points.
+    ///
+    /// Tuple variations that only provide explicit deltas for a subset of
+    /// points contribute only to those points; inferring the deltas for the
+    /// remaining points requires the glyph outline.
+    pub fn glyph_deltas(
+        &self,
+        gid: GlyphId,
+        coords: &[F2Dot14],
+        deltas: &mut [Point<Fixed>],
+    ) -> Result<(), ReadError> {
+        let Some(var_data) = self.glyph_variation_data(gid)? else {
+            return Ok(());
+        };
+        for (scalar, tuple) in var_data.active_tuples(coords) {
+            for delta in tuple.deltas() {
+                if let Some(target) = deltas.get_mut(delta.position as usize) {
+                    accumulate(target, delta.apply_scalar(scalar));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Computes the variation deltas for the four phantom points of the
+    /// given glyph at the given normalized coordinates.
+    ///
+    /// `point_count` is the number of points in the glyph outline (or the
+    /// number of components, for a composite glyph); the phantom points
+    /// follow these.
+    ///
+    /// The phantom points are not part of any contour, so their deltas are
+    /// never inferred and the explicit deltas are sufficient.
+    pub fn phantom_point_deltas(
+        &self,
+        gid: GlyphId,
+        coords: &[F2Dot14],
+        point_count: u16,
+    ) -> Result<[Point<Fixed>; 4], ReadError> {
+        let mut deltas = [Point::default(); 4];
+        let Some(var_data) = self.glyph_variation_data(gid)? else {
+            return Ok(deltas);
+        };
+        for (scalar, tuple) in var_data.active_tuples(coords) {
+            for delta in tuple.deltas() {
+                let Some(idx) = delta.position.checked_sub(point_count) else {
+                    continue;
+                };
+                if let Some(target) = deltas.get_mut(idx as usize) {
+                    accumulate(target, delta.apply_scalar(scalar));
+                }
+            }
+        }
+        Ok(deltas)
+    }
+}
+
+/// Adds a scaled delta to an accumulated delta, wrapping on overflow.
+fn accumulate(target: &mut Point<Fixed>, delta: Point<Fixed>) {
+    target.x = target.x.wrapping_add(delta.x);
+    target.y = target.y.wrapping_add(delta.y);
+}
+
+/// Flags and index packed into the `tupleVariationCount` field of the
+/// glyph variation data header.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct TupleVariationCount(u16);
+
+impl TupleVariationCount {
+    /// Flag indicating that some or all tuple variation tables reference a
+    /// shared set of “point” numbers.
+    const SHARED_POINT_NUMBERS: u16 = 0x8000;
+    /// Mask for the low 12 bits to give the count value.
+    const COUNT_MASK: u16 = 0x0FFF;
+
+    fn has_shared_point_numbers(self) -> bool {
+        self.0 & Self::SHARED_POINT_NUMBERS != 0
+    }
+
+    fn count(self) -> u16 {
+        self.0 & Self::COUNT_MASK
+    }
+}
+
+/// The [tupleIndex](https://learn.microsoft.com/en-us/typography/opentype/spec/otvarcommonformats#tuplevariationheader)
+/// field of a tuple variation header, containing flags and an index.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TupleIndex(u16);
+
+impl TupleIndex {
+    /// Flag indicating that this tuple variation header includes an
+    /// embedded peak tuple record.
+    pub const EMBEDDED_PEAK_TUPLE: u16 = 0x8000;
+    /// Flag indicating that this tuple variation table applies to an
+    /// intermediate region within the variation space.
+    pub const INTERMEDIATE_REGION: u16 = 0x4000;
+    /// Flag indicating that the serialized data for this tuple variation
+    /// table includes packed “point” number data.
+    pub const PRIVATE_POINT_NUMBERS: u16 = 0x2000;
+    /// Mask for the low 12 bits to give the shared tuple records index.
+    pub const TUPLE_INDEX_MASK: u16 = 0x0FFF;
+
+    /// Create a new tuple index from the raw value.
+    pub fn from_bits(bits: u16) -> Self {
+        TupleIndex(bits)
+    }
+
+    /// Returns the raw value.
+    pub fn bits(self) -> u16 {
+        self.0
+    }
+
+    /// `true` if the header includes an embedded peak tuple.
+    pub fn embedded_peak_tuple(self) -> bool {
+        self.0 & Self::EMBEDDED_PEAK_TUPLE != 0
+    }
+
+    /// `true` if the header includes intermediate start and end tuples.
+    pub fn intermediate_region(self) -> bool {
+        self.0 & Self::INTERMEDIATE_REGION != 0
+    }
+
+    /// `true` if the serialized data includes private point numbers.
+    pub fn private_point_numbers(self) -> bool {
+        self.0 & Self::PRIVATE_POINT_NUMBERS != 0
+    }
+
+    /// The index into the shared tuple records, used when there is no
+    /// embedded peak tuple.
+    pub fn tuple_index(self) -> u16 {
+        self.0 & Self::TUPLE_INDEX_MASK
+    }
+}
+
+/// The [GlyphVariationData](https://learn.microsoft.com/en-us/typography/opentype/spec/gvar#the-glyphvariationdata-table-array)
+/// table for a single glyph.
+///
+/// NOTE: this is handwritten, because the tuple variation headers have a
+/// variable length that depends on their flags.
+#[derive(Clone)]
+pub struct GlyphVariationData<'a> {
+    axis_count: u16,
+    shared_tuples: SharedTuples<'a>,
+    count: TupleVariationCount,
+    // the tuple variation headers
+    header_data: FontData<'a>,
+    // the serialized data, after any shared point numbers
+    serialized_data: FontData<'a>,
+    shared_point_numbers: Option<PackedPointNumbers<'a>>,
+}
+
+impl<'a> GlyphVariationData<'a> {
+    fn new(
+        data: FontData<'a>,
+        axis_count: u16,
+        shared_tuples: SharedTuples<'a>,
+    ) -> Result<Self, ReadError> {
+        let count = TupleVariationCount(data.read_at(0)?);
+        let data_offset: u16 = data.read_at(u16::RAW_BYTE_LEN)?;
+        let header_data = data
+            .split_off(2 * u16::RAW_BYTE_LEN)
+            .ok_or(ReadError::OutOfBounds)?;
+        let mut serialized_data = data
+            .split_off(data_offset as usize)
+            .ok_or(ReadError::OutOfBounds)?;
+        let shared_point_numbers = if count.has_shared_point_numbers() {
+            let (points, rest) = PackedPointNumbers::split_off_front(serialized_data)?;
+            serialized_data = rest;
+            Some(points)
+        } else {
+            None
+        };
+        Ok(GlyphVariationData {
+            axis_count,
+            shared_tuples,
+            count,
+            header_data,
+            serialized_data,
+            shared_point_numbers,
+        })
+    }
+
+    /// The number of tuple variation tables for this glyph.
+    pub fn tuple_variation_count(&self) -> u16 {
+        self.count.count()
+    }
+
+    /// Iterate over the tuple variations for this glyph.
+    pub fn tuples(&self) -> TupleVariationIter<'a> {
+        TupleVariationIter {
+            current: 0,
+            count: self.count.count(),
+            axis_count: self.axis_count,
+            shared_tuples: self.shared_tuples,
+            header_cursor: self.header_data.cursor(),
+            serialized_data: self.serialized_data,
+            shared_point_numbers: self.shared_point_numbers.clone(),
+        }
+    }
+
+    /// Iterate over the tuple variations that are active at the given
+    /// normalized coordinates, along with their scalars.
+    pub fn active_tuples(
+        &self,
+        coords: &'a [F2Dot14],
+    ) -> impl Iterator<Item = (Fixed, TupleVariation<'a>)> + 'a {
+        self.tuples().filter_map(move |tuple| {
+            let scalar = tuple.compute_scalar(coords)?;
+            Some((scalar, tuple))
+        })
+    }
+}
+
+/// An iterator over the tuple variations for a glyph.
+///
+/// Iteration stops at the first malformed tuple variation header.
+#[derive(Clone)]
+pub struct TupleVariationIter<'a> {
+    current: u16,
+    count: u16,
+    axis_count: u16,
+    shared_tuples: SharedTuples<'a>,
+    header_cursor: Cursor<'a>,
+    serialized_data: FontData<'a>,
+    shared_point_numbers: Option<PackedPointNumbers<'a>>,
+}
+
+impl<'a> TupleVariationIter<'a> {
+    fn next_tuple(&mut self) -> Option<TupleVariation<'a>> {
+        let data_size: u16 = self.header_cursor.read().ok()?;
+        let tuple_index = TupleIndex(self.header_cursor.read().ok()?);
+        let axis_count = self.axis_count as usize;
+        let peak = if tuple_index.embedded_peak_tuple() {
+            self.header_cursor.read_array(axis_count).ok()?
+        } else {
+            self.shared_tuples
+                .tuples()
+                .get(tuple_index.tuple_index() as usize)
+                .ok()?
+                .values()
+        };
+        let (intermediate_start, intermediate_end) = if tuple_index.intermediate_region() {
+            let start = self.header_cursor.read_array(axis_count).ok()?;
+            let end = self.header_cursor.read_array(axis_count).ok()?;
+            (Some(start), Some(end))
+        } else {
+            (None, None)
+        };
+        let data = self.serialized_data.slice(..data_size as usize)?;
+        self.serialized_data = self
+            .serialized_data
+            .split_off(data_size as usize)
+            .unwrap_or_default();
+        let (point_numbers, delta_data) = if tuple_index.private_point_numbers() {
+            PackedPointNumbers::split_off_front(data).ok()?
+        } else {
+            // without shared point numbers, the tuple applies to all points
+            let point_numbers = self
+                .shared_point_numbers
+                .clone()
+                .unwrap_or_else(PackedPointNumbers::all_points);
+            (point_numbers, data)
+        };
+        Some(TupleVariation {
+            tuple_index,
+            peak,
+            intermediate_start,
+            intermediate_end,
+            point_numbers,
+            delta_data,
+        })
+    }
+}
+
+impl<'a> Iterator for TupleVariationIter<'a> {
+    type Item = TupleVariation<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current >= self.count {
+            return None;
+        }
+        self.current += 1;
+        let next = self.next_tuple();
+        if next.is_none() {
+            // don't continue after encountering malformed data
+            self.current = self.count;
+        }
+        next
+    }
+}
+
+/// A single tuple variation for a glyph: a region of the variation space,
+/// and the deltas to apply within that region.
+#[derive(Clone)]
+pub struct TupleVariation<'a> {
+    tuple_index: TupleIndex,
+    peak: &'a [BigEndian<F2Dot14>],
+    intermediate_start: Option<&'a [BigEndian<F2Dot14>]>,
+    intermediate_end: Option<&'a [BigEndian<F2Dot14>]>,
+    point_numbers: PackedPointNumbers<'a>,
+    delta_data: FontData<'a>,
+}
+
+impl<'a> TupleVariation<'a> {
+    /// The flags and shared tuple index for this tuple variation.
+    pub fn tuple_index(&self) -> TupleIndex {
+        self.tuple_index
+    }
+
+    /// The peak coordinates of the region for this tuple variation.
+    pub fn peak(&self) -> &'a [BigEndian<F2Dot14>] {
+        self.peak
+    }
+
+    /// The intermediate start coordinates, if this tuple applies to an
+    /// intermediate region.
+    pub fn intermediate_start(&self) -> Option<&'a [BigEndian<F2Dot14>]> {
+        self.intermediate_start
+    }
+
+    /// The intermediate end coordinates, if this tuple applies to an
+    /// intermediate region.
+    pub fn intermediate_end(&self) -> Option<&'a [BigEndian<F2Dot14>]> {
+        self.intermediate_end
+    }
+
+    /// `true` if this tuple provides deltas for every point in the glyph.
+    pub fn has_deltas_for_all_points(&self) -> bool {
+        self.point_numbers.count() == 0
+    }
+
+    /// Computes the scalar for this tuple at the given normalized
+    /// coordinates, or `None` if the tuple is not active.
+    ///
+    /// Coordinates that are missing are treated as zero.
+    pub fn compute_scalar(&self, coords: &[F2Dot14]) -> Option<Fixed> {
+        let mut scalar = Fixed::ONE;
+        for (i, peak) in self.peak.iter().enumerate() {
+            let peak = peak.get();
+            let (start, end) = match (self.intermediate_start, self.intermediate_end) {
+                (Some(start), Some(end)) => (start.get(i)?.get(), end.get(i)?.get()),
+                _ => (peak.min(F2Dot14::ZERO), peak.max(F2Dot14::ZERO)),
+            };
+            let coord = coords.get(i).copied().unwrap_or_default();
+            let factor = region_axis_scalar(start, peak, end, coord);
+            if factor == Fixed::ZERO {
+                return None;
+            }
+            scalar = scalar * factor;
+        }
+        (scalar != Fixed::ZERO).then_some(scalar)
+    }
+
+    /// Iterate over the deltas for this tuple variation.
+    ///
+    /// If this tuple has deltas for all points, the `position` of each
+    /// delta is its index; otherwise it is the explicit point number.
+    pub fn deltas(&self) -> impl Iterator<Item = GlyphDelta> + 'a {
+        let deltas = PackedDeltas::new(self.delta_data);
+        let count = match self.point_numbers.count() {
+            0 => deltas.iter().count() / 2,
+            count => count as usize,
+        };
+        let points = self.point_numbers.iter();
+        let all_points = self.has_deltas_for_all_points();
+        let x_deltas = deltas.iter().take(count);
+        let y_deltas = deltas.iter().skip(count).take(count);
+        x_deltas
+            .zip(y_deltas)
+            .enumerate()
+            .zip(points.map(Some).chain(std::iter::repeat(None)))
+            .map_while(move |((i, (x_delta, y_delta)), point)| {
+                let position = if all_points { i as u16 } else { point? };
+                Some(GlyphDelta {
+                    position,
+                    x_delta,
+                    y_delta,
+                })
+            })
+    }
+}
+
+/// A delta for a single point in a glyph.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GlyphDelta {
+    /// The point number to which this delta applies.
+    pub position: u16,
+    /// The delta for the x coordinate.
+    pub x_delta: i32,
+    /// The delta for the y coordinate.
+    pub y_delta: i32,
+}
+
+impl GlyphDelta {
+    /// Returns this delta scaled by the given tuple scalar.
+    pub fn apply_scalar(self, scalar: Fixed) -> Point<Fixed> {
+        Point::new(Fixed::from_i32(self.x_delta), Fixed::from_i32(self.y_delta)) * scalar
+    }
+}
+
+/// [Packed "point" numbers](https://learn.microsoft.com/en-us/typography/opentype/spec/otvarcommonformats#packed-point-numbers)
+#[derive(Clone, Debug)]
+pub struct PackedPointNumbers<'a> {
+    data: FontData<'a>,
+}
+
+impl<'a> PackedPointNumbers<'a> {
+    /// Flag indicating the data type for a run of point numbers.
+    const POINTS_ARE_WORDS: u8 = 0x80;
+    /// Mask for the low 7 bits to give the number of point numbers in the
+    /// run, minus one.
+    const POINT_RUN_COUNT_MASK: u8 = 0x7F;
+
+    /// The point numbers that refer to all points.
+    fn all_points() -> Self {
+        PackedPointNumbers {
+            data: FontData::new(&[0]),
+        }
+    }
+
+    /// Read packed point numbers from the start of `data`, returning them
+    /// along with the remaining data.
+    fn split_off_front(data: FontData<'a>) -> Result<(Self, FontData<'a>), ReadError> {
+        let this = PackedPointNumbers { data };
+        let len = this.total_len().ok_or(ReadError::OutOfBounds)?;
+        let rest = data.split_off(len).ok_or(ReadError::OutOfBounds)?;
+        let data = data.slice(..len).ok_or(ReadError::OutOfBounds)?;
+        Ok((PackedPointNumbers { data }, rest))
+    }
+
+    /// The number of points in this set; zero indicates all points.
+    pub fn count(&self) -> u16 {
+        self.count_and_header_len()
+            .map(|(count, _)| count)
+            .unwrap_or(0)
+    }
+
+    fn count_and_header_len(&self) -> Option<(u16, usize)> {
+        let first: u8 = self.data.read_at(0).ok()?;
+        if first & Self::POINTS_ARE_WORDS == 0 {
+            Some((first as u16, 1))
+        } else {
+            let second: u8 = self.data.read_at(1).ok()?;
+            let count = ((first & Self::POINT_RUN_COUNT_MASK) as u16) << 8 | second as u16;
+            Some((count, 2))
+        }
+    }
+
+    // the total number of bytes used by the packed point numbers
+    fn total_len(&self) -> Option<usize> {
+        let (count, mut pos) = self.count_and_header_len()?;
+        let mut remaining = count as usize;
+        while remaining > 0 {
+            let control: u8 = self.data.read_at(pos).ok()?;
+            let run_count = (control & Self::POINT_RUN_COUNT_MASK) as usize + 1;
+            let point_len = if control & Self::POINTS_ARE_WORDS != 0 {
+                2
+            } else {
+                1
+            };
+            pos += 1 + run_count * point_len;
+            remaining = remaining.saturating_sub(run_count);
+        }
+        Some(pos)
+    }
+
+    /// Iterate over the point numbers in this set.
+    ///
+    /// This is empty if the set refers to all points.
+    pub fn iter(&self) -> impl Iterator<Item = u16> + 'a {
+        let (count, header_len) = self.count_and_header_len().unwrap_or_default();
+        let mut cursor = self.data.split_off(header_len).unwrap_or_default().cursor();
+        let mut run_remaining = 0u8;
+        let mut points_are_words = false;
+        let mut last_point = 0u16;
+        (0..count).map_while(move |_| {
+            if run_remaining == 0 {
+                let control: u8 = cursor.read().ok()?;
+                points_are_words = control & Self::POINTS_ARE_WORDS != 0;
+                run_remaining = (control & Self::POINT_RUN_COUNT_MASK) + 1;
+            }
+            run_remaining -= 1;
+            let delta = if points_are_words {
+                cursor.read::<u16>().ok()?
+            } else {
+                cursor.read::<u8>().ok()? as u16
+            };
+            last_point = last_point.wrapping_add(delta);
+            Some(last_point)
+        })
+    }
+}
+
+/// [Packed deltas](https://learn.microsoft.com/en-us/typography/opentype/spec/otvarcommonformats#packed-deltas)
+#[derive(Clone, Copy, Debug)]
+pub struct PackedDeltas<'a> {
+    data: FontData<'a>,
+}
+
+impl<'a> PackedDeltas<'a> {
+    /// Flag indicating that this run contains no data, and that the deltas
+    /// for this run are all zero.
+    const DELTAS_ARE_ZERO: u8 = 0x80;
+    /// Flag indicating the data type for delta values in the run.
+    const DELTAS_ARE_WORDS: u8 = 0x40;
+    /// Mask for the low 6 bits to give the number of delta values in the
+    /// run, minus one.
+    const DELTA_RUN_COUNT_MASK: u8 = 0x3F;
+
+    /// Create a new set of packed deltas from the provided data.
+    pub fn new(data: FontData<'a>) -> Self {
+        PackedDeltas { data }
+    }
+
+    /// Iterate over the unpacked delta values.
+    ///
+    /// If both the zero and word flags are set, the deltas are 32-bit.
+    pub fn iter(&self) -> impl Iterator<Item = i32> + 'a {
+        let mut cursor = self.data.cursor();
+        let mut run_remaining = 0u8;
+        let mut control = 0u8;
+        std::iter::from_fn(move || {
+            if run_remaining == 0 {
+                control = cursor.read().ok()?;
+                run_remaining = (control & Self::DELTA_RUN_COUNT_MASK) + 1;
+            }
+            run_remaining -= 1;
+            let is_zero = control & Self::DELTAS_ARE_ZERO != 0;
+            let is_words = control & Self::DELTAS_ARE_WORDS != 0;
+            match (is_zero, is_words) {
+                (true, true) => cursor.read::<i32>().ok(),
+                (true, false) => Some(0),
+                (false, true) => cursor.read::<i16>().ok().map(|x| x as i32),
+                (false, false) => cursor.read::<i8>().ok().map(|x| x as i32),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    fn f2dot14(val: f32) -> F2Dot14 {
+        F2Dot14::from_f32(val)
+    }
+
+    fn make_gvar() -> BeBuffer {
+        let mut buf = BeBuffer::new();
+        buf.extend([1u16, 0, 1, 1]); // version, axis count, shared tuple count
+        buf.push(26u32); // shared tuples offset
+        buf.extend([2u16, 0]); // glyph count, flags
+        buf.push(28u32); // glyph variation data array offset
+        buf.extend([0u16, 18, 18]); // offsets (divided by 2)
+        buf.push(f2dot14(1.0)); // shared tuples
+
+        // glyph variation data for glyph 0
+        buf.extend([0x8002u16, 14]); // count with shared points, data offset
+        buf.extend([8u16, 0]); // tuple 0 header: shared peak at index 0
+        buf.extend([13u16, 0xA000]); // tuple 1 header: embedded peak, private points
+        buf.push(f2dot14(-1.0));
+        buf.push(0u8); // shared point numbers: all points
+                       // tuple 0: x and y deltas as byte runs
+        buf.push(2u8);
+        buf.extend([10i8, 20, 30]);
+        buf.push(2u8);
+        buf.extend([-5i8, 0, 5]);
+        // tuple 1: private points 1 and 2
+        buf.extend([2u8, 1, 1, 1]);
+        buf.push(0x41u8);
+        buf.extend([100i16, 200]);
+        buf.push(0x80u8);
+        buf.push(0x40u8);
+        buf.push(-300i16);
+        buf
+    }
+
+    #[test]
+    fn read_header() {
+        let buf = make_gvar();
+        let gvar = Gvar::read(buf.font_data()).unwrap();
+        assert_eq!(gvar.axis_count(), 1);
+        assert_eq!(gvar.glyph_count(), 2);
+        let shared = gvar.shared_tuples().unwrap();
+        let tuple = shared.tuples().get(0).unwrap();
+        assert_eq!(tuple.values()[0].get(), f2dot14(1.0));
+        assert!(gvar
+            .glyph_variation_data(GlyphId::new(1))
+            .unwrap()
+            .is_none());
+        assert!(gvar.glyph_variation_data(GlyphId::new(2)).is_err());
+    }
+
+    #[test]
+    fn read_tuple_variations() {
+        let buf = make_gvar();
+        let gvar = Gvar::read(buf.font_data()).unwrap();
+        let var_data = gvar.glyph_variation_data(GlyphId::new(0)).unwrap().unwrap();
+        assert_eq!(var_data.tuple_variation_count(), 2);
+        let tuples = var_data.tuples().collect::<Vec<_>>();
+        assert_eq!(tuples.len(), 2);
+
+        assert!(tuples[0].has_deltas_for_all_points());
+        assert_eq!(tuples[0].peak()[0].get(), f2dot14(1.0));
+        let deltas = tuples[0]
+            .deltas()
+            .map(|d| (d.position, d.x_delta, d.y_delta))
+            .collect::<Vec<_>>();
+        assert_eq!(deltas, [(0, 10, -5), (1, 20, 0), (2, 30, 5)]);
+
+        assert!(!tuples[1].has_deltas_for_all_points());
+        assert!(tuples[1].tuple_index().embedded_peak_tuple());
+        assert_eq!(tuples[1].peak()[0].get(), f2dot14(-1.0));
+        let deltas = tuples[1]
+            .deltas()
+            .map(|d| (d.position, d.x_delta, d.y_delta))
+            .collect::<Vec<_>>();
+        assert_eq!(deltas, [(1, 100, 0), (2, 200, -300)]);
+    }
+
+    #[test]
+    fn glyph_deltas() {
+        let buf = make_gvar();
+        let gvar = Gvar::read(buf.font_data()).unwrap();
+        let to_f64 = |deltas: &[Point<Fixed>]| {
+            deltas
+                .iter()
+                .map(|p| (p.x.to_f64(), p.y.to_f64()))
+                .collect::<Vec<_>>()
+        };
+        let mut deltas = [Point::default(); 3];
+        gvar.glyph_deltas(GlyphId::new(0), &[f2dot14(0.5)], &mut deltas)
+            .unwrap();
+        assert_eq!(to_f64(&deltas), [(5.0, -2.5), (10.0, 0.0), (15.0, 2.5)]);
+        let mut deltas = [Point::default(); 3];
+        gvar.glyph_deltas(GlyphId::new(0), &[f2dot14(-0.5)], &mut deltas)
+            .unwrap();
+        assert_eq!(to_f64(&deltas), [(0.0, 0.0), (50.0, 0.0), (100.0, -150.0)]);
+        let mut deltas = [Point::default(); 3];
+        gvar.glyph_deltas(GlyphId::new(0), &[f2dot14(0.0)], &mut deltas)
+            .unwrap();
+        assert_eq!(to_f64(&deltas), [(0.0, 0.0); 3]);
+    }
+
+    #[test]
+    fn tuples_without_point_numbers() {
+        let mut buf = BeBuffer::new();
+        buf.extend([1u16, 0, 1, 0]); // version, axis count, shared tuple count
+        buf.push(24u32); // shared tuples offset
+        buf.extend([1u16, 0]); // glyph count, flags
+        buf.push(24u32); // glyph variation data array offset
+        buf.extend([0u16, 7]); // offsets (divided by 2)
+        buf.extend([1u16, 10]); // count without shared points, data offset
+        buf.extend([4u16, 0x8000]); // tuple header: embedded peak
+        buf.push(f2dot14(1.0));
+        // no point numbers, so deltas for all points
+        buf.extend([1u8, 10, 20]);
+        buf.push(0x81u8);
+
+        let gvar = Gvar::read(buf.font_data()).unwrap();
+        let var_data = gvar.glyph_variation_data(GlyphId::new(0)).unwrap().unwrap();
+        let tuples = var_data.tuples().collect::<Vec<_>>();
+        assert_eq!(tuples.len(), 1);
+        assert!(tuples[0].has_deltas_for_all_points());
+        let mut deltas = [Point::default(); 2];
+        gvar.glyph_deltas(GlyphId::new(0), &[f2dot14(1.0)], &mut deltas)
+            .unwrap();
+        assert_eq!(
+            deltas,
+            [
+                Point::new(Fixed::from_i32(10), Fixed::ZERO),
+                Point::new(Fixed::from_i32(20), Fixed::ZERO)
+            ]
+        );
+    }
+
+    #[test]
+    fn large_glyph_deltas_wrap() {
+        let mut buf = BeBuffer::new();
+        buf.extend([1u16, 0, 1, 0]); // version, axis count, shared tuple count
+        buf.push(24u32); // shared tuples offset
+        buf.extend([1u16, 0]); // glyph count, flags
+        buf.push(24u32); // glyph variation data array offset
+        buf.extend([0u16, 12]); // offsets (divided by 2)
+        buf.extend([2u16, 16]); // count without shared points, data offset
+        for _ in 0..2 {
+            buf.extend([4u16, 0x8000]); // tuple header: embedded peak
+            buf.push(f2dot14(1.0));
+        }
+        for _ in 0..2 {
+            buf.push(0x40u8);
+            buf.push(20000i16);
+            buf.push(0x80u8);
+        }
+
+        let gvar = Gvar::read(buf.font_data()).unwrap();
+        let mut deltas = [Point::default(); 1];
+        gvar.glyph_deltas(GlyphId::new(0), &[f2dot14(1.0)], &mut deltas)
+            .unwrap();
+        let delta = Fixed::from_i32(20000);
+        assert_eq!(deltas[0].x, delta.wrapping_add(delta));
+        let phantom = gvar
+            .phantom_point_deltas(GlyphId::new(0), &[f2dot14(1.0)], 0)
+            .unwrap();
+        assert_eq!(phantom[0].x, delta.wrapping_add(delta));
+    }
+
+    #[test]
+    fn intermediate_region_scalar() {
+        let mut buf = BeBuffer::new();
+        buf.extend([f2dot14(0.5), f2dot14(0.25), f2dot14(1.0)]);
+        let data = buf.font_data();
+        let tuple = TupleVariation {
+            tuple_index: TupleIndex(
+                TupleIndex::EMBEDDED_PEAK_TUPLE | TupleIndex::INTERMEDIATE_REGION,
+            ),
+            peak: data.read_array(0..2).unwrap(),
+            intermediate_start: Some(data.read_array(2..4).unwrap()),
+            intermediate_end: Some(data.read_array(4..6).unwrap()),
+            point_numbers: PackedPointNumbers {
+                data: FontData::new(&[0]),
+            },
+            delta_data: FontData::new(&[]),
+        };
+        let scalar = |coord: f32| tuple.compute_scalar(&[f2dot14(coord)]).map(|s| s.to_f64());
+        assert_eq!(scalar(0.25), None);
+        assert_eq!(scalar(0.375), Some(0.5));
+        assert_eq!(scalar(0.5), Some(1.0));
+        assert_eq!(scalar(0.75), Some(0.5));
+        assert_eq!(scalar(1.0), None);
+    }
+
+    #[test]
+    fn packed_point_numbers() {
+        let mut buf = BeBuffer::new();
+        // 130 points: a run of 2 byte points and a run of 128 word points
+        buf.extend([0x80u8, 130, 0x01, 2, 3]);
+        buf.push(0xFFu8);
+        buf.extend([1u16; 128]);
+        buf.push(0xFFu8); // trailing data
+        let (points, rest) = PackedPointNumbers::split_off_front(buf.font_data()).unwrap();
+        assert_eq!(points.count(), 130);
+        assert_eq!(rest.len(), 1);
+        let points = points.iter().collect::<Vec<_>>();
+        assert_eq!(points.len(), 130);
+        assert_eq!(&points[..3], &[2, 5, 6]);
+        assert_eq!(points.last(), Some(&133));
+    }
+
+    #[test]
+    fn packed_deltas() {
+        let mut buf = BeBuffer::new();
+        buf.extend([0x01u8, 0xFF, 0x02]); // two bytes
+        buf.push(0x82u8); // three zeros
+        buf.push(0x40u8); // one word
+        buf.push(-1000i16);
+        buf.push(0xC0u8); // one long
+        buf.push(100_000i32);
+        let deltas = PackedDeltas::new(buf.font_data())
+            .iter()
+            .collect::<Vec<_>>();
+        assert_eq!(deltas, [-1, 2, 0, 0, 0, -1000, 100_000]);
+    }
+}
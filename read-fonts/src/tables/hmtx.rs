@@ -6,3 +6,30 @@ use font_types::Tag;
 pub const TAG: Tag = Tag::new(b"hmtx");
 
 include!("../../generated/generated_hmtx.rs");
+
+impl<'a> Hmtx<'a> {
+    /// Returns the advance width for the given glyph.
+    ///
+    /// Glyphs beyond the end of the `h_metrics` array share the advance of
+    /// the last entry.
+    pub fn advance(&self, gid: GlyphId) -> Option<u16> {
+        let metrics = self.h_metrics();
+        metrics
+            .get(gid.to_u16() as usize)
+            .or_else(|| metrics.last())
+            .map(|metric| metric.advance_width())
+    }
+
+    /// Returns the left side bearing for the given glyph.
+    pub fn side_bearing(&self, gid: GlyphId) -> Option<i16> {
+        let idx = gid.to_u16() as usize;
+        let metrics = self.h_metrics();
+        match metrics.get(idx) {
+            Some(metric) => Some(metric.lsb()),
+            None => self
+                .left_side_bearings()
+                .get(idx - metrics.len())
+                .map(|lsb| lsb.get()),
+        }
+    }
+}
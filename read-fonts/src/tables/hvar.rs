@@ -0,0 +1,120 @@
+//! The [HVAR (Horizontal Metrics Variations)](https://docs.microsoft.com/en-us/typography/opentype/spec/hvar) table
+
+/// 'HVAR'
+pub const TAG: Tag = Tag::new(b"HVAR");
+
+include!("../../generated/generated_hvar.rs");
+
+use crate::layout::{DeltaSetIndexMap, ItemVariationStore};
+
+impl<'a> Hvar<'a> {
+    /// Returns the advance width delta for the given glyph at the given
+    /// normalized coordinates, in font units.
+    ///
+    /// If the table has no advance width mapping, the glyph id is used as
+    /// the index into the first item variation data subtable.
+    pub fn advance_width_delta(
+        &self,
+        gid: GlyphId,
+        coords: &[F2Dot14],
+    ) -> Result<Fixed, ReadError> {
+        let map = self.advance_width_mapping().transpose()?;
+        self.item_variation_store()?
+            .compute_mapped_delta(map.as_ref(), gid.to_u16() as u32, coords)
+    }
+
+    /// Returns the left side bearing delta for the given glyph at the given
+    /// normalized coordinates, or `None` if the table has no left side
+    /// bearing mapping.
+    pub fn lsb_delta(&self, gid: GlyphId, coords: &[F2Dot14]) -> Option<Result<Fixed, ReadError>> {
+        let map = self.lsb_mapping()?;
+        Some(self.mapped_delta(map, gid, coords))
+    }
+
+    /// Returns the right side bearing delta for the given glyph at the given
+    /// normalized coordinates, or `None` if the table has no right side
+    /// bearing mapping.
+    pub fn rsb_delta(&self, gid: GlyphId, coords: &[F2Dot14]) -> Option<Result<Fixed, ReadError>> {
+        let map = self.rsb_mapping()?;
+        Some(self.mapped_delta(map, gid, coords))
+    }
+
+    fn mapped_delta(
+        &self,
+        map: Result<DeltaSetIndexMap, ReadError>,
+        gid: GlyphId,
+        coords: &[F2Dot14],
+    ) -> Result<Fixed, ReadError> {
+        self.item_variation_store()?
+            .compute_mapped_delta(Some(&map?), gid.to_u16() as u32, coords)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    /// An item variation store with a single axis and region, peaking at
+    /// 1.0, with two delta sets: (10) and (-20).
+    fn push_var_store(buf: &mut BeBuffer) {
+        buf.push(1u16); // format
+        buf.push(12u32); // region list offset
+        buf.push(1u16); // item variation data count
+        buf.push(22u32); // item variation data offset
+                         // region list
+        buf.extend([1u16, 1]); // axis count, region count
+        buf.extend([F2Dot14::ZERO, F2Dot14::ONE, F2Dot14::ONE]);
+        // item variation data
+        buf.extend([2u16, 0, 1, 0]); // item count, word delta count, region count, region index
+        buf.extend([10i8, -20]);
+    }
+
+    #[test]
+    fn implicit_advance_mapping() {
+        let mut buf = BeBuffer::new();
+        buf.extend([1u16, 0]);
+        buf.push(20u32); // item variation store offset
+        buf.extend([0u32, 0, 0]); // no mappings
+        push_var_store(&mut buf);
+
+        let hvar = Hvar::read(buf.font_data()).unwrap();
+        let coords = [F2Dot14::from_f32(0.5)];
+        let delta = |gid| hvar.advance_width_delta(GlyphId::new(gid), &coords);
+        assert_eq!(delta(0).unwrap(), Fixed::from_i32(5));
+        assert_eq!(delta(1).unwrap(), Fixed::from_i32(-10));
+        assert!(delta(2).is_err());
+        assert!(hvar.lsb_delta(GlyphId::new(0), &coords).is_none());
+        assert!(hvar.rsb_delta(GlyphId::new(0), &coords).is_none());
+    }
+
+    #[test]
+    fn explicit_mappings() {
+        let mut buf = BeBuffer::new();
+        buf.extend([1u16, 0]);
+        buf.push(26u32); // item variation store offset
+        buf.extend([20u32, 20, 0]); // advance and lsb share a mapping
+                                    // format 0 map, 1-byte entries with 1 inner bit: gid 0 -> 1, gid 1 -> 0
+        buf.extend([0u8, 0]);
+        buf.push(2u16);
+        buf.extend([1u8, 0]);
+        push_var_store(&mut buf);
+
+        let hvar = Hvar::read(buf.font_data()).unwrap();
+        let coords = [F2Dot14::ONE];
+        let gid = GlyphId::new(0);
+        assert_eq!(
+            hvar.advance_width_delta(gid, &coords).unwrap(),
+            Fixed::from_i32(-20)
+        );
+        assert_eq!(
+            hvar.lsb_delta(gid, &coords).unwrap().unwrap(),
+            Fixed::from_i32(-20)
+        );
+        // later glyphs use the last entry
+        assert_eq!(
+            hvar.advance_width_delta(GlyphId::new(5), &coords).unwrap(),
+            Fixed::from_i32(10)
+        );
+    }
+}
@@ -0,0 +1,490 @@
+//! The legacy [kern (Kerning)][kern] table.
+//!
+//! This table exists in two incompatible variants: the original OpenType
+//! version, with a 16-bit header, and the Apple version, with a 32-bit
+//! header. Both are supported here, along with the format 0 (ordered pair
+//! list) and format 2 (class-based) subtables.
+//!
+//! [kern]: https://docs.microsoft.com/en-us/typography/opentype/spec/kern
+
+#[cfg(feature = "traversal")]
+use crate::traversal;
+
+/// 'kern'
+pub const TAG: Tag = Tag::new(b"kern");
+
+include!("../../generated/generated_kern.rs");
+
+const OT_HEADER_LEN: usize = 4;
+const AAT_HEADER_LEN: usize = 8;
+const OT_SUBTABLE_HEADER_LEN: usize = 6;
+const AAT_SUBTABLE_HEADER_LEN: usize = 8;
+
+/// The [kern] table.
+///
+/// [kern]: https://docs.microsoft.com/en-us/typography/opentype/spec/kern
+#[derive(Clone)]
+pub struct Kern<'a> {
+    data: FontData<'a>,
+    is_aat: bool,
+    n_tables: u32,
+}
+
+impl<'a> FontRead<'a> for Kern<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        match data.read_at::<u16>(0)? {
+            0 => Ok(Kern {
+                data,
+                is_aat: false,
+                n_tables: data.read_at::<u16>(2)? as u32,
+            }),
+            1 if data.read_at::<u16>(2)? == 0 => Ok(Kern {
+                data,
+                is_aat: true,
+                n_tables: data.read_at::<u32>(4)?,
+            }),
+            other => Err(ReadError::InvalidFormat(other as _)),
+        }
+    }
+}
+
+impl<'a> Kern<'a> {
+    /// `true` if this table uses the Apple (32-bit) header.
+    pub fn is_aat(&self) -> bool {
+        self.is_aat
+    }
+
+    /// The number of subtables.
+    pub fn n_tables(&self) -> u32 {
+        self.n_tables
+    }
+
+    /// Returns an iterator over the subtables in this table.
+    pub fn subtables(&self) -> impl Iterator<Item = Result<Subtable<'a>, ReadError>> + 'a {
+        let data = self.data;
+        let is_aat = self.is_aat;
+        let mut offset = if is_aat {
+            AAT_HEADER_LEN
+        } else {
+            OT_HEADER_LEN
+        };
+        let mut remaining = self.n_tables;
+        core::iter::from_fn(move || {
+            if remaining == 0 {
+                return None;
+            }
+            remaining -= 1;
+            let result = Subtable::read(data, offset, is_aat, remaining == 0);
+            match &result {
+                Ok(subtable) => offset += subtable.data.len(),
+                // we can't find the next subtable; stop after reporting this
+                Err(_) => remaining = 0,
+            }
+            Some(result)
+        })
+    }
+
+    /// Returns the kerning adjustment for the given pair of glyphs, in
+    /// design units.
+    ///
+    /// This accumulates the values of all horizontal subtables, skipping
+    /// cross-stream, minimum value and variation subtables. A subtable with the override
+    /// flag set replaces the value accumulated so far. Pairs that are not
+    /// kerned return `0`.
+    pub fn kerning(&self, left: GlyphId, right: GlyphId) -> i32 {
+        let mut value = 0;
+        for subtable in self.subtables() {
+            let Ok(subtable) = subtable else {
+                break;
+            };
+            if !subtable.is_horizontal()
+                || subtable.is_cross_stream()
+                || subtable.is_minimum()
+                || subtable.is_variable()
+            {
+                continue;
+            }
+            if let Some(adjustment) = subtable.kerning(left, right) {
+                if subtable.is_override() {
+                    value = 0;
+                }
+                value += adjustment;
+            }
+        }
+        value
+    }
+}
+
+/// A single kerning subtable, including its header.
+#[derive(Clone, Copy)]
+pub struct Subtable<'a> {
+    data: FontData<'a>,
+    is_aat: bool,
+    coverage: u16,
+}
+
+impl<'a> Subtable<'a> {
+    fn read(
+        table: FontData<'a>,
+        offset: usize,
+        is_aat: bool,
+        is_last: bool,
+    ) -> Result<Self, ReadError> {
+        let data = table.split_off(offset).ok_or(ReadError::OutOfBounds)?;
+        let (len, coverage) = if is_aat {
+            (data.read_at::<u32>(0)? as usize, data.read_at::<u16>(4)?)
+        } else {
+            (data.read_at::<u16>(2)? as usize, data.read_at::<u16>(4)?)
+        };
+        // Some fonts have a single format 0 subtable that is too large for
+        // the 16-bit length field; like other implementations, we let the
+        // last subtable extend to the end of the table.
+        let data = if is_last && !is_aat {
+            data
+        } else {
+            data.slice(..len).ok_or(ReadError::OutOfBounds)?
+        };
+        Ok(Subtable {
+            data,
+            is_aat,
+            coverage,
+        })
+    }
+
+    fn header_len(&self) -> usize {
+        if self.is_aat {
+            AAT_SUBTABLE_HEADER_LEN
+        } else {
+            OT_SUBTABLE_HEADER_LEN
+        }
+    }
+
+    /// The raw coverage field.
+    pub fn coverage(&self) -> u16 {
+        self.coverage
+    }
+
+    /// The subtable format.
+    pub fn format(&self) -> u8 {
+        if self.is_aat {
+            (self.coverage & 0xFF) as u8
+        } else {
+            (self.coverage >> 8) as u8
+        }
+    }
+
+    /// `true` if this subtable contains horizontal kerning values.
+    pub fn is_horizontal(&self) -> bool {
+        if self.is_aat {
+            self.coverage & 0x8000 == 0
+        } else {
+            self.coverage & 0x0001 != 0
+        }
+    }
+
+    /// `true` if the values in this subtable are minimum values, rather
+    /// than kerning values.
+    ///
+    /// This is always `false` for the Apple variant of the table.
+    pub fn is_minimum(&self) -> bool {
+        !self.is_aat && self.coverage & 0x0002 != 0
+    }
+
+    /// `true` if kerning is perpendicular to the flow of the text.
+    pub fn is_cross_stream(&self) -> bool {
+        if self.is_aat {
+            self.coverage & 0x4000 != 0
+        } else {
+            self.coverage & 0x0004 != 0
+        }
+    }
+
+    /// `true` if this subtable contains variation kerning values.
+    ///
+    /// This is always `false` for the OpenType variant of the table.
+    pub fn is_variable(&self) -> bool {
+        self.is_aat && self.coverage & 0x2000 != 0
+    }
+
+    /// `true` if the values in this subtable replace the value accumulated
+    /// so far, rather than being added to it.
+    ///
+    /// This is always `false` for the Apple variant of the table.
+    pub fn is_override(&self) -> bool {
+        !self.is_aat && self.coverage & 0x0008 != 0
+    }
+
+    /// Returns the format-specific contents of this subtable.
+    pub fn kind(&self) -> Result<SubtableKind<'a>, ReadError> {
+        match self.format() {
+            0 => {
+                let body = self
+                    .data
+                    .split_off(self.header_len())
+                    .ok_or(ReadError::OutOfBounds)?;
+                Subtable0::read(body).map(SubtableKind::Format0)
+            }
+            2 => Subtable2::read(self.data, self.header_len()).map(SubtableKind::Format2),
+            other => Err(ReadError::InvalidFormat(other as _)),
+        }
+    }
+
+    /// Returns the kerning value for the given pair of glyphs, if it is
+    /// present in this subtable.
+    pub fn kerning(&self, left: GlyphId, right: GlyphId) -> Option<i32> {
+        match self.kind().ok()? {
+            SubtableKind::Format0(subtable) => subtable.kerning(left, right),
+            SubtableKind::Format2(subtable) => subtable.kerning(left, right),
+        }
+    }
+}
+
+/// The contents of a kerning subtable, by format.
+#[derive(Clone)]
+pub enum SubtableKind<'a> {
+    Format0(Subtable0<'a>),
+    Format2(Subtable2<'a>),
+}
+
+impl<'a> Subtable0<'a> {
+    /// Returns the kerning value for the given pair of glyphs, if present.
+    pub fn kerning(&self, left: GlyphId, right: GlyphId) -> Option<i32> {
+        let key = pair_key(left, right);
+        let pairs = self.pairs();
+        pairs
+            .binary_search_by_key(&key, |pair| pair_key(pair.left(), pair.right()))
+            .ok()
+            .map(|idx| pairs[idx].value().to_i16() as i32)
+    }
+}
+
+fn pair_key(left: GlyphId, right: GlyphId) -> u32 {
+    ((left.to_u16() as u32) << 16) | right.to_u16() as u32
+}
+
+/// A [format 2](https://docs.microsoft.com/en-us/typography/opentype/spec/kern#format-2)
+/// class-based kerning subtable.
+///
+/// All offsets in this subtable are relative to the start of the subtable
+/// header, so this retains the data for the whole subtable.
+#[derive(Clone)]
+pub struct Subtable2<'a> {
+    data: FontData<'a>,
+    header_len: usize,
+}
+
+impl<'a> Subtable2<'a> {
+    fn read(data: FontData<'a>, header_len: usize) -> Result<Self, ReadError> {
+        // ensure the fixed fields are present
+        data.read_at::<u16>(header_len + 6)?;
+        Ok(Subtable2 { data, header_len })
+    }
+
+    /// The width, in bytes, of a row in the kerning value array.
+    pub fn row_width(&self) -> u16 {
+        self.data.read_at(self.header_len).unwrap_or_default()
+    }
+
+    /// Offset from the beginning of the subtable to the left-hand class
+    /// table.
+    pub fn left_class_table_offset(&self) -> Offset16 {
+        Offset16::new(self.data.read_at(self.header_len + 2).unwrap_or_default())
+    }
+
+    /// Offset from the beginning of the subtable to the right-hand class
+    /// table.
+    pub fn right_class_table_offset(&self) -> Offset16 {
+        Offset16::new(self.data.read_at(self.header_len + 4).unwrap_or_default())
+    }
+
+    /// Offset from the beginning of the subtable to the start of the
+    /// kerning value array.
+    pub fn kerning_array_offset(&self) -> Offset16 {
+        Offset16::new(self.data.read_at(self.header_len + 6).unwrap_or_default())
+    }
+
+    /// The left-hand class table.
+    pub fn left_class_table(&self) -> Result<Subtable2ClassTable<'a>, ReadError> {
+        self.left_class_table_offset().resolve(self.data)
+    }
+
+    /// The right-hand class table.
+    pub fn right_class_table(&self) -> Result<Subtable2ClassTable<'a>, ReadError> {
+        self.right_class_table_offset().resolve(self.data)
+    }
+
+    /// Returns the kerning value for the given pair of glyphs, if present.
+    pub fn kerning(&self, left: GlyphId, right: GlyphId) -> Option<i32> {
+        // left class values are pre-multiplied by the row width and include
+        // the offset of the kerning array; right class values are
+        // pre-multiplied by the size of a value.
+        let left_offset = self.left_class_table().ok()?.class_offset(left)? as usize;
+        let right_offset = self
+            .right_class_table()
+            .ok()
+            .and_then(|table| table.class_offset(right))
+            .unwrap_or_default() as usize;
+        let offset = left_offset + right_offset;
+        if offset < self.kerning_array_offset().to_u32() as usize {
+            return None;
+        }
+        self.data
+            .read_at::<i16>(offset)
+            .ok()
+            .map(|value| value as i32)
+    }
+}
+
+impl<'a> Subtable2ClassTable<'a> {
+    /// Returns the class value for the given glyph, if it is covered by this
+    /// table.
+    pub fn class_offset(&self, gid: GlyphId) -> Option<u16> {
+        let idx = gid.to_u16().checked_sub(self.first_glyph().to_u16())?;
+        self.offsets().get(idx as usize).map(|offset| offset.get())
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> traversal::SomeTable<'a> for Kern<'a> {
+    fn type_name(&self) -> &str {
+        "kern"
+    }
+
+    fn get_field(&self, idx: usize) -> Option<traversal::Field<'a>> {
+        match idx {
+            0usize if self.is_aat => Some(traversal::Field::new(
+                "version",
+                Version16Dot16::VERSION_1_0,
+            )),
+            0usize => Some(traversal::Field::new("version", 0u16)),
+            1usize => Some(traversal::Field::new("n_tables", self.n_tables)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for Kern<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn traversal::SomeTable<'a>).fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    fn format0_body(buf: &mut BeBuffer, pairs: &[(u16, u16, i16)]) {
+        buf.extend([pairs.len() as u16, 6, 0, 0]);
+        for (left, right, value) in pairs {
+            buf.extend([*left, *right]);
+            buf.push(*value);
+        }
+    }
+
+    #[test]
+    fn ot_format0() {
+        let pairs = [(1, 2, -50), (1, 5, 20), (3, 2, -10), (7, 1, 40)];
+        let mut buf = BeBuffer::new();
+        // header: version, nTables
+        buf.extend([0u16, 1]);
+        // subtable header: version, length, coverage (format 0, horizontal)
+        buf.extend([0u16, (6 + 8 + pairs.len() * 6) as u16, 0x0001]);
+        format0_body(&mut buf, &pairs);
+
+        let kern = Kern::read(buf.font_data()).unwrap();
+        assert!(!kern.is_aat());
+        assert_eq!(kern.n_tables(), 1);
+        let subtable = kern.subtables().next().unwrap().unwrap();
+        assert_eq!(subtable.format(), 0);
+        assert!(subtable.is_horizontal());
+        for (left, right, value) in pairs {
+            assert_eq!(
+                kern.kerning(GlyphId::new(left), GlyphId::new(right)),
+                value as i32
+            );
+        }
+        assert_eq!(kern.kerning(GlyphId::new(2), GlyphId::new(1)), 0);
+        assert_eq!(kern.kerning(GlyphId::new(1), GlyphId::new(3)), 0);
+    }
+
+    #[test]
+    fn ot_format0_skips_minimum() {
+        let mut buf = BeBuffer::new();
+        buf.extend([0u16, 2]);
+        buf.extend([0u16, 6 + 8 + 6, 0x0001]);
+        format0_body(&mut buf, &[(4, 5, -30)]);
+        // minimum value subtable should be ignored
+        buf.extend([0u16, 6 + 8 + 6, 0x0003]);
+        format0_body(&mut buf, &[(4, 5, -10)]);
+
+        let kern = Kern::read(buf.font_data()).unwrap();
+        let subtables = kern.subtables().collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(subtables.len(), 2);
+        assert!(subtables[1].is_minimum());
+        assert_eq!(kern.kerning(GlyphId::new(4), GlyphId::new(5)), -30);
+    }
+
+    #[test]
+    fn aat_format0_skips_cross_stream() {
+        let mut buf = BeBuffer::new();
+        // header: version 1.0, nTables
+        buf.extend([0x0001_0000u32, 2]);
+        // subtable header: length, coverage, tupleIndex
+        buf.push(8 + 8 + 6u32);
+        buf.extend([0x0000u16, 0]);
+        format0_body(&mut buf, &[(4, 5, -30)]);
+        // cross-stream subtable should be ignored
+        buf.push(8 + 8 + 6u32);
+        buf.extend([0x4000u16, 0]);
+        format0_body(&mut buf, &[(4, 5, 100)]);
+
+        let kern = Kern::read(buf.font_data()).unwrap();
+        assert!(kern.is_aat());
+        let subtables = kern.subtables().collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(subtables.len(), 2);
+        assert!(subtables[1].is_cross_stream());
+        assert_eq!(kern.kerning(GlyphId::new(4), GlyphId::new(5)), -30);
+    }
+
+    #[test]
+    fn ot_format2() {
+        let mut buf = BeBuffer::new();
+        buf.extend([0u16, 1]);
+        // subtable header; length filled in below
+        buf.extend([0u16, 0, 0x0201]);
+        // rowWidth, leftClassTable, rightClassTable, kerningArray
+        // (offsets are from the start of the subtable)
+        buf.extend([4u16, 14, 22, 30]);
+        // left class table at 14: glyphs 10..=11, rows 0 and 1
+        buf.extend([10u16, 2, 30, 34]);
+        // right class table at 22: glyphs 20..=21, columns 0 and 1
+        buf.extend([20u16, 2, 0, 2]);
+        // kerning array at 30
+        buf.extend([-1i16, -2, -3, -4]);
+        let mut data = buf.font_data().as_bytes().to_vec();
+        let len = (data.len() - 4) as u16;
+        data[6..8].copy_from_slice(&len.to_be_bytes());
+
+        let kern = Kern::read(FontData::new(&data)).unwrap();
+        let subtable = kern.subtables().next().unwrap().unwrap();
+        let SubtableKind::Format2(format2) = subtable.kind().unwrap() else {
+            panic!("expected format 2");
+        };
+        assert_eq!(format2.row_width(), 4);
+        let gid = GlyphId::new;
+        assert_eq!(kern.kerning(gid(10), gid(20)), -1);
+        assert_eq!(kern.kerning(gid(10), gid(21)), -2);
+        assert_eq!(kern.kerning(gid(11), gid(20)), -3);
+        assert_eq!(kern.kerning(gid(11), gid(21)), -4);
+        assert_eq!(kern.kerning(gid(9), gid(20)), 0);
+    }
+
+    #[test]
+    fn bad_version() {
+        let mut buf = BeBuffer::new();
+        buf.extend([2u16, 0]);
+        assert!(Kern::read(buf.font_data()).is_err());
+    }
+}
@@ -54,6 +54,22 @@ impl<'a> Loca<'a> {
             .ok_or(ReadError::OutOfBounds)?;
         super::glyf::Glyph::read(data)
     }
+
+    /// Returns the glyph for the given glyph id, or `None` if the glyph
+    /// has no outline data.
+    pub fn get_glyph(
+        &self,
+        gid: GlyphId,
+        glyf: &super::glyf::Glyf<'a>,
+    ) -> Result<Option<super::glyf::Glyph<'a>>, ReadError> {
+        let idx = gid.to_u16() as usize;
+        let start = self.get_raw(idx).ok_or(ReadError::OutOfBounds)?;
+        let end = self.get_raw(idx + 1).ok_or(ReadError::OutOfBounds)?;
+        if start == end {
+            return Ok(None);
+        }
+        self.get_glyf(gid, glyf).map(Some)
+    }
 }
 
 impl ReadArgs for Loca<'_> {
@@ -0,0 +1,214 @@
+//! The [MATH](https://learn.microsoft.com/en-us/typography/opentype/spec/math) table
+
+use crate::layout::{CoverageTable, Device};
+
+/// 'MATH'
+pub const TAG: Tag = Tag::new(b"MATH");
+
+include!("../../generated/generated_math.rs");
+
+impl<'a> MathGlyphInfo<'a> {
+    /// Returns true if the given glyph is an extended shape.
+    pub fn is_extended_shape(&self, glyph_id: GlyphId) -> Result<bool, ReadError> {
+        match self.extended_shape_coverage().transpose()? {
+            Some(coverage) => Ok(coverage.get(glyph_id).is_some()),
+            None => Ok(false),
+        }
+    }
+}
+
+impl<'a> MathItalicsCorrectionInfo<'a> {
+    /// Returns the italics correction for the given glyph, if it has one.
+    pub fn get(&self, glyph_id: GlyphId) -> Result<Option<&'a MathValueRecord>, ReadError> {
+        let coverage = self.italics_correction_coverage()?;
+        Ok(coverage
+            .get(glyph_id)
+            .and_then(|ix| self.italics_correction().get(ix as usize)))
+    }
+}
+
+impl<'a> MathTopAccentAttachment<'a> {
+    /// Returns the top accent attachment point for the given glyph, if it
+    /// has one.
+    pub fn get(&self, glyph_id: GlyphId) -> Result<Option<&'a MathValueRecord>, ReadError> {
+        let coverage = self.top_accent_coverage()?;
+        Ok(coverage
+            .get(glyph_id)
+            .and_then(|ix| self.top_accent_attachment().get(ix as usize)))
+    }
+}
+
+impl<'a> MathKernInfo<'a> {
+    /// Returns the kern info record for the given glyph, if it has one.
+    ///
+    /// The kern tables it references are resolved against this table's data.
+    pub fn get(&self, glyph_id: GlyphId) -> Result<Option<&'a MathKernInfoRecord>, ReadError> {
+        let coverage = self.math_kern_coverage()?;
+        Ok(coverage
+            .get(glyph_id)
+            .and_then(|ix| self.math_kern_info_records().get(ix as usize)))
+    }
+}
+
+impl<'a> MathKern<'a> {
+    /// Returns the kern value that applies at the given height.
+    ///
+    /// The heights divide the vertical axis into `height_count + 1` ranges,
+    /// each with its own kern value.
+    pub fn kern_at_height(&self, height: i16) -> Option<&'a MathValueRecord> {
+        let ix = self
+            .correction_height()
+            .partition_point(|rec| rec.value().to_i16() <= height);
+        self.kern_values().get(ix)
+    }
+}
+
+impl<'a> MathVariants<'a> {
+    /// Returns the construction for the given glyph when growing vertically.
+    pub fn vert_construction(
+        &self,
+        glyph_id: GlyphId,
+    ) -> Result<Option<MathGlyphConstruction<'a>>, ReadError> {
+        let coverage = self.vert_glyph_coverage()?;
+        self.construction(coverage, self.vert_glyph_construction_offsets(), glyph_id)
+    }
+
+    /// Returns the construction for the given glyph when growing
+    /// horizontally.
+    pub fn horiz_construction(
+        &self,
+        glyph_id: GlyphId,
+    ) -> Result<Option<MathGlyphConstruction<'a>>, ReadError> {
+        let coverage = self.horiz_glyph_coverage()?;
+        self.construction(coverage, self.horiz_glyph_construction_offsets(), glyph_id)
+    }
+
+    fn construction(
+        &self,
+        coverage: CoverageTable,
+        offsets: &[BigEndian<Offset16>],
+        glyph_id: GlyphId,
+    ) -> Result<Option<MathGlyphConstruction<'a>>, ReadError> {
+        let offset = match coverage.get(glyph_id) {
+            Some(ix) => offsets.get(ix as usize).ok_or(ReadError::OutOfBounds)?,
+            None => return Ok(None),
+        };
+        offset.get().resolve(self.offset_data()).map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    /// Glyph 5 has an italics correction and a kern table, glyphs 10-12 are
+    /// extended shapes and glyph 7 has a vertical construction with an
+    /// assembly of two parts.
+    fn test_math() -> BeBuffer {
+        let mut buf = BeBuffer::new();
+        buf.extend([1u16, 0, 10, 224, 288]);
+        // math constants, with only the axis height set
+        buf.extend([80i16, 60, 0, 0]);
+        for i in 0..51 {
+            buf.extend([if i == 1 { 250i16 } else { 0 }, 0]);
+        }
+        buf.push(60i16);
+        assert_eq!(buf.len(), 224);
+        // glyph info
+        buf.extend([8u16, 0, 22, 32]);
+        // italics correction info, with its coverage
+        buf.extend([8u16, 1, 30, 0]);
+        buf.extend([1u16, 1, 5]);
+        // extended shape coverage
+        buf.extend([2u16, 1, 10, 12, 0]);
+        assert_eq!(buf.len(), 256);
+        // kern info, with its coverage and a top right kern
+        buf.extend([12u16, 1, 18, 0, 0, 0]);
+        buf.extend([1u16, 1, 5]);
+        buf.push(1u16);
+        buf.extend([100i16, 0, -10, 0, 20, 0]);
+        assert_eq!(buf.len(), 288);
+        // variants, with vertical and horizontal coverage
+        buf.extend([5u16, 12, 18, 1, 0, 22]);
+        buf.extend([1u16, 1, 7]);
+        buf.extend([1u16, 0]);
+        // glyph construction and assembly
+        buf.extend([8u16, 1, 8, 500]);
+        buf.extend([3u16, 0, 2]);
+        buf.extend([9u16, 0, 50, 300, 0]);
+        buf.extend([10u16, 50, 50, 200, 1]);
+        buf
+    }
+
+    #[test]
+    fn constants() {
+        let buf = test_math();
+        let math = Math::read(buf.font_data()).unwrap();
+        let constants = math.math_constants().unwrap();
+        assert_eq!(constants.script_percent_scale_down(), 80);
+        assert_eq!(constants.script_script_percent_scale_down(), 60);
+        assert_eq!(constants.axis_height().value(), FWord::new(250));
+        assert_eq!(constants.math_leading().value(), FWord::new(0));
+        assert_eq!(constants.radical_degree_bottom_raise_percent(), 60);
+    }
+
+    #[test]
+    fn glyph_info() {
+        let buf = test_math();
+        let math = Math::read(buf.font_data()).unwrap();
+        let info = math.math_glyph_info().unwrap();
+        let italics = info.math_italics_correction_info().unwrap().unwrap();
+        let correction = italics.get(GlyphId::new(5)).unwrap().unwrap();
+        assert_eq!(correction.value(), FWord::new(30));
+        assert!(italics.get(GlyphId::new(6)).unwrap().is_none());
+        assert!(info.math_top_accent_attachment().is_none());
+
+        assert!(!info.is_extended_shape(GlyphId::new(9)).unwrap());
+        assert!(info.is_extended_shape(GlyphId::new(11)).unwrap());
+
+        let kern_info = info.math_kern_info().unwrap().unwrap();
+        let record = kern_info.get(GlyphId::new(5)).unwrap().unwrap();
+        assert!(record.top_left_math_kern(kern_info.offset_data()).is_none());
+        let kern = record
+            .top_right_math_kern(kern_info.offset_data())
+            .unwrap()
+            .unwrap();
+        let kern_at = |height| kern.kern_at_height(height).unwrap().value().to_i16();
+        assert_eq!(kern_at(0), -10);
+        assert_eq!(kern_at(100), 20);
+        assert_eq!(kern_at(200), 20);
+    }
+
+    #[test]
+    fn variants() {
+        let buf = test_math();
+        let math = Math::read(buf.font_data()).unwrap();
+        let variants = math.math_variants().unwrap();
+        assert_eq!(variants.min_connector_overlap(), UfWord::new(5));
+        assert!(variants
+            .horiz_construction(GlyphId::new(7))
+            .unwrap()
+            .is_none());
+        let construction = variants
+            .vert_construction(GlyphId::new(7))
+            .unwrap()
+            .unwrap();
+        let variant = &construction.math_glyph_variant_records()[0];
+        assert_eq!(variant.variant_glyph(), GlyphId::new(8));
+        assert_eq!(variant.advance_measurement(), UfWord::new(500));
+
+        let assembly = construction.glyph_assembly().unwrap().unwrap();
+        assert_eq!(assembly.italics_correction().value(), FWord::new(3));
+        let parts = assembly.part_records();
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0].full_advance(), UfWord::new(300));
+        assert!(!parts[0]
+            .part_flags()
+            .contains(GlyphPartFlags::EXTENDER_FLAG));
+        assert_eq!(parts[1].glyph_id(), GlyphId::new(10));
+        assert!(parts[1]
+            .part_flags()
+            .contains(GlyphPartFlags::EXTENDER_FLAG));
+    }
+}
@@ -0,0 +1,146 @@
+//! The [MVAR (Metrics Variations)](https://docs.microsoft.com/en-us/typography/opentype/spec/mvar) table
+
+/// 'MVAR'
+pub const TAG: Tag = Tag::new(b"MVAR");
+
+include!("../../generated/generated_mvar.rs");
+
+use crate::layout::ItemVariationStore;
+
+/// [Value tags](https://learn.microsoft.com/en-us/typography/opentype/spec/mvar#value-tags)
+/// identifying the font-wide metrics that may be varied.
+pub mod tags {
+    use font_types::Tag;
+
+    /// Horizontal ascender: `OS/2.sTypoAscender`
+    pub const HASC: Tag = Tag::new(b"hasc");
+    /// Horizontal descender: `OS/2.sTypoDescender`
+    pub const HDSC: Tag = Tag::new(b"hdsc");
+    /// Horizontal line gap: `OS/2.sTypoLineGap`
+    pub const HLGP: Tag = Tag::new(b"hlgp");
+    /// Horizontal clipping ascent: `OS/2.usWinAscent`
+    pub const HCLA: Tag = Tag::new(b"hcla");
+    /// Horizontal clipping descent: `OS/2.usWinDescent`
+    pub const HCLD: Tag = Tag::new(b"hcld");
+    /// Vertical ascender: `vhea.ascent`
+    pub const VASC: Tag = Tag::new(b"vasc");
+    /// Vertical descender: `vhea.descent`
+    pub const VDSC: Tag = Tag::new(b"vdsc");
+    /// Vertical line gap: `vhea.lineGap`
+    pub const VLGP: Tag = Tag::new(b"vlgp");
+    /// Horizontal caret rise: `hhea.caretSlopeRise`
+    pub const HCRS: Tag = Tag::new(b"hcrs");
+    /// Horizontal caret run: `hhea.caretSlopeRun`
+    pub const HCRN: Tag = Tag::new(b"hcrn");
+    /// Horizontal caret offset: `hhea.caretOffset`
+    pub const HCOF: Tag = Tag::new(b"hcof");
+    /// Vertical caret rise: `vhea.caretSlopeRise`
+    pub const VCRS: Tag = Tag::new(b"vcrs");
+    /// Vertical caret run: `vhea.caretSlopeRun`
+    pub const VCRN: Tag = Tag::new(b"vcrn");
+    /// Vertical caret offset: `vhea.caretOffset`
+    pub const VCOF: Tag = Tag::new(b"vcof");
+    /// X height: `OS/2.sxHeight`
+    pub const XHGT: Tag = Tag::new(b"xhgt");
+    /// Cap height: `OS/2.sCapHeight`
+    pub const CPHT: Tag = Tag::new(b"cpht");
+    /// Subscript em x size: `OS/2.ySubscriptXSize`
+    pub const SBXS: Tag = Tag::new(b"sbxs");
+    /// Subscript em y size: `OS/2.ySubscriptYSize`
+    pub const SBYS: Tag = Tag::new(b"sbys");
+    /// Subscript em x offset: `OS/2.ySubscriptXOffset`
+    pub const SBXO: Tag = Tag::new(b"sbxo");
+    /// Subscript em y offset: `OS/2.ySubscriptYOffset`
+    pub const SBYO: Tag = Tag::new(b"sbyo");
+    /// Superscript em x size: `OS/2.ySuperscriptXSize`
+    pub const SPXS: Tag = Tag::new(b"spxs");
+    /// Superscript em y size: `OS/2.ySuperscriptYSize`
+    pub const SPYS: Tag = Tag::new(b"spys");
+    /// Superscript em x offset: `OS/2.ySuperscriptXOffset`
+    pub const SPXO: Tag = Tag::new(b"spxo");
+    /// Superscript em y offset: `OS/2.ySuperscriptYOffset`
+    pub const SPYO: Tag = Tag::new(b"spyo");
+    /// Strikeout size: `OS/2.yStrikeoutSize`
+    pub const STRS: Tag = Tag::new(b"strs");
+    /// Strikeout offset: `OS/2.yStrikeoutPosition`
+    pub const STRO: Tag = Tag::new(b"stro");
+    /// Underline size: `post.underlineThickness`
+    pub const UNDS: Tag = Tag::new(b"unds");
+    /// Underline offset: `post.underlinePosition`
+    pub const UNDO: Tag = Tag::new(b"undo");
+    /// Gasp range maximum ppem values: `gasp.gaspRanges[0..=9].rangeMaxPPEM`
+    pub const GSP: [Tag; 10] = [
+        Tag::new(b"gsp0"),
+        Tag::new(b"gsp1"),
+        Tag::new(b"gsp2"),
+        Tag::new(b"gsp3"),
+        Tag::new(b"gsp4"),
+        Tag::new(b"gsp5"),
+        Tag::new(b"gsp6"),
+        Tag::new(b"gsp7"),
+        Tag::new(b"gsp8"),
+        Tag::new(b"gsp9"),
+    ];
+}
+
+impl<'a> Mvar<'a> {
+    /// Returns the value record for the metric with the given tag.
+    pub fn value_record(&self, tag: Tag) -> Option<&'a ValueRecord> {
+        let records = self.value_records();
+        records
+            .binary_search_by_key(&tag, |rec| rec.value_tag())
+            .ok()
+            .and_then(|idx| records.get(idx))
+    }
+
+    /// Returns the delta for the metric with the given tag at the given
+    /// normalized coordinates, in font units.
+    ///
+    /// Returns zero if the metric is not varied.
+    pub fn metric_delta(&self, tag: Tag, coords: &[F2Dot14]) -> Result<Fixed, ReadError> {
+        let Some(record) = self.value_record(tag) else {
+            return Ok(Fixed::ZERO);
+        };
+        let Some(store) = self.item_variation_store() else {
+            return Ok(Fixed::ZERO);
+        };
+        store?.compute_delta(
+            record.delta_set_outer_index(),
+            record.delta_set_inner_index(),
+            coords,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    #[test]
+    fn metric_deltas() {
+        let mut buf = BeBuffer::new();
+        buf.extend([1u16, 0, 0, 8, 2]);
+        buf.push(28u16); // item variation store offset
+        buf.push(tags::HASC);
+        buf.extend([0u16, 1]);
+        buf.push(tags::XHGT);
+        buf.extend([0u16, 0]);
+        // item variation store: one axis, one region peaking at 1.0
+        buf.push(1u16);
+        buf.push(12u32); // region list offset
+        buf.push(1u16);
+        buf.push(22u32); // item variation data offset
+        buf.extend([1u16, 1]);
+        buf.extend([F2Dot14::ZERO, F2Dot14::ONE, F2Dot14::ONE]);
+        buf.extend([2u16, 0, 1, 0]);
+        buf.extend([40i8, -100]);
+
+        let mvar = Mvar::read(buf.font_data()).unwrap();
+        let coords = [F2Dot14::from_f32(0.5)];
+        let delta = |tag| mvar.metric_delta(tag, &coords).unwrap();
+        assert_eq!(delta(tags::HASC), Fixed::from_i32(-50));
+        assert_eq!(delta(tags::XHGT), Fixed::from_i32(20));
+        assert_eq!(delta(tags::CPHT), Fixed::ZERO);
+    }
+}
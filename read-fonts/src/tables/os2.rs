@@ -0,0 +1,58 @@
+//! The [OS/2](https://docs.microsoft.com/en-us/typography/opentype/spec/os2) table
+
+/// 'OS/2'
+pub const TAG: Tag = Tag::new(b"OS/2");
+
+include!("../../generated/generated_os2.rs");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    /// The fields common to all versions, with a weight class of 700.
+    fn version_0_fields(version: u16) -> BeBuffer {
+        let mut buf = BeBuffer::new();
+        buf.extend([version, 500, 700, 5]);
+        buf.extend([0u16; 12]);
+        buf.extend([0u8; 10]); // panose
+        buf.extend([0u32; 4]);
+        buf.push(Tag::new(b"NONE"));
+        buf.push(SelectionFlags::BOLD.bits());
+        buf.extend([0x20u16, 0x7e]);
+        buf.extend([800i16, -200, 0]);
+        buf.extend([900u16, 250]);
+        buf
+    }
+
+    #[test]
+    fn version_0() {
+        let buf = version_0_fields(0);
+        let os2 = Os2::read(buf.font_data()).unwrap();
+        assert_eq!(os2.us_weight_class(), 700);
+        assert_eq!(os2.ach_vend_id(), Tag::new(b"NONE"));
+        assert!(os2.fs_selection().contains(SelectionFlags::BOLD));
+        assert_eq!(os2.us_win_descent(), 250);
+        assert_eq!(os2.ul_code_page_range_1(), None);
+        assert_eq!(os2.sx_height(), None);
+    }
+
+    #[test]
+    fn version_5() {
+        let mut buf = version_0_fields(5);
+        buf.extend([1u32, 0]);
+        buf.extend([500i16, 700]);
+        buf.extend([0u16, 0x20, 2]);
+        buf.extend([120u16, 480]);
+        let os2 = Os2::read(buf.font_data()).unwrap();
+        assert_eq!(os2.ul_code_page_range_1(), Some(1));
+        assert_eq!(os2.s_cap_height(), Some(700));
+        assert_eq!(os2.us_max_context(), Some(2));
+        assert_eq!(os2.us_lower_optical_point_size(), Some(120));
+        assert_eq!(os2.us_upper_optical_point_size(), Some(480));
+
+        // a version 5 table must include the version 5 fields
+        let buf = version_0_fields(5);
+        assert!(Os2::read(buf.font_data()).is_err());
+    }
+}
@@ -0,0 +1,6 @@
+//! The [prep (Control Value Program)](https://learn.microsoft.com/en-us/typography/opentype/spec/prep) table
+
+/// 'prep'
+pub const TAG: Tag = Tag::new(b"prep");
+
+include!("../../generated/generated_prep.rs");
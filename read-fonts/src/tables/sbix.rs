@@ -0,0 +1,27 @@
+//! The [sbix](https://learn.microsoft.com/en-us/typography/opentype/spec/sbix) table
+
+/// 'sbix'
+pub const TAG: Tag = Tag::new(b"sbix");
+
+include!("../../generated/generated_sbix.rs");
+
+impl<'a> Strike<'a> {
+    /// Returns the glyph data for the given glyph, or `None` if the strike
+    /// has no data for it.
+    pub fn glyph_data(&self, glyph_id: GlyphId) -> Result<Option<GlyphData<'a>>, ReadError> {
+        let offsets = self.glyph_data_offsets();
+        let ix = glyph_id.to_u16() as usize;
+        let (start, end) = match (offsets.get(ix), offsets.get(ix + 1)) {
+            (Some(start), Some(end)) => (start.get() as usize, end.get() as usize),
+            _ => return Err(ReadError::OutOfBounds),
+        };
+        if start >= end {
+            return Ok(None);
+        }
+        let data = self
+            .offset_data()
+            .slice(start..end)
+            .ok_or(ReadError::OutOfBounds)?;
+        GlyphData::read(data).map(Some)
+    }
+}
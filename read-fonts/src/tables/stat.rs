@@ -0,0 +1,92 @@
+//! The [STAT](https://docs.microsoft.com/en-us/typography/opentype/spec/stat) table
+
+/// 'STAT'
+pub const TAG: Tag = Tag::new(b"STAT");
+
+include!("../../generated/generated_stat.rs");
+
+impl<'a> AxisValue<'a> {
+    /// The flags for this axis value.
+    pub fn flags(&self) -> AxisValueTableFlags {
+        match self {
+            Self::Format1(table) => table.flags(),
+            Self::Format2(table) => table.flags(),
+            Self::Format3(table) => table.flags(),
+            Self::Format4(table) => table.flags(),
+        }
+    }
+
+    /// The name ID for entries in the 'name' table that provide a display
+    /// string for this axis value.
+    pub fn value_name_id(&self) -> u16 {
+        match self {
+            Self::Format1(table) => table.value_name_id(),
+            Self::Format2(table) => table.value_name_id(),
+            Self::Format3(table) => table.value_name_id(),
+            Self::Format4(table) => table.value_name_id(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    #[test]
+    fn smoke_test() {
+        let mut buf = BeBuffer::new();
+        buf.extend([1u16, 2, 8, 2]); // version, axis size, axis count
+        buf.push(20u32); // design axes offset
+        buf.push(2u16); // axis value count
+        buf.push(36u32); // axis value array offset
+        buf.push(2u16); // elided fallback name id
+
+        // design axes
+        buf.push(Tag::new(b"wght"));
+        buf.extend([256u16, 0]);
+        buf.push(Tag::new(b"ital"));
+        buf.extend([257u16, 1]);
+
+        // axis value offsets
+        buf.extend([4u16, 16]);
+
+        // format 1
+        buf.extend([1u16, 0, 0x2, 258]);
+        buf.push(Fixed::from_i32(400));
+
+        // format 4
+        buf.extend([4u16, 2, 0, 259]);
+        buf.push(0u16);
+        buf.push(Fixed::from_i32(700));
+        buf.push(1u16);
+        buf.push(Fixed::ONE);
+
+        let stat = Stat::read(buf.font_data()).unwrap();
+        assert_eq!(stat.version(), MajorMinor::VERSION_1_2);
+        assert_eq!(stat.elided_fallback_name_id(), Some(2));
+        let axes = stat.design_axes().unwrap().unwrap();
+        let tags = axes
+            .axis_records()
+            .iter()
+            .map(|rec| rec.axis_tag())
+            .collect::<Vec<_>>();
+        assert_eq!(tags, [Tag::new(b"wght"), Tag::new(b"ital")]);
+
+        let values = stat.axis_value_array().unwrap().unwrap();
+        let values = values.axis_value().collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(values.len(), 2);
+        let AxisValue::Format1(first) = &values[0] else {
+            panic!("wrong format");
+        };
+        assert_eq!(first.value(), Fixed::from_i32(400));
+        assert!(values[0]
+            .flags()
+            .contains(AxisValueTableFlags::ELIDABLE_AXIS_VALUE_NAME));
+        let AxisValue::Format4(second) = &values[1] else {
+            panic!("wrong format");
+        };
+        assert_eq!(second.axis_values().len(), 2);
+        assert_eq!(values[1].value_name_id(), 259);
+    }
+}
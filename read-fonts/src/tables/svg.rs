@@ -0,0 +1,99 @@
+//! The [SVG](https://learn.microsoft.com/en-us/typography/opentype/spec/svg) table
+
+use core::ops::RangeInclusive;
+
+/// 'SVG '
+pub const TAG: Tag = Tag::new(b"SVG");
+
+include!("../../generated/generated_svg.rs");
+
+/// The magic bytes at the start of a gzip stream.
+const GZIP_MAGIC: [u8; 2] = [0x1F, 0x8B];
+
+/// An SVG document, along with the range of glyphs it covers.
+#[derive(Clone, Debug)]
+pub struct SvgDocument<'a> {
+    /// The raw document data, which may be gzip compressed.
+    pub data: &'a [u8],
+    /// The range of glyphs whose outlines are defined by the document.
+    pub glyph_range: RangeInclusive<GlyphId>,
+}
+
+impl SvgDocument<'_> {
+    /// Returns true if the document data is gzip compressed.
+    pub fn is_compressed(&self) -> bool {
+        self.data.starts_with(&GZIP_MAGIC)
+    }
+}
+
+impl<'a> Svg<'a> {
+    /// Returns the SVG document containing the given glyph, or `None` if the
+    /// table has no document for it.
+    pub fn svg_document_for_glyph(
+        &self,
+        glyph_id: GlyphId,
+    ) -> Result<Option<SvgDocument<'a>>, ReadError> {
+        let list = self.svg_document_list()?;
+        let records = list.document_records();
+        // records are sorted and non-overlapping
+        let ix = records.partition_point(|rec| rec.end_glyph_id() < glyph_id);
+        let record = match records.get(ix) {
+            Some(record) if record.start_glyph_id() <= glyph_id => record,
+            _ => return Ok(None),
+        };
+        let start = record.svg_doc_offset() as usize;
+        let end = start.saturating_add(record.svg_doc_length() as usize);
+        let data = list
+            .offset_data()
+            .slice(start..end)
+            .ok_or(ReadError::OutOfBounds)?;
+        Ok(Some(SvgDocument {
+            data: data.as_bytes(),
+            glyph_range: record.start_glyph_id()..=record.end_glyph_id(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    #[test]
+    fn document_lookup() {
+        let mut buf = BeBuffer::new();
+        buf.push(0u16);
+        buf.extend([10u32, 0]);
+        // document list with a plain document for glyphs 2-4 and a gzipped
+        // one for glyph 7
+        buf.push(2u16);
+        buf.extend([2u16, 4]);
+        buf.extend([26u32, 5]);
+        buf.extend([7u16, 7]);
+        buf.extend([31u32, 3]);
+        buf.extend(*b"<svg>");
+        buf.extend([0x1Fu8, 0x8B, 0x08]);
+
+        let svg = Svg::read(buf.font_data()).unwrap();
+        for gid in 2..=4 {
+            let doc = svg
+                .svg_document_for_glyph(GlyphId::new(gid))
+                .unwrap()
+                .unwrap();
+            assert_eq!(doc.data, b"<svg>");
+            assert_eq!(doc.glyph_range, GlyphId::new(2)..=GlyphId::new(4));
+            assert!(!doc.is_compressed());
+        }
+        let doc = svg
+            .svg_document_for_glyph(GlyphId::new(7))
+            .unwrap()
+            .unwrap();
+        assert!(doc.is_compressed());
+        for gid in [0, 1, 5, 6, 8] {
+            assert!(svg
+                .svg_document_for_glyph(GlyphId::new(gid))
+                .unwrap()
+                .is_none());
+        }
+    }
+}
@@ -0,0 +1,8 @@
+//! the [vhea (Vertical Header)](https://docs.microsoft.com/en-us/typography/opentype/spec/vhea) table
+
+use font_types::Tag;
+
+/// 'vhea'
+pub const TAG: Tag = Tag::new(b"vhea");
+
+include!("../../generated/generated_vhea.rs");
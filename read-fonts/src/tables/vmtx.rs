@@ -0,0 +1,60 @@
+//! The [vmtx (Vertical Metrics)](https://docs.microsoft.com/en-us/typography/opentype/spec/vmtx) table
+
+use font_types::Tag;
+
+/// 'vmtx'
+pub const TAG: Tag = Tag::new(b"vmtx");
+
+include!("../../generated/generated_vmtx.rs");
+
+impl<'a> Vmtx<'a> {
+    /// Returns the advance height for the given glyph.
+    ///
+    /// Glyphs beyond the end of the `v_metrics` array share the advance of
+    /// the last entry.
+    pub fn advance(&self, gid: GlyphId) -> Option<u16> {
+        let metrics = self.v_metrics();
+        metrics
+            .get(gid.to_u16() as usize)
+            .or_else(|| metrics.last())
+            .map(|metric| metric.advance())
+    }
+
+    /// Returns the top side bearing for the given glyph.
+    pub fn side_bearing(&self, gid: GlyphId) -> Option<i16> {
+        let idx = gid.to_u16() as usize;
+        let metrics = self.v_metrics();
+        match metrics.get(idx) {
+            Some(metric) => Some(metric.side_bearing()),
+            None => self
+                .top_side_bearings()
+                .get(idx - metrics.len())
+                .map(|tsb| tsb.get()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    #[test]
+    fn metrics() {
+        let mut buf = BeBuffer::new();
+        buf.extend([1000u16, 80, 900, 120]);
+        buf.extend([40i16, 60]);
+        let vmtx = Vmtx::read_with_args(buf.font_data(), &(2, 4)).unwrap();
+        let advances = (0..5)
+            .map(|gid| vmtx.advance(GlyphId::new(gid)))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            advances,
+            [Some(1000), Some(900), Some(900), Some(900), Some(900)]
+        );
+        let tsbs = (0..5)
+            .map(|gid| vmtx.side_bearing(GlyphId::new(gid)))
+            .collect::<Vec<_>>();
+        assert_eq!(tsbs, [Some(80), Some(120), Some(40), Some(60), None]);
+    }
+}
@@ -0,0 +1,44 @@
+//! The [VORG (Vertical Origin)](https://docs.microsoft.com/en-us/typography/opentype/spec/vorg) table.
+
+/// 'VORG'
+pub const TAG: Tag = Tag::new(b"VORG");
+
+include!("../../generated/generated_vorg.rs");
+
+impl<'a> Vorg<'a> {
+    /// Returns the y coordinate of the vertical origin for the given glyph.
+    ///
+    /// Glyphs without an explicit entry use the default vertical origin.
+    pub fn vertical_origin_y(&self, gid: GlyphId) -> i16 {
+        let metrics = self.vert_origin_y_metrics();
+        match metrics.binary_search_by_key(&gid, |rec| rec.glyph_id()) {
+            Ok(idx) => metrics[idx].vert_origin_y(),
+            Err(_) => self.default_vert_origin_y(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    #[test]
+    fn vertical_origins() {
+        let mut buf = BeBuffer::new();
+        buf.extend([1u16, 0]);
+        buf.push(880i16);
+        buf.push(2u16);
+        buf.push(3u16);
+        buf.push(900i16);
+        buf.push(7u16);
+        buf.push(-20i16);
+        let vorg = Vorg::read(buf.font_data()).unwrap();
+        let origin = |gid| vorg.vertical_origin_y(GlyphId::new(gid));
+        assert_eq!(origin(0), 880);
+        assert_eq!(origin(3), 900);
+        assert_eq!(origin(5), 880);
+        assert_eq!(origin(7), -20);
+        assert_eq!(origin(8), 880);
+    }
+}
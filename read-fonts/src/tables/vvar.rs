@@ -0,0 +1,63 @@
+//! The [VVAR (Vertical Metrics Variations)](https://docs.microsoft.com/en-us/typography/opentype/spec/vvar) table
+
+/// 'VVAR'
+pub const TAG: Tag = Tag::new(b"VVAR");
+
+include!("../../generated/generated_vvar.rs");
+
+use crate::layout::{DeltaSetIndexMap, ItemVariationStore};
+
+impl<'a> Vvar<'a> {
+    /// Returns the advance height delta for the given glyph at the given
+    /// normalized coordinates, in font units.
+    ///
+    /// If the table has no advance height mapping, the glyph id is used as
+    /// the index into the first item variation data subtable.
+    pub fn advance_height_delta(
+        &self,
+        gid: GlyphId,
+        coords: &[F2Dot14],
+    ) -> Result<Fixed, ReadError> {
+        let map = self.advance_height_mapping().transpose()?;
+        self.item_variation_store()?
+            .compute_mapped_delta(map.as_ref(), gid.to_u16() as u32, coords)
+    }
+
+    /// Returns the top side bearing delta for the given glyph at the given
+    /// normalized coordinates, or `None` if the table has no top side
+    /// bearing mapping.
+    pub fn tsb_delta(&self, gid: GlyphId, coords: &[F2Dot14]) -> Option<Result<Fixed, ReadError>> {
+        let map = self.tsb_mapping()?;
+        Some(self.mapped_delta(map, gid, coords))
+    }
+
+    /// Returns the bottom side bearing delta for the given glyph at the given
+    /// normalized coordinates, or `None` if the table has no bottom side
+    /// bearing mapping.
+    pub fn bsb_delta(&self, gid: GlyphId, coords: &[F2Dot14]) -> Option<Result<Fixed, ReadError>> {
+        let map = self.bsb_mapping()?;
+        Some(self.mapped_delta(map, gid, coords))
+    }
+
+    /// Returns the vertical origin delta for the given glyph at the given
+    /// normalized coordinates, or `None` if the table has no vertical
+    /// origin mapping.
+    pub fn v_org_delta(
+        &self,
+        gid: GlyphId,
+        coords: &[F2Dot14],
+    ) -> Option<Result<Fixed, ReadError>> {
+        let map = self.v_org_mapping()?;
+        Some(self.mapped_delta(map, gid, coords))
+    }
+
+    fn mapped_delta(
+        &self,
+        map: Result<DeltaSetIndexMap, ReadError>,
+        gid: GlyphId,
+        coords: &[F2Dot14],
+    ) -> Result<Fixed, ReadError> {
+        self.item_variation_store()?
+            .compute_mapped_delta(Some(&map?), gid.to_u16() as u32, coords)
+    }
+}
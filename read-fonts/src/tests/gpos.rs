@@ -194,7 +194,7 @@ fn sequencelookuprecord() {
 //FIXME: turn this back on when we support device records
 //#[test]
 //fn valueformattable() {
-//// https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#example-14-valueformat-table-and-valuerecord
+// https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#example-14-valueformat-table-and-valuerecord
 
 //#[rustfmt::skip]
 //let bytes = [
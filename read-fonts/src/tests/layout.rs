@@ -39,3 +39,116 @@ fn example_3_featurelist_and_feature() {
     assert!(feature.feature_params_offset().is_null());
     assert_eq!(feature.lookup_list_indices().len(), 1);
 }
+
+fn make_item_variation_store() -> crate::test_helpers::BeBuffer {
+    let mut buf = crate::test_helpers::BeBuffer::new();
+    // header
+    buf.push(1u16);
+    buf.push(16u32);
+    buf.push(2u16);
+    buf.extend([32u32, 48]);
+    // region list: one axis, two regions
+    buf.extend([1u16, 2]);
+    buf.extend([0.0, 1.0, 1.0, -1.0, -1.0, 0.0].map(F2Dot14::from_f32));
+    // item variation data 0: one word delta, two regions
+    buf.extend([2u16, 1, 2, 0, 1]);
+    buf.push(100i16);
+    buf.push(-10i8);
+    buf.push(-300i16);
+    buf.push(20i8);
+    // item variation data 1: long deltas
+    buf.extend([1u16, 0x8001, 1, 0]);
+    buf.push(70_000i32);
+    buf
+}
+
+#[test]
+fn item_variation_store() {
+    let buf = make_item_variation_store();
+    let store = ItemVariationStore::read(buf.font_data()).unwrap();
+    let regions = store.variation_region_list().unwrap();
+    assert_eq!(regions.axis_count(), 1);
+    assert_eq!(regions.region_count(), 2);
+    let data = store
+        .item_variation_data()
+        .next()
+        .unwrap()
+        .unwrap()
+        .unwrap();
+    assert_eq!(data.item_count(), 2);
+    assert_eq!(data.delta_set(1).unwrap().collect::<Vec<_>>(), [-300, 20]);
+    assert!(data.delta_set(2).is_err());
+}
+
+#[test]
+fn item_variation_store_compute_delta() {
+    let buf = make_item_variation_store();
+    let store = ItemVariationStore::read(buf.font_data()).unwrap();
+    let delta = |outer, inner, coord: f32| {
+        store
+            .compute_delta(outer, inner, &[F2Dot14::from_f32(coord)])
+            .unwrap()
+            .to_f64()
+    };
+    assert_eq!(delta(0, 0, 0.0), 0.0);
+    assert_eq!(delta(0, 0, 0.5), 50.0);
+    assert_eq!(delta(0, 0, -0.5), -5.0);
+    assert_eq!(delta(0, 1, 1.0), -300.0);
+    assert_eq!(delta(0, 1, -1.0), 20.0);
+    // 32-bit deltas
+    assert_eq!(delta(1, 0, 0.25), 17_500.0);
+    assert!(store.compute_delta(2, 0, &[]).is_err());
+}
+
+#[test]
+fn delta_set_index_map() {
+    let mut buf = crate::test_helpers::BeBuffer::new();
+    // format 0, 2-byte entries, 5 bits of inner index
+    buf.extend([0u8, 0x14]);
+    buf.push(3u16);
+    buf.extend([1u16, 32, 33]);
+    let map = DeltaSetIndexMap::read(buf.font_data()).unwrap();
+    let get = |idx| {
+        let index = map.get(idx).unwrap();
+        (index.outer, index.inner)
+    };
+    assert_eq!(get(0), (0, 1));
+    assert_eq!(get(1), (1, 0));
+    assert_eq!(get(2), (1, 1));
+    // out of range uses the last entry
+    assert_eq!(get(100), (1, 1));
+
+    let mut buf = crate::test_helpers::BeBuffer::new();
+    // format 1, 1-byte entries, 8 bits of inner index
+    buf.extend([1u8, 0x07]);
+    buf.push(2u32);
+    buf.extend([7u8, 9]);
+    let map = DeltaSetIndexMap::read(buf.font_data()).unwrap();
+    assert_eq!(map.get(1).unwrap(), DeltaSetIndex { outer: 0, inner: 9 });
+
+    // an empty map has no entries to use
+    let mut buf = crate::test_helpers::BeBuffer::new();
+    buf.extend([0u8, 0x00]);
+    buf.push(0u16);
+    let map = DeltaSetIndexMap::read(buf.font_data()).unwrap();
+    assert!(map.get(0).is_err());
+}
+
+#[test]
+fn coverage_index() {
+    let mut buf = crate::test_helpers::BeBuffer::new();
+    buf.extend([1u16, 3, 2, 5, 6]);
+    let format1 = CoverageTable::read(buf.font_data()).unwrap();
+    let mut buf = crate::test_helpers::BeBuffer::new();
+    buf.extend([2u16, 2, 2, 2, 0, 5, 6, 1]);
+    let format2 = CoverageTable::read(buf.font_data()).unwrap();
+    for coverage in [format1, format2] {
+        let get = |gid| coverage.get(GlyphId::new(gid));
+        assert_eq!(get(2), Some(0));
+        assert_eq!(get(5), Some(1));
+        assert_eq!(get(6), Some(2));
+        assert_eq!(get(1), None);
+        assert_eq!(get(3), None);
+        assert_eq!(get(7), None);
+    }
+}
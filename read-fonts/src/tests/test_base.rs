@@ -0,0 +1,91 @@
+use font_types::MajorMinor;
+
+use super::*;
+use crate::test_helpers::BeBuffer;
+
+/// A version 1.0 table with a horizontal axis that has `ideo` and `romn`
+/// baselines for `latn` and `DFLT`, and a MinMax for `latn`.
+fn test_base() -> BeBuffer {
+    let mut buf = BeBuffer::new();
+    buf.push(MajorMinor::VERSION_1_0);
+    buf.extend([8u16, 0]);
+    // axis
+    buf.extend([4u16, 14]);
+    // tag list
+    buf.push(2u16);
+    buf.extend([Tag::new(b"ideo"), Tag::new(b"romn")]);
+    assert_eq!(buf.len(), 22);
+    // script list
+    buf.push(2u16);
+    buf.push(Tag::new(b"DFLT"));
+    buf.push(14u16);
+    buf.push(Tag::new(b"latn"));
+    buf.push(36u16);
+    assert_eq!(buf.len(), 36);
+    // DFLT base script, with its base values and coords
+    buf.extend([6u16, 0, 0]);
+    buf.extend([1u16, 2, 8, 12]);
+    buf.extend([1u16, 0xFF88, 1, 0]);
+    assert_eq!(buf.len(), 58);
+    // latn base script, with values, a default MinMax and a MinMax for
+    // `dflt`
+    buf.extend([12u16, 34, 1]);
+    buf.push(Tag::new(b"dflt"));
+    buf.push(34u16);
+    buf.extend([1u16, 2, 8, 16]);
+    buf.extend([2u16, 0xFF98, 10, 3]);
+    buf.extend([3u16, 0, 0]);
+    // MinMax with a min coord and a feature record
+    buf.extend([14u16, 0, 1]);
+    buf.push(Tag::new(b"kern"));
+    buf.extend([0u16, 14]);
+    buf.extend([1u16, 0xFF00]);
+    buf
+}
+
+#[test]
+fn baselines() {
+    let buf = test_base();
+    let base = Base::read(buf.font_data()).unwrap();
+    assert_eq!(base.version(), MajorMinor::VERSION_1_0);
+    assert!(base.vert_axis().is_none());
+    assert!(base.item_var_store_offset().is_none());
+    let axis = base.horiz_axis().unwrap().unwrap();
+    let baseline = |script: &[u8], baseline: &[u8]| {
+        axis.baseline(Tag::new(script), Tag::new(baseline))
+            .unwrap()
+            .map(|coord| coord.coordinate())
+    };
+    assert_eq!(baseline(b"DFLT", b"ideo"), Some(-120));
+    assert_eq!(baseline(b"DFLT", b"romn"), Some(0));
+    assert_eq!(baseline(b"latn", b"ideo"), Some(-104));
+    assert_eq!(baseline(b"latn", b"romn"), Some(0));
+    // falls back to DFLT
+    assert_eq!(baseline(b"grek", b"ideo"), Some(-120));
+    assert_eq!(baseline(b"latn", b"hang"), None);
+
+    let latn = axis.base_script(Tag::new(b"latn")).unwrap().unwrap();
+    let values = latn.base_values().unwrap().unwrap();
+    assert_eq!(values.default_baseline_index(), 1);
+    let Some(Ok(BaseCoord::Format2(coord))) = values.base_coord().next() else {
+        panic!("expected a format 2 base coord");
+    };
+    assert_eq!((coord.reference_glyph(), coord.base_coord_point()), (10, 3));
+    let Some(Ok(BaseCoord::Format3(coord))) = values.base_coord().nth(1) else {
+        panic!("expected a format 3 base coord");
+    };
+    assert!(coord.device().is_none());
+
+    let min_max = latn.default_min_max().unwrap().unwrap();
+    assert_eq!(min_max.min_coord().unwrap().unwrap().coordinate(), -256);
+    assert!(min_max.max_coord().is_none());
+    let record = &min_max.feat_min_max_records()[0];
+    assert_eq!(record.feature_table_tag(), Tag::new(b"kern"));
+    assert!(record.min_coord(min_max.offset_data()).is_none());
+    let max = record.max_coord(min_max.offset_data()).unwrap().unwrap();
+    assert_eq!(max.coordinate(), -256);
+    let lang_sys = &latn.base_lang_sys_records()[0];
+    assert_eq!(lang_sys.base_lang_sys_tag(), Tag::new(b"dflt"));
+    let lang_min_max = lang_sys.min_max(latn.offset_data()).unwrap();
+    assert_eq!(lang_min_max.feat_min_max_count(), 1);
+}
@@ -23,7 +23,7 @@ impl BeBuffer {
         }
     }
 
-    pub fn font_data(&self) -> FontData {
+    pub fn font_data(&self) -> FontData<'_> {
         FontData::new(&self.0)
     }
 }
@@ -34,3 +34,44 @@ impl std::ops::Deref for BeBuffer {
         &self.0
     }
 }
+
+/// Builds a CFF INDEX with one byte offsets.
+pub fn cff_index(objects: &[&[u8]]) -> Vec<u8> {
+    index_with_count(&(objects.len() as u16).to_be_bytes(), objects)
+}
+
+/// Builds a CFF2 INDEX with one byte offsets.
+pub fn cff2_index(objects: &[&[u8]]) -> Vec<u8> {
+    index_with_count(&(objects.len() as u32).to_be_bytes(), objects)
+}
+
+fn index_with_count(count: &[u8], objects: &[&[u8]]) -> Vec<u8> {
+    let mut buf = count.to_vec();
+    if objects.is_empty() {
+        return buf;
+    }
+    buf.push(1);
+    let mut offset = 1;
+    buf.push(offset);
+    for object in objects {
+        offset += object.len() as u8;
+        buf.push(offset);
+    }
+    objects
+        .iter()
+        .for_each(|object| buf.extend_from_slice(object));
+    buf
+}
+
+/// Encodes a DICT integer operand in five bytes.
+pub fn dict_int(value: i32) -> Vec<u8> {
+    let mut buf = vec![29];
+    buf.extend(value.to_be_bytes());
+    buf
+}
+
+/// Encodes a small charstring integer operand.
+pub fn charstring_num(value: i32) -> u8 {
+    assert!((-107..=107).contains(&value));
+    (value + 139) as u8
+}
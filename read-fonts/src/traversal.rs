@@ -11,10 +11,10 @@ use font_types::{
 };
 
 use crate::{
-    array::ComputedArray,
+    array::{ComputedArray, VarLenArray, VarSize},
     layout::gpos::ValueRecord,
     read::{ComputeSize, ReadArgs},
-    FontData, FontReadWithArgs, ReadError,
+    FontData, FontRead, FontReadWithArgs, ReadError,
 };
 
 /// Types of fields in font tables.
@@ -125,6 +125,18 @@ impl<'a> FieldType<'a> {
         .into()
     }
 
+    // Convenience method for handling arrays of variable-length tables
+    pub fn var_array<T>(
+        type_name: &'static str,
+        array: VarLenArray<'a, T>,
+        _data: FontData<'a>,
+    ) -> FieldType<'a>
+    where
+        T: FontRead<'a> + VarSize + SomeTable<'a> + 'a,
+    {
+        VarLenArrayOfTables { type_name, array }.into()
+    }
+
     pub fn offset_array<O>(
         type_name: &'static str,
         offsets: &'a [O],
@@ -302,6 +314,34 @@ where
     }
 }
 
+// only used as Box<dyn SomeArray<'a>>
+struct VarLenArrayOfTables<'a, T> {
+    type_name: &'static str,
+    array: VarLenArray<'a, T>,
+}
+
+impl<'a, T> SomeArray<'a> for VarLenArrayOfTables<'a, T>
+where
+    T: FontRead<'a> + VarSize + SomeTable<'a> + 'a,
+{
+    fn type_name(&self) -> &str {
+        self.type_name
+    }
+
+    fn len(&self) -> usize {
+        self.array.iter().count()
+    }
+
+    fn get(&self, idx: usize) -> Option<FieldType<'a>> {
+        let item = self.array.get(idx)?.ok()?;
+        Some(FieldType::Record(RecordResolver {
+            name: self.type_name,
+            get_field: Box::new(move |idx, _data| item.get_field(idx)),
+            data: FontData::new(&[]),
+        }))
+    }
+}
+
 // only used as Box<dyn SomeArray<'a>>
 struct ArrayOfRecords<'a, T> {
     pub(crate) type_name: &'static str,
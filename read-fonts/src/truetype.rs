@@ -0,0 +1,14 @@
+//! Support for TrueType hinting instructions.
+//!
+//! This includes a decoder for instruction streams and, with the `std`
+//! feature, an interpreter that produces grid-fitted outlines.
+//!
+//! See the [TrueType instruction set](https://learn.microsoft.com/en-us/typography/opentype/spec/tt_instructions).
+
+#[cfg(feature = "std")]
+pub(crate) mod hint;
+mod instruction;
+
+#[cfg(feature = "std")]
+pub use hint::{HintError, HintingInstance, InterpreterVersion};
+pub use instruction::{Decoder, InlineOperands, Instruction, Opcode};
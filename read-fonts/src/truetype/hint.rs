@@ -0,0 +1,493 @@
+//! A TrueType bytecode interpreter, for hinting glyph outlines.
+//!
+//! The behavior follows FreeType's TrueType interpreter, including its
+//! "v40" backward compatibility mode for subpixel rendering.
+
+use std::{borrow::Cow, fmt};
+
+use font_types::F2Dot14;
+
+use crate::{metrics::optional, ReadError, TableProvider};
+
+mod engine;
+mod graphics_state;
+mod math;
+mod zone;
+
+use engine::{Definition, Engine, Program, MAX_RUN_INSTRUCTIONS, STACK_PADDING};
+use graphics_state::GraphicsState;
+pub(crate) use math::{mul_fix, round};
+pub(crate) use zone::{PointFlags, Zone};
+
+/// Selects the behavior of the interpreter.
+///
+/// These match the interpreter versions of FreeType's TrueType driver.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub enum InterpreterVersion {
+    /// The behavior of the original TrueType rasterizer, where instructions
+    /// may move points along both axes.
+    V35,
+    /// Hinting for subpixel rendering.
+    ///
+    /// Unless the font opts out with `INSTCTRL`, instructions run in
+    /// backward compatibility mode: points only move vertically, and not
+    /// at all once `IUP` has been called on both axes. This keeps
+    /// horizontal metrics and shapes intact, and is what most text
+    /// rendering uses today.
+    #[default]
+    V40,
+}
+
+/// An error that occurred while running a TrueType program.
+#[derive(Clone, Debug)]
+pub enum HintError {
+    /// A table could not be read.
+    Read(ReadError),
+    /// A value was pushed onto a full stack.
+    StackOverflow,
+    /// A value was popped from an empty stack.
+    StackUnderflow,
+    /// `CINDEX` or `MINDEX` referred to an element beyond the stack.
+    InvalidStackIndex(i32),
+    /// Functions were nested more deeply than allowed.
+    CallStackOverflow,
+    /// A point index was out of bounds for its zone.
+    InvalidPointIndex(usize),
+    /// A contour index was out of bounds.
+    InvalidContourIndex(usize),
+    /// A CVT index was out of bounds.
+    InvalidCvtIndex(i32),
+    /// A storage index was out of bounds.
+    InvalidStorageIndex(i32),
+    /// A function was called that was not defined, or defined with an
+    /// index beyond `maxp.maxFunctionDefs`.
+    InvalidFunction(i32),
+    /// A zone other than 0 (twilight) or 1 (glyph) was selected.
+    InvalidZone(i32),
+    /// An opcode that is not in the instruction set and has no `IDEF`.
+    InvalidOpcode(u8),
+    /// A jump went outside of the program.
+    InvalidJump,
+    /// An argument was out of range.
+    InvalidArgument,
+    /// An `IF` without a matching `EIF`.
+    UnbalancedIf,
+    /// An `ENDF` outside of a function.
+    UnexpectedEndf,
+    /// A program or definition ended prematurely.
+    UnexpectedEndOfProgram,
+    /// An `FDEF` or `IDEF` inside another definition.
+    NestedDefinition,
+    /// An `FDEF` or `IDEF` in the instructions of a glyph.
+    DefinitionInGlyphProgram,
+    /// `DIV` by zero.
+    DivideByZero,
+    /// A program ran for too many instructions, most likely because it
+    /// does not terminate.
+    ExceededExecutionBudget,
+}
+
+impl From<ReadError> for HintError {
+    fn from(value: ReadError) -> Self {
+        Self::Read(value)
+    }
+}
+
+impl fmt::Display for HintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HintError::Read(err) => write!(f, "{err}"),
+            HintError::StackOverflow => write!(f, "Stack overflow"),
+            HintError::StackUnderflow => write!(f, "Stack underflow"),
+            HintError::InvalidStackIndex(idx) => write!(f, "Invalid stack index {idx}"),
+            HintError::CallStackOverflow => write!(f, "Function calls nested too deeply"),
+            HintError::InvalidPointIndex(idx) => write!(f, "Invalid point index {idx}"),
+            HintError::InvalidContourIndex(idx) => write!(f, "Invalid contour index {idx}"),
+            HintError::InvalidCvtIndex(idx) => write!(f, "Invalid CVT index {idx}"),
+            HintError::InvalidStorageIndex(idx) => write!(f, "Invalid storage index {idx}"),
+            HintError::InvalidFunction(idx) => write!(f, "Invalid function {idx}"),
+            HintError::InvalidZone(zone) => write!(f, "Invalid zone {zone}"),
+            HintError::InvalidOpcode(opcode) => write!(f, "Invalid opcode 0x{opcode:02X}"),
+            HintError::InvalidJump => write!(f, "Jump out of bounds"),
+            HintError::InvalidArgument => write!(f, "Invalid argument"),
+            HintError::UnbalancedIf => write!(f, "IF without matching EIF"),
+            HintError::UnexpectedEndf => write!(f, "ENDF outside of a function"),
+            HintError::UnexpectedEndOfProgram => write!(f, "Unexpected end of program"),
+            HintError::NestedDefinition => write!(f, "Nested function definition"),
+            HintError::DefinitionInGlyphProgram => {
+                write!(f, "Function definition in glyph program")
+            }
+            HintError::DivideByZero => write!(f, "Division by zero"),
+            HintError::ExceededExecutionBudget => write!(
+                f,
+                "Program exceeded the limit of {MAX_RUN_INSTRUCTIONS} instructions"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for HintError {}
+
+/// The state needed to hint the glyphs of a font at one size and location
+/// in variation space.
+///
+/// Creating an instance runs the font program (`fpgm`) and the control
+/// value program (`prep`). Glyphs are then hinted with
+/// [`GlyfOutlines::hinted_outline`](crate::outline::GlyfOutlines::hinted_outline).
+///
+/// Every program run is limited to a fixed number of instructions, so
+/// fonts with programs that never terminate produce an error rather than
+/// hanging. Changes that a glyph program makes to the CVT, storage area or
+/// twilight zone are discarded afterwards, so each glyph is hinted the
+/// same way regardless of which glyphs came before it.
+///
+/// Variations of the CVT (from a `cvar` table) are not applied.
+#[derive(Clone, Debug)]
+pub struct HintingInstance<'a> {
+    fpgm: &'a [u8],
+    prep: &'a [u8],
+    version: InterpreterVersion,
+    ppem: u16,
+    /// Converts font units to 26.6 pixels, as a 16.16 value.
+    scale: i32,
+    coords: Vec<F2Dot14>,
+    axis_count: u16,
+    max_stack: usize,
+    functions: Vec<Option<Definition>>,
+    instruction_defs: Vec<Option<Definition>>,
+    cvt: Vec<i32>,
+    storage: Vec<i32>,
+    twilight: Zone,
+    graphics_state: GraphicsState,
+}
+
+impl<'a> HintingInstance<'a> {
+    /// Creates an instance for hinting at the given size in pixels per em,
+    /// at the given normalized variation coordinates.
+    ///
+    /// The `head` and `maxp` tables are required. Fonts without `fpgm`,
+    /// `prep` or `cvt` tables are treated as if those were empty.
+    pub fn new(
+        font: &impl TableProvider<'a>,
+        ppem: u16,
+        coords: &[F2Dot14],
+        version: InterpreterVersion,
+    ) -> Result<Self, HintError> {
+        let units_per_em = font.head()?.units_per_em();
+        if units_per_em == 0 {
+            return Err(ReadError::MalformedData("units per em is zero").into());
+        }
+        let maxp = font.maxp()?;
+        let fpgm = optional(font.fpgm())?.map(|fpgm| fpgm.instructions());
+        let prep = optional(font.prep())?.map(|prep| prep.instructions());
+        let axis_count = optional(font.fvar())?.map(|fvar| fvar.axis_count());
+        let scale = math::div_fix(ppem as i32 * 64, units_per_em as i32);
+        let cvt = optional(font.cvt())?
+            .map(|cvt| {
+                cvt.values()
+                    .iter()
+                    .map(|value| math::mul_fix(value.get().to_i16() as i32, scale))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let max_stack = maxp.max_stack_elements().unwrap_or_default() as usize + STACK_PADDING;
+        // FreeType reserves room for four phantom points in the twilight
+        // zone, and some fonts rely on it
+        let twilight_len = maxp.max_twilight_points().unwrap_or_default() as usize + 4;
+        let mut instance = Self {
+            fpgm: fpgm.unwrap_or_default(),
+            prep: prep.unwrap_or_default(),
+            version,
+            ppem,
+            scale,
+            coords: coords.to_vec(),
+            axis_count: axis_count.unwrap_or_default(),
+            max_stack,
+            functions: vec![None; maxp.max_function_defs().unwrap_or_default() as usize],
+            instruction_defs: vec![None; 256],
+            cvt,
+            storage: vec![0; maxp.max_storage().unwrap_or_default() as usize],
+            twilight: Zone::with_len(twilight_len),
+            graphics_state: GraphicsState::default(),
+        };
+        instance.run_setup_programs()?;
+        Ok(instance)
+    }
+
+    /// The size of this instance, in pixels per em.
+    pub fn ppem(&self) -> u16 {
+        self.ppem
+    }
+
+    /// The normalized variation coordinates of this instance.
+    pub fn coords(&self) -> &[F2Dot14] {
+        &self.coords
+    }
+
+    /// The interpreter version used by this instance.
+    pub fn version(&self) -> InterpreterVersion {
+        self.version
+    }
+
+    /// The factor for converting font units to 26.6 pixels, as a 16.16
+    /// value.
+    pub(crate) fn scale(&self) -> i32 {
+        self.scale
+    }
+
+    /// Returns `false` if the control value program has disabled hinting
+    /// at this size.
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.graphics_state.instruct_control & 1 == 0
+    }
+
+    /// Returns `true` if composite glyph offsets should be rounded along
+    /// the x axis, which is only done when points may move horizontally.
+    pub(crate) fn rounds_x_offsets(&self) -> bool {
+        self.version == InterpreterVersion::V35
+    }
+
+    fn engine<'b>(&'b self, glyph_program: &'b [u8]) -> Engine<'b> {
+        let mut engine = Engine::new([self.fpgm, self.prep, glyph_program], self.max_stack);
+        engine.functions = Cow::Borrowed(&self.functions);
+        engine.instruction_defs = Cow::Borrowed(&self.instruction_defs);
+        engine.cvt = Cow::Borrowed(&self.cvt);
+        engine.storage = Cow::Borrowed(&self.storage);
+        engine.twilight = Cow::Borrowed(&self.twilight);
+        engine.gs = self.graphics_state;
+        engine.version = self.version;
+        engine.ppem = self.ppem as i32;
+        engine.scale = self.scale;
+        engine.coords = &self.coords;
+        engine.axis_count = self.axis_count;
+        engine
+    }
+
+    /// Runs the font program and then the control value program, keeping
+    /// the state they leave behind for hinting glyphs.
+    fn run_setup_programs(&mut self) -> Result<(), HintError> {
+        let mut engine = self.engine(&[]);
+        engine.run(Program::Font)?;
+        engine.gs = GraphicsState::default();
+        engine.run(Program::ControlValue)?;
+        let Engine {
+            functions,
+            instruction_defs,
+            cvt,
+            storage,
+            twilight,
+            gs,
+            ..
+        } = engine;
+        let state = (
+            functions.into_owned(),
+            instruction_defs.into_owned(),
+            cvt.into_owned(),
+            storage.into_owned(),
+            twilight.into_owned(),
+        );
+        (
+            self.functions,
+            self.instruction_defs,
+            self.cvt,
+            self.storage,
+            self.twilight,
+        ) = state;
+        self.graphics_state = gs;
+        Ok(())
+    }
+
+    /// Hints the points of a glyph.
+    ///
+    /// The zone holds the scaled points of the glyph followed by its four
+    /// phantom points. The phantom points are rounded to the grid before
+    /// running the instructions.
+    pub(crate) fn hint_glyph(
+        &self,
+        zone: &mut Zone,
+        instructions: &[u8],
+        is_composite: bool,
+    ) -> Result<(), HintError> {
+        let len = zone.len();
+        if len < 4 || !self.is_enabled() {
+            return Ok(());
+        }
+        let phantom = &mut zone.points[len - 4..];
+        phantom[0].x = math::round(phantom[0].x);
+        phantom[1].x = math::round(phantom[1].x);
+        phantom[2].y = math::round(phantom[2].y);
+        phantom[3].y = math::round(phantom[3].y);
+        if instructions.is_empty() {
+            return Ok(());
+        }
+        let phantom = [0, 1, 2, 3].map(|idx| zone.points[len - 4 + idx]);
+        zone.original.clone_from(&zone.points);
+        let mut engine = self.engine(instructions);
+        if self.graphics_state.instruct_control & 2 != 0 {
+            engine.gs = GraphicsState::default();
+        }
+        engine.glyph = std::mem::take(zone);
+        engine.is_composite = is_composite;
+        engine.unscaled_scale = if is_composite { 0x10000 } else { self.scale };
+        engine.backward_compatibility = self.version == InterpreterVersion::V40
+            && self.graphics_state.instruct_control & 4 == 0;
+        let result = engine.run(Program::Glyph);
+        *zone = std::mem::take(&mut engine.glyph);
+        // nothing moves horizontally in backward compatibility mode, so
+        // the metrics are kept as they were
+        if engine.backward_compatibility {
+            zone.points[len - 4..].copy_from_slice(&phantom);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use font_types::Point;
+
+    use super::*;
+
+    /// An instance with the given font and control value programs and
+    /// CVT, at 1:1 scale.
+    fn instance<'a>(
+        fpgm: &'a [u8],
+        prep: &'a [u8],
+        cvt: &[i32],
+        version: InterpreterVersion,
+    ) -> Result<HintingInstance<'a>, HintError> {
+        let mut instance = HintingInstance {
+            fpgm,
+            prep,
+            version,
+            ppem: 16,
+            scale: 0x10000,
+            coords: Vec::new(),
+            axis_count: 0,
+            max_stack: 64,
+            functions: vec![None; 8],
+            instruction_defs: vec![None; 256],
+            cvt: cvt.to_vec(),
+            storage: vec![0; 8],
+            twilight: Zone::with_len(4),
+            graphics_state: GraphicsState::default(),
+        };
+        instance.run_setup_programs()?;
+        Ok(instance)
+    }
+
+    /// A zone with a single contour, followed by four phantom points.
+    fn zone(points: &[(i32, i32)]) -> Zone {
+        let mut zone = Zone::with_len(points.len() + 4);
+        for (idx, (x, y)) in points.iter().enumerate() {
+            zone.unscaled[idx] = Point::new(*x, *y);
+            zone.points[idx] = Point::new(*x, *y);
+        }
+        zone.contours.push(points.len() - 1);
+        zone
+    }
+
+    fn hint(
+        instance: &HintingInstance,
+        points: &[(i32, i32)],
+        instructions: &[u8],
+    ) -> Result<Vec<(i32, i32)>, HintError> {
+        let mut zone = zone(points);
+        instance.hint_glyph(&mut zone, instructions, false)?;
+        Ok(zone.points[..points.len()]
+            .iter()
+            .map(|point| (point.x, point.y))
+            .collect())
+    }
+
+    #[test]
+    fn mdap_and_mirp() {
+        let instance = instance(&[], &[], &[128], InterpreterVersion::V35).unwrap();
+        let points = [(10, 20), (100, 150), (60, 80)];
+        let instructions = [
+            // PUSHB 0, MDAP[rnd]: round point 0 in x
+            0xB0, 0, 0x2F,
+            // PUSHB 1 0, MIRP[rp0,min,rnd,grey]: point 1 at cvt[0] from
+            // point 0
+            0xB1, 1, 0, 0xFC, // PUSHB 2, IUP[x]
+            0xB0, 2, 0x31,
+        ];
+        let hinted = hint(&instance, &points, &instructions).unwrap();
+        // point 2 is interpolated between the two touched points
+        assert_eq!(hinted, [(0, 20), (128, 150), (71, 80)]);
+    }
+
+    #[test]
+    fn backward_compatibility() {
+        let points = [(10, 20), (100, 150)];
+        let instructions = [
+            // SVTCA[x], PUSHB 0, MDAP[rnd]
+            0x01, 0xB0, 0, 0x2F, // SVTCA[y], PUSHB 1, MDAP[rnd]
+            0x00, 0xB0, 1, 0x2F,
+        ];
+        let instance = instance(&[], &[], &[], InterpreterVersion::V40).unwrap();
+        let hinted = hint(&instance, &points, &instructions).unwrap();
+        // only the vertical movement is applied
+        assert_eq!(hinted, [(10, 20), (100, 128)]);
+        // INSTCTRL[3] in prep opts out of backward compatibility
+        let prep = [0xB1, 4, 3, 0x8E];
+        let instance = self::instance(&[], &prep, &[], InterpreterVersion::V40).unwrap();
+        let hinted = hint(&instance, &points, &instructions).unwrap();
+        assert_eq!(hinted, [(0, 20), (100, 128)]);
+    }
+
+    #[test]
+    fn functions_and_storage() {
+        // function 0 stores its argument doubled in storage slot 1
+        let fpgm = [0xB0, 0, 0x2C, 0x20, 0x60, 0xB0, 1, 0x23, 0x42, 0x2D];
+        // call it three times with LOOPCALL, reading the value back
+        let prep = [0xB3, 5, 5, 5, 3, 0xB0, 0, 0x2A];
+        let instance = instance(&fpgm, &prep, &[], InterpreterVersion::V40).unwrap();
+        assert_eq!(instance.storage[1], 10);
+        assert!(instance.functions[0].is_some());
+        // glyphs may call functions but not define them
+        let points = [(0, 0)];
+        assert!(hint(&instance, &points, &[0xB1, 5, 0, 0x2B]).is_ok());
+        assert!(matches!(
+            hint(&instance, &points, &[0xB0, 1, 0x2C, 0x2D]),
+            Err(HintError::DefinitionInGlyphProgram)
+        ));
+    }
+
+    #[test]
+    fn point_size() {
+        // MPS, then store the result in storage slot 0
+        let prep = [0x4C, 0xB0, 0, 0x23, 0x42];
+        let v35 = instance(&[], &prep, &[], InterpreterVersion::V35).unwrap();
+        assert_eq!(v35.storage[0], 16);
+        let v40 = instance(&[], &prep, &[], InterpreterVersion::V40).unwrap();
+        assert_eq!(v40.storage[0], 16 * 64);
+    }
+
+    #[test]
+    fn control_flow() {
+        // IF 0 { WS 0 1 } ELSE { WS 0 2 } EIF; JMPR over WS 1 3
+        let prep = [
+            0xB0, 0, 0x58, 0xB1, 0, 1, 0x42, 0x1B, 0xB1, 0, 2, 0x42, 0x59, 0xB0, 5, 0x1C, 0xB1, 1,
+            3, 0x42,
+        ];
+        let instance = instance(&[], &prep, &[], InterpreterVersion::V40).unwrap();
+        assert_eq!(&instance.storage[..2], [2, 0]);
+    }
+
+    #[test]
+    fn execution_budget() {
+        // PUSHW -3, JMPR: jumps back to the push forever
+        let prep = [0xB8, 0xFF, 0xFD, 0x1C];
+        assert!(matches!(
+            instance(&[], &prep, &[], InterpreterVersion::V40),
+            Err(HintError::ExceededExecutionBudget)
+        ));
+        // a function that calls itself runs out of call stack
+        let fpgm = [0xB0, 0, 0x2C, 0xB0, 0, 0x2B, 0x2D, 0xB0, 0, 0x2B];
+        assert!(matches!(
+            instance(&fpgm, &[], &[], InterpreterVersion::V40),
+            Err(HintError::CallStackOverflow)
+        ));
+    }
+}
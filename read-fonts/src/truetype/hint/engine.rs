@@ -0,0 +1,903 @@
+//! The instruction execution loop.
+//!
+//! Instructions that move points live in the `outline` submodule; this
+//! module handles control flow, the stack, storage and the graphics state.
+
+use std::borrow::Cow;
+
+use font_types::{F2Dot14, Point};
+
+use super::{
+    graphics_state::{GraphicsState, RoundMode, ZonePointer},
+    math,
+    zone::Zone,
+    HintError, InterpreterVersion,
+};
+use crate::truetype::{Decoder, Instruction};
+
+mod outline;
+
+/// The maximum number of instructions executed by a single run of a
+/// program, including any functions it calls.
+///
+/// This bounds the time spent on fonts with (accidentally or deliberately)
+/// non-terminating programs.
+pub(crate) const MAX_RUN_INSTRUCTIONS: usize = 1_000_000;
+
+/// The maximum depth of nested function calls.
+pub(crate) const MAX_CALL_DEPTH: usize = 32;
+
+/// The number of extra stack slots allowed beyond `maxp.maxStackElements`,
+/// since many fonts underestimate it.
+pub(crate) const STACK_PADDING: usize = 32;
+
+/// Identifies one of the three kinds of program.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub(crate) enum Program {
+    /// The font program, from the `fpgm` table.
+    Font = 0,
+    /// The control value program, from the `prep` table.
+    ControlValue = 1,
+    /// The instructions of a glyph.
+    Glyph = 2,
+}
+
+/// The location of a function or instruction definition.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub(crate) struct Definition {
+    pub program: Program,
+    /// The offset of the first instruction of the body.
+    pub start: usize,
+    /// The offset of the `ENDF` instruction.
+    pub end: usize,
+}
+
+#[derive(Copy, Clone, Debug)]
+struct CallRecord {
+    program: Program,
+    return_pc: usize,
+    remaining: u32,
+    definition: Definition,
+}
+
+/// Executes TrueType programs.
+///
+/// State that is only read by glyph programs is borrowed from the hinting
+/// instance and copied on first write, so each glyph starts from the state
+/// left by the control value program.
+pub(crate) struct Engine<'a> {
+    pub programs: [&'a [u8]; 3],
+    pub functions: Cow<'a, [Option<Definition>]>,
+    pub instruction_defs: Cow<'a, [Option<Definition>]>,
+    pub cvt: Cow<'a, [i32]>,
+    pub storage: Cow<'a, [i32]>,
+    pub twilight: Cow<'a, Zone>,
+    pub glyph: Zone,
+    pub gs: GraphicsState,
+    pub version: InterpreterVersion,
+    pub ppem: i32,
+    /// Converts font units to 26.6, for CVT values and distances.
+    pub scale: i32,
+    /// Converts the unscaled coordinates of the glyph zone to 26.6.
+    ///
+    /// This is 1.0 for composite glyphs, whose components are already
+    /// scaled.
+    pub unscaled_scale: i32,
+    pub coords: &'a [F2Dot14],
+    pub axis_count: u16,
+    pub is_composite: bool,
+    /// Set in [`InterpreterVersion::V40`] unless the font opts out; blocks
+    /// movement along the x axis and any movement after `IUP`.
+    pub backward_compatibility: bool,
+    iupx_called: bool,
+    iupy_called: bool,
+    stack: Vec<i32>,
+    max_stack: usize,
+    call_stack: Vec<CallRecord>,
+    program: Program,
+    pc: usize,
+}
+
+impl<'a> Engine<'a> {
+    pub(crate) fn new(programs: [&'a [u8]; 3], max_stack: usize) -> Self {
+        Self {
+            programs,
+            functions: Cow::Borrowed(&[]),
+            instruction_defs: Cow::Borrowed(&[]),
+            cvt: Cow::Borrowed(&[]),
+            storage: Cow::Borrowed(&[]),
+            twilight: Cow::Owned(Zone::default()),
+            glyph: Zone::default(),
+            gs: GraphicsState::default(),
+            version: InterpreterVersion::V40,
+            ppem: 0,
+            scale: 0x10000,
+            unscaled_scale: 0x10000,
+            coords: &[],
+            axis_count: 0,
+            is_composite: false,
+            backward_compatibility: false,
+            iupx_called: false,
+            iupy_called: false,
+            stack: Vec::with_capacity(max_stack),
+            max_stack,
+            call_stack: Vec::new(),
+            program: Program::Font,
+            pc: 0,
+        }
+    }
+
+    /// Runs the given program from the start.
+    pub(crate) fn run(&mut self, program: Program) -> Result<(), HintError> {
+        self.gs.reset_for_program();
+        self.stack.clear();
+        self.call_stack.clear();
+        self.iupx_called = false;
+        self.iupy_called = false;
+        self.program = program;
+        self.pc = 0;
+        let mut count = 0;
+        loop {
+            let bytecode = self.programs[self.program as usize];
+            if self.pc >= bytecode.len() {
+                if !self.call_stack.is_empty() {
+                    return Err(HintError::UnexpectedEndOfProgram);
+                }
+                return Ok(());
+            }
+            count += 1;
+            if count > MAX_RUN_INSTRUCTIONS {
+                return Err(HintError::ExceededExecutionBudget);
+            }
+            let mut decoder = Decoder::new(bytecode, self.pc);
+            let Some(instruction) = decoder.decode() else {
+                return Ok(());
+            };
+            let instruction = instruction?;
+            self.pc = decoder.pc;
+            self.execute(&instruction)?;
+        }
+    }
+
+    fn execute(&mut self, instruction: &Instruction) -> Result<(), HintError> {
+        let opcode = instruction.opcode.to_u8();
+        match opcode {
+            // SVTCA, SPVTCA, SFVTCA
+            0x00..=0x05 => {
+                let axis = if opcode & 1 != 0 {
+                    Point::new(0x4000, 0)
+                } else {
+                    Point::new(0, 0x4000)
+                };
+                if opcode < 0x04 {
+                    self.gs.proj_vector = axis;
+                    self.gs.dual_proj_vector = axis;
+                }
+                if !matches!(opcode, 0x02 | 0x03) {
+                    self.gs.freedom_vector = axis;
+                }
+                self.gs.update_fdotp();
+            }
+            // SPVTL, SFVTL
+            0x06..=0x09 => {
+                let p2 = self.pop_usize()?;
+                let p1 = self.pop_usize()?;
+                let a = self.zone(self.gs.zp1).point(p1)?;
+                let b = self.zone(self.gs.zp2).point(p2)?;
+                let vector = line_vector(a, b, opcode & 1 != 0);
+                if opcode < 0x08 {
+                    self.gs.proj_vector = vector;
+                    self.gs.dual_proj_vector = vector;
+                } else {
+                    self.gs.freedom_vector = vector;
+                }
+                self.gs.update_fdotp();
+            }
+            // SPVFS, SFVFS
+            0x0A | 0x0B => {
+                let y = self.pop()? as i16 as i32;
+                let x = self.pop()? as i16 as i32;
+                let vector = math::normalize(x, y);
+                if opcode == 0x0A {
+                    self.gs.proj_vector = vector;
+                    self.gs.dual_proj_vector = vector;
+                } else {
+                    self.gs.freedom_vector = vector;
+                }
+                self.gs.update_fdotp();
+            }
+            // GPV, GFV
+            0x0C | 0x0D => {
+                let vector = if opcode == 0x0C {
+                    self.gs.proj_vector
+                } else {
+                    self.gs.freedom_vector
+                };
+                self.push(vector.x)?;
+                self.push(vector.y)?;
+            }
+            // SFVTPV
+            0x0E => {
+                self.gs.freedom_vector = self.gs.proj_vector;
+                self.gs.update_fdotp();
+            }
+            0x0F => self.op_isect()?,
+            0x10 => self.gs.rp0 = self.pop_usize()?,
+            0x11 => self.gs.rp1 = self.pop_usize()?,
+            0x12 => self.gs.rp2 = self.pop_usize()?,
+            0x13..=0x16 => {
+                let zone = ZonePointer::new(self.pop()?)?;
+                match opcode {
+                    0x13 => self.gs.zp0 = zone,
+                    0x14 => self.gs.zp1 = zone,
+                    0x15 => self.gs.zp2 = zone,
+                    _ => {
+                        self.gs.zp0 = zone;
+                        self.gs.zp1 = zone;
+                        self.gs.zp2 = zone;
+                    }
+                }
+            }
+            // SLOOP
+            0x17 => {
+                let count = self.pop()?;
+                if count < 0 {
+                    return Err(HintError::InvalidArgument);
+                }
+                self.gs.loop_counter = count.min(0xFFFF) as u32;
+            }
+            0x18 => self.gs.round_state.mode = RoundMode::Grid,
+            0x19 => self.gs.round_state.mode = RoundMode::HalfGrid,
+            0x1A => self.gs.min_distance = self.pop()?,
+            // ELSE: only reached after taking the IF branch
+            0x1B => self.skip_branch(false)?,
+            // JMPR
+            0x1C => {
+                let offset = self.pop()?;
+                self.jump(instruction.pc, offset)?;
+            }
+            0x1D => self.gs.control_value_cutin = self.pop()?,
+            0x1E => self.gs.single_width_cutin = self.pop()?,
+            0x1F => {
+                let value = self.pop()?;
+                self.gs.single_width = math::mul_fix(value, self.scale);
+            }
+            // DUP
+            0x20 => {
+                let value = self.pop()?;
+                self.push(value)?;
+                self.push(value)?;
+            }
+            // POP
+            0x21 => {
+                self.pop()?;
+            }
+            // CLEAR
+            0x22 => self.stack.clear(),
+            // SWAP
+            0x23 => {
+                let a = self.pop()?;
+                let b = self.pop()?;
+                self.push(a)?;
+                self.push(b)?;
+            }
+            // DEPTH
+            0x24 => self.push(self.stack.len() as i32)?,
+            // CINDEX, MINDEX
+            0x25 | 0x26 => {
+                let index = self.pop()?;
+                let len = self.stack.len();
+                if index <= 0 || index as usize > len {
+                    return Err(HintError::InvalidStackIndex(index));
+                }
+                let position = len - index as usize;
+                let value = if opcode == 0x25 {
+                    self.stack[position]
+                } else {
+                    self.stack.remove(position)
+                };
+                self.push(value)?;
+            }
+            0x27 => self.op_alignpts()?,
+            0x29 => self.op_utp()?,
+            // LOOPCALL
+            0x2A => {
+                let index = self.pop()?;
+                let count = self.pop()?;
+                self.call(index, count.max(0) as u32)?;
+            }
+            // CALL
+            0x2B => {
+                let index = self.pop()?;
+                self.call(index, 1)?;
+            }
+            // FDEF
+            0x2C => {
+                let index = self.pop()?;
+                let definition = self.define()?;
+                let slot = usize::try_from(index)
+                    .ok()
+                    .filter(|index| *index < self.functions.len())
+                    .ok_or(HintError::InvalidFunction(index))?;
+                self.functions.to_mut()[slot] = Some(definition);
+            }
+            // ENDF
+            0x2D => {
+                let record = self
+                    .call_stack
+                    .last_mut()
+                    .ok_or(HintError::UnexpectedEndf)?;
+                if record.remaining > 1 {
+                    record.remaining -= 1;
+                    self.pc = record.definition.start;
+                } else {
+                    self.program = record.program;
+                    self.pc = record.return_pc;
+                    self.call_stack.pop();
+                }
+            }
+            0x2E | 0x2F => self.op_mdap(opcode)?,
+            0x30 | 0x31 => self.op_iup(opcode)?,
+            0x32 | 0x33 => self.op_shp(opcode)?,
+            0x34 | 0x35 => self.op_shc(opcode)?,
+            0x36 | 0x37 => self.op_shz(opcode)?,
+            0x38 => self.op_shpix()?,
+            0x39 => self.op_ip()?,
+            0x3A | 0x3B => self.op_msirp(opcode)?,
+            0x3C => self.op_alignrp()?,
+            0x3D => self.gs.round_state.mode = RoundMode::DoubleGrid,
+            0x3E | 0x3F => self.op_miap(opcode)?,
+            // NPUSHB, NPUSHW, PUSHB, PUSHW
+            0x40 | 0x41 | 0xB0..=0xBF => {
+                for value in instruction.inline_operands.values() {
+                    self.push(value)?;
+                }
+            }
+            // WS
+            0x42 => {
+                let value = self.pop()?;
+                let index = self.pop()?;
+                let slot = self.storage_index(index)?;
+                self.storage.to_mut()[slot] = value;
+            }
+            // RS
+            0x43 => {
+                let index = self.pop()?;
+                let slot = self.storage_index(index)?;
+                self.push(self.storage[slot])?;
+            }
+            // WCVTP
+            0x44 => {
+                let value = self.pop()?;
+                let index = self.pop()?;
+                self.write_cvt(index, value)?;
+            }
+            // RCVT
+            0x45 => {
+                let index = self.pop()?;
+                let value = self.read_cvt(index)?;
+                self.push(value)?;
+            }
+            0x46 | 0x47 => self.op_gc(opcode)?,
+            0x48 => self.op_scfs()?,
+            0x49 | 0x4A => self.op_md(opcode)?,
+            // MPPEM
+            0x4B => self.push(self.ppem)?,
+            // MPS: v35 reports the size in pixels, while v40 reports the 26.6
+            // point size at 72 dpi
+            0x4C => match self.version {
+                InterpreterVersion::V35 => self.push(self.ppem)?,
+                InterpreterVersion::V40 => self.push(self.ppem * 64)?,
+            },
+            0x4D => self.gs.auto_flip = true,
+            0x4E => self.gs.auto_flip = false,
+            // DEBUG
+            0x4F => {
+                self.pop()?;
+            }
+            // LT, LTEQ, GT, GTEQ, EQ, NEQ, AND, OR
+            0x50..=0x55 | 0x5A | 0x5B => {
+                let b = self.pop()?;
+                let a = self.pop()?;
+                let result = match opcode {
+                    0x50 => a < b,
+                    0x51 => a <= b,
+                    0x52 => a > b,
+                    0x53 => a >= b,
+                    0x54 => a == b,
+                    0x55 => a != b,
+                    0x5A => a != 0 && b != 0,
+                    _ => a != 0 || b != 0,
+                };
+                self.push(result as i32)?;
+            }
+            // ODD, EVEN
+            0x56 | 0x57 => {
+                let value = self.pop()?;
+                let value = self.gs.round_state.round(value) & 127;
+                let result = if opcode == 0x56 {
+                    value == 64
+                } else {
+                    value == 0
+                };
+                self.push(result as i32)?;
+            }
+            // IF
+            0x58 => {
+                if self.pop()? == 0 {
+                    self.skip_branch(true)?;
+                }
+            }
+            // EIF
+            0x59 => {}
+            // NOT
+            0x5C => {
+                let value = self.pop()?;
+                self.push((value == 0) as i32)?;
+            }
+            0x5D | 0x71 | 0x72 => self.op_deltap(opcode)?,
+            0x5E => self.gs.delta_base = self.pop()?,
+            // SDS
+            0x5F => {
+                let shift = self.pop()?;
+                if !(0..=6).contains(&shift) {
+                    return Err(HintError::InvalidArgument);
+                }
+                self.gs.delta_shift = shift;
+            }
+            // ADD, SUB, DIV, MUL, MAX, MIN
+            0x60..=0x63 | 0x8B | 0x8C => {
+                let b = self.pop()?;
+                let a = self.pop()?;
+                let result = match opcode {
+                    0x60 => a.wrapping_add(b),
+                    0x61 => a.wrapping_sub(b),
+                    0x62 => {
+                        if b == 0 {
+                            return Err(HintError::DivideByZero);
+                        }
+                        math::mul_div_no_round(a, 64, b)
+                    }
+                    0x63 => math::mul_div(a, b, 64),
+                    0x8B => a.max(b),
+                    _ => a.min(b),
+                };
+                self.push(result)?;
+            }
+            // ABS, NEG, FLOOR, CEILING, ROUND, NROUND
+            0x64..=0x6F => {
+                let value = self.pop()?;
+                let result = match opcode {
+                    0x64 => value.wrapping_abs(),
+                    0x65 => value.wrapping_neg(),
+                    0x66 => math::floor(value),
+                    0x67 => math::ceil(value),
+                    0x68..=0x6B => self.gs.round_state.round(value),
+                    // engine compensation is always zero
+                    _ => value,
+                };
+                self.push(result)?;
+            }
+            // WCVTF
+            0x70 => {
+                let value = self.pop()?;
+                let index = self.pop()?;
+                self.write_cvt(index, math::mul_fix(value, self.scale))?;
+            }
+            0x73..=0x75 => self.op_deltac(opcode)?,
+            // SROUND, S45ROUND
+            0x76 | 0x77 => {
+                let selector = self.pop()?;
+                let (mode, grid_period) = if opcode == 0x76 {
+                    (RoundMode::Super, 0x4000)
+                } else {
+                    (RoundMode::Super45, 0x2D41)
+                };
+                self.gs.round_state.mode = mode;
+                self.gs.round_state.set_super(selector, grid_period);
+            }
+            // JROT, JROF
+            0x78 | 0x79 => {
+                let condition = self.pop()? != 0;
+                let offset = self.pop()?;
+                if condition == (opcode == 0x78) {
+                    self.jump(instruction.pc, offset)?;
+                }
+            }
+            0x7A => self.gs.round_state.mode = RoundMode::Off,
+            0x7C => self.gs.round_state.mode = RoundMode::UpToGrid,
+            0x7D => self.gs.round_state.mode = RoundMode::DownToGrid,
+            // SANGW, AA: obsolete
+            0x7E | 0x7F => {
+                self.pop()?;
+            }
+            0x80 => self.op_flippt()?,
+            0x81 | 0x82 => self.op_fliprg(opcode)?,
+            // SCANCTRL
+            0x85 => {
+                let value = self.pop()?;
+                self.set_scan_control(value);
+            }
+            0x86 | 0x87 => self.op_sdpvtl(opcode)?,
+            // GETINFO
+            0x88 => {
+                let selector = self.pop()?;
+                let info = self.info(selector);
+                self.push(info)?;
+            }
+            // IDEF
+            0x89 => {
+                let opcode = self.pop()?;
+                let definition = self.define()?;
+                let slot = usize::try_from(opcode)
+                    .ok()
+                    .filter(|opcode| *opcode < self.instruction_defs.len())
+                    .ok_or(HintError::InvalidArgument)?;
+                self.instruction_defs.to_mut()[slot] = Some(definition);
+            }
+            // ROLL
+            0x8A => {
+                let a = self.pop()?;
+                let b = self.pop()?;
+                let c = self.pop()?;
+                self.push(b)?;
+                self.push(a)?;
+                self.push(c)?;
+            }
+            // SCANTYPE
+            0x8D => {
+                let value = self.pop()?;
+                if value >= 0 {
+                    self.gs.scan_type = value & 0xFFFF;
+                }
+            }
+            // INSTCTRL
+            0x8E => {
+                let selector = self.pop()?;
+                let value = self.pop()?;
+                self.set_instruct_control(selector, value);
+            }
+            // GETVARIATION
+            0x91 if self.axis_count != 0 => {
+                for idx in 0..self.axis_count as usize {
+                    let coord = self.coords.get(idx).copied().unwrap_or_default();
+                    self.push(coord.to_bits() as i32)?;
+                }
+            }
+            // GETDATA
+            0x92 if self.axis_count != 0 => self.push(17)?,
+            0xC0..=0xDF => self.op_mdrp(opcode)?,
+            0xE0..=0xFF => self.op_mirp(opcode)?,
+            _ => {
+                let definition = self
+                    .instruction_defs
+                    .get(opcode as usize)
+                    .copied()
+                    .flatten()
+                    .ok_or(HintError::InvalidOpcode(opcode))?;
+                self.enter(definition, 1)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn push(&mut self, value: i32) -> Result<(), HintError> {
+        if self.stack.len() >= self.max_stack {
+            return Err(HintError::StackOverflow);
+        }
+        self.stack.push(value);
+        Ok(())
+    }
+
+    fn pop(&mut self) -> Result<i32, HintError> {
+        self.stack.pop().ok_or(HintError::StackUnderflow)
+    }
+
+    /// Pops a point, contour or reference point index.
+    ///
+    /// Negative values become indices that are always out of bounds.
+    fn pop_usize(&mut self) -> Result<usize, HintError> {
+        Ok(usize::try_from(self.pop()?).unwrap_or(usize::MAX))
+    }
+
+    fn zone(&self, zone: ZonePointer) -> &Zone {
+        match zone {
+            ZonePointer::Twilight => &self.twilight,
+            ZonePointer::Glyph => &self.glyph,
+        }
+    }
+
+    fn zone_mut(&mut self, zone: ZonePointer) -> &mut Zone {
+        match zone {
+            ZonePointer::Twilight => self.twilight.to_mut(),
+            ZonePointer::Glyph => &mut self.glyph,
+        }
+    }
+
+    fn storage_index(&self, index: i32) -> Result<usize, HintError> {
+        usize::try_from(index)
+            .ok()
+            .filter(|index| *index < self.storage.len())
+            .ok_or(HintError::InvalidStorageIndex(index))
+    }
+
+    fn cvt_index(&self, index: i32) -> Result<usize, HintError> {
+        usize::try_from(index)
+            .ok()
+            .filter(|index| *index < self.cvt.len())
+            .ok_or(HintError::InvalidCvtIndex(index))
+    }
+
+    fn read_cvt(&self, index: i32) -> Result<i32, HintError> {
+        Ok(self.cvt[self.cvt_index(index)?])
+    }
+
+    fn write_cvt(&mut self, index: i32, value: i32) -> Result<(), HintError> {
+        let index = self.cvt_index(index)?;
+        self.cvt.to_mut()[index] = value;
+        Ok(())
+    }
+
+    /// Moves to the instruction `offset` bytes from `from`.
+    fn jump(&mut self, from: usize, offset: i32) -> Result<(), HintError> {
+        let len = self.programs[self.program as usize].len();
+        let target = from as i64 + offset as i64;
+        if target < 0 || target > len as i64 {
+            return Err(HintError::InvalidJump);
+        }
+        self.pc = target as usize;
+        Ok(())
+    }
+
+    /// Skips past the matching `EIF`, or `ELSE` if `stop_at_else` is set.
+    fn skip_branch(&mut self, stop_at_else: bool) -> Result<(), HintError> {
+        let mut decoder = Decoder::new(self.programs[self.program as usize], self.pc);
+        let mut depth = 0usize;
+        while let Some(instruction) = decoder.decode() {
+            match instruction?.opcode.to_u8() {
+                // IF
+                0x58 => depth += 1,
+                // ELSE
+                0x1B if depth == 0 && stop_at_else => {
+                    self.pc = decoder.pc;
+                    return Ok(());
+                }
+                // EIF
+                0x59 => {
+                    if depth == 0 {
+                        self.pc = decoder.pc;
+                        return Ok(());
+                    }
+                    depth -= 1;
+                }
+                _ => {}
+            }
+        }
+        Err(HintError::UnbalancedIf)
+    }
+
+    /// Records the body of an `FDEF` or `IDEF` starting at the current
+    /// instruction and skips past it.
+    fn define(&mut self) -> Result<Definition, HintError> {
+        if self.program == Program::Glyph {
+            return Err(HintError::DefinitionInGlyphProgram);
+        }
+        let start = self.pc;
+        let mut decoder = Decoder::new(self.programs[self.program as usize], start);
+        while let Some(instruction) = decoder.decode() {
+            let instruction = instruction?;
+            match instruction.opcode.to_u8() {
+                // FDEF, IDEF
+                0x2C | 0x89 => return Err(HintError::NestedDefinition),
+                // ENDF
+                0x2D => {
+                    self.pc = decoder.pc;
+                    return Ok(Definition {
+                        program: self.program,
+                        start,
+                        end: instruction.pc,
+                    });
+                }
+                _ => {}
+            }
+        }
+        Err(HintError::UnexpectedEndOfProgram)
+    }
+
+    fn call(&mut self, index: i32, count: u32) -> Result<(), HintError> {
+        let definition = usize::try_from(index)
+            .ok()
+            .and_then(|index| self.functions.get(index).copied().flatten())
+            .ok_or(HintError::InvalidFunction(index))?;
+        self.enter(definition, count)
+    }
+
+    /// Calls a definition `count` times.
+    fn enter(&mut self, definition: Definition, count: u32) -> Result<(), HintError> {
+        if count == 0 {
+            return Ok(());
+        }
+        if self.call_stack.len() >= MAX_CALL_DEPTH {
+            return Err(HintError::CallStackOverflow);
+        }
+        self.call_stack.push(CallRecord {
+            program: self.program,
+            return_pc: self.pc,
+            remaining: count,
+            definition,
+        });
+        self.program = definition.program;
+        self.pc = definition.start;
+        Ok(())
+    }
+
+    /// Computes the result of `GETINFO`.
+    ///
+    /// We claim to render grayscale, which is what text rendered with these
+    /// outlines looks like.
+    fn info(&self, selector: i32) -> i32 {
+        let mut result = 0;
+        if selector & 1 != 0 {
+            result = match self.version {
+                InterpreterVersion::V35 => 35,
+                InterpreterVersion::V40 => 40,
+            };
+        }
+        if selector & 8 != 0 && self.axis_count != 0 {
+            result |= 1 << 10;
+        }
+        match self.version {
+            InterpreterVersion::V35 => {
+                if selector & 32 != 0 {
+                    result |= 1 << 12;
+                }
+            }
+            InterpreterVersion::V40 => {
+                // subpixel hinting, subpixel positioning, symmetrical
+                // smoothing and grayscale ClearType
+                for (selector_bit, result_bit) in [(6, 13), (10, 17), (11, 18), (12, 19)] {
+                    if selector & (1 << selector_bit) != 0 {
+                        result |= 1 << result_bit;
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    fn set_instruct_control(&mut self, selector: i32, value: i32) {
+        // selectors are indices, and values are the corresponding flag
+        if !(1..=3).contains(&selector) {
+            return;
+        }
+        let flag = 1 << (selector - 1);
+        if value != 0 && value != flag {
+            return;
+        }
+        match self.program {
+            Program::ControlValue => {
+                self.gs.instruct_control &= !(flag as u8);
+                self.gs.instruct_control |= value as u8;
+            }
+            // glyphs may only toggle backward compatibility
+            Program::Glyph if selector == 3 && self.version == InterpreterVersion::V40 => {
+                self.backward_compatibility = value != 4;
+            }
+            _ => {}
+        }
+    }
+
+    fn set_scan_control(&mut self, value: i32) {
+        let threshold = value & 0xFF;
+        match threshold {
+            0xFF => self.gs.scan_control = true,
+            0 => self.gs.scan_control = false,
+            _ => {
+                // we never render rotated or stretched glyphs
+                if value & 0x100 != 0 && self.ppem <= threshold {
+                    self.gs.scan_control = true;
+                }
+                if value & 0x800 != 0 && self.ppem > threshold {
+                    self.gs.scan_control = false;
+                }
+            }
+        }
+    }
+}
+
+/// Computes the unit vector from `b` to `a`, rotated counterclockwise if
+/// `perpendicular` is set.
+fn line_vector(a: Point<i32>, b: Point<i32>, perpendicular: bool) -> Point<i32> {
+    let (mut x, mut y) = (a.x.wrapping_sub(b.x), a.y.wrapping_sub(b.y));
+    if x == 0 && y == 0 {
+        x = 0x4000;
+    }
+    if perpendicular {
+        (x, y) = (y.wrapping_neg(), x);
+    }
+    math::normalize(x, y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Runs the given instructions as a glyph program over points with the
+    /// given positions and contour end points, at 16ppem in v35 mode.
+    ///
+    /// The positions are used for the unscaled, original and current
+    /// positions, at 1:1 scale.
+    pub(super) fn run<'a>(
+        points: &[(i32, i32)],
+        contours: &[usize],
+        cvt: &[i32],
+        instructions: &'a [u8],
+    ) -> Result<Engine<'a>, HintError> {
+        let mut engine = Engine::new([&[], &[], instructions], 64);
+        engine.version = InterpreterVersion::V35;
+        engine.ppem = 16;
+        engine.cvt = Cow::Owned(cvt.to_vec());
+        engine.storage = Cow::Owned(vec![0; 8]);
+        engine.twilight = Cow::Owned(Zone::with_len(4));
+        let mut glyph = Zone::with_len(points.len());
+        for (idx, (x, y)) in points.iter().enumerate() {
+            let point = Point::new(*x, *y);
+            glyph.unscaled[idx] = point;
+            glyph.original[idx] = point;
+            glyph.points[idx] = point;
+        }
+        glyph.contours = contours.to_vec();
+        engine.glyph = glyph;
+        engine.run(Program::Glyph)?;
+        Ok(engine)
+    }
+
+    /// The current positions of the points in a zone.
+    pub(super) fn positions(zone: &Zone) -> Vec<(i32, i32)> {
+        zone.points.iter().map(|point| (point.x, point.y)).collect()
+    }
+
+    #[test]
+    fn arithmetic() {
+        let instructions = [
+            // MUL 96 128, DIV 96 128, SUB 10 3
+            0xB1, 96, 128, 0x63, 0xB1, 96, 128, 0x62, 0xB1, 10, 3, 0x61,
+            // ABS(NEG 5), FLOOR 100, CEILING 100
+            0xB0, 5, 0x65, 0x64, 0xB0, 100, 0x66, 0xB0, 100, 0x67,
+        ];
+        let engine = run(&[], &[], &[], &instructions).unwrap();
+        assert_eq!(engine.stack, [192, 48, 7, 5, 64, 128]);
+        // DIV 1 0
+        assert!(matches!(
+            run(&[], &[], &[], &[0xB1, 1, 0, 0x62]),
+            Err(HintError::DivideByZero)
+        ));
+    }
+
+    #[test]
+    fn stack_manipulation() {
+        // PUSHB 1 2 3, ROLL
+        let engine = run(&[], &[], &[], &[0xB2, 1, 2, 3, 0x8A]).unwrap();
+        assert_eq!(engine.stack, [2, 3, 1]);
+        // PUSHB 1 2 3 3, MINDEX, PUSHB 2, CINDEX
+        let instructions = [0xB3, 1, 2, 3, 3, 0x26, 0xB0, 2, 0x25];
+        let engine = run(&[], &[], &[], &instructions).unwrap();
+        assert_eq!(engine.stack, [2, 3, 1, 3]);
+        // PUSHB 1 2, CINDEX
+        assert!(matches!(
+            run(&[], &[], &[], &[0xB1, 1, 2, 0x25]),
+            Err(HintError::InvalidStackIndex(2))
+        ));
+    }
+
+    #[test]
+    fn vectors() {
+        // PUSHB 1 0, SPVTL[parallel], GPV
+        let points = [(0, 0), (0, 64)];
+        let engine = run(&points, &[1], &[], &[0xB1, 1, 0, 0x06, 0x0C]).unwrap();
+        assert_eq!(engine.stack, [0, 0x4000]);
+        // SFVTCA[y], GFV, SVTCA[x], GPV
+        let engine = run(&[], &[], &[], &[0x04, 0x0D, 0x01, 0x0C]).unwrap();
+        assert_eq!(engine.stack, [0, 0x4000, 0x4000, 0]);
+    }
+}
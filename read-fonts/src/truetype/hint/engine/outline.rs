@@ -0,0 +1,891 @@
+//! Instructions that measure and move points.
+//!
+//! These follow the FreeType implementation closely, including its handling
+//! of undocumented behavior in the twilight zone and the restrictions of
+//! backward compatibility mode.
+
+use font_types::Point;
+
+use super::{Engine, HintError};
+use crate::truetype::hint::{
+    graphics_state::ZonePointer,
+    math,
+    zone::{PointFlags, Zone},
+};
+
+impl Engine<'_> {
+    /// Returns `true` if backward compatibility mode is active and `IUP`
+    /// has been called on both axes, after which points stay put.
+    fn is_post_iup(&self) -> bool {
+        self.backward_compatibility && self.iupx_called && self.iupy_called
+    }
+
+    /// Moves a point by a distance along the projection vector, in the
+    /// direction of the freedom vector.
+    fn move_point(
+        &mut self,
+        zone: ZonePointer,
+        idx: usize,
+        distance: i32,
+    ) -> Result<(), HintError> {
+        let fv = self.gs.freedom_vector;
+        let delta = self.gs.move_along_freedom_vector(distance);
+        let block_x = self.backward_compatibility;
+        let block_y = self.is_post_iup();
+        let zone = self.zone_mut(zone);
+        zone.check_point(idx)?;
+        if fv.x != 0 {
+            if !block_x {
+                zone.points[idx].x = zone.points[idx].x.wrapping_add(delta.x);
+            }
+            zone.flags[idx].insert(PointFlags::TOUCHED_X);
+        }
+        if fv.y != 0 {
+            if !block_y {
+                zone.points[idx].y = zone.points[idx].y.wrapping_add(delta.y);
+            }
+            zone.flags[idx].insert(PointFlags::TOUCHED_Y);
+        }
+        Ok(())
+    }
+
+    /// Like [`move_point`](Self::move_point), but for the original position.
+    fn move_original(
+        &mut self,
+        zone: ZonePointer,
+        idx: usize,
+        distance: i32,
+    ) -> Result<(), HintError> {
+        let delta = self.gs.move_along_freedom_vector(distance);
+        let zone = self.zone_mut(zone);
+        zone.check_point(idx)?;
+        let point = &mut zone.original[idx];
+        *point = Point::new(point.x.wrapping_add(delta.x), point.y.wrapping_add(delta.y));
+        Ok(())
+    }
+
+    /// Moves a point in zone 2 by the given amounts, for the shift
+    /// instructions.
+    fn move_zp2_point(
+        &mut self,
+        idx: usize,
+        dx: i32,
+        dy: i32,
+        touch: bool,
+    ) -> Result<(), HintError> {
+        let fv = self.gs.freedom_vector;
+        let block_x = self.backward_compatibility;
+        let block_y = self.is_post_iup();
+        let zone = self.zone_mut(self.gs.zp2);
+        zone.check_point(idx)?;
+        if fv.x != 0 {
+            if !block_x {
+                zone.points[idx].x = zone.points[idx].x.wrapping_add(dx);
+            }
+            if touch {
+                zone.flags[idx].insert(PointFlags::TOUCHED_X);
+            }
+        }
+        if fv.y != 0 {
+            if !block_y {
+                zone.points[idx].y = zone.points[idx].y.wrapping_add(dy);
+            }
+            if touch {
+                zone.flags[idx].insert(PointFlags::TOUCHED_Y);
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the zone and index of the reference point used by the shift
+    /// instructions, along with how far it has moved.
+    fn point_displacement(
+        &self,
+        opcode: u8,
+    ) -> Result<(ZonePointer, usize, Point<i32>), HintError> {
+        let (zone, point) = if opcode & 1 != 0 {
+            (self.gs.zp0, self.gs.rp1)
+        } else {
+            (self.gs.zp1, self.gs.rp2)
+        };
+        let z = self.zone(zone);
+        let distance = self.gs.project(z.point(point)?, z.original(point)?);
+        Ok((zone, point, self.gs.move_along_freedom_vector(distance)))
+    }
+
+    /// Measures the original distance from `b` to `a` along the dual
+    /// projection vector.
+    fn measure_distance(
+        &self,
+        zone_a: ZonePointer,
+        a: usize,
+        zone_b: ZonePointer,
+        b: usize,
+    ) -> Result<i32, HintError> {
+        // points in the twilight zone have no unscaled positions
+        if zone_a == ZonePointer::Twilight || zone_b == ZonePointer::Twilight {
+            Ok(self.gs.dual_project(
+                self.zone(zone_a).original(a)?,
+                self.zone(zone_b).original(b)?,
+            ))
+        } else {
+            let distance = self.gs.dual_project(
+                self.zone(zone_a).unscaled(a)?,
+                self.zone(zone_b).unscaled(b)?,
+            );
+            Ok(math::mul_fix(distance, self.unscaled_scale))
+        }
+    }
+
+    /// Applies the minimum distance, preserving the sign of `original`.
+    fn apply_min_distance(&self, distance: i32, original: i32) -> i32 {
+        let min_distance = self.gs.min_distance;
+        if original >= 0 {
+            distance.max(min_distance)
+        } else {
+            distance.min(min_distance.wrapping_neg())
+        }
+    }
+
+    /// Takes the loop counter, resetting it for the next instruction.
+    fn take_loop_counter(&mut self) -> u32 {
+        std::mem::replace(&mut self.gs.loop_counter, 1)
+    }
+
+    /// ISECT: moves a point to the intersection of two lines.
+    pub(super) fn op_isect(&mut self) -> Result<(), HintError> {
+        let b1 = self.pop_usize()?;
+        let b0 = self.pop_usize()?;
+        let a1 = self.pop_usize()?;
+        let a0 = self.pop_usize()?;
+        let point = self.pop_usize()?;
+        let (zp0, zp1) = (self.zone(self.gs.zp0), self.zone(self.gs.zp1));
+        let (pb0, pb1) = (zp0.point(b0)?, zp0.point(b1)?);
+        let (pa0, pa1) = (zp1.point(a0)?, zp1.point(a1)?);
+        let db = Point::new(pb1.x.wrapping_sub(pb0.x), pb1.y.wrapping_sub(pb0.y));
+        let da = Point::new(pa1.x.wrapping_sub(pa0.x), pa1.y.wrapping_sub(pa0.y));
+        let d = Point::new(pb0.x.wrapping_sub(pa0.x), pb0.y.wrapping_sub(pa0.y));
+        let discriminant = math::mul_div(da.x, db.y.wrapping_neg(), 64)
+            .wrapping_add(math::mul_div(da.y, db.x, 64));
+        let dot_product = math::mul_div(da.x, db.x, 64).wrapping_add(math::mul_div(da.y, db.y, 64));
+        // reject nearly parallel lines by thresholding the tangent of the
+        // angle between them at 1/19 (about 3 degrees)
+        let result = if 19 * (discriminant as i64).abs() > (dot_product as i64).abs() {
+            let value = math::mul_div(d.x, db.y.wrapping_neg(), 64)
+                .wrapping_add(math::mul_div(d.y, db.x, 64));
+            Point::new(
+                pa0.x.wrapping_add(math::mul_div(value, da.x, discriminant)),
+                pa0.y.wrapping_add(math::mul_div(value, da.y, discriminant)),
+            )
+        } else {
+            // take the middle of the middles of the lines
+            let sum = |a: i32, b: i32, c: i32, d: i32| {
+                ((a as i64 + b as i64 + c as i64 + d as i64) / 4) as i32
+            };
+            Point::new(
+                sum(pa0.x, pa1.x, pb0.x, pb1.x),
+                sum(pa0.y, pa1.y, pb0.y, pb1.y),
+            )
+        };
+        let zone = self.zone_mut(self.gs.zp2);
+        zone.check_point(point)?;
+        zone.points[point] = result;
+        zone.flags[point].insert(PointFlags::TOUCHED);
+        Ok(())
+    }
+
+    /// ALIGNPTS: moves two points to their midpoint.
+    pub(super) fn op_alignpts(&mut self) -> Result<(), HintError> {
+        let p2 = self.pop_usize()?;
+        let p1 = self.pop_usize()?;
+        let distance = self.gs.project(
+            self.zone(self.gs.zp0).point(p2)?,
+            self.zone(self.gs.zp1).point(p1)?,
+        ) / 2;
+        self.move_point(self.gs.zp1, p1, distance)?;
+        self.move_point(self.gs.zp0, p2, distance.wrapping_neg())
+    }
+
+    /// UTP: marks a point as untouched along the freedom vector.
+    pub(super) fn op_utp(&mut self) -> Result<(), HintError> {
+        let point = self.pop_usize()?;
+        let fv = self.gs.freedom_vector;
+        let zone = self.zone_mut(self.gs.zp0);
+        zone.check_point(point)?;
+        if fv.x != 0 {
+            zone.flags[point].remove(PointFlags::TOUCHED_X);
+        }
+        if fv.y != 0 {
+            zone.flags[point].remove(PointFlags::TOUCHED_Y);
+        }
+        Ok(())
+    }
+
+    /// MDAP: touches a point, optionally rounding it.
+    pub(super) fn op_mdap(&mut self, opcode: u8) -> Result<(), HintError> {
+        let point = self.pop_usize()?;
+        let zp0 = self.gs.zp0;
+        let position = self.zone(zp0).point(point)?;
+        let distance = if opcode & 1 != 0 {
+            let current = self.gs.project(position, Point::default());
+            self.gs.round_state.round(current).wrapping_sub(current)
+        } else {
+            0
+        };
+        self.move_point(zp0, point, distance)?;
+        self.gs.rp0 = point;
+        self.gs.rp1 = point;
+        Ok(())
+    }
+
+    /// MIAP: moves a point to the position given by a CVT entry.
+    pub(super) fn op_miap(&mut self, opcode: u8) -> Result<(), HintError> {
+        let cvt_index = self.pop()?;
+        let point = self.pop_usize()?;
+        let zp0 = self.gs.zp0;
+        self.zone(zp0).check_point(point)?;
+        let mut distance = self.read_cvt(cvt_index)?;
+        if zp0 == ZonePointer::Twilight {
+            // undocumented: twilight points are first placed along the
+            // freedom vector
+            let fv = self.gs.freedom_vector;
+            let position = Point::new(math::mul14(distance, fv.x), math::mul14(distance, fv.y));
+            let zone = self.zone_mut(zp0);
+            zone.original[point] = position;
+            zone.points[point] = position;
+        }
+        let original_distance = self
+            .gs
+            .project(self.zone(zp0).point(point)?, Point::default());
+        if opcode & 1 != 0 {
+            if distance.wrapping_sub(original_distance).wrapping_abs() > self.gs.control_value_cutin
+            {
+                distance = original_distance;
+            }
+            distance = self.gs.round_state.round(distance);
+        }
+        self.move_point(zp0, point, distance.wrapping_sub(original_distance))?;
+        self.gs.rp0 = point;
+        self.gs.rp1 = point;
+        Ok(())
+    }
+
+    /// MDRP: keeps the original distance between a point and rp0.
+    pub(super) fn op_mdrp(&mut self, opcode: u8) -> Result<(), HintError> {
+        let point = self.pop_usize()?;
+        let (zp0, zp1, rp0) = (self.gs.zp0, self.gs.zp1, self.gs.rp0);
+        self.zone(zp1).check_point(point)?;
+        self.zone(zp0).check_point(rp0)?;
+        let mut original_distance = self.measure_distance(zp1, point, zp0, rp0)?;
+        let (single_width, cutin) = (self.gs.single_width, self.gs.single_width_cutin);
+        if cutin > 0
+            && original_distance < single_width.wrapping_add(cutin)
+            && original_distance > single_width.wrapping_sub(cutin)
+        {
+            original_distance = if original_distance >= 0 {
+                single_width
+            } else {
+                single_width.wrapping_neg()
+            };
+        }
+        let mut distance = if opcode & 4 != 0 {
+            self.gs.round_state.round(original_distance)
+        } else {
+            original_distance
+        };
+        if opcode & 8 != 0 {
+            distance = self.apply_min_distance(distance, original_distance);
+        }
+        let current_distance = self
+            .gs
+            .project(self.zone(zp1).point(point)?, self.zone(zp0).point(rp0)?);
+        self.move_point(zp1, point, distance.wrapping_sub(current_distance))?;
+        self.gs.rp1 = rp0;
+        self.gs.rp2 = point;
+        if opcode & 16 != 0 {
+            self.gs.rp0 = point;
+        }
+        Ok(())
+    }
+
+    /// MIRP: moves a point to the distance from rp0 given by a CVT entry.
+    pub(super) fn op_mirp(&mut self, opcode: u8) -> Result<(), HintError> {
+        let cvt_index = self.pop()?;
+        let point = self.pop_usize()?;
+        let (zp0, zp1, rp0) = (self.gs.zp0, self.gs.zp1, self.gs.rp0);
+        self.zone(zp1).check_point(point)?;
+        self.zone(zp0).check_point(rp0)?;
+        // undocumented: an index of -1 refers to a zero distance
+        let mut cvt_distance = match cvt_index {
+            -1 => 0,
+            _ => self.read_cvt(cvt_index)?,
+        };
+        let single_width = self.gs.single_width;
+        if cvt_distance.wrapping_sub(single_width).wrapping_abs() < self.gs.single_width_cutin {
+            cvt_distance = if cvt_distance >= 0 {
+                single_width
+            } else {
+                single_width.wrapping_neg()
+            };
+        }
+        if zp1 == ZonePointer::Twilight {
+            // undocumented: twilight points are first placed at the CVT
+            // distance from rp0 along the freedom vector
+            let origin = self.zone(zp0).original(rp0)?;
+            let fv = self.gs.freedom_vector;
+            let position = Point::new(
+                origin.x.wrapping_add(math::mul14(cvt_distance, fv.x)),
+                origin.y.wrapping_add(math::mul14(cvt_distance, fv.y)),
+            );
+            let zone = self.zone_mut(zp1);
+            zone.original[point] = position;
+            zone.points[point] = position;
+        }
+        let original_distance = self.gs.dual_project(
+            self.zone(zp1).original(point)?,
+            self.zone(zp0).original(rp0)?,
+        );
+        let current_distance = self
+            .gs
+            .project(self.zone(zp1).point(point)?, self.zone(zp0).point(rp0)?);
+        if self.gs.auto_flip && (original_distance ^ cvt_distance) < 0 {
+            cvt_distance = cvt_distance.wrapping_neg();
+        }
+        let mut distance = if opcode & 4 != 0 {
+            // undocumented: the cut-in test only applies when both points
+            // are in the same zone
+            if zp0 == zp1
+                && cvt_distance.wrapping_sub(original_distance).wrapping_abs()
+                    > self.gs.control_value_cutin
+            {
+                cvt_distance = original_distance;
+            }
+            self.gs.round_state.round(cvt_distance)
+        } else {
+            cvt_distance
+        };
+        if opcode & 8 != 0 {
+            distance = self.apply_min_distance(distance, original_distance);
+        }
+        self.move_point(zp1, point, distance.wrapping_sub(current_distance))?;
+        self.gs.rp1 = rp0;
+        if opcode & 16 != 0 {
+            self.gs.rp0 = point;
+        }
+        self.gs.rp2 = point;
+        Ok(())
+    }
+
+    /// MSIRP: moves a point to the given distance from rp0.
+    pub(super) fn op_msirp(&mut self, opcode: u8) -> Result<(), HintError> {
+        let distance = self.pop()?;
+        let point = self.pop_usize()?;
+        let (zp0, zp1, rp0) = (self.gs.zp0, self.gs.zp1, self.gs.rp0);
+        self.zone(zp1).check_point(point)?;
+        let reference = self.zone(zp0).point(rp0)?;
+        if zp1 == ZonePointer::Twilight {
+            // undocumented: twilight points start out at rp0
+            let origin = self.zone(zp0).original(rp0)?;
+            self.zone_mut(zp1).original[point] = origin;
+            self.move_original(zp1, point, distance)?;
+            let zone = self.zone_mut(zp1);
+            zone.points[point] = zone.original[point];
+        }
+        let current_distance = self.gs.project(self.zone(zp1).point(point)?, reference);
+        self.move_point(zp1, point, distance.wrapping_sub(current_distance))?;
+        self.gs.rp1 = rp0;
+        self.gs.rp2 = point;
+        if opcode & 1 != 0 {
+            self.gs.rp0 = point;
+        }
+        Ok(())
+    }
+
+    /// ALIGNRP: moves points onto rp0 along the projection vector.
+    pub(super) fn op_alignrp(&mut self) -> Result<(), HintError> {
+        let (zp0, zp1) = (self.gs.zp0, self.gs.zp1);
+        for _ in 0..self.take_loop_counter() {
+            let point = self.pop_usize()?;
+            let reference = self.zone(zp0).point(self.gs.rp0)?;
+            let distance = self.gs.project(self.zone(zp1).point(point)?, reference);
+            self.move_point(zp1, point, distance.wrapping_neg())?;
+        }
+        Ok(())
+    }
+
+    /// SHP: shifts points by the movement of a reference point.
+    pub(super) fn op_shp(&mut self, opcode: u8) -> Result<(), HintError> {
+        let (_, _, delta) = self.point_displacement(opcode)?;
+        for _ in 0..self.take_loop_counter() {
+            let point = self.pop_usize()?;
+            self.move_zp2_point(point, delta.x, delta.y, true)?;
+        }
+        Ok(())
+    }
+
+    /// SHC: shifts a contour by the movement of a reference point.
+    pub(super) fn op_shc(&mut self, opcode: u8) -> Result<(), HintError> {
+        let contour = self.pop_usize()?;
+        let contours = &self.glyph.contours;
+        let Some(&end) = contours.get(contour) else {
+            return Err(HintError::InvalidContourIndex(contour));
+        };
+        let start = match contour {
+            0 => 0,
+            _ => contours[contour - 1] + 1,
+        };
+        let (zone, reference, delta) = self.point_displacement(opcode)?;
+        let zp2 = self.gs.zp2;
+        // the twilight zone has no contours, so all points are shifted
+        let limit = match zp2 {
+            ZonePointer::Twilight => self.twilight.len(),
+            ZonePointer::Glyph => end + 1,
+        };
+        for point in start..limit {
+            if zone != zp2 || point != reference {
+                self.move_zp2_point(point, delta.x, delta.y, true)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// SHZ: shifts all points in a zone by the movement of a reference
+    /// point.
+    pub(super) fn op_shz(&mut self, opcode: u8) -> Result<(), HintError> {
+        // the zone is validated, but zp2 is what gets shifted
+        ZonePointer::new(self.pop()?)?;
+        let (zone, reference, delta) = self.point_displacement(opcode)?;
+        let zp2 = self.gs.zp2;
+        // undocumented: phantom points are not shifted
+        let limit = match zp2 {
+            ZonePointer::Twilight => self.twilight.len(),
+            ZonePointer::Glyph => self.glyph.contours.last().map_or(0, |end| end + 1),
+        };
+        for point in 0..limit {
+            if zone != zp2 || point != reference {
+                self.move_zp2_point(point, delta.x, delta.y, false)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// SHPIX: shifts points by a distance along the freedom vector.
+    pub(super) fn op_shpix(&mut self) -> Result<(), HintError> {
+        let amount = self.pop()?;
+        let fv = self.gs.freedom_vector;
+        let (dx, dy) = (math::mul14(amount, fv.x), math::mul14(amount, fv.y));
+        let in_twilight = [self.gs.zp0, self.gs.zp1, self.gs.zp2]
+            .iter()
+            .all(|zone| *zone == ZonePointer::Twilight);
+        for _ in 0..self.take_loop_counter() {
+            let point = self.pop_usize()?;
+            if self.backward_compatibility {
+                // only allow vertical movement of points in the twilight
+                // zone, or of points already touched in y before IUP, as
+                // for DELTAP
+                let touched_y = self
+                    .zone(self.gs.zp2)
+                    .flags
+                    .get(point)
+                    .is_some_and(|flags| flags.contains(PointFlags::TOUCHED_Y));
+                if in_twilight
+                    || (!(self.iupx_called && self.iupy_called)
+                        && ((self.is_composite && fv.y != 0) || touched_y))
+                {
+                    self.move_zp2_point(point, 0, dy, true)?;
+                }
+            } else {
+                self.move_zp2_point(point, dx, dy, true)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// IP: interpolates points between rp1 and rp2.
+    pub(super) fn op_ip(&mut self) -> Result<(), HintError> {
+        let count = self.take_loop_counter();
+        let (zp0, zp1, zp2) = (self.gs.zp0, self.gs.zp1, self.gs.zp2);
+        let in_twilight = [zp0, zp1, zp2].contains(&ZonePointer::Twilight);
+        let base_position = |zone: &Zone, idx: usize| {
+            if in_twilight {
+                zone.original(idx)
+            } else {
+                zone.unscaled(idx)
+            }
+        };
+        let (rp1, rp2) = (self.gs.rp1, self.gs.rp2);
+        let (Ok(current_base), Ok(original_base)) = (
+            self.zone(zp0).point(rp1),
+            base_position(self.zone(zp0), rp1),
+        ) else {
+            // invalid reference points occur in real fonts, so ignore
+            // the instruction rather than failing
+            for _ in 0..count {
+                self.pop()?;
+            }
+            return Ok(());
+        };
+        let (original_range, current_range) = match (
+            base_position(self.zone(zp1), rp2),
+            self.zone(zp1).point(rp2),
+        ) {
+            (Ok(original), Ok(current)) => (
+                self.gs.dual_project(original, original_base),
+                self.gs.project(current, current_base),
+            ),
+            _ => (0, 0),
+        };
+        for _ in 0..count {
+            let point = self.pop_usize()?;
+            let zone = self.zone(zp2);
+            let (Ok(original), Ok(current)) = (base_position(zone, point), zone.point(point))
+            else {
+                continue;
+            };
+            let original_distance = self.gs.dual_project(original, original_base);
+            let current_distance = self.gs.project(current, current_base);
+            let new_distance = match (original_distance, original_range) {
+                (0, _) => 0,
+                (_, 0) => original_distance,
+                _ => math::mul_div(original_distance, current_range, original_range),
+            };
+            self.move_point(zp2, point, new_distance.wrapping_sub(current_distance))?;
+        }
+        Ok(())
+    }
+
+    /// IUP: interpolates untouched points in the glyph.
+    pub(super) fn op_iup(&mut self, opcode: u8) -> Result<(), HintError> {
+        let x_axis = opcode & 1 != 0;
+        if self.backward_compatibility {
+            // only the first IUP on each axis has any effect
+            if self.iupx_called && self.iupy_called {
+                return Ok(());
+            }
+            if x_axis {
+                self.iupx_called = true;
+            } else {
+                self.iupy_called = true;
+            }
+        }
+        self.glyph.interpolate_untouched(x_axis);
+        Ok(())
+    }
+
+    /// GC: gets the coordinate of a point along the projection vector.
+    pub(super) fn op_gc(&mut self, opcode: u8) -> Result<(), HintError> {
+        let point = self.pop_usize()?;
+        let zone = self.zone(self.gs.zp2);
+        let value = if opcode & 1 != 0 {
+            self.gs
+                .dual_project(zone.original(point)?, Point::default())
+        } else {
+            self.gs.project(zone.point(point)?, Point::default())
+        };
+        self.push(value)
+    }
+
+    /// SCFS: sets the coordinate of a point along the projection vector.
+    pub(super) fn op_scfs(&mut self) -> Result<(), HintError> {
+        let value = self.pop()?;
+        let point = self.pop_usize()?;
+        let zp2 = self.gs.zp2;
+        let current = self
+            .gs
+            .project(self.zone(zp2).point(point)?, Point::default());
+        self.move_point(zp2, point, value.wrapping_sub(current))?;
+        if zp2 == ZonePointer::Twilight {
+            // undocumented: twilight points also move their original
+            // position
+            let zone = self.zone_mut(zp2);
+            zone.original[point] = zone.points[point];
+        }
+        Ok(())
+    }
+
+    /// MD: measures the distance between two points.
+    pub(super) fn op_md(&mut self, opcode: u8) -> Result<(), HintError> {
+        let k = self.pop_usize()?;
+        let l = self.pop_usize()?;
+        let (zp0, zp1) = (self.gs.zp0, self.gs.zp1);
+        // the low bit selects the current outline for MD[0] (0x49)
+        let distance = if opcode & 1 != 0 {
+            self.gs
+                .project(self.zone(zp0).point(l)?, self.zone(zp1).point(k)?)
+        } else {
+            self.measure_distance(zp0, l, zp1, k)?
+        };
+        self.push(distance)
+    }
+
+    /// DELTAP1, DELTAP2, DELTAP3: moves points at specific sizes.
+    pub(super) fn op_deltap(&mut self, opcode: u8) -> Result<(), HintError> {
+        let count = self.pop()?;
+        let base = match opcode {
+            0x71 => 16,
+            0x72 => 32,
+            _ => 0,
+        };
+        let zp0 = self.gs.zp0;
+        for _ in 0..count.max(0) {
+            let point = self.pop_usize()?;
+            let arg = self.pop()?;
+            // invalid points occur in real fonts, so skip them rather than
+            // failing
+            let Some(flags) = self.zone(zp0).flags.get(point).copied() else {
+                continue;
+            };
+            let Some(distance) = self.delta_distance(arg, base) else {
+                continue;
+            };
+            if self.backward_compatibility {
+                let fv = self.gs.freedom_vector;
+                if !(self.iupx_called && self.iupy_called)
+                    && ((self.is_composite && fv.y != 0) || flags.contains(PointFlags::TOUCHED_Y))
+                {
+                    self.move_point(zp0, point, distance)?;
+                }
+            } else {
+                self.move_point(zp0, point, distance)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// DELTAC1, DELTAC2, DELTAC3: adjusts CVT entries at specific sizes.
+    pub(super) fn op_deltac(&mut self, opcode: u8) -> Result<(), HintError> {
+        let count = self.pop()?;
+        let base = match opcode {
+            0x74 => 16,
+            0x75 => 32,
+            _ => 0,
+        };
+        for _ in 0..count.max(0) {
+            let index = self.pop()?;
+            let arg = self.pop()?;
+            let Some(distance) = self.delta_distance(arg, base) else {
+                continue;
+            };
+            if let Ok(index) = self.cvt_index(index) {
+                let cvt = self.cvt.to_mut();
+                cvt[index] = cvt[index].wrapping_add(distance);
+            }
+        }
+        Ok(())
+    }
+
+    /// Decodes the argument of a delta instruction, returning the distance
+    /// if it applies at the current size.
+    fn delta_distance(&self, arg: i32, base: i32) -> Option<i32> {
+        let ppem = ((arg & 0xF0) >> 4)
+            .wrapping_add(base)
+            .wrapping_add(self.gs.delta_base);
+        if ppem != self.ppem {
+            return None;
+        }
+        let mut steps = (arg & 0xF) - 8;
+        if steps >= 0 {
+            steps += 1;
+        }
+        Some(steps * (1 << (6 - self.gs.delta_shift)))
+    }
+
+    /// FLIPPT: toggles whether points are on the curve.
+    pub(super) fn op_flippt(&mut self) -> Result<(), HintError> {
+        let count = self.take_loop_counter();
+        if self.is_post_iup() {
+            // as in FreeType, the points are left on the stack
+            return Ok(());
+        }
+        let zp0 = self.gs.zp0;
+        for _ in 0..count {
+            let point = self.pop_usize()?;
+            let zone = self.zone_mut(zp0);
+            zone.check_point(point)?;
+            let flags = &mut zone.flags[point];
+            if flags.contains(PointFlags::ON_CURVE) {
+                flags.remove(PointFlags::ON_CURVE);
+            } else {
+                flags.insert(PointFlags::ON_CURVE);
+            }
+        }
+        Ok(())
+    }
+
+    /// FLIPRGON, FLIPRGOFF: sets whether a range of points is on the curve.
+    pub(super) fn op_fliprg(&mut self, opcode: u8) -> Result<(), HintError> {
+        let high = self.pop_usize()?;
+        let low = self.pop_usize()?;
+        if self.is_post_iup() {
+            return Ok(());
+        }
+        let zone = self.zone_mut(self.gs.zp0);
+        zone.check_point(high)?;
+        zone.check_point(low)?;
+        for flags in zone.flags.iter_mut().take(high + 1).skip(low) {
+            if opcode == 0x81 {
+                flags.insert(PointFlags::ON_CURVE);
+            } else {
+                flags.remove(PointFlags::ON_CURVE);
+            }
+        }
+        Ok(())
+    }
+
+    /// SDPVTL: sets the dual projection vector from the original positions
+    /// of two points, and the projection vector from their current
+    /// positions.
+    pub(super) fn op_sdpvtl(&mut self, opcode: u8) -> Result<(), HintError> {
+        let p2 = self.pop_usize()?;
+        let p1 = self.pop_usize()?;
+        let (zp1, zp2) = (self.zone(self.gs.zp1), self.zone(self.gs.zp2));
+        let perpendicular = opcode & 1 != 0;
+        let dual = super::line_vector(zp1.original(p1)?, zp2.original(p2)?, perpendicular);
+        let proj = super::line_vector(zp1.point(p1)?, zp2.point(p2)?, perpendicular);
+        self.gs.dual_proj_vector = dual;
+        self.gs.proj_vector = proj;
+        self.gs.update_fdotp();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::{positions, run};
+    use crate::truetype::hint::zone::PointFlags;
+
+    #[test]
+    fn mdrp() {
+        let points = [(0, 0), (100, 0), (150, 0)];
+        // PUSHB 2 1, MDRP[rp0,rnd] 1, MDRP[rnd] 2
+        let engine = run(&points, &[2], &[], &[0xB1, 2, 1, 0xD4, 0xC4]).unwrap();
+        assert_eq!(positions(&engine.glyph), [(0, 0), (128, 0), (192, 0)]);
+        assert_eq!((engine.gs.rp0, engine.gs.rp1, engine.gs.rp2), (1, 1, 2));
+        // a short distance is rounded to zero, then raised to the minimum
+        // PUSHB 1, MDRP[min,rnd]
+        let engine = run(&[(0, 0), (10, 0)], &[1], &[], &[0xB0, 1, 0xCC]).unwrap();
+        assert_eq!(positions(&engine.glyph), [(0, 0), (64, 0)]);
+    }
+
+    #[test]
+    fn shp() {
+        let points = [(10, 0), (20, 0), (50, 5)];
+        // PUSHB 0, MDAP[rnd]; PUSHB 2 1 2, SLOOP, SHP[rp1]
+        let instructions = [0xB0, 0, 0x2F, 0xB2, 2, 1, 2, 0x17, 0x33];
+        let engine = run(&points, &[2], &[], &instructions).unwrap();
+        // both points follow rp1, which moved by -10
+        assert_eq!(positions(&engine.glyph), [(0, 0), (10, 0), (40, 5)]);
+        assert!(engine.glyph.flags[2].contains(PointFlags::TOUCHED_X));
+    }
+
+    #[test]
+    fn shc_and_shz() {
+        let points = [(10, 0), (20, 0), (30, 0), (40, 0)];
+        // PUSHB 0, MDAP[rnd]; PUSHB 1, SHC[rp1]
+        let engine = run(&points, &[1, 3], &[], &[0xB0, 0, 0x2F, 0xB0, 1, 0x35]).unwrap();
+        assert_eq!(
+            positions(&engine.glyph),
+            [(0, 0), (20, 0), (20, 0), (30, 0)]
+        );
+        assert!(engine.glyph.flags[2].contains(PointFlags::TOUCHED_X));
+        // PUSHB 0, SHC[rp1] shifts the contour of the reference point,
+        // except for the reference point itself
+        let engine = run(&points, &[1, 3], &[], &[0xB0, 0, 0x2F, 0xB0, 0, 0x35]).unwrap();
+        assert_eq!(
+            positions(&engine.glyph),
+            [(0, 0), (10, 0), (30, 0), (40, 0)]
+        );
+        // PUSHB 1, SHZ[rp1] shifts every other point without touching it
+        let engine = run(&points, &[1, 3], &[], &[0xB0, 0, 0x2F, 0xB0, 1, 0x37]).unwrap();
+        assert_eq!(
+            positions(&engine.glyph),
+            [(0, 0), (10, 0), (20, 0), (30, 0)]
+        );
+        assert!(!engine.glyph.flags[2].contains(PointFlags::TOUCHED_X));
+    }
+
+    #[test]
+    fn ip() {
+        let points = [(0, 0), (50, 0), (100, 0)];
+        // PUSHB 0, MDAP[nr]; PUSHB 2 0, MIRP[nr] to cvt[0]; PUSHB 1, IP
+        let instructions = [0xB0, 0, 0x2E, 0xB1, 2, 0, 0xE0, 0xB0, 1, 0x39];
+        let engine = run(&points, &[2], &[200], &instructions).unwrap();
+        // the range between the reference points doubled
+        assert_eq!(positions(&engine.glyph), [(0, 0), (100, 0), (200, 0)]);
+    }
+
+    #[test]
+    fn msirp() {
+        let points = [(0, 0), (50, 0), (60, 0)];
+        // PUSHB 1 100, MSIRP[rp0]; PUSHB 2 30, MSIRP; then with zp1 in the
+        // twilight zone, PUSHB 0 64, MSIRP
+        let instructions = [
+            0xB1, 1, 100, 0x3B, 0xB1, 2, 30, 0x3A, 0xB0, 0, 0x14, 0xB1, 0, 64, 0x3A,
+        ];
+        let engine = run(&points, &[2], &[], &instructions).unwrap();
+        assert_eq!(positions(&engine.glyph), [(0, 0), (100, 0), (130, 0)]);
+        // the twilight point is placed relative to rp0 in the glyph
+        assert_eq!(positions(&engine.twilight)[0], (164, 0));
+    }
+
+    #[test]
+    fn miap() {
+        let points = [(10, 0), (100, 0), (100, 0)];
+        let instructions = [
+            // PUSHB 1 0, MIAP[rnd]; PUSHB 0 1, MIAP[nr]
+            0xB1, 1, 0, 0x3F, 0xB1, 0, 1, 0x3E,
+            // PUSHB 2 2, MIAP[rnd]: beyond the cut-in, so the original
+            // position is rounded instead
+            0xB1, 2, 2, 0x3F,
+        ];
+        let engine = run(&points, &[2], &[128, 40, 300], &instructions).unwrap();
+        assert_eq!(positions(&engine.glyph), [(40, 0), (128, 0), (128, 0)]);
+        assert_eq!((engine.gs.rp0, engine.gs.rp1), (2, 2));
+    }
+
+    #[test]
+    fn alignrp() {
+        let points = [(0, 5), (30, 10), (70, 15)];
+        // PUSHB 1, SRP0; PUSHB 0 2 2, SLOOP, ALIGNRP
+        let instructions = [0xB0, 1, 0x10, 0xB2, 0, 2, 2, 0x17, 0x3C];
+        let engine = run(&points, &[2], &[], &instructions).unwrap();
+        assert_eq!(positions(&engine.glyph), [(30, 5), (30, 10), (30, 15)]);
+    }
+
+    #[test]
+    fn isect() {
+        // PUSHB 0 1 2 3 4, ISECT
+        let instructions = [0xB4, 0, 1, 2, 3, 4, 0x0F];
+        // the diagonals of a square
+        let points = [(0, 0), (0, 0), (128, 128), (0, 128), (128, 0)];
+        let engine = run(&points, &[4], &[], &instructions).unwrap();
+        assert_eq!(positions(&engine.glyph)[0], (64, 64));
+        assert!(engine.glyph.flags[0].contains(PointFlags::TOUCHED));
+        // parallel lines meet in the middle
+        let points = [(0, 0), (0, 0), (128, 0), (0, 64), (128, 64)];
+        let engine = run(&points, &[4], &[], &instructions).unwrap();
+        assert_eq!(positions(&engine.glyph)[0], (64, 32));
+    }
+
+    #[test]
+    fn deltap() {
+        let points = [(0, 0), (0, 0)];
+        let instructions = [
+            // DELTAP1 with deltas for 16ppem at points 0 and 1, and one for
+            // 15ppem that is ignored
+            0xB6, 0x7F, 0, 0x70, 1, 0x6F, 0, 3, 0x5D,
+            // SDB 0; DELTAP2 for 16ppem at point 0
+            0xB0, 0, 0x5E, 0xB2, 0x0F, 0, 1, 0x71,
+        ];
+        let engine = run(&points, &[1], &[], &instructions).unwrap();
+        assert_eq!(positions(&engine.glyph), [(128, 0), (-64, 0)]);
+    }
+
+    #[test]
+    fn deltac() {
+        // DELTAC1 with deltas for 16ppem and 15ppem at cvt[0]
+        let instructions = [0xB4, 0x7F, 0, 0x6F, 0, 2, 0x73];
+        let engine = run(&[], &[], &[100], &instructions).unwrap();
+        assert_eq!(engine.cvt[0], 164);
+    }
+}
@@ -0,0 +1,375 @@
+//! The graphics state of the interpreter.
+//!
+//! See <https://learn.microsoft.com/en-us/typography/opentype/spec/tt_graphics_state>
+
+use font_types::Point;
+
+use super::{math, HintError};
+
+/// Selects either the twilight zone or the points of the glyph.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub(crate) enum ZonePointer {
+    Twilight,
+    Glyph,
+}
+
+impl ZonePointer {
+    pub(crate) fn new(value: i32) -> Result<Self, HintError> {
+        match value {
+            0 => Ok(Self::Twilight),
+            1 => Ok(Self::Glyph),
+            _ => Err(HintError::InvalidZone(value)),
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub(crate) enum RoundMode {
+    Grid,
+    HalfGrid,
+    DoubleGrid,
+    DownToGrid,
+    UpToGrid,
+    Off,
+    Super,
+    Super45,
+}
+
+/// The rounding mode, along with the parameters set by `SROUND` and
+/// `S45ROUND`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub(crate) struct RoundState {
+    pub mode: RoundMode,
+    pub period: i32,
+    pub phase: i32,
+    pub threshold: i32,
+}
+
+impl Default for RoundState {
+    fn default() -> Self {
+        Self {
+            mode: RoundMode::Grid,
+            period: 64,
+            phase: 0,
+            threshold: 0,
+        }
+    }
+}
+
+impl RoundState {
+    /// Rounds a 26.6 distance, preserving its sign.
+    pub(crate) fn round(&self, distance: i32) -> i32 {
+        let d = distance;
+        match self.mode {
+            RoundMode::Grid => {
+                if d >= 0 {
+                    math::round(d).max(0)
+                } else {
+                    math::round(d.wrapping_neg()).wrapping_neg().min(0)
+                }
+            }
+            RoundMode::HalfGrid => {
+                if d >= 0 {
+                    let value = math::floor(d).wrapping_add(32);
+                    if value < 0 {
+                        32
+                    } else {
+                        value
+                    }
+                } else {
+                    let value = math::floor(d.wrapping_neg())
+                        .wrapping_add(32)
+                        .wrapping_neg();
+                    if value > 0 {
+                        -32
+                    } else {
+                        value
+                    }
+                }
+            }
+            RoundMode::DoubleGrid => {
+                if d >= 0 {
+                    (d.wrapping_add(16) & !31).max(0)
+                } else {
+                    (d.wrapping_neg().wrapping_add(16) & !31)
+                        .wrapping_neg()
+                        .min(0)
+                }
+            }
+            RoundMode::DownToGrid => {
+                if d >= 0 {
+                    math::floor(d).max(0)
+                } else {
+                    math::floor(d.wrapping_neg()).wrapping_neg().min(0)
+                }
+            }
+            RoundMode::UpToGrid => {
+                if d >= 0 {
+                    math::ceil(d).max(0)
+                } else {
+                    math::ceil(d.wrapping_neg()).wrapping_neg().min(0)
+                }
+            }
+            RoundMode::Off => d,
+            RoundMode::Super => {
+                let offset = self.threshold - self.phase;
+                if d >= 0 {
+                    let value = (d.wrapping_add(offset) & -self.period).wrapping_add(self.phase);
+                    if value < 0 {
+                        self.phase
+                    } else {
+                        value
+                    }
+                } else {
+                    let value = (offset.wrapping_sub(d) & -self.period).wrapping_neg();
+                    let value = value.wrapping_sub(self.phase);
+                    if value > 0 {
+                        -self.phase
+                    } else {
+                        value
+                    }
+                }
+            }
+            RoundMode::Super45 => {
+                let offset = self.threshold - self.phase;
+                if d >= 0 {
+                    let value = d.wrapping_add(offset) / self.period * self.period;
+                    let value = value.wrapping_add(self.phase);
+                    if value < 0 {
+                        self.phase
+                    } else {
+                        value
+                    }
+                } else {
+                    let value = (offset.wrapping_sub(d) / self.period * self.period).wrapping_neg();
+                    let value = value.wrapping_sub(self.phase);
+                    if value > 0 {
+                        -self.phase
+                    } else {
+                        value
+                    }
+                }
+            }
+        }
+    }
+
+    /// Sets the parameters for super rounding from the selector given to
+    /// `SROUND` or `S45ROUND`.
+    ///
+    /// The grid period is a 2.14 value: 1 for `SROUND` and √2/2 for
+    /// `S45ROUND`.
+    pub(crate) fn set_super(&mut self, selector: i32, grid_period: i32) {
+        let period = match selector & 0xC0 {
+            0x00 => grid_period / 2,
+            0x80 => grid_period * 2,
+            // 0xC0 is reserved
+            _ => grid_period,
+        };
+        let phase = match selector & 0x30 {
+            0x00 => 0,
+            0x10 => period / 4,
+            0x20 => period / 2,
+            _ => period * 3 / 4,
+        };
+        let threshold = if selector & 0x0F == 0 {
+            period - 1
+        } else {
+            ((selector & 0x0F) - 4) * period / 8
+        };
+        // convert from 2.14 to 26.6
+        self.period = (period >> 8).max(1);
+        self.phase = phase >> 8;
+        self.threshold = threshold >> 8;
+    }
+}
+
+/// The variables that control the behavior of instructions.
+///
+/// Defaults are as given in the specification.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub(crate) struct GraphicsState {
+    pub auto_flip: bool,
+    pub control_value_cutin: i32,
+    pub delta_base: i32,
+    pub delta_shift: i32,
+    pub dual_proj_vector: Point<i32>,
+    pub freedom_vector: Point<i32>,
+    pub proj_vector: Point<i32>,
+    /// The dot product of the freedom and projection vectors, used to
+    /// convert distances along the projection vector into movement along
+    /// the freedom vector.
+    pub fdotp: i32,
+    pub instruct_control: u8,
+    pub loop_counter: u32,
+    pub min_distance: i32,
+    pub round_state: RoundState,
+    pub rp0: usize,
+    pub rp1: usize,
+    pub rp2: usize,
+    pub scan_control: bool,
+    pub scan_type: i32,
+    pub single_width_cutin: i32,
+    pub single_width: i32,
+    pub zp0: ZonePointer,
+    pub zp1: ZonePointer,
+    pub zp2: ZonePointer,
+}
+
+impl Default for GraphicsState {
+    fn default() -> Self {
+        let x_axis = Point::new(0x4000, 0);
+        Self {
+            auto_flip: true,
+            control_value_cutin: 68,
+            delta_base: 9,
+            delta_shift: 3,
+            dual_proj_vector: x_axis,
+            freedom_vector: x_axis,
+            proj_vector: x_axis,
+            fdotp: 0x4000,
+            instruct_control: 0,
+            loop_counter: 1,
+            min_distance: 64,
+            round_state: RoundState::default(),
+            rp0: 0,
+            rp1: 0,
+            rp2: 0,
+            scan_control: false,
+            scan_type: 0,
+            single_width_cutin: 0,
+            single_width: 0,
+            zp0: ZonePointer::Glyph,
+            zp1: ZonePointer::Glyph,
+            zp2: ZonePointer::Glyph,
+        }
+    }
+}
+
+impl GraphicsState {
+    /// Resets the state that does not carry over between programs.
+    ///
+    /// Everything else is retained from the control value program.
+    pub(crate) fn reset_for_program(&mut self) {
+        let defaults = Self::default();
+        self.dual_proj_vector = defaults.dual_proj_vector;
+        self.freedom_vector = defaults.freedom_vector;
+        self.proj_vector = defaults.proj_vector;
+        self.fdotp = defaults.fdotp;
+        self.round_state = defaults.round_state;
+        self.loop_counter = defaults.loop_counter;
+        self.zp0 = defaults.zp0;
+        self.zp1 = defaults.zp1;
+        self.zp2 = defaults.zp2;
+    }
+
+    /// Must be called after changing the freedom or projection vectors.
+    pub(crate) fn update_fdotp(&mut self) {
+        let fdotp = math::dot14(self.freedom_vector, self.proj_vector);
+        // avoid huge movements when the vectors are nearly perpendicular
+        self.fdotp = if fdotp.abs() < 0x400 { 0x4000 } else { fdotp };
+    }
+
+    /// Projects the vector from `b` to `a` onto the projection vector.
+    pub(crate) fn project(&self, a: Point<i32>, b: Point<i32>) -> i32 {
+        let d = Point::new(a.x.wrapping_sub(b.x), a.y.wrapping_sub(b.y));
+        math::dot14(d, self.proj_vector)
+    }
+
+    /// Projects the vector from `b` to `a` onto the dual projection vector.
+    pub(crate) fn dual_project(&self, a: Point<i32>, b: Point<i32>) -> i32 {
+        let d = Point::new(a.x.wrapping_sub(b.x), a.y.wrapping_sub(b.y));
+        math::dot14(d, self.dual_proj_vector)
+    }
+
+    /// Converts a distance along the projection vector into a movement
+    /// along the freedom vector.
+    pub(crate) fn move_along_freedom_vector(&self, distance: i32) -> Point<i32> {
+        Point::new(
+            math::mul_div(distance, self.freedom_vector.x, self.fdotp),
+            math::mul_div(distance, self.freedom_vector.y, self.fdotp),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_all(mode: RoundMode, values: &[i32]) -> Vec<i32> {
+        let state = RoundState {
+            mode,
+            ..Default::default()
+        };
+        values.iter().map(|value| state.round(*value)).collect()
+    }
+
+    #[test]
+    fn round_modes() {
+        let values = [-96, -20, 0, 20, 40, 96];
+        assert_eq!(
+            round_all(RoundMode::Grid, &values),
+            [-128, 0, 0, 0, 64, 128]
+        );
+        assert_eq!(
+            round_all(RoundMode::HalfGrid, &values),
+            [-96, -32, 32, 32, 32, 96]
+        );
+        assert_eq!(
+            round_all(RoundMode::DoubleGrid, &values),
+            [-96, -32, 0, 32, 32, 96]
+        );
+        assert_eq!(
+            round_all(RoundMode::DownToGrid, &values),
+            [-64, 0, 0, 0, 0, 64]
+        );
+        assert_eq!(
+            round_all(RoundMode::UpToGrid, &values),
+            [-128, -64, 0, 64, 64, 128]
+        );
+        assert_eq!(round_all(RoundMode::Off, &values), values);
+    }
+
+    #[test]
+    fn round_min_value() {
+        // reachable from instructions; must not overflow
+        let modes = [
+            RoundMode::Grid,
+            RoundMode::HalfGrid,
+            RoundMode::DoubleGrid,
+            RoundMode::DownToGrid,
+            RoundMode::UpToGrid,
+            RoundMode::Off,
+            RoundMode::Super,
+            RoundMode::Super45,
+        ];
+        for mode in modes {
+            let mut state = RoundState {
+                mode,
+                ..Default::default()
+            };
+            if mode == RoundMode::Super45 {
+                state.set_super(0x48, 0x2D41);
+            }
+            state.round(i32::MIN);
+            state.round(i32::MAX);
+        }
+    }
+
+    #[test]
+    fn super_round() {
+        let mut state = RoundState {
+            mode: RoundMode::Super,
+            ..Default::default()
+        };
+        // period 1, phase 1/2, threshold 1/2: rounds to the nearest half
+        // pixel center
+        state.set_super(0x68, 0x4000);
+        assert_eq!((state.period, state.phase, state.threshold), (64, 32, 32));
+        assert_eq!(state.round(70), 96);
+        assert_eq!(state.round(-70), -96);
+        state.mode = RoundMode::Super45;
+        state.set_super(0x48, 0x2D41);
+        assert_eq!(state.period, 45);
+        assert_eq!(state.round(50), 45);
+    }
+}
@@ -0,0 +1,101 @@
+//! Fixed point arithmetic for the interpreter.
+//!
+//! Coordinates and distances are 26.6 values, unit vectors are 2.14 values
+//! and scale factors are 16.16 values, all stored in an `i32`. The rounding
+//! behavior matches FreeType, since small differences here are visible in
+//! hinted output.
+
+use font_types::Point;
+
+/// Computes `a * b / c`, rounding to the nearest integer.
+///
+/// Division by zero saturates rather than panicking.
+pub(crate) fn mul_div(a: i32, b: i32, c: i32) -> i32 {
+    let negative = (a < 0) ^ (b < 0) ^ (c < 0);
+    let (a, b, c) = ((a as i64).abs(), (b as i64).abs(), (c as i64).abs());
+    let value = if c > 0 {
+        (a * b + (c >> 1)) / c
+    } else {
+        i32::MAX as i64
+    };
+    let value = if negative { -value } else { value };
+    value as i32
+}
+
+/// Computes `a * b / c`, truncating the result.
+pub(crate) fn mul_div_no_round(a: i32, b: i32, c: i32) -> i32 {
+    let negative = (a < 0) ^ (b < 0) ^ (c < 0);
+    let (a, b, c) = ((a as i64).abs(), (b as i64).abs(), (c as i64).abs());
+    let value = if c > 0 { a * b / c } else { i32::MAX as i64 };
+    let value = if negative { -value } else { value };
+    value as i32
+}
+
+/// Multiplies by a 16.16 value.
+pub(crate) fn mul_fix(a: i32, b: i32) -> i32 {
+    mul_div(a, b, 0x10000)
+}
+
+/// Divides, producing a 16.16 value.
+pub(crate) fn div_fix(a: i32, b: i32) -> i32 {
+    mul_div(a, 0x10000, b)
+}
+
+/// Multiplies by a 2.14 value.
+pub(crate) fn mul14(a: i32, b: i32) -> i32 {
+    let product = a as i64 * b as i64;
+    let product = product + 0x2000 + (product >> 63);
+    (product >> 14) as i32
+}
+
+/// Computes the dot product of a vector with a 2.14 vector.
+pub(crate) fn dot14(a: Point<i32>, b: Point<i32>) -> i32 {
+    let product = a.x as i64 * b.x as i64 + a.y as i64 * b.y as i64;
+    let product = product + 0x2000 + (product >> 63);
+    (product >> 14) as i32
+}
+
+pub(crate) fn floor(x: i32) -> i32 {
+    x & !63
+}
+
+pub(crate) fn ceil(x: i32) -> i32 {
+    x.wrapping_add(63) & !63
+}
+
+pub(crate) fn round(x: i32) -> i32 {
+    x.wrapping_add(32) & !63
+}
+
+/// Scales a vector to unit length, as a 2.14 value.
+///
+/// The zero vector becomes the x axis.
+pub(crate) fn normalize(x: i32, y: i32) -> Point<i32> {
+    if x == 0 && y == 0 {
+        return Point::new(0x4000, 0);
+    }
+    let (x, y) = (x as f64, y as f64);
+    let len = x.hypot(y);
+    Point::new(
+        (x * 16384.0 / len).round() as i32,
+        (y * 16384.0 / len).round() as i32,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rounding() {
+        assert_eq!(mul_div(10, 3, 4), 8);
+        assert_eq!(mul_div(-10, 3, 4), -8);
+        assert_eq!(mul_div_no_round(10, 3, 4), 7);
+        assert_eq!(mul_div(1, 1, 0), i32::MAX);
+        assert_eq!(mul14(100, 0x2000), 50);
+        assert_eq!(mul14(-100, 0x2000), -50);
+        assert_eq!((floor(-1), ceil(1), round(31), round(32)), (-64, 64, 0, 64));
+        assert_eq!(normalize(3, 4), Point::new(9830, 13107));
+        assert_eq!(normalize(0, 0), Point::new(0x4000, 0));
+    }
+}
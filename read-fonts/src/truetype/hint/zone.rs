@@ -0,0 +1,252 @@
+//! Point storage for the twilight and glyph zones.
+
+use font_types::Point;
+
+use super::{math, HintError};
+
+/// Per-point flags tracked by the interpreter.
+#[derive(Copy, Clone, PartialEq, Eq, Default, Debug)]
+pub(crate) struct PointFlags(u8);
+
+impl PointFlags {
+    pub const ON_CURVE: Self = Self(1);
+    pub const TOUCHED_X: Self = Self(2);
+    pub const TOUCHED_Y: Self = Self(4);
+    pub const TOUCHED: Self = Self(2 | 4);
+
+    pub(crate) fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub(crate) fn insert(&mut self, other: Self) {
+        self.0 |= other.0;
+    }
+
+    pub(crate) fn remove(&mut self, other: Self) {
+        self.0 &= !other.0;
+    }
+}
+
+/// A set of points that instructions operate on.
+///
+/// Each point has three positions: the unscaled position in font units, the
+/// original scaled position and the current position. The latter two are
+/// 26.6 values.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Zone {
+    pub unscaled: Vec<Point<i32>>,
+    pub original: Vec<Point<i32>>,
+    pub points: Vec<Point<i32>>,
+    pub flags: Vec<PointFlags>,
+    /// The index of the last point of each contour.
+    pub contours: Vec<usize>,
+}
+
+impl Zone {
+    /// Creates a zone of the given size with every point at the origin.
+    pub(crate) fn with_len(len: usize) -> Self {
+        Self {
+            unscaled: vec![Point::default(); len],
+            original: vec![Point::default(); len],
+            points: vec![Point::default(); len],
+            flags: vec![PointFlags::default(); len],
+            contours: Vec::new(),
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub(crate) fn check_point(&self, idx: usize) -> Result<usize, HintError> {
+        if idx < self.len() {
+            Ok(idx)
+        } else {
+            Err(HintError::InvalidPointIndex(idx))
+        }
+    }
+
+    pub(crate) fn point(&self, idx: usize) -> Result<Point<i32>, HintError> {
+        self.points
+            .get(idx)
+            .copied()
+            .ok_or(HintError::InvalidPointIndex(idx))
+    }
+
+    pub(crate) fn original(&self, idx: usize) -> Result<Point<i32>, HintError> {
+        self.original
+            .get(idx)
+            .copied()
+            .ok_or(HintError::InvalidPointIndex(idx))
+    }
+
+    pub(crate) fn unscaled(&self, idx: usize) -> Result<Point<i32>, HintError> {
+        self.unscaled
+            .get(idx)
+            .copied()
+            .ok_or(HintError::InvalidPointIndex(idx))
+    }
+
+    /// Interpolates untouched points along one axis, as done by `IUP`.
+    ///
+    /// Each untouched point is moved according to the touched points before
+    /// and after it in its contour.
+    pub(crate) fn interpolate_untouched(&mut self, x_axis: bool) {
+        let mask = if x_axis {
+            PointFlags::TOUCHED_X
+        } else {
+            PointFlags::TOUCHED_Y
+        };
+        let mut axis = Axis {
+            zone: self,
+            is_x: x_axis,
+        };
+        let n_points = axis.zone.len();
+        let mut point = 0;
+        for contour_idx in 0..axis.zone.contours.len() {
+            let end_point = axis.zone.contours[contour_idx].min(n_points.saturating_sub(1));
+            let first_point = point;
+            while point <= end_point && !axis.zone.flags[point].contains(mask) {
+                point += 1;
+            }
+            if point <= end_point {
+                let first_touched = point;
+                let mut cur_touched = point;
+                point += 1;
+                while point <= end_point {
+                    if axis.zone.flags[point].contains(mask) {
+                        axis.interpolate(cur_touched + 1, point - 1, cur_touched, point);
+                        cur_touched = point;
+                    }
+                    point += 1;
+                }
+                if cur_touched == first_touched {
+                    axis.shift(first_point, end_point, cur_touched);
+                } else {
+                    axis.interpolate(cur_touched + 1, end_point, cur_touched, first_touched);
+                    if first_touched > 0 {
+                        axis.interpolate(
+                            first_point,
+                            first_touched - 1,
+                            cur_touched,
+                            first_touched,
+                        );
+                    }
+                }
+            }
+            point = point.max(end_point + 1);
+        }
+    }
+}
+
+/// Access to a single coordinate of the points in a zone.
+struct Axis<'a> {
+    zone: &'a mut Zone,
+    is_x: bool,
+}
+
+impl Axis<'_> {
+    fn get(&self, point: Point<i32>) -> i32 {
+        if self.is_x {
+            point.x
+        } else {
+            point.y
+        }
+    }
+
+    fn unscaled(&self, idx: usize) -> i32 {
+        self.get(self.zone.unscaled[idx])
+    }
+
+    fn original(&self, idx: usize) -> i32 {
+        self.get(self.zone.original[idx])
+    }
+
+    fn current(&self, idx: usize) -> i32 {
+        self.get(self.zone.points[idx])
+    }
+
+    fn set_current(&mut self, idx: usize, value: i32) {
+        let point = &mut self.zone.points[idx];
+        if self.is_x {
+            point.x = value;
+        } else {
+            point.y = value;
+        }
+    }
+
+    /// Moves the points in `p1..=p2`, other than `p`, by the distance that
+    /// `p` has moved.
+    fn shift(&mut self, p1: usize, p2: usize, p: usize) {
+        let delta = self.current(p).wrapping_sub(self.original(p));
+        if delta == 0 {
+            return;
+        }
+        for idx in (p1..=p2).filter(|idx| *idx != p) {
+            let value = self.current(idx).wrapping_add(delta);
+            self.set_current(idx, value);
+        }
+    }
+
+    /// Interpolates the points in `p1..=p2` between the reference points
+    /// `ref1` and `ref2`.
+    fn interpolate(&mut self, p1: usize, p2: usize, mut ref1: usize, mut ref2: usize) {
+        if p1 > p2 || ref1 >= self.zone.len() || ref2 >= self.zone.len() {
+            return;
+        }
+        if self.unscaled(ref1) > self.unscaled(ref2) {
+            std::mem::swap(&mut ref1, &mut ref2);
+        }
+        let (orus1, orus2) = (self.unscaled(ref1), self.unscaled(ref2));
+        let (org1, org2) = (self.original(ref1), self.original(ref2));
+        let (cur1, cur2) = (self.current(ref1), self.current(ref2));
+        let delta1 = cur1.wrapping_sub(org1);
+        let delta2 = cur2.wrapping_sub(org2);
+        let scale = (cur1 != cur2 && orus1 != orus2)
+            .then(|| math::div_fix(cur2.wrapping_sub(cur1), orus2.wrapping_sub(orus1)));
+        for idx in p1..=p2 {
+            let org = self.original(idx);
+            let value = if org <= org1 {
+                org.wrapping_add(delta1)
+            } else if org >= org2 {
+                org.wrapping_add(delta2)
+            } else if let Some(scale) = scale {
+                let orus = self.unscaled(idx).wrapping_sub(orus1);
+                cur1.wrapping_add(math::mul_fix(orus, scale))
+            } else {
+                cur1
+            };
+            self.set_current(idx, value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interpolate_untouched() {
+        // a single contour along the x axis; points 0 and 2 are touched
+        let xs = [0, 50, 100, 150];
+        let mut zone = Zone::with_len(4);
+        for (idx, x) in xs.iter().enumerate() {
+            zone.unscaled[idx] = Point::new(*x, 0);
+            zone.original[idx] = Point::new(*x, 0);
+            zone.points[idx] = Point::new(*x, 0);
+        }
+        zone.contours.push(3);
+        zone.points[0].x = 10;
+        zone.points[2].x = 120;
+        zone.flags[0].insert(PointFlags::TOUCHED_X);
+        zone.flags[2].insert(PointFlags::TOUCHED_X);
+        zone.interpolate_untouched(true);
+        let xs = zone.points.iter().map(|point| point.x).collect::<Vec<_>>();
+        // point 1 is interpolated, point 3 is past the touched range and is
+        // shifted with point 2
+        assert_eq!(xs, [10, 65, 120, 170]);
+        // nothing was touched in y
+        zone.interpolate_untouched(false);
+        assert!(zone.points.iter().all(|point| point.y == 0));
+    }
+}
@@ -0,0 +1,403 @@
+//! Decoding of TrueType instruction streams.
+
+use std::fmt;
+
+use crate::ReadError;
+
+/// A TrueType instruction opcode.
+///
+/// Many instructions are encoded as a family of opcodes where the low bits
+/// of the opcode carry flags; [`flags`](Self::flags) returns those bits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Opcode(u8);
+
+impl Opcode {
+    pub const NPUSHB: Self = Self(0x40);
+    pub const NPUSHW: Self = Self(0x41);
+
+    pub const fn from_u8(raw: u8) -> Self {
+        Self(raw)
+    }
+
+    pub const fn to_u8(self) -> u8 {
+        self.0
+    }
+
+    /// The mnemonic of the instruction, without any flags.
+    ///
+    /// Returns "UNKNOWN" for opcodes that are not part of the instruction
+    /// set. These may still be given meaning by an `IDEF` in the font.
+    pub fn name(self) -> &'static str {
+        match self.0 {
+            0x00..=0x01 => "SVTCA",
+            0x02..=0x03 => "SPVTCA",
+            0x04..=0x05 => "SFVTCA",
+            0x06..=0x07 => "SPVTL",
+            0x08..=0x09 => "SFVTL",
+            0x0A => "SPVFS",
+            0x0B => "SFVFS",
+            0x0C => "GPV",
+            0x0D => "GFV",
+            0x0E => "SFVTPV",
+            0x0F => "ISECT",
+            0x10 => "SRP0",
+            0x11 => "SRP1",
+            0x12 => "SRP2",
+            0x13 => "SZP0",
+            0x14 => "SZP1",
+            0x15 => "SZP2",
+            0x16 => "SZPS",
+            0x17 => "SLOOP",
+            0x18 => "RTG",
+            0x19 => "RTHG",
+            0x1A => "SMD",
+            0x1B => "ELSE",
+            0x1C => "JMPR",
+            0x1D => "SCVTCI",
+            0x1E => "SSWCI",
+            0x1F => "SSW",
+            0x20 => "DUP",
+            0x21 => "POP",
+            0x22 => "CLEAR",
+            0x23 => "SWAP",
+            0x24 => "DEPTH",
+            0x25 => "CINDEX",
+            0x26 => "MINDEX",
+            0x27 => "ALIGNPTS",
+            0x29 => "UTP",
+            0x2A => "LOOPCALL",
+            0x2B => "CALL",
+            0x2C => "FDEF",
+            0x2D => "ENDF",
+            0x2E..=0x2F => "MDAP",
+            0x30..=0x31 => "IUP",
+            0x32..=0x33 => "SHP",
+            0x34..=0x35 => "SHC",
+            0x36..=0x37 => "SHZ",
+            0x38 => "SHPIX",
+            0x39 => "IP",
+            0x3A..=0x3B => "MSIRP",
+            0x3C => "ALIGNRP",
+            0x3D => "RTDG",
+            0x3E..=0x3F => "MIAP",
+            0x40 => "NPUSHB",
+            0x41 => "NPUSHW",
+            0x42 => "WS",
+            0x43 => "RS",
+            0x44 => "WCVTP",
+            0x45 => "RCVT",
+            0x46..=0x47 => "GC",
+            0x48 => "SCFS",
+            0x49..=0x4A => "MD",
+            0x4B => "MPPEM",
+            0x4C => "MPS",
+            0x4D => "FLIPON",
+            0x4E => "FLIPOFF",
+            0x4F => "DEBUG",
+            0x50 => "LT",
+            0x51 => "LTEQ",
+            0x52 => "GT",
+            0x53 => "GTEQ",
+            0x54 => "EQ",
+            0x55 => "NEQ",
+            0x56 => "ODD",
+            0x57 => "EVEN",
+            0x58 => "IF",
+            0x59 => "EIF",
+            0x5A => "AND",
+            0x5B => "OR",
+            0x5C => "NOT",
+            0x5D => "DELTAP1",
+            0x5E => "SDB",
+            0x5F => "SDS",
+            0x60 => "ADD",
+            0x61 => "SUB",
+            0x62 => "DIV",
+            0x63 => "MUL",
+            0x64 => "ABS",
+            0x65 => "NEG",
+            0x66 => "FLOOR",
+            0x67 => "CEILING",
+            0x68..=0x6B => "ROUND",
+            0x6C..=0x6F => "NROUND",
+            0x70 => "WCVTF",
+            0x71 => "DELTAP2",
+            0x72 => "DELTAP3",
+            0x73 => "DELTAC1",
+            0x74 => "DELTAC2",
+            0x75 => "DELTAC3",
+            0x76 => "SROUND",
+            0x77 => "S45ROUND",
+            0x78 => "JROT",
+            0x79 => "JROF",
+            0x7A => "ROFF",
+            0x7C => "RUTG",
+            0x7D => "RDTG",
+            0x7E => "SANGW",
+            0x7F => "AA",
+            0x80 => "FLIPPT",
+            0x81 => "FLIPRGON",
+            0x82 => "FLIPRGOFF",
+            0x85 => "SCANCTRL",
+            0x86..=0x87 => "SDPVTL",
+            0x88 => "GETINFO",
+            0x89 => "IDEF",
+            0x8A => "ROLL",
+            0x8B => "MAX",
+            0x8C => "MIN",
+            0x8D => "SCANTYPE",
+            0x8E => "INSTCTRL",
+            0x91 => "GETVARIATION",
+            0x92 => "GETDATA",
+            0xB0..=0xB7 => "PUSHB",
+            0xB8..=0xBF => "PUSHW",
+            0xC0..=0xDF => "MDRP",
+            0xE0..=0xFF => "MIRP",
+            _ => "UNKNOWN",
+        }
+    }
+
+    /// Returns `true` if this opcode is part of the instruction set.
+    pub fn is_defined(self) -> bool {
+        self.name() != "UNKNOWN"
+    }
+
+    /// Returns `true` if this is one of the push instructions, which take
+    /// their operands from the instruction stream.
+    pub fn is_push(self) -> bool {
+        matches!(self.0, 0x40 | 0x41 | 0xB0..=0xBF)
+    }
+
+    /// Returns the flag bits encoded in the opcode, and the number of bits,
+    /// for opcodes that belong to a family.
+    ///
+    /// For `PUSHB` and `PUSHW` these bits are one less than the number of
+    /// values pushed.
+    pub fn flags(self) -> Option<(u8, u32)> {
+        let bits = match self.0 {
+            0x00..=0x09 | 0x2E..=0x37 | 0x3A | 0x3B | 0x3E | 0x3F => 1,
+            0x46 | 0x47 | 0x49 | 0x4A | 0x86 | 0x87 => 1,
+            0x68..=0x6F => 2,
+            0xB0..=0xBF => 3,
+            0xC0..=0xFF => 5,
+            _ => return None,
+        };
+        let base = match self.0 {
+            // MD is the only family that does not start on an even opcode
+            0x49 | 0x4A => 0x49,
+            raw => raw & !((1 << bits) - 1),
+        };
+        Some((self.0 - base, bits))
+    }
+}
+
+impl fmt::Display for Opcode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.is_defined() {
+            return write!(f, "UNKNOWN[{:#04x}]", self.0);
+        }
+        f.write_str(self.name())?;
+        match self.flags() {
+            Some((flags, bits)) if !self.is_push() => {
+                write!(f, "[{flags:0width$b}]", width = bits as usize)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// The values pushed by a push instruction, read from the instruction
+/// stream.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct InlineOperands<'a> {
+    bytes: &'a [u8],
+    is_words: bool,
+}
+
+impl<'a> InlineOperands<'a> {
+    /// The number of values.
+    pub fn len(&self) -> usize {
+        if self.is_words {
+            self.bytes.len() / 2
+        } else {
+            self.bytes.len()
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Returns an iterator over the values.
+    ///
+    /// Bytes are zero extended and words are sign extended, matching the
+    /// way they are placed on the stack.
+    pub fn values(&self) -> impl Iterator<Item = i32> + 'a {
+        let step = if self.is_words { 2 } else { 1 };
+        self.bytes.chunks_exact(step).map(|chunk| match chunk {
+            [hi, lo] => i16::from_be_bytes([*hi, *lo]) as i32,
+            [byte] => *byte as i32,
+            _ => unreachable!(),
+        })
+    }
+}
+
+/// A decoded instruction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Instruction<'a> {
+    /// The offset of the opcode in the instruction stream.
+    pub pc: usize,
+    pub opcode: Opcode,
+    /// The values pushed by a push instruction. Empty for all others.
+    pub inline_operands: InlineOperands<'a>,
+}
+
+impl Instruction<'_> {
+    /// The size of the encoded instruction, in bytes.
+    pub fn len(&self) -> usize {
+        let count_len = matches!(self.opcode, Opcode::NPUSHB | Opcode::NPUSHW) as usize;
+        1 + count_len + self.inline_operands.bytes.len()
+    }
+
+    /// Always `false`; every instruction has at least an opcode.
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+}
+
+impl fmt::Display for Instruction<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.opcode.fmt(f)?;
+        for value in self.inline_operands.values() {
+            write!(f, " {value}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Decodes instructions from a stream of TrueType bytecode, such as the
+/// contents of the `fpgm` and `prep` tables or the instructions of a glyph.
+#[derive(Clone, Debug)]
+pub struct Decoder<'a> {
+    bytecode: &'a [u8],
+    /// The offset of the next instruction.
+    pub pc: usize,
+}
+
+impl<'a> Decoder<'a> {
+    /// Creates a decoder that starts at the given offset.
+    pub fn new(bytecode: &'a [u8], pc: usize) -> Self {
+        Self { bytecode, pc }
+    }
+
+    /// Decodes the instruction at the current offset and advances past it.
+    ///
+    /// Returns `None` at the end of the stream.
+    pub fn decode(&mut self) -> Option<Result<Instruction<'a>, ReadError>> {
+        let pc = self.pc;
+        let opcode = Opcode::from_u8(*self.bytecode.get(pc)?);
+        let mut start = pc + 1;
+        let (count, is_words) = match opcode.0 {
+            0x40 | 0x41 => {
+                let Some(count) = self.bytecode.get(start) else {
+                    return Some(Err(ReadError::OutOfBounds));
+                };
+                start += 1;
+                (*count as usize, opcode == Opcode::NPUSHW)
+            }
+            0xB0..=0xB7 => ((opcode.0 - 0xB0) as usize + 1, false),
+            0xB8..=0xBF => ((opcode.0 - 0xB8) as usize + 1, true),
+            _ => (0, false),
+        };
+        let end = start + count * if is_words { 2 } else { 1 };
+        let Some(bytes) = self.bytecode.get(start..end) else {
+            return Some(Err(ReadError::OutOfBounds));
+        };
+        self.pc = end;
+        Some(Ok(Instruction {
+            pc,
+            opcode,
+            inline_operands: InlineOperands { bytes, is_words },
+        }))
+    }
+}
+
+impl<'a> Iterator for Decoder<'a> {
+    type Item = Result<Instruction<'a>, ReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = self.decode();
+        if matches!(result, Some(Err(_))) {
+            // don't keep returning the same error
+            self.pc = self.bytecode.len();
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn disassemble(bytecode: &[u8]) -> Vec<String> {
+        Decoder::new(bytecode, 0)
+            .map(|ins| ins.unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn decode_pushes() {
+        let bytecode = [
+            [0x40, 2, 10, 255].as_slice(),
+            &[0x41, 1, 0xFF, 0x38],
+            &[0xB2, 1, 2, 3],
+            &[0xB9, 0x00, 0x40, 0x80, 0x00],
+        ]
+        .concat();
+        assert_eq!(
+            disassemble(&bytecode),
+            [
+                "NPUSHB 10 255",
+                "NPUSHW -200",
+                "PUSHB 1 2 3",
+                "PUSHW 64 -32768"
+            ]
+        );
+        let lens = Decoder::new(&bytecode, 0)
+            .map(|ins| ins.unwrap().len())
+            .collect::<Vec<_>>();
+        assert_eq!(lens, [4, 4, 4, 5]);
+    }
+
+    #[test]
+    fn decode_flags() {
+        let bytecode = [0x01, 0x2E, 0x31, 0x4A, 0x6A, 0xCD, 0xE3, 0x2B, 0x28, 0x8F];
+        assert_eq!(
+            disassemble(&bytecode),
+            [
+                "SVTCA[1]",
+                "MDAP[0]",
+                "IUP[1]",
+                "MD[1]",
+                "ROUND[10]",
+                "MDRP[01101]",
+                "MIRP[00011]",
+                "CALL",
+                "UNKNOWN[0x28]",
+                "UNKNOWN[0x8f]",
+            ]
+        );
+        assert_eq!(Opcode::from_u8(0x49).flags(), Some((0, 1)));
+        assert_eq!(Opcode::from_u8(0xB3).flags(), Some((3, 3)));
+        assert_eq!(Opcode::from_u8(0x2B).flags(), None);
+    }
+
+    #[test]
+    fn truncated() {
+        let mut decoder = Decoder::new(&[0x20, 0xB1, 5], 0);
+        assert_eq!(decoder.next().unwrap().unwrap().opcode.name(), "DUP");
+        assert!(decoder.next().unwrap().is_err());
+        assert!(decoder.next().is_none());
+        assert!(Decoder::new(&[0x40], 0).next().unwrap().is_err());
+    }
+}
@@ -0,0 +1,43 @@
+#![parse_module(read_fonts::tables::avar)]
+
+/// The [avar (Axis Variations)](https://docs.microsoft.com/en-us/typography/opentype/spec/avar) table
+table Avar {
+    /// Major version number of the axis variations table — set to 1 or 2.
+    /// Minor version number of the axis variations table — set to 0.
+    #[version]
+    version: BigEndian<MajorMinor>,
+    /// Permanently reserved; set to zero.
+    #[skip_getter]
+    #[compile(0)]
+    reserved: BigEndian<u16>,
+    /// The number of variation axes for this font. This must be the same number as axisCount in the 'fvar' table.
+    axis_count: BigEndian<u16>,
+    /// The segment maps array — one segment map for each axis, in the order of axes specified in the 'fvar' table.
+    #[count($axis_count)]
+    axis_segment_maps: VarLenArray<SegmentMaps<'a>>,
+    /// Offset to DeltaSetIndexMap table (may be NULL).
+    #[available(MajorMinor::VERSION_2_0)]
+    #[nullable]
+    axis_index_map_offset: BigEndian<Offset32<DeltaSetIndexMap>>,
+    /// Offset to ItemVariationStore (may be NULL).
+    #[available(MajorMinor::VERSION_2_0)]
+    #[nullable]
+    var_store_offset: BigEndian<Offset32<ItemVariationStore>>,
+}
+
+/// [SegmentMaps](https://docs.microsoft.com/en-us/typography/opentype/spec/avar#table-formats) record
+table SegmentMaps {
+    /// The number of correspondence pairs for this axis.
+    position_map_count: BigEndian<u16>,
+    /// The array of axis value map records for this axis.
+    #[count($position_map_count)]
+    axis_value_maps: [AxisValueMap],
+}
+
+/// [AxisValueMap](https://docs.microsoft.com/en-us/typography/opentype/spec/avar#table-formats) record
+record AxisValueMap {
+    /// A normalized coordinate value obtained using default normalization.
+    from_coordinate: BigEndian<F2Dot14>,
+    /// The modified, normalized coordinate value.
+    to_coordinate: BigEndian<F2Dot14>,
+}
@@ -0,0 +1,244 @@
+#![parse_module(read_fonts::bitmap)]
+
+/// [BitmapSize](https://learn.microsoft.com/en-us/typography/opentype/spec/eblc#bitmapsize-record) record.
+record BitmapSize {
+    /// Offset to index subtable from beginning of EBLC/CBLC.
+    #[read_offset_with($number_of_index_subtables)]
+    index_subtable_list_offset: BigEndian<Offset32<IndexSubtableList>>,
+    /// Number of bytes in corresponding index subtables and array.
+    index_subtable_list_size: BigEndian<u32>,
+    /// There is an IndexSubtableRecord for each range or format change.
+    number_of_index_subtables: BigEndian<u32>,
+    /// Not used; set to 0.
+    color_ref: BigEndian<u32>,
+    /// Line metrics for text rendered horizontally.
+    hori: SbitLineMetrics,
+    /// Line metrics for text rendered vertically.
+    vert: SbitLineMetrics,
+    /// Lowest glyph index for this size.
+    start_glyph_index: BigEndian<GlyphId>,
+    /// Highest glyph index for this size.
+    end_glyph_index: BigEndian<GlyphId>,
+    /// Horizontal pixels per em.
+    ppem_x: BigEndian<u8>,
+    /// Vertical pixels per em.
+    ppem_y: BigEndian<u8>,
+    /// The Microsoft rasterizer v.1.7 or greater supports the following
+    /// bitDepth values, as described below: 1, 2, 4, and 8 (and 32 for CBLC).
+    bit_depth: BigEndian<u8>,
+    /// Vertical or horizontal.
+    flags: BigEndian<BitmapFlags>,
+}
+
+/// [SbitLineMetrics](https://learn.microsoft.com/en-us/typography/opentype/spec/eblc#sbitlinemetrics-record) record.
+#[copy]
+record SbitLineMetrics {
+    /// Distance from the baseline to the top of the line, in pixels.
+    ascender: BigEndian<i8>,
+    /// Distance from the baseline to the bottom of the line, in pixels.
+    descender: BigEndian<i8>,
+    /// Maximum pixel width of glyphs in the strike.
+    width_max: BigEndian<u8>,
+    /// Rise of the caret slope, typically set to 1 for non-italic fonts.
+    caret_slope_numerator: BigEndian<i8>,
+    /// Run of the caret slope, typically set to 0 for non-italic fonts.
+    caret_slope_denominator: BigEndian<u8>,
+    /// Offset in pixels to move the caret for proper positioning.
+    caret_offset: BigEndian<i8>,
+    /// Minimum of horiBearingX (vertBearingY for vertical metrics).
+    min_origin_sb: BigEndian<i8>,
+    /// Minimum of horiAdvance - horiBearingX - width (vertAdvance -
+    /// vertBearingY - height for vertical metrics).
+    min_advance_sb: BigEndian<i8>,
+    /// Maximum of horiBearingY (vertBearingX for vertical metrics).
+    max_before_bl: BigEndian<i8>,
+    /// Minimum of horiBearingY - height (vertBearingX - width for vertical
+    /// metrics).
+    min_after_bl: BigEndian<i8>,
+    #[skip_getter]
+    pad1: BigEndian<i8>,
+    #[skip_getter]
+    pad2: BigEndian<i8>,
+}
+
+/// [Bitmap flags](https://learn.microsoft.com/en-us/typography/opentype/spec/eblc#bitmap-flags).
+flags u8 BitmapFlags {
+    /// Horizontal.
+    HORIZONTAL_METRICS = 0x01,
+    /// Vertical.
+    VERTICAL_METRICS = 0x02,
+}
+
+/// [IndexSubtableList](https://learn.microsoft.com/en-us/typography/opentype/spec/eblc#indexsubtablelist-table) table.
+#[read_args(number_of_index_subtables: u32)]
+table IndexSubtableList {
+    /// Array of IndexSubtableRecords.
+    #[count($number_of_index_subtables)]
+    index_subtable_records: [IndexSubtableRecord],
+}
+
+/// [IndexSubtableRecord](https://learn.microsoft.com/en-us/typography/opentype/spec/eblc#indexsubtablerecord-record) record.
+record IndexSubtableRecord {
+    /// First glyph ID of this range.
+    first_glyph_index: BigEndian<GlyphId>,
+    /// Last glyph ID of this range (inclusive).
+    last_glyph_index: BigEndian<GlyphId>,
+    /// Offset to an IndexSubtable from the start of the IndexSubtableList.
+    index_subtable_offset: BigEndian<Offset32<IndexSubtable>>,
+}
+
+/// [IndexSubtables](https://learn.microsoft.com/en-us/typography/opentype/spec/eblc#indexsubtables) format type.
+format u16 IndexSubtable {
+    Format1(IndexSubtable1),
+    Format2(IndexSubtable2),
+    Format3(IndexSubtable3),
+    Format4(IndexSubtable4),
+    Format5(IndexSubtable5),
+}
+
+/// [IndexSubTable1](https://learn.microsoft.com/en-us/typography/opentype/spec/eblc#indexsubtable1-variable-metrics-glyphs-with-4-byte-offsets): variable-metrics glyphs with 4-byte offsets.
+table IndexSubtable1 {
+    /// Format of this IndexSubTable.
+    #[format = 1]
+    index_format: BigEndian<u16>,
+    /// Format of EBDT image data.
+    image_format: BigEndian<u16>,
+    /// Offset to image data in EBDT table.
+    image_data_offset: BigEndian<u32>,
+    /// Offsets into the image data, from imageDataOffset, for each glyph
+    /// in the range, followed by the end of the last glyph.
+    #[count(..)]
+    sbit_offsets: [BigEndian<u32>],
+}
+
+/// [IndexSubTable2](https://learn.microsoft.com/en-us/typography/opentype/spec/eblc#indexsubtable2-all-glyphs-have-identical-metrics): all glyphs have identical metrics.
+table IndexSubtable2 {
+    /// Format of this IndexSubTable.
+    #[format = 2]
+    index_format: BigEndian<u16>,
+    /// Format of EBDT image data.
+    image_format: BigEndian<u16>,
+    /// Offset to image data in EBDT table.
+    image_data_offset: BigEndian<u32>,
+    /// All the glyphs are of the same size.
+    image_size: BigEndian<u32>,
+    /// All glyphs have the same metrics; glyph data may be compressed,
+    /// byte-aligned, or bit-aligned.
+    #[count(1)]
+    big_metrics: [BigGlyphMetrics],
+}
+
+/// [IndexSubTable3](https://learn.microsoft.com/en-us/typography/opentype/spec/eblc#indexsubtable3-variable-metrics-glyphs-with-2-byte-offsets): variable-metrics glyphs with 2-byte offsets.
+table IndexSubtable3 {
+    /// Format of this IndexSubTable.
+    #[format = 3]
+    index_format: BigEndian<u16>,
+    /// Format of EBDT image data.
+    image_format: BigEndian<u16>,
+    /// Offset to image data in EBDT table.
+    image_data_offset: BigEndian<u32>,
+    /// Offsets into the image data, from imageDataOffset, for each glyph
+    /// in the range, followed by the end of the last glyph.
+    #[count(..)]
+    sbit_offsets: [BigEndian<u16>],
+}
+
+/// [IndexSubTable4](https://learn.microsoft.com/en-us/typography/opentype/spec/eblc#indexsubtable4-variable-metrics-glyphs-with-sparse-glyph-codes): variable-metrics glyphs with sparse glyph codes.
+table IndexSubtable4 {
+    /// Format of this IndexSubTable.
+    #[format = 4]
+    index_format: BigEndian<u16>,
+    /// Format of EBDT image data.
+    image_format: BigEndian<u16>,
+    /// Offset to image data in EBDT table.
+    image_data_offset: BigEndian<u32>,
+    /// Array length.
+    num_glyphs: BigEndian<u32>,
+    /// One per glyph, sorted by glyph ID, followed by a final entry
+    /// giving the end of the last glyph.
+    #[count(($num_glyphs as usize).saturating_add(1))]
+    glyph_array: [GlyphIdOffsetPair],
+}
+
+/// [GlyphIdOffsetPair](https://learn.microsoft.com/en-us/typography/opentype/spec/eblc#glyphidoffsetpair-record) record.
+record GlyphIdOffsetPair {
+    /// Glyph ID of glyph present.
+    glyph_id: BigEndian<GlyphId>,
+    /// Location in EBDT, from imageDataOffset.
+    sbit_offset: BigEndian<u16>,
+}
+
+/// [IndexSubTable5](https://learn.microsoft.com/en-us/typography/opentype/spec/eblc#indexsubtable5-constant-metrics-glyphs-with-sparse-glyph-codes): constant-metrics glyphs with sparse glyph codes.
+table IndexSubtable5 {
+    /// Format of this IndexSubTable.
+    #[format = 5]
+    index_format: BigEndian<u16>,
+    /// Format of EBDT image data.
+    image_format: BigEndian<u16>,
+    /// Offset to image data in EBDT table.
+    image_data_offset: BigEndian<u32>,
+    /// All glyphs have the same data size.
+    image_size: BigEndian<u32>,
+    /// All glyphs have the same metrics.
+    #[count(1)]
+    big_metrics: [BigGlyphMetrics],
+    /// Array length.
+    num_glyphs: BigEndian<u32>,
+    /// One per glyph, sorted by glyph ID.
+    #[count($num_glyphs)]
+    glyph_array: [BigEndian<GlyphId>],
+}
+
+/// [BigGlyphMetrics](https://learn.microsoft.com/en-us/typography/opentype/spec/eblc#bigglyphmetrics) record.
+#[copy]
+record BigGlyphMetrics {
+    /// Number of rows of data.
+    height: BigEndian<u8>,
+    /// Number of columns of data.
+    width: BigEndian<u8>,
+    /// Distance in pixels from the horizontal origin to the left edge of
+    /// the bitmap.
+    hori_bearing_x: BigEndian<i8>,
+    /// Distance in pixels from the horizontal origin to the top edge of
+    /// the bitmap.
+    hori_bearing_y: BigEndian<i8>,
+    /// Horizontal advance width in pixels.
+    hori_advance: BigEndian<u8>,
+    /// Distance in pixels from the vertical origin to the left edge of the
+    /// bitmap.
+    vert_bearing_x: BigEndian<i8>,
+    /// Distance in pixels from the vertical origin to the top edge of the
+    /// bitmap.
+    vert_bearing_y: BigEndian<i8>,
+    /// Vertical advance width in pixels.
+    vert_advance: BigEndian<u8>,
+}
+
+/// [SmallGlyphMetrics](https://learn.microsoft.com/en-us/typography/opentype/spec/eblc#smallglyphmetrics) record.
+#[copy]
+record SmallGlyphMetrics {
+    /// Number of rows of data.
+    height: BigEndian<u8>,
+    /// Number of columns of data.
+    width: BigEndian<u8>,
+    /// Distance in pixels from the horizontal origin to the left edge of
+    /// the bitmap (for horizontal text); or distance in pixels from the
+    /// vertical origin to the top edge of the bitmap (for vertical text).
+    bearing_x: BigEndian<i8>,
+    /// Distance in pixels from the horizontal origin to the top edge of
+    /// the bitmap (for horizontal text); or distance in pixels from the
+    /// vertical origin to the left edge of the bitmap (for vertical text).
+    bearing_y: BigEndian<i8>,
+    /// Horizontal or vertical advance width in pixels.
+    advance: BigEndian<u8>,
+}
+
+/// [EbdtComponent](https://learn.microsoft.com/en-us/typography/opentype/spec/ebdt#ebdtcomponent-record) record.
+record EbdtComponent {
+    /// Component glyph ID.
+    glyph_id: BigEndian<GlyphId>,
+    /// Position of component left.
+    x_offset: BigEndian<i8>,
+    /// Position of component top.
+    y_offset: BigEndian<i8>,
+}
@@ -0,0 +1,11 @@
+#![parse_module(read_fonts::tables::cbdt)]
+
+/// The [CBDT (Color Bitmap Data)](https://learn.microsoft.com/en-us/typography/opentype/spec/cbdt) table
+table Cbdt {
+    /// Major version of the CBDT table, = 3.
+    #[compile(3)]
+    major_version: BigEndian<u16>,
+    /// Minor version of the CBDT table, = 0.
+    #[compile(0)]
+    minor_version: BigEndian<u16>,
+}
@@ -0,0 +1,16 @@
+#![parse_module(read_fonts::tables::cblc)]
+
+/// The [CBLC (Color Bitmap Location)](https://learn.microsoft.com/en-us/typography/opentype/spec/cblc) table
+table Cblc {
+    /// Major version of the CBLC table, = 3.
+    #[compile(3)]
+    major_version: BigEndian<u16>,
+    /// Minor version of the CBLC table, = 0.
+    #[compile(0)]
+    minor_version: BigEndian<u16>,
+    /// Number of BitmapSize records.
+    num_sizes: BigEndian<u32>,
+    /// BitmapSize records array.
+    #[count($num_sizes)]
+    bitmap_sizes: [BitmapSize],
+}
@@ -0,0 +1,20 @@
+#![parse_module(read_fonts::tables::cff)]
+
+/// [Compact Font Format](https://learn.microsoft.com/en-us/typography/opentype/spec/cff) table header
+table Cff {
+    /// Format major version (starting at 1).
+    major: BigEndian<u8>,
+    /// Format minor version (starting at 0).
+    minor: BigEndian<u8>,
+    /// Header size (bytes).
+    hdr_size: BigEndian<u8>,
+    /// Absolute offset size.
+    off_size: BigEndian<u8>,
+    /// Padding bytes before the start of the Name INDEX.
+    #[skip_getter]
+    #[count(($hdr_size as usize).saturating_sub(4))]
+    _padding: [u8],
+    /// Remaining table data.
+    #[count(..)]
+    trailing_data: [u8],
+}
@@ -0,0 +1,23 @@
+#![parse_module(read_fonts::tables::cff2)]
+
+/// [Compact Font Format (CFF) version 2](https://learn.microsoft.com/en-us/typography/opentype/spec/cff2) table header
+table Cff2 {
+    /// Format major version (set to 2).
+    major_version: BigEndian<u8>,
+    /// Format minor version (set to zero).
+    minor_version: BigEndian<u8>,
+    /// Header size (bytes).
+    header_size: BigEndian<u8>,
+    /// Length of Top DICT structure in bytes.
+    top_dict_length: BigEndian<u16>,
+    /// Padding bytes before the start of the Top DICT.
+    #[skip_getter]
+    #[count(($header_size as usize).saturating_sub(5))]
+    _padding: [u8],
+    /// Data containing the Top DICT.
+    #[count($top_dict_length)]
+    top_dict_data: [u8],
+    /// Remaining table data.
+    #[count(..)]
+    trailing_data: [u8],
+}
@@ -292,10 +292,12 @@ record VariationSelector {
     var_selector: BigEndian<Uint24>,
     /// Offset from the start of the format 14 subtable to Default UVS
     /// Table. May be 0.
-    default_uvs_offset: BigEndian<Offset32>,
+    #[nullable]
+    default_uvs_offset: BigEndian<Offset32<DefaultUvs>>,
     /// Offset from the start of the format 14 subtable to Non-Default
     /// UVS Table. May be 0.
-    non_default_uvs_offset: BigEndian<Offset32>,
+    #[nullable]
+    non_default_uvs_offset: BigEndian<Offset32<NonDefaultUvs>>,
 }
 
 /// [Default UVS table](https://docs.microsoft.com/en-us/typography/opentype/spec/cmap#default-uvs-table)
@@ -307,6 +309,15 @@ table DefaultUvs {
     ranges: [UnicodeRange],
 }
 
+/// [Non-Default UVS table](https://docs.microsoft.com/en-us/typography/opentype/spec/cmap#non-default-uvs-table)
+table NonDefaultUvs {
+    /// Number of UVS Mappings that follow
+    num_uvs_mappings: BigEndian<u32>,
+    /// Array of UVSMapping records.
+    #[count($num_uvs_mappings)]
+    uvs_mapping: [UVSMapping],
+}
+
 /// Part of [Cmap14]
 record UVSMapping {
     /// Base Unicode value of the UVS
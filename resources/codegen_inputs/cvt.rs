@@ -0,0 +1,9 @@
+#![parse_module(read_fonts::tables::cvt)]
+
+/// The [cvt (Control Value Table)](https://learn.microsoft.com/en-us/typography/opentype/spec/cvt) table
+table Cvt {
+    /// List of n values referenceable by instructions. n is the number
+    /// of FWORD items that fit in the size of the table.
+    #[count(..)]
+    values: [BigEndian<FWord>],
+}
@@ -0,0 +1,11 @@
+#![parse_module(read_fonts::tables::ebdt)]
+
+/// The [EBDT (Embedded Bitmap Data)](https://learn.microsoft.com/en-us/typography/opentype/spec/ebdt) table
+table Ebdt {
+    /// Major version of the EBDT table, = 2.
+    #[compile(2)]
+    major_version: BigEndian<u16>,
+    /// Minor version of the EBDT table, = 0.
+    #[compile(0)]
+    minor_version: BigEndian<u16>,
+}
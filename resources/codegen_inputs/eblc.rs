@@ -0,0 +1,16 @@
+#![parse_module(read_fonts::tables::eblc)]
+
+/// The [EBLC (Embedded Bitmap Location)](https://learn.microsoft.com/en-us/typography/opentype/spec/eblc) table
+table Eblc {
+    /// Major version of the EBLC table, = 2.
+    #[compile(2)]
+    major_version: BigEndian<u16>,
+    /// Minor version of the EBLC table, = 0.
+    #[compile(0)]
+    minor_version: BigEndian<u16>,
+    /// Number of BitmapSize records.
+    num_sizes: BigEndian<u32>,
+    /// BitmapSize records array.
+    #[count($num_sizes)]
+    bitmap_sizes: [BitmapSize],
+}
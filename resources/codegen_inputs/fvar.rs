@@ -0,0 +1,61 @@
+#![parse_module(read_fonts::tables::fvar)]
+
+/// The [fvar (Font Variations)](https://docs.microsoft.com/en-us/typography/opentype/spec/fvar) table
+table Fvar {
+    /// Major version number of the font variations table — set to 1.
+    /// Minor version number of the font variations table — set to 0.
+    #[compile(MajorMinor::VERSION_1_0)]
+    version: BigEndian<MajorMinor>,
+    /// Offset in bytes from the beginning of the table to the start of
+    /// the VariationAxisRecord array. The InstanceRecord array directly
+    /// follows.
+    #[read_offset_with($axis_count, $instance_count, $instance_size)]
+    axis_instance_arrays_offset: BigEndian<Offset16<AxisInstanceArrays>>,
+    /// This field is permanently reserved. Set to 2.
+    #[skip_getter]
+    #[compile(2)]
+    reserved: BigEndian<u16>,
+    /// The number of variation axes in the font (the number of records
+    /// in the axes array).
+    axis_count: BigEndian<u16>,
+    /// The size in bytes of each VariationAxisRecord — set to 20
+    /// (0x0014) for this version.
+    #[compile(20)]
+    axis_size: BigEndian<u16>,
+    /// The number of named instances defined in the font (the number
+    /// of records in the instances array).
+    instance_count: BigEndian<u16>,
+    /// The size in bytes of each InstanceRecord — set to either
+    /// axisCount * sizeof(Fixed) + 4, or to axisCount * sizeof(Fixed)
+    /// + 6.
+    instance_size: BigEndian<u16>,
+}
+
+/// Shim table to handle combined axis and instance arrays.
+#[read_args(axis_count: u16, instance_count: u16, instance_size: u16)]
+table AxisInstanceArrays {
+    /// Variation axis record array.
+    #[count($axis_count)]
+    axes: [VariationAxisRecord],
+    /// Instance record array.
+    #[count($instance_count)]
+    #[read_with($axis_count, $instance_size)]
+    instances: ComputedArray<InstanceRecord<'a>>,
+}
+
+/// The [VariationAxisRecord](https://docs.microsoft.com/en-us/typography/opentype/spec/fvar#variationaxisrecord)
+record VariationAxisRecord {
+    /// Tag identifying the design variation for the axis.
+    axis_tag: BigEndian<Tag>,
+    /// The minimum coordinate value for the axis.
+    min_value: BigEndian<Fixed>,
+    /// The default coordinate value for the axis.
+    default_value: BigEndian<Fixed>,
+    /// The maximum coordinate value for the axis.
+    max_value: BigEndian<Fixed>,
+    /// Axis qualifiers — see details below.
+    flags: BigEndian<u16>,
+    /// The name ID for entries in the 'name' table that provide a
+    /// display name for this axis.
+    axis_name_id: BigEndian<u16>,
+}
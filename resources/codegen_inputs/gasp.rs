@@ -0,0 +1,36 @@
+#![parse_module(read_fonts::tables::gasp)]
+
+/// The [gasp (Grid-fitting and Scan-conversion Procedure)](https://learn.microsoft.com/en-us/typography/opentype/spec/gasp) table
+table Gasp {
+    /// Version number (set to 1)
+    version: BigEndian<u16>,
+    /// Number of records to follow
+    #[compile(array_len($gasp_ranges))]
+    num_ranges: BigEndian<u16>,
+    /// Sorted by ppem
+    #[count($num_ranges)]
+    gasp_ranges: [GaspRange],
+}
+
+/// A [gasp range](https://learn.microsoft.com/en-us/typography/opentype/spec/gasp#gasp-table-formats) record
+record GaspRange {
+    /// Upper limit of range, in PPEM
+    range_max_ppem: BigEndian<u16>,
+    /// Flags describing desired rasterizer behavior.
+    #[to_owned(convert_gasp_behavior(obj.range_gasp_behavior()))]
+    range_gasp_behavior: BigEndian<GaspRangeBehavior>,
+}
+
+/// Flags describing the desired rasterizer behavior for a [GaspRange].
+flags u16 GaspRangeBehavior {
+    /// Use gridfitting
+    GASP_GRIDFIT = 0x0001,
+    /// Use grayscale rendering
+    GASP_DOGRAY = 0x0002,
+    /// Use gridfitting with ClearType symmetric smoothing. Only
+    /// supported in version 1 'gasp'
+    GASP_SYMMETRIC_GRIDFIT = 0x0004,
+    /// Use smoothing along multiple axes with ClearType®. Only
+    /// supported in version 1 'gasp'
+    GASP_SYMMETRIC_SMOOTHING = 0x0008,
+}
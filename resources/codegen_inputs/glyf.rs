@@ -43,7 +43,7 @@ table SimpleGlyph {
     instruction_length: BigEndian<u16>,
     /// Array of instruction byte code for the glyph.
     #[count($instruction_length)]
-    instructions: [BigEndian<u8>],
+    instructions: [u8],
     #[count(..)]
     //#[hidden]
     /// the raw data for flags & x/y coordinates
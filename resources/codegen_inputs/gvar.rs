@@ -0,0 +1,65 @@
+#![parse_module(read_fonts::tables::gvar)]
+
+/// The ['gvar' header](https://learn.microsoft.com/en-us/typography/opentype/spec/gvar#gvar-header)
+table Gvar {
+    /// Major/minor version number of the glyph variations table — set to (1,0).
+    #[compile(MajorMinor::VERSION_1_0)]
+    version: BigEndian<MajorMinor>,
+    /// The number of variation axes for this font. This must be the
+    /// same number as axisCount in the 'fvar' table.
+    axis_count: BigEndian<u16>,
+    /// The number of shared tuple records. Shared tuple records can be
+    /// referenced within glyph variation data tables for multiple
+    /// glyphs, as opposed to other tuple records stored directly
+    /// within a glyph variation data table.
+    shared_tuple_count: BigEndian<u16>,
+    /// Offset from the start of this table to the shared tuple records.
+    #[read_offset_with($shared_tuple_count, $axis_count)]
+    shared_tuples_offset: BigEndian<Offset32<SharedTuples>>,
+    /// The number of glyphs in this font. This must match the number
+    /// of glyphs stored elsewhere in the font.
+    glyph_count: BigEndian<u16>,
+    /// Bit-field that gives the format of the offset array that
+    /// follows. If bit 0 is clear, the offsets are uint16; if bit 0 is
+    /// set, the offsets are uint32.
+    flags: BigEndian<GvarFlags>,
+    /// Offset from the start of this table to the array of
+    /// GlyphVariationData tables.
+    glyph_variation_data_array_offset: BigEndian<u32>,
+    /// Offsets from the start of the GlyphVariationData array to each
+    /// GlyphVariationData table; these are either u16 or u32, depending
+    /// on the value of [flags](Self::flags).
+    #[count(offsets_byte_len($glyph_count, $flags))]
+    glyph_variation_data_offsets: [u8],
+}
+
+/// Flags used in the [Gvar] header
+flags u16 GvarFlags {
+    /// If set, the offsets to GlyphVariationData tables are uint32;
+    /// otherwise they are uint16 values divided by 2.
+    LONG_OFFSETS = 1,
+}
+
+/// Array of tuple records shared across all glyph variation data tables.
+#[read_args(shared_tuple_count: u16, axis_count: u16)]
+table SharedTuples {
+    /// The shared tuple records.
+    #[count($shared_tuple_count)]
+    #[read_with($axis_count)]
+    tuples: ComputedArray<Tuple<'a>>,
+}
+
+/// [Tuple Records](https://learn.microsoft.com/en-us/typography/opentype/spec/otvarcommonformats#tuple-records)
+///
+/// The tuple variation store formats reference regions within the font’s
+/// variation space using tuple records. A tuple record identifies a position
+/// in terms of normalized coordinates, which use F2DOT14 values.
+#[read_args(axis_count: u16)]
+record Tuple<'a> {
+    /// Coordinate array specifying a position within the font’s variation space.
+    ///
+    /// The number of elements must match the axisCount specified in the
+    /// 'fvar' table.
+    #[count($axis_count)]
+    values: [BigEndian<F2Dot14>],
+}
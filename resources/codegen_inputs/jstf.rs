@@ -0,0 +1,128 @@
+#![parse_module(read_fonts::layout::jstf)]
+
+/// The [JSTF](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf) (Justification) table
+table Jstf {
+    /// Major and minor version of the JSTF table, = (1, 0)
+    #[compile(MajorMinor::VERSION_1_0)]
+    version: BigEndian<MajorMinor>,
+    /// Number of JstfScriptRecords in this table
+    #[compile(array_len($jstf_script_records))]
+    jstf_script_count: BigEndian<u16>,
+    /// Array of JstfScriptRecords, in alphabetical order by
+    /// jstfScriptTag
+    #[count($jstf_script_count)]
+    jstf_script_records: [JstfScriptRecord],
+}
+
+/// [JstfScriptRecord](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#jstfscriptrecord)
+record JstfScriptRecord {
+    /// 4-byte JstfScript identification
+    jstf_script_tag: BigEndian<Tag>,
+    /// Offset to JstfScript table, from beginning of JSTF Header
+    jstf_script_offset: BigEndian<Offset16<JstfScript>>,
+}
+
+/// [JstfScript Table](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#jstfscript-table)
+table JstfScript {
+    /// Offset to ExtenderGlyph table, from beginning of JstfScript
+    /// table (may be NULL)
+    #[nullable]
+    extender_glyph_offset: BigEndian<Offset16<ExtenderGlyph>>,
+    /// Offset to default JstfLangSys table, from beginning of
+    /// JstfScript table (may be NULL)
+    #[nullable]
+    def_jstf_lang_sys_offset: BigEndian<Offset16<JstfLangSys>>,
+    /// Number of JstfLangSysRecords in this table — may be zero (0)
+    #[compile(array_len($jstf_lang_sys_records))]
+    jstf_lang_sys_count: BigEndian<u16>,
+    /// Array of JstfLangSysRecords, in alphabetical order by
+    /// JstfLangSysTag
+    #[count($jstf_lang_sys_count)]
+    jstf_lang_sys_records: [JstfLangSysRecord],
+}
+
+/// [JstfLangSysRecord](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#jstfscript-table)
+record JstfLangSysRecord {
+    /// 4-byte JstfLangSys identifier
+    jstf_lang_sys_tag: BigEndian<Tag>,
+    /// Offset to JstfLangSys table, from beginning of JstfScript table
+    jstf_lang_sys_offset: BigEndian<Offset16<JstfLangSys>>,
+}
+
+/// [Extender Glyph Table](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#extender-glyph-table)
+table ExtenderGlyph {
+    /// Number of extender glyphs in this script
+    #[compile(array_len($extender_glyphs))]
+    glyph_count: BigEndian<u16>,
+    /// Extender glyph IDs — in increasing numerical order
+    #[count($glyph_count)]
+    extender_glyphs: [BigEndian<GlyphId>],
+}
+
+/// [JstfLangSys Table](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#jstflangsys-table)
+table JstfLangSys {
+    /// Number of JstfPriority tables
+    #[compile(array_len($jstf_priority_offsets))]
+    jstf_priority_count: BigEndian<u16>,
+    /// Array of offsets to JstfPriority tables, from beginning of
+    /// JstfLangSys table, in priority order
+    #[count($jstf_priority_count)]
+    jstf_priority_offsets: [BigEndian<Offset16<JstfPriority>>],
+}
+
+/// [JstfPriority Table](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#jstfpriority-table)
+table JstfPriority {
+    /// Offset to shrinkage-enable JstfGSUBModList table, from
+    /// beginning of JstfPriority table (may be NULL)
+    #[nullable]
+    gsub_shrinkage_enable_offset: BigEndian<Offset16<JstfModList>>,
+    /// Offset to shrinkage-disable JstfGSUBModList table, from
+    /// beginning of JstfPriority table (may be NULL)
+    #[nullable]
+    gsub_shrinkage_disable_offset: BigEndian<Offset16<JstfModList>>,
+    /// Offset to shrinkage-enable JstfGPOSModList table, from
+    /// beginning of JstfPriority table (may be NULL)
+    #[nullable]
+    gpos_shrinkage_enable_offset: BigEndian<Offset16<JstfModList>>,
+    /// Offset to shrinkage-disable JstfGPOSModList table, from
+    /// beginning of JstfPriority table (may be NULL)
+    #[nullable]
+    gpos_shrinkage_disable_offset: BigEndian<Offset16<JstfModList>>,
+    /// Offset to shrinkage JstfMax table, from beginning of
+    /// JstfPriority table (may be NULL)
+    #[nullable]
+    shrinkage_jstf_max_offset: BigEndian<Offset16<PositionLookupList>>,
+    /// Offset to extension-enable JstfGSUBModList table, from
+    /// beginnning of JstfPriority table (may be NULL)
+    #[nullable]
+    gsub_extension_enable_offset: BigEndian<Offset16<JstfModList>>,
+    /// Offset to extension-disable JstfGSUBModList table, from
+    /// beginning of JstfPriority table (may be NULL)
+    #[nullable]
+    gsub_extension_disable_offset: BigEndian<Offset16<JstfModList>>,
+    /// Offset to extension-enable JstfGPOSModList table, from
+    /// beginning of JstfPriority table (may be NULL)
+    #[nullable]
+    gpos_extension_enable_offset: BigEndian<Offset16<JstfModList>>,
+    /// Offset to extension-disable JstfGPOSModList table, from
+    /// beginning of JstfPriority table (may be NULL)
+    #[nullable]
+    gpos_extension_disable_offset: BigEndian<Offset16<JstfModList>>,
+    /// Offset to extension JstfMax table, from beginning of
+    /// JstfPriority table (may be NULL)
+    #[nullable]
+    extension_jstf_max_offset: BigEndian<Offset16<PositionLookupList>>,
+}
+
+/// [JstfGSUBModList](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#jstfgsubmodlist-table)
+/// and [JstfGPOSModList](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#jstfgposmodlist-table)
+/// tables
+table JstfModList {
+    /// Number of lookups for this modification
+    #[compile(array_len($lookup_indices))]
+    lookup_count: BigEndian<u16>,
+    /// Array of indices into the GSUB or GPOS LookupList, in
+    /// increasing numerical order
+    #[count($lookup_count)]
+    lookup_indices: [BigEndian<u16>],
+}
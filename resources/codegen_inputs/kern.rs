@@ -0,0 +1,50 @@
+#![parse_module(read_fonts::tables::kern)]
+
+/// The body of a [format 0](https://docs.microsoft.com/en-us/typography/opentype/spec/kern#format-0)
+/// kerning subtable.
+///
+/// This follows the subtable header, which differs between the
+/// OpenType and Apple variants of the table.
+table Subtable0 {
+    /// The number of kerning pairs in the table.
+    n_pairs: BigEndian<u16>,
+    /// The largest power of two less than or equal to the value of nPairs,
+    /// multiplied by the size in bytes of an entry in the table.
+    search_range: BigEndian<u16>,
+    /// This is calculated as log2 of the largest power of two less than or
+    /// equal to the value of nPairs. This value indicates how many
+    /// iterations of the search loop will have to be made.
+    entry_selector: BigEndian<u16>,
+    /// The value of nPairs minus the largest power of two less than or
+    /// equal to nPairs, and then multiplied by the size in bytes of an
+    /// entry in the table.
+    range_shift: BigEndian<u16>,
+    /// Array of KernPair records, sorted by left and then right glyph.
+    #[count($n_pairs)]
+    pairs: [KernPair],
+}
+
+/// A kerning pair record.
+record KernPair {
+    /// The glyph index for the left-hand glyph in the kerning pair.
+    left: BigEndian<GlyphId>,
+    /// The glyph index for the right-hand glyph in the kerning pair.
+    right: BigEndian<GlyphId>,
+    /// The kerning value for the above pair, in design units. If this
+    /// value is greater than zero, the characters will be moved apart. If
+    /// this value is less than zero, the character will be moved closer
+    /// together.
+    value: BigEndian<FWord>,
+}
+
+/// A class table used by a [format 2](https://docs.microsoft.com/en-us/typography/opentype/spec/kern#format-2)
+/// kerning subtable.
+table Subtable2ClassTable {
+    /// First glyph in class range.
+    first_glyph: BigEndian<GlyphId>,
+    /// Number of glyphs in class range.
+    n_glyphs: BigEndian<u16>,
+    /// The offsets array for all glyphs in the range.
+    #[count($n_glyphs)]
+    offsets: [BigEndian<u16>],
+}
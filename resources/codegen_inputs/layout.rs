@@ -704,3 +704,124 @@ table CharacterVariantParams {
     character: [BigEndian<Uint24>],
 }
 
+
+/// [Item Variation Store](https://learn.microsoft.com/en-us/typography/opentype/spec/otvarcommonformats#item-variation-store-header-and-item-variation-data-subtables)
+table ItemVariationStore {
+    /// Format — set to 1
+    #[format = 1]
+    format: BigEndian<u16>,
+    /// Offset in bytes from the start of the item variation store to
+    /// the variation region list.
+    variation_region_list_offset: BigEndian<Offset32<VariationRegionList>>,
+    /// The number of item variation data subtables.
+    #[compile(array_len($item_variation_data_offsets))]
+    item_variation_data_count: BigEndian<u16>,
+    /// Offsets in bytes from the start of the item variation store to
+    /// each item variation data subtable.
+    #[nullable]
+    #[count($item_variation_data_count)]
+    item_variation_data_offsets: [BigEndian<Offset32<ItemVariationData>>],
+}
+
+/// [Variation Region List](https://learn.microsoft.com/en-us/typography/opentype/spec/otvarcommonformats#variation-regions)
+table VariationRegionList {
+    /// The number of variation axes for this font. This must be the
+    /// same number as axisCount in the 'fvar' table.
+    #[compile(self.compute_axis_count())]
+    axis_count: BigEndian<u16>,
+    /// The number of variation region tables in the variation region
+    /// list. Must be less than 32,768.
+    #[compile(array_len($variation_regions))]
+    region_count: BigEndian<u16>,
+    /// Array of variation regions.
+    #[count($region_count)]
+    #[read_with($axis_count)]
+    variation_regions: ComputedArray<VariationRegion<'a>>,
+}
+
+/// The [VariationRegion](https://learn.microsoft.com/en-us/typography/opentype/spec/otvarcommonformats#variation-regions) record
+#[read_args(axis_count: u16)]
+record VariationRegion<'a> {
+    /// Array of region axis coordinates records, in the order of axes
+    /// given in the 'fvar' table.
+    #[count($axis_count)]
+    region_axes: [RegionAxisCoordinates],
+}
+
+/// The [RegionAxisCoordinates](https://learn.microsoft.com/en-us/typography/opentype/spec/otvarcommonformats#variation-regions) record
+record RegionAxisCoordinates {
+    /// The region start coordinate value for the current axis.
+    start_coord: BigEndian<F2Dot14>,
+    /// The region peak coordinate value for the current axis.
+    peak_coord: BigEndian<F2Dot14>,
+    /// The region end coordinate value for the current axis.
+    end_coord: BigEndian<F2Dot14>,
+}
+
+/// The [ItemVariationData](https://learn.microsoft.com/en-us/typography/opentype/spec/otvarcommonformats#item-variation-store-header-and-item-variation-data-subtables) subtable
+table ItemVariationData {
+    /// The number of delta sets for distinct items.
+    item_count: BigEndian<u16>,
+    /// A packed field: the high bit is a flag indicating that deltas are
+    /// 32-bit (for the first word_count regions) or 16-bit (for the
+    /// remaining regions); the low 15 bits are the count of 'word' deltas.
+    word_delta_count: BigEndian<u16>,
+    /// The number of variation regions referenced.
+    #[compile(array_len($region_indexes))]
+    region_index_count: BigEndian<u16>,
+    /// Array of indices into the variation region list for the regions
+    /// referenced by this item variation data table.
+    #[count($region_index_count)]
+    region_indexes: [BigEndian<u16>],
+    /// Delta-set rows.
+    #[count(delta_sets_byte_len($item_count, $word_delta_count, $region_index_count))]
+    delta_sets: [u8],
+}
+
+/// [DeltaSetIndexMap](https://learn.microsoft.com/en-us/typography/opentype/spec/otvarcommonformats#associating-target-items-to-variation-data)
+format u8 DeltaSetIndexMap {
+    Format0(DeltaSetIndexMapFormat0),
+    Format1(DeltaSetIndexMapFormat1),
+}
+
+/// The [DeltaSetIndexMap](https://learn.microsoft.com/en-us/typography/opentype/spec/otvarcommonformats#associating-target-items-to-variation-data) table format 0
+table DeltaSetIndexMapFormat0 {
+    /// DeltaSetIndexMap format: set to 0.
+    #[format = 0]
+    format: BigEndian<u8>,
+    /// A packed field that describes the compressed representation of
+    /// delta-set indices.
+    #[to_owned(convert_entry_format(obj.entry_format()))]
+    entry_format: BigEndian<EntryFormat>,
+    /// The number of mapping entries.
+    map_count: BigEndian<u16>,
+    /// The delta-set index mapping data.
+    #[count(map_data_byte_len($entry_format, $map_count))]
+    map_data: [u8],
+}
+
+/// The [DeltaSetIndexMap](https://learn.microsoft.com/en-us/typography/opentype/spec/otvarcommonformats#associating-target-items-to-variation-data) table format 1
+table DeltaSetIndexMapFormat1 {
+    /// DeltaSetIndexMap format: set to 1.
+    #[format = 1]
+    format: BigEndian<u8>,
+    /// A packed field that describes the compressed representation of
+    /// delta-set indices.
+    #[to_owned(convert_entry_format(obj.entry_format()))]
+    entry_format: BigEndian<EntryFormat>,
+    /// The number of mapping entries.
+    map_count: BigEndian<u32>,
+    /// The delta-set index mapping data.
+    #[count(map_data_byte_len($entry_format, $map_count))]
+    map_data: [u8],
+}
+
+/// Entry format for a [DeltaSetIndexMap].
+flags u8 EntryFormat {
+    /// Mask for the low 4 bits, which give the count of bits minus one
+    /// that are used in each entry for the inner-level index.
+    INNER_INDEX_BIT_COUNT_MASK = 0x0F,
+    /// Mask for bits that indicate the size in bytes minus one of each
+    /// entry.
+    MAP_ENTRY_SIZE_MASK = 0x30,
+}
@@ -0,0 +1,150 @@
+#![parse_module(read_fonts::tables::os2)]
+
+/// [`OS/2`](https://docs.microsoft.com/en-us/typography/opentype/spec/os2)
+#[validation_method(validate_panose)]
+table Os2 {
+    /// The version: 0-5
+    #[version]
+    #[compile(self.compute_version())]
+    version: BigEndian<u16>,
+    /// The Average Character Width parameter specifies the arithmetic
+    /// average of the escapement (width) of all non-zero width glyphs
+    /// in the font.
+    x_avg_char_width: BigEndian<i16>,
+    /// Indicates the visual weight (degree of blackness or thickness
+    /// of strokes) of the characters in the font. Values from 1 to
+    /// 1000 are valid.
+    us_weight_class: BigEndian<u16>,
+    /// Indicates a relative change from the normal aspect ratio (width
+    /// to height ratio) as specified by a font designer for the glyphs
+    /// in a font.
+    us_width_class: BigEndian<u16>,
+    /// Indicates font embedding licensing rights for the font.
+    fs_type: BigEndian<u16>,
+    /// The recommended horizontal size in font design units for
+    /// subscripts for this font.
+    y_subscript_x_size: BigEndian<i16>,
+    /// The recommended vertical size in font design units for
+    /// subscripts for this font.
+    y_subscript_y_size: BigEndian<i16>,
+    /// The recommended horizontal offset in font design units for
+    /// subscripts for this font.
+    y_subscript_x_offset: BigEndian<i16>,
+    /// The recommended vertical offset in font design units from the
+    /// baseline for subscripts for this font.
+    y_subscript_y_offset: BigEndian<i16>,
+    /// The recommended horizontal size in font design units for
+    /// superscripts for this font.
+    y_superscript_x_size: BigEndian<i16>,
+    /// The recommended vertical size in font design units for
+    /// superscripts for this font.
+    y_superscript_y_size: BigEndian<i16>,
+    /// The recommended horizontal offset in font design units for
+    /// superscripts for this font.
+    y_superscript_x_offset: BigEndian<i16>,
+    /// The recommended vertical offset in font design units from the
+    /// baseline for superscripts for this font.
+    y_superscript_y_offset: BigEndian<i16>,
+    /// Thickness of the strikeout stroke in font design units.
+    y_strikeout_size: BigEndian<i16>,
+    /// The position of the top of the strikeout stroke relative to the
+    /// baseline in font design units.
+    y_strikeout_position: BigEndian<i16>,
+    /// This parameter is a classification of font-family design.
+    s_family_class: BigEndian<i16>,
+    /// Additional specifications are required for PANOSE to classify
+    /// non-Latin character sets.
+    #[count(10)]
+    panose_10: [u8],
+    /// Unicode Character Range (Bits 0–31).
+    ul_unicode_range_1: BigEndian<u32>,
+    /// Unicode Character Range (Bits 32–63).
+    ul_unicode_range_2: BigEndian<u32>,
+    /// Unicode Character Range (Bits 64–95).
+    ul_unicode_range_3: BigEndian<u32>,
+    /// Unicode Character Range (Bits 96–127).
+    ul_unicode_range_4: BigEndian<u32>,
+    /// The four-character identifier for the vendor of the given type
+    /// face.
+    ach_vend_id: BigEndian<Tag>,
+    /// Contains information concerning the nature of the font patterns.
+    #[to_owned(convert_selection_flags(obj.fs_selection()))]
+    fs_selection: BigEndian<SelectionFlags>,
+    /// The minimum Unicode index (character code) in this font.
+    us_first_char_index: BigEndian<u16>,
+    /// The maximum Unicode index (character code) in this font.
+    us_last_char_index: BigEndian<u16>,
+    /// The typographic ascender for this font.
+    s_typo_ascender: BigEndian<i16>,
+    /// The typographic descender for this font.
+    s_typo_descender: BigEndian<i16>,
+    /// The typographic line gap for this font.
+    s_typo_line_gap: BigEndian<i16>,
+    /// The “Windows ascender” metric. This should be used to specify
+    /// the height above the baseline for a clipping region.
+    us_win_ascent: BigEndian<u16>,
+    /// The “Windows descender” metric. This should be used to specify
+    /// the vertical extent below the baseline for a clipping region.
+    us_win_descent: BigEndian<u16>,
+    /// Code page character range bits 0–31.
+    #[available(1)]
+    ul_code_page_range_1: BigEndian<u32>,
+    /// Code page character range bits 32–63.
+    #[available(1)]
+    ul_code_page_range_2: BigEndian<u32>,
+    /// This metric specifies the distance between the baseline and the
+    /// approximate height of non-ascending lowercase letters.
+    #[available(2)]
+    sx_height: BigEndian<i16>,
+    /// This metric specifies the distance between the baseline and the
+    /// approximate height of uppercase letters.
+    #[available(2)]
+    s_cap_height: BigEndian<i16>,
+    /// This is the Unicode code point, in UTF-16 encoding, of a
+    /// character that can be used for a default glyph.
+    #[available(2)]
+    us_default_char: BigEndian<u16>,
+    /// This is the Unicode code point, in UTF-16 encoding, of a
+    /// character that can be used as a default break character.
+    #[available(2)]
+    us_break_char: BigEndian<u16>,
+    /// This field is used for fonts with multiple optional bit tables.
+    #[available(2)]
+    us_max_context: BigEndian<u16>,
+    /// This value is the lower value of the size range for which this
+    /// font has been designed, in TWIPs.
+    #[available(5)]
+    us_lower_optical_point_size: BigEndian<u16>,
+    /// This value is the upper value of the size range for which this
+    /// font has been designed, in TWIPs.
+    #[available(5)]
+    us_upper_optical_point_size: BigEndian<u16>,
+}
+
+/// OS/2 [selection flags](https://learn.microsoft.com/en-us/typography/opentype/spec/os2#fsselection)
+flags u16 SelectionFlags {
+    /// Bit 0: Font contains italic or oblique glyphs, otherwise they are
+    /// upright.
+    ITALIC = 0x0001,
+    /// Bit 1: Glyphs are underscored.
+    UNDERSCORE = 0x0002,
+    /// Bit 2: Glyphs have their foreground and background reversed.
+    NEGATIVE = 0x0004,
+    /// Bit 3: Outline (hollow) glyphs, otherwise they are solid.
+    OUTLINED = 0x0008,
+    /// Bit 4: Glyphs are overstruck.
+    STRIKEOUT = 0x0010,
+    /// Bit 5: Glyphs are emboldened.
+    BOLD = 0x0020,
+    /// Bit 6: Glyphs are in the standard weight/style for the font.
+    REGULAR = 0x0040,
+    /// Bit 7: Use the typographic ascender, descender and line gap
+    /// metrics for default line spacing.
+    USE_TYPO_METRICS = 0x0080,
+    /// Bit 8: The font has name table strings consistent with a
+    /// weight/width/slope family without requiring use of name IDs 21
+    /// and 22.
+    WWS = 0x0100,
+    /// Bit 9: Font contains oblique glyphs.
+    OBLIQUE = 0x0200,
+}
@@ -1,65 +1,54 @@
-/// [STAT](https://docs.microsoft.com/en-us/typography/opentype/spec/stat) (Style Attributes Table)
-#[offset_host]
-Stat1_0<'a> {
-    /// Major version number of the style attributes table — set to 1.
-    major_version: BigEndian<u16>,
-    /// Minor version number of the style attributes table — set to 2.
-    minor_version: BigEndian<u16>,
-    /// The size in bytes of each axis record.
-    design_axis_size: BigEndian<u16>,
-    /// The number of axis records. In a font with an 'fvar' table,
-    /// this value must be greater than or equal to the axisCount value
-    /// in the 'fvar' table. In all fonts, must be greater than zero if
-    /// axisValueCount is greater than zero.
-    design_axis_count: BigEndian<u16>,
-    /// Offset in bytes from the beginning of the STAT table to the
-    /// start of the design axes array. If designAxisCount is zero, set
-    /// to zero; if designAxisCount is greater than zero, must be
-    /// greater than zero.
-    design_axes_offset: BigEndian<Offset32>,
-    /// The number of axis value tables.
-    axis_value_count: BigEndian<u16>,
-    /// Offset in bytes from the beginning of the STAT table to the
-    /// start of the design axes value offsets array. If axisValueCount
-    /// is zero, set to zero; if axisValueCount is greater than zero,
-    /// must be greater than zero.
-    offset_to_axis_value_offsets: BigEndian<Offset32>,
-}
+#![parse_module(read_fonts::tables::stat)]
 
 /// [STAT](https://docs.microsoft.com/en-us/typography/opentype/spec/stat) (Style Attributes Table)
-#[offset_host]
-Stat1_2<'a> {
-    /// Major version number of the style attributes table — set to 1.
-    major_version: BigEndian<u16>,
-    /// Minor version number of the style attributes table — set to 2.
-    minor_version: BigEndian<u16>,
+table Stat {
+    /// Major/minor version number. Set to 1.2 for new fonts.
+    #[version]
+    #[compile(MajorMinor::VERSION_1_2)]
+    version: BigEndian<MajorMinor>,
     /// The size in bytes of each axis record.
+    #[compile(8)]
     design_axis_size: BigEndian<u16>,
     /// The number of axis records. In a font with an 'fvar' table,
     /// this value must be greater than or equal to the axisCount value
     /// in the 'fvar' table. In all fonts, must be greater than zero if
     /// axisValueCount is greater than zero.
+    #[compile(self.compute_design_axis_count())]
     design_axis_count: BigEndian<u16>,
     /// Offset in bytes from the beginning of the STAT table to the
     /// start of the design axes array. If designAxisCount is zero, set
     /// to zero; if designAxisCount is greater than zero, must be
     /// greater than zero.
-    design_axes_offset: BigEndian<Offset32>,
+    #[nullable]
+    #[read_offset_with($design_axis_count)]
+    design_axes_offset: BigEndian<Offset32<AxisRecordArray>>,
     /// The number of axis value tables.
+    #[compile(self.compute_axis_value_count())]
     axis_value_count: BigEndian<u16>,
     /// Offset in bytes from the beginning of the STAT table to the
     /// start of the design axes value offsets array. If axisValueCount
     /// is zero, set to zero; if axisValueCount is greater than zero,
     /// must be greater than zero.
-    offset_to_axis_value_offsets: BigEndian<Offset32>,
+    #[nullable]
+    #[read_offset_with($axis_value_count)]
+    axis_value_array_offset: BigEndian<Offset32<AxisValueArray>>,
     /// Name ID used as fallback when projection of names into a
     /// particular font model produces a subfamily name containing only
     /// elidable elements.
+    #[available(MajorMinor::VERSION_1_1)]
     elided_fallback_name_id: BigEndian<u16>,
 }
 
+/// The [array of axis records](https://docs.microsoft.com/en-us/typography/opentype/spec/stat#axis-records)
+#[read_args(design_axis_count: u16)]
+table AxisRecordArray {
+    /// The design-axes array.
+    #[count($design_axis_count)]
+    axis_records: [AxisRecord],
+}
+
 /// [Axis Records](https://docs.microsoft.com/en-us/typography/opentype/spec/stat#axis-records)
-AxisRecord {
+record AxisRecord {
     /// A tag identifying the axis of design variation.
     axis_tag: BigEndian<Tag>,
     /// The name ID for entries in the 'name' table that provide a
@@ -71,40 +60,35 @@ AxisRecord {
     axis_ordering: BigEndian<u16>,
 }
 
-/// [STAT](https://docs.microsoft.com/en-us/typography/opentype/spec/stat) (Style Attributes Table)
-#[format(MajorMinor)]
-#[generate_getters]
-enum Stat<'a> {
-    #[version(MajorMinor::VERSION_1_0)]
-    Version1_0(Stat1_0<'a>),
-    #[version(MajorMinor::VERSION_1_2)]
-    Version1_2(Stat1_2<'a>),
+/// An array of [AxisValue] tables.
+#[read_args(axis_value_count: u16)]
+table AxisValueArray {
+    /// Array of offsets to axis value tables, in bytes from the start
+    /// of the axis value offsets array.
+    #[count($axis_value_count)]
+    axis_value_offsets: [BigEndian<Offset16<AxisValue>>],
 }
 
-
 /// [Axis Value Tables](https://docs.microsoft.com/en-us/typography/opentype/spec/stat#axis-value-tables)
-#[format(u16)]
-enum AxisValue<'a> {
-    #[version(1)]
+format u16 AxisValue {
     Format1(AxisValueFormat1),
-    #[version(2)]
     Format2(AxisValueFormat2),
-    #[version(3)]
     Format3(AxisValueFormat3),
-    #[version(4)]
-    Format4(AxisValueFormat4<'a>),
+    Format4(AxisValueFormat4),
 }
 
 /// [Axis value table format 1](https://docs.microsoft.com/en-us/typography/opentype/spec/stat#axis-value-table-format-1)
-AxisValueFormat1 {
+table AxisValueFormat1 {
     /// Format identifier — set to 1.
+    #[format = 1]
     format: BigEndian<u16>,
     /// Zero-base index into the axis record array identifying the axis
     /// of design variation to which the axis value table applies. Must
     /// be less than designAxisCount.
     axis_index: BigEndian<u16>,
     /// Flags — see below for details.
-    flags: BigEndian<AxisValueFlags>,
+    #[to_owned(convert_axis_value_flags(obj.flags()))]
+    flags: BigEndian<AxisValueTableFlags>,
     /// The name ID for entries in the 'name' table that provide a
     /// display string for this attribute value.
     value_name_id: BigEndian<u16>,
@@ -113,15 +97,17 @@ AxisValueFormat1 {
 }
 
 /// [Axis value table format 2](https://docs.microsoft.com/en-us/typography/opentype/spec/stat#axis-value-table-format-2)
-AxisValueFormat2 {
+table AxisValueFormat2 {
     /// Format identifier — set to 2.
+    #[format = 2]
     format: BigEndian<u16>,
     /// Zero-base index into the axis record array identifying the axis
     /// of design variation to which the axis value table applies. Must
     /// be less than designAxisCount.
     axis_index: BigEndian<u16>,
     /// Flags — see below for details.
-    flags: BigEndian<AxisValueFlags>,
+    #[to_owned(convert_axis_value_flags(obj.flags()))]
+    flags: BigEndian<AxisValueTableFlags>,
     /// The name ID for entries in the 'name' table that provide a
     /// display string for this attribute value.
     value_name_id: BigEndian<u16>,
@@ -136,15 +122,17 @@ AxisValueFormat2 {
 }
 
 /// [Axis value table format 3](https://docs.microsoft.com/en-us/typography/opentype/spec/stat#axis-value-table-format-3)
-AxisValueFormat3 {
+table AxisValueFormat3 {
     /// Format identifier — set to 3.
+    #[format = 3]
     format: BigEndian<u16>,
     /// Zero-base index into the axis record array identifying the axis
     /// of design variation to which the axis value table applies. Must
     /// be less than designAxisCount.
     axis_index: BigEndian<u16>,
     /// Flags — see below for details.
-    flags: BigEndian<AxisValueFlags>,
+    #[to_owned(convert_axis_value_flags(obj.flags()))]
+    flags: BigEndian<AxisValueTableFlags>,
     /// The name ID for entries in the 'name' table that provide a
     /// display string for this attribute value.
     value_name_id: BigEndian<u16>,
@@ -155,25 +143,28 @@ AxisValueFormat3 {
 }
 
 /// [Axis value table format 4](https://docs.microsoft.com/en-us/typography/opentype/spec/stat#axis-value-table-format-4)
-AxisValueFormat4<'a> {
+table AxisValueFormat4 {
     /// Format identifier — set to 4.
+    #[format = 4]
     format: BigEndian<u16>,
     /// The total number of axes contributing to this axis-values
     /// combination.
+    #[compile(array_len($axis_values))]
     axis_count: BigEndian<u16>,
     /// Flags — see below for details.
-    flags: BigEndian<AxisValueFlags>,
+    #[to_owned(convert_axis_value_flags(obj.flags()))]
+    flags: BigEndian<AxisValueTableFlags>,
     /// The name ID for entries in the 'name' table that provide a
     /// display string for this combination of axis values.
     value_name_id: BigEndian<u16>,
     /// Array of AxisValue records that provide the combination of axis
     /// values, one for each contributing axis.
-    #[count(axis_count)]
+    #[count($axis_count)]
     axis_values: [AxisValueRecord],
 }
 
 /// Part of [AxisValueFormat4]
-AxisValueRecord {
+record AxisValueRecord {
     /// Zero-base index into the axis record array identifying the axis
     /// to which this value applies. Must be less than designAxisCount.
     axis_index: BigEndian<u16>,
@@ -182,8 +173,7 @@ AxisValueRecord {
 }
 
 /// [Axis value table flags](https://docs.microsoft.com/en-us/typography/opentype/spec/stat#flags).
-#[flags(u16)]
-AxisValueFlags {
+flags u16 AxisValueTableFlags {
     /// If set, this axis value table provides axis value information
     /// that is applicable to other fonts within the same font family.
     /// This is used if the other fonts were released earlier and did
@@ -0,0 +1,54 @@
+#![parse_module(read_fonts::tables::vhea)]
+
+/// [vhea](https://docs.microsoft.com/en-us/typography/opentype/spec/vhea) Vertical Header Table
+table Vhea {
+    /// The major/minor version (1, 1)
+    #[compile(Version16Dot16::VERSION_1_1)]
+    version: BigEndian<Version16Dot16>,
+    /// Typographic ascent.
+    ascender: BigEndian<FWord>,
+    /// Typographic descent.
+    descender: BigEndian<FWord>,
+    /// Typographic line gap. Negative LineGap values are treated as
+    /// zero in some legacy platform implementations.
+    line_gap: BigEndian<FWord>,
+    /// Maximum advance height value in 'vmtx' table.
+    advance_height_max: BigEndian<UfWord>,
+    /// Minimum top sidebearing value in 'vmtx' table for glyphs with
+    /// contours (empty glyphs should be ignored).
+    min_top_side_bearing: BigEndian<FWord>,
+    /// Minimum bottom sidebearing value
+    min_bottom_side_bearing: BigEndian<FWord>,
+    /// Defined as max( tsb + (yMax-yMin)).
+    y_max_extent: BigEndian<FWord>,
+    /// Used to calculate the slope of the cursor (rise/run); 1 for
+    /// vertical caret, 0 for horizontal.
+    caret_slope_rise: BigEndian<i16>,
+    /// 0 for vertical caret, 1 for horizontal.
+    caret_slope_run: BigEndian<i16>,
+    /// The amount by which a slanted highlight on a glyph needs to be
+    /// shifted to produce the best appearance. Set to 0 for
+    /// non-slanted fonts
+    caret_offset: BigEndian<i16>,
+    /// set to 0
+    #[skip_getter]
+    #[compile(0)]
+    reserved1: BigEndian<i16>,
+    /// set to 0
+    #[skip_getter]
+    #[compile(0)]
+    reserved2: BigEndian<i16>,
+    /// set to 0
+    #[skip_getter]
+    #[compile(0)]
+    reserved3: BigEndian<i16>,
+    /// set to 0
+    #[skip_getter]
+    #[compile(0)]
+    reserved4: BigEndian<i16>,
+    /// 0 for current format.
+    #[compile(0)]
+    metric_data_format: BigEndian<i16>,
+    /// Number of advance heights in the vertical metrics (`vmtx`) table.
+    number_of_long_ver_metrics: BigEndian<u16>,
+}
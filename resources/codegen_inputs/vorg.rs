@@ -0,0 +1,27 @@
+#![parse_module(read_fonts::tables::vorg)]
+
+/// The [VORG (Vertical Origin)](https://docs.microsoft.com/en-us/typography/opentype/spec/vorg) table.
+table Vorg {
+    /// Major/minor version number. Set to 1.0.
+    #[compile(MajorMinor::VERSION_1_0)]
+    version: BigEndian<MajorMinor>,
+    /// The y coordinate of a glyph’s vertical origin, in the font’s design
+    /// coordinate system, to be used if no entry is present for the glyph
+    /// in the vertOriginYMetrics array.
+    default_vert_origin_y: BigEndian<i16>,
+    /// Number of elements in the vertOriginYMetrics array.
+    #[compile(array_len($vert_origin_y_metrics))]
+    num_vert_origin_y_metrics: BigEndian<u16>,
+    /// Array of VertOriginYMetrics records, sorted by glyph ID.
+    #[count($num_vert_origin_y_metrics)]
+    vert_origin_y_metrics: [VertOriginYMetrics],
+}
+
+/// Vertical origin Y metrics record.
+record VertOriginYMetrics {
+    /// Glyph index.
+    glyph_id: BigEndian<GlyphId>,
+    /// Y coordinate, in the font’s design coordinate system, of the
+    /// vertical origin of glyph with index glyphIndex.
+    vert_origin_y: BigEndian<i16>,
+}
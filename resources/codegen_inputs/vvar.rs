@@ -0,0 +1,23 @@
+#![parse_module(read_fonts::tables::vvar)]
+
+/// The [VVAR (Vertical Metrics Variations)](https://docs.microsoft.com/en-us/typography/opentype/spec/vvar) table
+table Vvar {
+    /// Major version number of the vertical metrics variations table — set to 1.
+    /// Minor version number of the vertical metrics variations table — set to 0.
+    #[compile(MajorMinor::VERSION_1_0)]
+    version: BigEndian<MajorMinor>,
+    /// Offset in bytes from the start of this table to the item variation store table.
+    item_variation_store_offset: BigEndian<Offset32<ItemVariationStore>>,
+    /// Offset in bytes from the start of this table to the delta-set index mapping for advance heights (may be NULL).
+    #[nullable]
+    advance_height_mapping_offset: BigEndian<Offset32<DeltaSetIndexMap>>,
+    /// Offset in bytes from the start of this table to the delta-set index mapping for top side bearings (may be NULL).
+    #[nullable]
+    tsb_mapping_offset: BigEndian<Offset32<DeltaSetIndexMap>>,
+    /// Offset in bytes from the start of this table to the delta-set index mapping for bottom side bearings (may be NULL).
+    #[nullable]
+    bsb_mapping_offset: BigEndian<Offset32<DeltaSetIndexMap>>,
+    /// Offset in bytes from the start of this table to the delta-set index mapping for Y coordinates of vertical origins (may be NULL).
+    #[nullable]
+    v_org_mapping_offset: BigEndian<Offset32<DeltaSetIndexMap>>,
+}
@@ -0,0 +1,723 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+#[allow(unused_imports)]
+use crate::codegen_prelude::*;
+
+/// The [BASE](https://learn.microsoft.com/en-us/typography/opentype/spec/base) (Baseline) table
+#[derive(Clone, Debug)]
+pub struct Base {
+    /// Offset to horizontal Axis table, from beginning of BASE table (may be NULL)
+    pub horiz_axis_offset: NullableOffsetMarker<Axis>,
+    /// Offset to vertical Axis table, from beginning of BASE table (may be NULL)
+    pub vert_axis_offset: NullableOffsetMarker<Axis>,
+    /// Offset to Item Variation Store table, from beginning of BASE table (may be null)
+    pub item_var_store_offset: NullableOffsetMarker<ItemVariationStore, WIDTH_32>,
+}
+
+impl FontWrite for Base {
+    #[allow(clippy::unnecessary_cast)]
+    fn write_into(&self, writer: &mut TableWriter) {
+        let version = self.compute_version() as MajorMinor;
+        version.write_into(writer);
+        self.horiz_axis_offset.write_into(writer);
+        self.vert_axis_offset.write_into(writer);
+        version
+            .compatible(MajorMinor::VERSION_1_1)
+            .then(|| self.item_var_store_offset.write_into(writer));
+    }
+}
+
+impl Validate for Base {
+    fn validate_impl(&self, ctx: &mut ValidationCtx) {
+        ctx.in_table("Base", |ctx| {
+            ctx.in_field("horiz_axis_offset", |ctx| {
+                self.horiz_axis_offset.validate_impl(ctx);
+            });
+            ctx.in_field("vert_axis_offset", |ctx| {
+                self.vert_axis_offset.validate_impl(ctx);
+            });
+            ctx.in_field("item_var_store_offset", |ctx| {
+                self.item_var_store_offset.validate_impl(ctx);
+            });
+        })
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromObjRef<read_fonts::layout::base::Base<'a>> for Base {
+    fn from_obj_ref(obj: &read_fonts::layout::base::Base<'a>, _: FontData) -> Self {
+        Base {
+            horiz_axis_offset: obj.horiz_axis().into(),
+            vert_axis_offset: obj.vert_axis().into(),
+            item_var_store_offset: obj.item_var_store().into(),
+        }
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromTableRef<read_fonts::layout::base::Base<'a>> for Base {}
+
+#[cfg(feature = "parsing")]
+impl<'a> FontRead<'a> for Base {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        <read_fonts::layout::base::Base as FontRead>::read(data).map(|x| x.to_owned_table())
+    }
+}
+
+/// [Axis Table](https://learn.microsoft.com/en-us/typography/opentype/spec/base#axis-tables-horizaxis-and-vertaxis)
+#[derive(Clone, Debug)]
+pub struct Axis {
+    /// Offset to BaseTagList table, from beginning of Axis table (may
+    /// be NULL)
+    pub base_tag_list_offset: NullableOffsetMarker<BaseTagList>,
+    /// Offset to BaseScriptList table, from beginning of Axis table
+    pub base_script_list_offset: OffsetMarker<BaseScriptList>,
+}
+
+impl FontWrite for Axis {
+    fn write_into(&self, writer: &mut TableWriter) {
+        self.base_tag_list_offset.write_into(writer);
+        self.base_script_list_offset.write_into(writer);
+    }
+}
+
+impl Validate for Axis {
+    fn validate_impl(&self, ctx: &mut ValidationCtx) {
+        ctx.in_table("Axis", |ctx| {
+            ctx.in_field("base_tag_list_offset", |ctx| {
+                self.base_tag_list_offset.validate_impl(ctx);
+            });
+            ctx.in_field("base_script_list_offset", |ctx| {
+                self.base_script_list_offset.validate_impl(ctx);
+            });
+        })
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromObjRef<read_fonts::layout::base::Axis<'a>> for Axis {
+    fn from_obj_ref(obj: &read_fonts::layout::base::Axis<'a>, _: FontData) -> Self {
+        Axis {
+            base_tag_list_offset: obj.base_tag_list().into(),
+            base_script_list_offset: obj.base_script_list().into(),
+        }
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromTableRef<read_fonts::layout::base::Axis<'a>> for Axis {}
+
+#[cfg(feature = "parsing")]
+impl<'a> FontRead<'a> for Axis {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        <read_fonts::layout::base::Axis as FontRead>::read(data).map(|x| x.to_owned_table())
+    }
+}
+
+/// [BaseTagList Table](https://learn.microsoft.com/en-us/typography/opentype/spec/base#basetaglist-table)
+#[derive(Clone, Debug)]
+pub struct BaseTagList {
+    /// Array of 4-byte baseline identification tags — must be in
+    /// alphabetical order
+    pub baseline_tags: Vec<Tag>,
+}
+
+impl FontWrite for BaseTagList {
+    #[allow(clippy::unnecessary_cast)]
+    fn write_into(&self, writer: &mut TableWriter) {
+        (array_len(&self.baseline_tags).unwrap() as u16).write_into(writer);
+        self.baseline_tags.write_into(writer);
+    }
+}
+
+impl Validate for BaseTagList {
+    fn validate_impl(&self, ctx: &mut ValidationCtx) {
+        ctx.in_table("BaseTagList", |ctx| {
+            ctx.in_field("baseline_tags", |ctx| {
+                if self.baseline_tags.len() > (u16::MAX as usize) {
+                    ctx.report("array excedes max length");
+                }
+            });
+        })
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromObjRef<read_fonts::layout::base::BaseTagList<'a>> for BaseTagList {
+    fn from_obj_ref(obj: &read_fonts::layout::base::BaseTagList<'a>, _: FontData) -> Self {
+        BaseTagList {
+            baseline_tags: obj.baseline_tags().iter().map(|x| x.get()).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromTableRef<read_fonts::layout::base::BaseTagList<'a>> for BaseTagList {}
+
+#[cfg(feature = "parsing")]
+impl<'a> FontRead<'a> for BaseTagList {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        <read_fonts::layout::base::BaseTagList as FontRead>::read(data).map(|x| x.to_owned_table())
+    }
+}
+
+/// [BaseScriptList Table](https://learn.microsoft.com/en-us/typography/opentype/spec/base#basescriptlist-table)
+#[derive(Clone, Debug)]
+pub struct BaseScriptList {
+    /// Array of BaseScriptRecords, in alphabetical order by
+    /// baseScriptTag
+    pub base_script_records: Vec<BaseScriptRecord>,
+}
+
+impl FontWrite for BaseScriptList {
+    #[allow(clippy::unnecessary_cast)]
+    fn write_into(&self, writer: &mut TableWriter) {
+        (array_len(&self.base_script_records).unwrap() as u16).write_into(writer);
+        self.base_script_records.write_into(writer);
+    }
+}
+
+impl Validate for BaseScriptList {
+    fn validate_impl(&self, ctx: &mut ValidationCtx) {
+        ctx.in_table("BaseScriptList", |ctx| {
+            ctx.in_field("base_script_records", |ctx| {
+                if self.base_script_records.len() > (u16::MAX as usize) {
+                    ctx.report("array excedes max length");
+                }
+                self.base_script_records.validate_impl(ctx);
+            });
+        })
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromObjRef<read_fonts::layout::base::BaseScriptList<'a>> for BaseScriptList {
+    fn from_obj_ref(obj: &read_fonts::layout::base::BaseScriptList<'a>, _: FontData) -> Self {
+        let offset_data = obj.offset_data();
+        BaseScriptList {
+            base_script_records: obj
+                .base_script_records()
+                .iter()
+                .map(|x| FromObjRef::from_obj_ref(x, offset_data))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromTableRef<read_fonts::layout::base::BaseScriptList<'a>> for BaseScriptList {}
+
+#[cfg(feature = "parsing")]
+impl<'a> FontRead<'a> for BaseScriptList {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        <read_fonts::layout::base::BaseScriptList as FontRead>::read(data)
+            .map(|x| x.to_owned_table())
+    }
+}
+
+/// [BaseScriptRecord](https://learn.microsoft.com/en-us/typography/opentype/spec/base#basescriptrecord)
+#[derive(Clone, Debug)]
+pub struct BaseScriptRecord {
+    /// 4-byte script identification tag
+    pub base_script_tag: Tag,
+    /// Offset to BaseScript table, from beginning of BaseScriptList
+    pub base_script_offset: OffsetMarker<BaseScript>,
+}
+
+impl FontWrite for BaseScriptRecord {
+    fn write_into(&self, writer: &mut TableWriter) {
+        self.base_script_tag.write_into(writer);
+        self.base_script_offset.write_into(writer);
+    }
+}
+
+impl Validate for BaseScriptRecord {
+    fn validate_impl(&self, ctx: &mut ValidationCtx) {
+        ctx.in_table("BaseScriptRecord", |ctx| {
+            ctx.in_field("base_script_offset", |ctx| {
+                self.base_script_offset.validate_impl(ctx);
+            });
+        })
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl FromObjRef<read_fonts::layout::base::BaseScriptRecord> for BaseScriptRecord {
+    fn from_obj_ref(
+        obj: &read_fonts::layout::base::BaseScriptRecord,
+        offset_data: FontData,
+    ) -> Self {
+        BaseScriptRecord {
+            base_script_tag: obj.base_script_tag(),
+            base_script_offset: obj.base_script(offset_data).into(),
+        }
+    }
+}
+
+/// [BaseScript Table](https://learn.microsoft.com/en-us/typography/opentype/spec/base#basescript-table)
+#[derive(Clone, Debug)]
+pub struct BaseScript {
+    /// Offset to BaseValues table, from beginning of BaseScript table (may be NULL)
+    pub base_values_offset: NullableOffsetMarker<BaseValues>,
+    /// Offset to MinMax table, from beginning of BaseScript table (may be NULL)
+    pub default_min_max_offset: NullableOffsetMarker<MinMax>,
+    /// Array of BaseLangSysRecords, in alphabetical order by
+    /// BaseLangSysTag
+    pub base_lang_sys_records: Vec<BaseLangSysRecord>,
+}
+
+impl FontWrite for BaseScript {
+    #[allow(clippy::unnecessary_cast)]
+    fn write_into(&self, writer: &mut TableWriter) {
+        self.base_values_offset.write_into(writer);
+        self.default_min_max_offset.write_into(writer);
+        (array_len(&self.base_lang_sys_records).unwrap() as u16).write_into(writer);
+        self.base_lang_sys_records.write_into(writer);
+    }
+}
+
+impl Validate for BaseScript {
+    fn validate_impl(&self, ctx: &mut ValidationCtx) {
+        ctx.in_table("BaseScript", |ctx| {
+            ctx.in_field("base_values_offset", |ctx| {
+                self.base_values_offset.validate_impl(ctx);
+            });
+            ctx.in_field("default_min_max_offset", |ctx| {
+                self.default_min_max_offset.validate_impl(ctx);
+            });
+            ctx.in_field("base_lang_sys_records", |ctx| {
+                if self.base_lang_sys_records.len() > (u16::MAX as usize) {
+                    ctx.report("array excedes max length");
+                }
+                self.base_lang_sys_records.validate_impl(ctx);
+            });
+        })
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromObjRef<read_fonts::layout::base::BaseScript<'a>> for BaseScript {
+    fn from_obj_ref(obj: &read_fonts::layout::base::BaseScript<'a>, _: FontData) -> Self {
+        let offset_data = obj.offset_data();
+        BaseScript {
+            base_values_offset: obj.base_values().into(),
+            default_min_max_offset: obj.default_min_max().into(),
+            base_lang_sys_records: obj
+                .base_lang_sys_records()
+                .iter()
+                .map(|x| FromObjRef::from_obj_ref(x, offset_data))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromTableRef<read_fonts::layout::base::BaseScript<'a>> for BaseScript {}
+
+#[cfg(feature = "parsing")]
+impl<'a> FontRead<'a> for BaseScript {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        <read_fonts::layout::base::BaseScript as FontRead>::read(data).map(|x| x.to_owned_table())
+    }
+}
+
+/// [BaseLangSysRecord](https://learn.microsoft.com/en-us/typography/opentype/spec/base#baselangsysrecord)
+#[derive(Clone, Debug)]
+pub struct BaseLangSysRecord {
+    /// 4-byte language system identification tag
+    pub base_lang_sys_tag: Tag,
+    /// Offset to MinMax table, from beginning of BaseScript table
+    pub min_max_offset: OffsetMarker<MinMax>,
+}
+
+impl FontWrite for BaseLangSysRecord {
+    fn write_into(&self, writer: &mut TableWriter) {
+        self.base_lang_sys_tag.write_into(writer);
+        self.min_max_offset.write_into(writer);
+    }
+}
+
+impl Validate for BaseLangSysRecord {
+    fn validate_impl(&self, ctx: &mut ValidationCtx) {
+        ctx.in_table("BaseLangSysRecord", |ctx| {
+            ctx.in_field("min_max_offset", |ctx| {
+                self.min_max_offset.validate_impl(ctx);
+            });
+        })
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl FromObjRef<read_fonts::layout::base::BaseLangSysRecord> for BaseLangSysRecord {
+    fn from_obj_ref(
+        obj: &read_fonts::layout::base::BaseLangSysRecord,
+        offset_data: FontData,
+    ) -> Self {
+        BaseLangSysRecord {
+            base_lang_sys_tag: obj.base_lang_sys_tag(),
+            min_max_offset: obj.min_max(offset_data).into(),
+        }
+    }
+}
+
+/// [BaseValues](https://learn.microsoft.com/en-us/typography/opentype/spec/base#basevalues-table) table
+#[derive(Clone, Debug)]
+pub struct BaseValues {
+    /// Index number of default baseline for this script — equals
+    /// index position of baseline tag in baselineTags array of the
+    /// BaseTagList
+    pub default_baseline_index: u16,
+    /// Array of offsets to BaseCoord tables, from beginning of
+    /// BaseValues table — order matches baselineTags array in the
+    /// BaseTagList
+    pub base_coord_offsets: Vec<OffsetMarker<BaseCoord>>,
+}
+
+impl FontWrite for BaseValues {
+    #[allow(clippy::unnecessary_cast)]
+    fn write_into(&self, writer: &mut TableWriter) {
+        self.default_baseline_index.write_into(writer);
+        (array_len(&self.base_coord_offsets).unwrap() as u16).write_into(writer);
+        self.base_coord_offsets.write_into(writer);
+    }
+}
+
+impl Validate for BaseValues {
+    fn validate_impl(&self, ctx: &mut ValidationCtx) {
+        ctx.in_table("BaseValues", |ctx| {
+            ctx.in_field("base_coord_offsets", |ctx| {
+                if self.base_coord_offsets.len() > (u16::MAX as usize) {
+                    ctx.report("array excedes max length");
+                }
+                self.base_coord_offsets.validate_impl(ctx);
+            });
+        })
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromObjRef<read_fonts::layout::base::BaseValues<'a>> for BaseValues {
+    fn from_obj_ref(obj: &read_fonts::layout::base::BaseValues<'a>, _: FontData) -> Self {
+        BaseValues {
+            default_baseline_index: obj.default_baseline_index(),
+            base_coord_offsets: obj.base_coord().map(|x| x.into()).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromTableRef<read_fonts::layout::base::BaseValues<'a>> for BaseValues {}
+
+#[cfg(feature = "parsing")]
+impl<'a> FontRead<'a> for BaseValues {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        <read_fonts::layout::base::BaseValues as FontRead>::read(data).map(|x| x.to_owned_table())
+    }
+}
+
+/// [MinMax](https://learn.microsoft.com/en-us/typography/opentype/spec/base#minmax-table) table
+#[derive(Clone, Debug)]
+pub struct MinMax {
+    /// Offset to BaseCoord table that defines the minimum extent
+    /// value, from the beginning of MinMax table (may be NULL)
+    pub min_coord_offset: NullableOffsetMarker<BaseCoord>,
+    /// Offset to BaseCoord table that defines maximum extent value,
+    /// from the beginning of MinMax table (may be NULL)
+    pub max_coord_offset: NullableOffsetMarker<BaseCoord>,
+    /// Array of FeatMinMaxRecords, in alphabetical order by
+    /// featureTableTag
+    pub feat_min_max_records: Vec<FeatMinMaxRecord>,
+}
+
+impl FontWrite for MinMax {
+    #[allow(clippy::unnecessary_cast)]
+    fn write_into(&self, writer: &mut TableWriter) {
+        self.min_coord_offset.write_into(writer);
+        self.max_coord_offset.write_into(writer);
+        (array_len(&self.feat_min_max_records).unwrap() as u16).write_into(writer);
+        self.feat_min_max_records.write_into(writer);
+    }
+}
+
+impl Validate for MinMax {
+    fn validate_impl(&self, ctx: &mut ValidationCtx) {
+        ctx.in_table("MinMax", |ctx| {
+            ctx.in_field("min_coord_offset", |ctx| {
+                self.min_coord_offset.validate_impl(ctx);
+            });
+            ctx.in_field("max_coord_offset", |ctx| {
+                self.max_coord_offset.validate_impl(ctx);
+            });
+            ctx.in_field("feat_min_max_records", |ctx| {
+                if self.feat_min_max_records.len() > (u16::MAX as usize) {
+                    ctx.report("array excedes max length");
+                }
+                self.feat_min_max_records.validate_impl(ctx);
+            });
+        })
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromObjRef<read_fonts::layout::base::MinMax<'a>> for MinMax {
+    fn from_obj_ref(obj: &read_fonts::layout::base::MinMax<'a>, _: FontData) -> Self {
+        let offset_data = obj.offset_data();
+        MinMax {
+            min_coord_offset: obj.min_coord().into(),
+            max_coord_offset: obj.max_coord().into(),
+            feat_min_max_records: obj
+                .feat_min_max_records()
+                .iter()
+                .map(|x| FromObjRef::from_obj_ref(x, offset_data))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromTableRef<read_fonts::layout::base::MinMax<'a>> for MinMax {}
+
+#[cfg(feature = "parsing")]
+impl<'a> FontRead<'a> for MinMax {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        <read_fonts::layout::base::MinMax as FontRead>::read(data).map(|x| x.to_owned_table())
+    }
+}
+
+/// [FeatMinMaxRecord](https://learn.microsoft.com/en-us/typography/opentype/spec/base#minmax-table)
+#[derive(Clone, Debug)]
+pub struct FeatMinMaxRecord {
+    /// 4-byte feature identification tag — must match feature tag in
+    /// FeatureList
+    pub feature_table_tag: Tag,
+    /// Offset to BaseCoord table that defines the minimum extent
+    /// value, from beginning of MinMax table (may be NULL)
+    pub min_coord_offset: NullableOffsetMarker<BaseCoord>,
+    /// Offset to BaseCoord table that defines the maximum extent
+    /// value, from beginning of MinMax table (may be NULL)
+    pub max_coord_offset: NullableOffsetMarker<BaseCoord>,
+}
+
+impl FontWrite for FeatMinMaxRecord {
+    fn write_into(&self, writer: &mut TableWriter) {
+        self.feature_table_tag.write_into(writer);
+        self.min_coord_offset.write_into(writer);
+        self.max_coord_offset.write_into(writer);
+    }
+}
+
+impl Validate for FeatMinMaxRecord {
+    fn validate_impl(&self, ctx: &mut ValidationCtx) {
+        ctx.in_table("FeatMinMaxRecord", |ctx| {
+            ctx.in_field("min_coord_offset", |ctx| {
+                self.min_coord_offset.validate_impl(ctx);
+            });
+            ctx.in_field("max_coord_offset", |ctx| {
+                self.max_coord_offset.validate_impl(ctx);
+            });
+        })
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl FromObjRef<read_fonts::layout::base::FeatMinMaxRecord> for FeatMinMaxRecord {
+    fn from_obj_ref(
+        obj: &read_fonts::layout::base::FeatMinMaxRecord,
+        offset_data: FontData,
+    ) -> Self {
+        FeatMinMaxRecord {
+            feature_table_tag: obj.feature_table_tag(),
+            min_coord_offset: obj.min_coord(offset_data).into(),
+            max_coord_offset: obj.max_coord(offset_data).into(),
+        }
+    }
+}
+
+/// [BaseCoord Tables](https://learn.microsoft.com/en-us/typography/opentype/spec/base#basecoord-tables)
+#[derive(Clone, Debug)]
+pub enum BaseCoord {
+    Format1(BaseCoordFormat1),
+    Format2(BaseCoordFormat2),
+    Format3(BaseCoordFormat3),
+}
+
+impl FontWrite for BaseCoord {
+    fn write_into(&self, writer: &mut TableWriter) {
+        match self {
+            Self::Format1(item) => item.write_into(writer),
+            Self::Format2(item) => item.write_into(writer),
+            Self::Format3(item) => item.write_into(writer),
+        }
+    }
+}
+
+impl Validate for BaseCoord {
+    fn validate_impl(&self, ctx: &mut ValidationCtx) {
+        match self {
+            Self::Format1(item) => item.validate_impl(ctx),
+            Self::Format2(item) => item.validate_impl(ctx),
+            Self::Format3(item) => item.validate_impl(ctx),
+        }
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl FromObjRef<read_fonts::layout::base::BaseCoord<'_>> for BaseCoord {
+    fn from_obj_ref(obj: &read_fonts::layout::base::BaseCoord, _: FontData) -> Self {
+        use read_fonts::layout::base::BaseCoord as ObjRefType;
+        match obj {
+            ObjRefType::Format1(item) => BaseCoord::Format1(item.to_owned_table()),
+            ObjRefType::Format2(item) => BaseCoord::Format2(item.to_owned_table()),
+            ObjRefType::Format3(item) => BaseCoord::Format3(item.to_owned_table()),
+        }
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl FromTableRef<read_fonts::layout::base::BaseCoord<'_>> for BaseCoord {}
+
+#[cfg(feature = "parsing")]
+impl<'a> FontRead<'a> for BaseCoord {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        <read_fonts::layout::base::BaseCoord as FontRead>::read(data).map(|x| x.to_owned_table())
+    }
+}
+
+/// [BaseCoordFormat1](https://learn.microsoft.com/en-us/typography/opentype/spec/base#basecoord-format-1)
+#[derive(Clone, Debug)]
+pub struct BaseCoordFormat1 {
+    /// X or Y value, in design units
+    pub coordinate: i16,
+}
+
+impl FontWrite for BaseCoordFormat1 {
+    #[allow(clippy::unnecessary_cast)]
+    fn write_into(&self, writer: &mut TableWriter) {
+        (1 as u16).write_into(writer);
+        self.coordinate.write_into(writer);
+    }
+}
+
+impl Validate for BaseCoordFormat1 {
+    fn validate_impl(&self, _ctx: &mut ValidationCtx) {}
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromObjRef<read_fonts::layout::base::BaseCoordFormat1<'a>> for BaseCoordFormat1 {
+    fn from_obj_ref(obj: &read_fonts::layout::base::BaseCoordFormat1<'a>, _: FontData) -> Self {
+        BaseCoordFormat1 {
+            coordinate: obj.coordinate(),
+        }
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromTableRef<read_fonts::layout::base::BaseCoordFormat1<'a>> for BaseCoordFormat1 {}
+
+#[cfg(feature = "parsing")]
+impl<'a> FontRead<'a> for BaseCoordFormat1 {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        <read_fonts::layout::base::BaseCoordFormat1 as FontRead>::read(data)
+            .map(|x| x.to_owned_table())
+    }
+}
+
+/// [BaseCoordFormat2](https://learn.microsoft.com/en-us/typography/opentype/spec/base#basecoord-format-2)
+#[derive(Clone, Debug)]
+pub struct BaseCoordFormat2 {
+    /// X or Y value, in design units
+    pub coordinate: i16,
+    /// Glyph ID of control glyph
+    pub reference_glyph: u16,
+    /// Index of contour point on the reference glyph
+    pub base_coord_point: u16,
+}
+
+impl FontWrite for BaseCoordFormat2 {
+    #[allow(clippy::unnecessary_cast)]
+    fn write_into(&self, writer: &mut TableWriter) {
+        (2 as u16).write_into(writer);
+        self.coordinate.write_into(writer);
+        self.reference_glyph.write_into(writer);
+        self.base_coord_point.write_into(writer);
+    }
+}
+
+impl Validate for BaseCoordFormat2 {
+    fn validate_impl(&self, _ctx: &mut ValidationCtx) {}
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromObjRef<read_fonts::layout::base::BaseCoordFormat2<'a>> for BaseCoordFormat2 {
+    fn from_obj_ref(obj: &read_fonts::layout::base::BaseCoordFormat2<'a>, _: FontData) -> Self {
+        BaseCoordFormat2 {
+            coordinate: obj.coordinate(),
+            reference_glyph: obj.reference_glyph(),
+            base_coord_point: obj.base_coord_point(),
+        }
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromTableRef<read_fonts::layout::base::BaseCoordFormat2<'a>> for BaseCoordFormat2 {}
+
+#[cfg(feature = "parsing")]
+impl<'a> FontRead<'a> for BaseCoordFormat2 {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        <read_fonts::layout::base::BaseCoordFormat2 as FontRead>::read(data)
+            .map(|x| x.to_owned_table())
+    }
+}
+
+/// [BaseCoordFormat3](https://learn.microsoft.com/en-us/typography/opentype/spec/base#basecoord-format-3)
+#[derive(Clone, Debug)]
+pub struct BaseCoordFormat3 {
+    /// X or Y value, in design units
+    pub coordinate: i16,
+    /// Offset to Device table (non-variable font) / Variation Index
+    /// table (variable font) for X or Y value, from beginning of
+    /// BaseCoord table (may be NULL).
+    pub device_offset: NullableOffsetMarker<Device>,
+}
+
+impl FontWrite for BaseCoordFormat3 {
+    #[allow(clippy::unnecessary_cast)]
+    fn write_into(&self, writer: &mut TableWriter) {
+        (3 as u16).write_into(writer);
+        self.coordinate.write_into(writer);
+        self.device_offset.write_into(writer);
+    }
+}
+
+impl Validate for BaseCoordFormat3 {
+    fn validate_impl(&self, ctx: &mut ValidationCtx) {
+        ctx.in_table("BaseCoordFormat3", |ctx| {
+            ctx.in_field("device_offset", |ctx| {
+                self.device_offset.validate_impl(ctx);
+            });
+        })
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromObjRef<read_fonts::layout::base::BaseCoordFormat3<'a>> for BaseCoordFormat3 {
+    fn from_obj_ref(obj: &read_fonts::layout::base::BaseCoordFormat3<'a>, _: FontData) -> Self {
+        BaseCoordFormat3 {
+            coordinate: obj.coordinate(),
+            device_offset: obj.device().into(),
+        }
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromTableRef<read_fonts::layout::base::BaseCoordFormat3<'a>> for BaseCoordFormat3 {}
+
+#[cfg(feature = "parsing")]
+impl<'a> FontRead<'a> for BaseCoordFormat3 {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        <read_fonts::layout::base::BaseCoordFormat3 as FontRead>::read(data)
+            .map(|x| x.to_owned_table())
+    }
+}
@@ -0,0 +1,43 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+#[allow(unused_imports)]
+use crate::codegen_prelude::*;
+
+/// The [cvt (Control Value Table)](https://learn.microsoft.com/en-us/typography/opentype/spec/cvt) table
+#[derive(Clone, Debug)]
+pub struct Cvt {
+    /// List of n values referenceable by instructions. n is the number
+    /// of FWORD items that fit in the size of the table.
+    pub values: Vec<FWord>,
+}
+
+impl FontWrite for Cvt {
+    fn write_into(&self, writer: &mut TableWriter) {
+        self.values.write_into(writer);
+    }
+}
+
+impl Validate for Cvt {
+    fn validate_impl(&self, _ctx: &mut ValidationCtx) {}
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromObjRef<read_fonts::tables::cvt::Cvt<'a>> for Cvt {
+    fn from_obj_ref(obj: &read_fonts::tables::cvt::Cvt<'a>, _: FontData) -> Self {
+        Cvt {
+            values: obj.values().iter().map(|x| x.get()).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromTableRef<read_fonts::tables::cvt::Cvt<'a>> for Cvt {}
+
+#[cfg(feature = "parsing")]
+impl<'a> FontRead<'a> for Cvt {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        <read_fonts::tables::cvt::Cvt as FontRead>::read(data).map(|x| x.to_owned_table())
+    }
+}
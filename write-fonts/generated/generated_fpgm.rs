@@ -0,0 +1,43 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+#[allow(unused_imports)]
+use crate::codegen_prelude::*;
+
+/// The [fpgm (Font Program)](https://learn.microsoft.com/en-us/typography/opentype/spec/fpgm) table
+#[derive(Clone, Debug)]
+pub struct Fpgm {
+    /// Instructions. n is the number of uint8 items that fit in the
+    /// size of the table.
+    pub instructions: Vec<u8>,
+}
+
+impl FontWrite for Fpgm {
+    fn write_into(&self, writer: &mut TableWriter) {
+        self.instructions.write_into(writer);
+    }
+}
+
+impl Validate for Fpgm {
+    fn validate_impl(&self, _ctx: &mut ValidationCtx) {}
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromObjRef<read_fonts::tables::fpgm::Fpgm<'a>> for Fpgm {
+    fn from_obj_ref(obj: &read_fonts::tables::fpgm::Fpgm<'a>, _: FontData) -> Self {
+        Fpgm {
+            instructions: obj.instructions().to_owned(),
+        }
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromTableRef<read_fonts::tables::fpgm::Fpgm<'a>> for Fpgm {}
+
+#[cfg(feature = "parsing")]
+impl<'a> FontRead<'a> for Fpgm {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        <read_fonts::tables::fpgm::Fpgm as FontRead>::read(data).map(|x| x.to_owned_table())
+    }
+}
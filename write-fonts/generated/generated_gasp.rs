@@ -0,0 +1,100 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+#[allow(unused_imports)]
+use crate::codegen_prelude::*;
+
+/// The [gasp (Grid-fitting and Scan-conversion Procedure)](https://learn.microsoft.com/en-us/typography/opentype/spec/gasp) table
+#[derive(Clone, Debug)]
+pub struct Gasp {
+    /// Version number (set to 1)
+    pub version: u16,
+    /// Sorted by ppem
+    pub gasp_ranges: Vec<GaspRange>,
+}
+
+impl FontWrite for Gasp {
+    #[allow(clippy::unnecessary_cast)]
+    fn write_into(&self, writer: &mut TableWriter) {
+        self.version.write_into(writer);
+        (array_len(&self.gasp_ranges).unwrap() as u16).write_into(writer);
+        self.gasp_ranges.write_into(writer);
+    }
+}
+
+impl Validate for Gasp {
+    fn validate_impl(&self, ctx: &mut ValidationCtx) {
+        ctx.in_table("Gasp", |ctx| {
+            ctx.in_field("gasp_ranges", |ctx| {
+                if self.gasp_ranges.len() > (u16::MAX as usize) {
+                    ctx.report("array excedes max length");
+                }
+                self.gasp_ranges.validate_impl(ctx);
+            });
+        })
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromObjRef<read_fonts::tables::gasp::Gasp<'a>> for Gasp {
+    fn from_obj_ref(obj: &read_fonts::tables::gasp::Gasp<'a>, _: FontData) -> Self {
+        let offset_data = obj.offset_data();
+        Gasp {
+            version: obj.version(),
+            gasp_ranges: obj
+                .gasp_ranges()
+                .iter()
+                .map(|x| FromObjRef::from_obj_ref(x, offset_data))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromTableRef<read_fonts::tables::gasp::Gasp<'a>> for Gasp {}
+
+#[cfg(feature = "parsing")]
+impl<'a> FontRead<'a> for Gasp {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        <read_fonts::tables::gasp::Gasp as FontRead>::read(data).map(|x| x.to_owned_table())
+    }
+}
+
+/// A [gasp range](https://learn.microsoft.com/en-us/typography/opentype/spec/gasp#gasp-table-formats) record
+#[derive(Clone, Debug)]
+pub struct GaspRange {
+    /// Upper limit of range, in PPEM
+    pub range_max_ppem: u16,
+    /// Flags describing desired rasterizer behavior.
+    pub range_gasp_behavior: GaspRangeBehavior,
+}
+
+impl FontWrite for GaspRange {
+    fn write_into(&self, writer: &mut TableWriter) {
+        self.range_max_ppem.write_into(writer);
+        self.range_gasp_behavior.write_into(writer);
+    }
+}
+
+impl Validate for GaspRange {
+    fn validate_impl(&self, _ctx: &mut ValidationCtx) {}
+}
+
+#[cfg(feature = "parsing")]
+impl FromObjRef<read_fonts::tables::gasp::GaspRange> for GaspRange {
+    fn from_obj_ref(obj: &read_fonts::tables::gasp::GaspRange, _: FontData) -> Self {
+        GaspRange {
+            range_max_ppem: obj.range_max_ppem(),
+            range_gasp_behavior: convert_gasp_behavior(obj.range_gasp_behavior()),
+        }
+    }
+}
+
+bitflags::bitflags! { # [doc = " Flags describing the desired rasterizer behavior for a [GaspRange]."] pub struct GaspRangeBehavior : u16 { # [doc = " Use gridfitting"] const GASP_GRIDFIT = 0x0001 ; # [doc = " Use grayscale rendering"] const GASP_DOGRAY = 0x0002 ; # [doc = " Use gridfitting with ClearType symmetric smoothing. Only"] # [doc = " supported in version 1 'gasp'"] const GASP_SYMMETRIC_GRIDFIT = 0x0004 ; # [doc = " Use smoothing along multiple axes with ClearType®. Only"] # [doc = " supported in version 1 'gasp'"] const GASP_SYMMETRIC_SMOOTHING = 0x0008 ; } }
+
+impl FontWrite for GaspRangeBehavior {
+    fn write_into(&self, writer: &mut TableWriter) {
+        writer.write_slice(&self.bits().to_be_bytes())
+    }
+}
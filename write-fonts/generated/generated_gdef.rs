@@ -25,7 +25,7 @@ pub struct Gdef {
     pub mark_glyph_sets_def_offset: NullableOffsetMarker<MarkGlyphSets>,
     /// Offset to the Item Variation Store table, from beginning of
     /// GDEF header (may be NULL)
-    pub item_var_store_offset: NullableOffsetMarker<ClassDef, WIDTH_32>,
+    pub item_var_store_offset: NullableOffsetMarker<ItemVariationStore, WIDTH_32>,
 }
 
 impl FontWrite for Gdef {
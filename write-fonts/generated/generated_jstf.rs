@@ -0,0 +1,466 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+#[allow(unused_imports)]
+use crate::codegen_prelude::*;
+
+/// The [JSTF](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf) (Justification) table
+#[derive(Clone, Debug)]
+pub struct Jstf {
+    /// Array of JstfScriptRecords, in alphabetical order by
+    /// jstfScriptTag
+    pub jstf_script_records: Vec<JstfScriptRecord>,
+}
+
+impl FontWrite for Jstf {
+    #[allow(clippy::unnecessary_cast)]
+    fn write_into(&self, writer: &mut TableWriter) {
+        (MajorMinor::VERSION_1_0 as MajorMinor).write_into(writer);
+        (array_len(&self.jstf_script_records).unwrap() as u16).write_into(writer);
+        self.jstf_script_records.write_into(writer);
+    }
+}
+
+impl Validate for Jstf {
+    fn validate_impl(&self, ctx: &mut ValidationCtx) {
+        ctx.in_table("Jstf", |ctx| {
+            ctx.in_field("jstf_script_records", |ctx| {
+                if self.jstf_script_records.len() > (u16::MAX as usize) {
+                    ctx.report("array excedes max length");
+                }
+                self.jstf_script_records.validate_impl(ctx);
+            });
+        })
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromObjRef<read_fonts::layout::jstf::Jstf<'a>> for Jstf {
+    fn from_obj_ref(obj: &read_fonts::layout::jstf::Jstf<'a>, _: FontData) -> Self {
+        let offset_data = obj.offset_data();
+        Jstf {
+            jstf_script_records: obj
+                .jstf_script_records()
+                .iter()
+                .map(|x| FromObjRef::from_obj_ref(x, offset_data))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromTableRef<read_fonts::layout::jstf::Jstf<'a>> for Jstf {}
+
+#[cfg(feature = "parsing")]
+impl<'a> FontRead<'a> for Jstf {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        <read_fonts::layout::jstf::Jstf as FontRead>::read(data).map(|x| x.to_owned_table())
+    }
+}
+
+/// [JstfScriptRecord](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#jstfscriptrecord)
+#[derive(Clone, Debug)]
+pub struct JstfScriptRecord {
+    /// 4-byte JstfScript identification
+    pub jstf_script_tag: Tag,
+    /// Offset to JstfScript table, from beginning of JSTF Header
+    pub jstf_script_offset: OffsetMarker<JstfScript>,
+}
+
+impl FontWrite for JstfScriptRecord {
+    fn write_into(&self, writer: &mut TableWriter) {
+        self.jstf_script_tag.write_into(writer);
+        self.jstf_script_offset.write_into(writer);
+    }
+}
+
+impl Validate for JstfScriptRecord {
+    fn validate_impl(&self, ctx: &mut ValidationCtx) {
+        ctx.in_table("JstfScriptRecord", |ctx| {
+            ctx.in_field("jstf_script_offset", |ctx| {
+                self.jstf_script_offset.validate_impl(ctx);
+            });
+        })
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl FromObjRef<read_fonts::layout::jstf::JstfScriptRecord> for JstfScriptRecord {
+    fn from_obj_ref(
+        obj: &read_fonts::layout::jstf::JstfScriptRecord,
+        offset_data: FontData,
+    ) -> Self {
+        JstfScriptRecord {
+            jstf_script_tag: obj.jstf_script_tag(),
+            jstf_script_offset: obj.jstf_script(offset_data).into(),
+        }
+    }
+}
+
+/// [JstfScript Table](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#jstfscript-table)
+#[derive(Clone, Debug)]
+pub struct JstfScript {
+    /// Offset to ExtenderGlyph table, from beginning of JstfScript
+    /// table (may be NULL)
+    pub extender_glyph_offset: NullableOffsetMarker<ExtenderGlyph>,
+    /// Offset to default JstfLangSys table, from beginning of
+    /// JstfScript table (may be NULL)
+    pub def_jstf_lang_sys_offset: NullableOffsetMarker<JstfLangSys>,
+    /// Array of JstfLangSysRecords, in alphabetical order by
+    /// JstfLangSysTag
+    pub jstf_lang_sys_records: Vec<JstfLangSysRecord>,
+}
+
+impl FontWrite for JstfScript {
+    #[allow(clippy::unnecessary_cast)]
+    fn write_into(&self, writer: &mut TableWriter) {
+        self.extender_glyph_offset.write_into(writer);
+        self.def_jstf_lang_sys_offset.write_into(writer);
+        (array_len(&self.jstf_lang_sys_records).unwrap() as u16).write_into(writer);
+        self.jstf_lang_sys_records.write_into(writer);
+    }
+}
+
+impl Validate for JstfScript {
+    fn validate_impl(&self, ctx: &mut ValidationCtx) {
+        ctx.in_table("JstfScript", |ctx| {
+            ctx.in_field("extender_glyph_offset", |ctx| {
+                self.extender_glyph_offset.validate_impl(ctx);
+            });
+            ctx.in_field("def_jstf_lang_sys_offset", |ctx| {
+                self.def_jstf_lang_sys_offset.validate_impl(ctx);
+            });
+            ctx.in_field("jstf_lang_sys_records", |ctx| {
+                if self.jstf_lang_sys_records.len() > (u16::MAX as usize) {
+                    ctx.report("array excedes max length");
+                }
+                self.jstf_lang_sys_records.validate_impl(ctx);
+            });
+        })
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromObjRef<read_fonts::layout::jstf::JstfScript<'a>> for JstfScript {
+    fn from_obj_ref(obj: &read_fonts::layout::jstf::JstfScript<'a>, _: FontData) -> Self {
+        let offset_data = obj.offset_data();
+        JstfScript {
+            extender_glyph_offset: obj.extender_glyph().into(),
+            def_jstf_lang_sys_offset: obj.def_jstf_lang_sys().into(),
+            jstf_lang_sys_records: obj
+                .jstf_lang_sys_records()
+                .iter()
+                .map(|x| FromObjRef::from_obj_ref(x, offset_data))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromTableRef<read_fonts::layout::jstf::JstfScript<'a>> for JstfScript {}
+
+#[cfg(feature = "parsing")]
+impl<'a> FontRead<'a> for JstfScript {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        <read_fonts::layout::jstf::JstfScript as FontRead>::read(data).map(|x| x.to_owned_table())
+    }
+}
+
+/// [JstfLangSysRecord](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#jstfscript-table)
+#[derive(Clone, Debug)]
+pub struct JstfLangSysRecord {
+    /// 4-byte JstfLangSys identifier
+    pub jstf_lang_sys_tag: Tag,
+    /// Offset to JstfLangSys table, from beginning of JstfScript table
+    pub jstf_lang_sys_offset: OffsetMarker<JstfLangSys>,
+}
+
+impl FontWrite for JstfLangSysRecord {
+    fn write_into(&self, writer: &mut TableWriter) {
+        self.jstf_lang_sys_tag.write_into(writer);
+        self.jstf_lang_sys_offset.write_into(writer);
+    }
+}
+
+impl Validate for JstfLangSysRecord {
+    fn validate_impl(&self, ctx: &mut ValidationCtx) {
+        ctx.in_table("JstfLangSysRecord", |ctx| {
+            ctx.in_field("jstf_lang_sys_offset", |ctx| {
+                self.jstf_lang_sys_offset.validate_impl(ctx);
+            });
+        })
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl FromObjRef<read_fonts::layout::jstf::JstfLangSysRecord> for JstfLangSysRecord {
+    fn from_obj_ref(
+        obj: &read_fonts::layout::jstf::JstfLangSysRecord,
+        offset_data: FontData,
+    ) -> Self {
+        JstfLangSysRecord {
+            jstf_lang_sys_tag: obj.jstf_lang_sys_tag(),
+            jstf_lang_sys_offset: obj.jstf_lang_sys(offset_data).into(),
+        }
+    }
+}
+
+/// [Extender Glyph Table](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#extender-glyph-table)
+#[derive(Clone, Debug)]
+pub struct ExtenderGlyph {
+    /// Extender glyph IDs — in increasing numerical order
+    pub extender_glyphs: Vec<GlyphId>,
+}
+
+impl FontWrite for ExtenderGlyph {
+    #[allow(clippy::unnecessary_cast)]
+    fn write_into(&self, writer: &mut TableWriter) {
+        (array_len(&self.extender_glyphs).unwrap() as u16).write_into(writer);
+        self.extender_glyphs.write_into(writer);
+    }
+}
+
+impl Validate for ExtenderGlyph {
+    fn validate_impl(&self, ctx: &mut ValidationCtx) {
+        ctx.in_table("ExtenderGlyph", |ctx| {
+            ctx.in_field("extender_glyphs", |ctx| {
+                if self.extender_glyphs.len() > (u16::MAX as usize) {
+                    ctx.report("array excedes max length");
+                }
+            });
+        })
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromObjRef<read_fonts::layout::jstf::ExtenderGlyph<'a>> for ExtenderGlyph {
+    fn from_obj_ref(obj: &read_fonts::layout::jstf::ExtenderGlyph<'a>, _: FontData) -> Self {
+        ExtenderGlyph {
+            extender_glyphs: obj.extender_glyphs().iter().map(|x| x.get()).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromTableRef<read_fonts::layout::jstf::ExtenderGlyph<'a>> for ExtenderGlyph {}
+
+#[cfg(feature = "parsing")]
+impl<'a> FontRead<'a> for ExtenderGlyph {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        <read_fonts::layout::jstf::ExtenderGlyph as FontRead>::read(data)
+            .map(|x| x.to_owned_table())
+    }
+}
+
+/// [JstfLangSys Table](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#jstflangsys-table)
+#[derive(Clone, Debug)]
+pub struct JstfLangSys {
+    /// Array of offsets to JstfPriority tables, from beginning of
+    /// JstfLangSys table, in priority order
+    pub jstf_priority_offsets: Vec<OffsetMarker<JstfPriority>>,
+}
+
+impl FontWrite for JstfLangSys {
+    #[allow(clippy::unnecessary_cast)]
+    fn write_into(&self, writer: &mut TableWriter) {
+        (array_len(&self.jstf_priority_offsets).unwrap() as u16).write_into(writer);
+        self.jstf_priority_offsets.write_into(writer);
+    }
+}
+
+impl Validate for JstfLangSys {
+    fn validate_impl(&self, ctx: &mut ValidationCtx) {
+        ctx.in_table("JstfLangSys", |ctx| {
+            ctx.in_field("jstf_priority_offsets", |ctx| {
+                if self.jstf_priority_offsets.len() > (u16::MAX as usize) {
+                    ctx.report("array excedes max length");
+                }
+                self.jstf_priority_offsets.validate_impl(ctx);
+            });
+        })
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromObjRef<read_fonts::layout::jstf::JstfLangSys<'a>> for JstfLangSys {
+    fn from_obj_ref(obj: &read_fonts::layout::jstf::JstfLangSys<'a>, _: FontData) -> Self {
+        JstfLangSys {
+            jstf_priority_offsets: obj.jstf_priority().map(|x| x.into()).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromTableRef<read_fonts::layout::jstf::JstfLangSys<'a>> for JstfLangSys {}
+
+#[cfg(feature = "parsing")]
+impl<'a> FontRead<'a> for JstfLangSys {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        <read_fonts::layout::jstf::JstfLangSys as FontRead>::read(data).map(|x| x.to_owned_table())
+    }
+}
+
+/// [JstfPriority Table](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#jstfpriority-table)
+#[derive(Clone, Debug)]
+pub struct JstfPriority {
+    /// Offset to shrinkage-enable JstfGSUBModList table, from
+    /// beginning of JstfPriority table (may be NULL)
+    pub gsub_shrinkage_enable_offset: NullableOffsetMarker<JstfModList>,
+    /// Offset to shrinkage-disable JstfGSUBModList table, from
+    /// beginning of JstfPriority table (may be NULL)
+    pub gsub_shrinkage_disable_offset: NullableOffsetMarker<JstfModList>,
+    /// Offset to shrinkage-enable JstfGPOSModList table, from
+    /// beginning of JstfPriority table (may be NULL)
+    pub gpos_shrinkage_enable_offset: NullableOffsetMarker<JstfModList>,
+    /// Offset to shrinkage-disable JstfGPOSModList table, from
+    /// beginning of JstfPriority table (may be NULL)
+    pub gpos_shrinkage_disable_offset: NullableOffsetMarker<JstfModList>,
+    /// Offset to shrinkage JstfMax table, from beginning of
+    /// JstfPriority table (may be NULL)
+    pub shrinkage_jstf_max_offset: NullableOffsetMarker<PositionLookupList>,
+    /// Offset to extension-enable JstfGSUBModList table, from
+    /// beginnning of JstfPriority table (may be NULL)
+    pub gsub_extension_enable_offset: NullableOffsetMarker<JstfModList>,
+    /// Offset to extension-disable JstfGSUBModList table, from
+    /// beginning of JstfPriority table (may be NULL)
+    pub gsub_extension_disable_offset: NullableOffsetMarker<JstfModList>,
+    /// Offset to extension-enable JstfGPOSModList table, from
+    /// beginning of JstfPriority table (may be NULL)
+    pub gpos_extension_enable_offset: NullableOffsetMarker<JstfModList>,
+    /// Offset to extension-disable JstfGPOSModList table, from
+    /// beginning of JstfPriority table (may be NULL)
+    pub gpos_extension_disable_offset: NullableOffsetMarker<JstfModList>,
+    /// Offset to extension JstfMax table, from beginning of
+    /// JstfPriority table (may be NULL)
+    pub extension_jstf_max_offset: NullableOffsetMarker<PositionLookupList>,
+}
+
+impl FontWrite for JstfPriority {
+    fn write_into(&self, writer: &mut TableWriter) {
+        self.gsub_shrinkage_enable_offset.write_into(writer);
+        self.gsub_shrinkage_disable_offset.write_into(writer);
+        self.gpos_shrinkage_enable_offset.write_into(writer);
+        self.gpos_shrinkage_disable_offset.write_into(writer);
+        self.shrinkage_jstf_max_offset.write_into(writer);
+        self.gsub_extension_enable_offset.write_into(writer);
+        self.gsub_extension_disable_offset.write_into(writer);
+        self.gpos_extension_enable_offset.write_into(writer);
+        self.gpos_extension_disable_offset.write_into(writer);
+        self.extension_jstf_max_offset.write_into(writer);
+    }
+}
+
+impl Validate for JstfPriority {
+    fn validate_impl(&self, ctx: &mut ValidationCtx) {
+        ctx.in_table("JstfPriority", |ctx| {
+            ctx.in_field("gsub_shrinkage_enable_offset", |ctx| {
+                self.gsub_shrinkage_enable_offset.validate_impl(ctx);
+            });
+            ctx.in_field("gsub_shrinkage_disable_offset", |ctx| {
+                self.gsub_shrinkage_disable_offset.validate_impl(ctx);
+            });
+            ctx.in_field("gpos_shrinkage_enable_offset", |ctx| {
+                self.gpos_shrinkage_enable_offset.validate_impl(ctx);
+            });
+            ctx.in_field("gpos_shrinkage_disable_offset", |ctx| {
+                self.gpos_shrinkage_disable_offset.validate_impl(ctx);
+            });
+            ctx.in_field("shrinkage_jstf_max_offset", |ctx| {
+                self.shrinkage_jstf_max_offset.validate_impl(ctx);
+            });
+            ctx.in_field("gsub_extension_enable_offset", |ctx| {
+                self.gsub_extension_enable_offset.validate_impl(ctx);
+            });
+            ctx.in_field("gsub_extension_disable_offset", |ctx| {
+                self.gsub_extension_disable_offset.validate_impl(ctx);
+            });
+            ctx.in_field("gpos_extension_enable_offset", |ctx| {
+                self.gpos_extension_enable_offset.validate_impl(ctx);
+            });
+            ctx.in_field("gpos_extension_disable_offset", |ctx| {
+                self.gpos_extension_disable_offset.validate_impl(ctx);
+            });
+            ctx.in_field("extension_jstf_max_offset", |ctx| {
+                self.extension_jstf_max_offset.validate_impl(ctx);
+            });
+        })
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromObjRef<read_fonts::layout::jstf::JstfPriority<'a>> for JstfPriority {
+    fn from_obj_ref(obj: &read_fonts::layout::jstf::JstfPriority<'a>, _: FontData) -> Self {
+        JstfPriority {
+            gsub_shrinkage_enable_offset: obj.gsub_shrinkage_enable().into(),
+            gsub_shrinkage_disable_offset: obj.gsub_shrinkage_disable().into(),
+            gpos_shrinkage_enable_offset: obj.gpos_shrinkage_enable().into(),
+            gpos_shrinkage_disable_offset: obj.gpos_shrinkage_disable().into(),
+            shrinkage_jstf_max_offset: obj.shrinkage_jstf_max().into(),
+            gsub_extension_enable_offset: obj.gsub_extension_enable().into(),
+            gsub_extension_disable_offset: obj.gsub_extension_disable().into(),
+            gpos_extension_enable_offset: obj.gpos_extension_enable().into(),
+            gpos_extension_disable_offset: obj.gpos_extension_disable().into(),
+            extension_jstf_max_offset: obj.extension_jstf_max().into(),
+        }
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromTableRef<read_fonts::layout::jstf::JstfPriority<'a>> for JstfPriority {}
+
+#[cfg(feature = "parsing")]
+impl<'a> FontRead<'a> for JstfPriority {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        <read_fonts::layout::jstf::JstfPriority as FontRead>::read(data).map(|x| x.to_owned_table())
+    }
+}
+
+/// [JstfGSUBModList](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#jstfgsubmodlist-table)
+/// and [JstfGPOSModList](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#jstfgposmodlist-table)
+/// tables
+#[derive(Clone, Debug)]
+pub struct JstfModList {
+    /// Array of indices into the GSUB or GPOS LookupList, in
+    /// increasing numerical order
+    pub lookup_indices: Vec<u16>,
+}
+
+impl FontWrite for JstfModList {
+    #[allow(clippy::unnecessary_cast)]
+    fn write_into(&self, writer: &mut TableWriter) {
+        (array_len(&self.lookup_indices).unwrap() as u16).write_into(writer);
+        self.lookup_indices.write_into(writer);
+    }
+}
+
+impl Validate for JstfModList {
+    fn validate_impl(&self, ctx: &mut ValidationCtx) {
+        ctx.in_table("JstfModList", |ctx| {
+            ctx.in_field("lookup_indices", |ctx| {
+                if self.lookup_indices.len() > (u16::MAX as usize) {
+                    ctx.report("array excedes max length");
+                }
+            });
+        })
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromObjRef<read_fonts::layout::jstf::JstfModList<'a>> for JstfModList {
+    fn from_obj_ref(obj: &read_fonts::layout::jstf::JstfModList<'a>, _: FontData) -> Self {
+        JstfModList {
+            lookup_indices: obj.lookup_indices().iter().map(|x| x.get()).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromTableRef<read_fonts::layout::jstf::JstfModList<'a>> for JstfModList {}
+
+#[cfg(feature = "parsing")]
+impl<'a> FontRead<'a> for JstfModList {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        <read_fonts::layout::jstf::JstfModList as FontRead>::read(data).map(|x| x.to_owned_table())
+    }
+}
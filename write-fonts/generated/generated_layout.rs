@@ -2503,3 +2503,399 @@ impl<'a> FontRead<'a> for CharacterVariantParams {
             .map(|x| x.to_owned_table())
     }
 }
+
+/// [Item Variation Store](https://learn.microsoft.com/en-us/typography/opentype/spec/otvarcommonformats#item-variation-store-header-and-item-variation-data-subtables)
+#[derive(Clone, Debug)]
+pub struct ItemVariationStore {
+    /// Offset in bytes from the start of the item variation store to
+    /// the variation region list.
+    pub variation_region_list_offset: OffsetMarker<VariationRegionList, WIDTH_32>,
+    /// Offsets in bytes from the start of the item variation store to
+    /// each item variation data subtable.
+    pub item_variation_data_offsets: Vec<NullableOffsetMarker<ItemVariationData, WIDTH_32>>,
+}
+
+impl FontWrite for ItemVariationStore {
+    #[allow(clippy::unnecessary_cast)]
+    fn write_into(&self, writer: &mut TableWriter) {
+        (1 as u16).write_into(writer);
+        self.variation_region_list_offset.write_into(writer);
+        (array_len(&self.item_variation_data_offsets).unwrap() as u16).write_into(writer);
+        self.item_variation_data_offsets.write_into(writer);
+    }
+}
+
+impl Validate for ItemVariationStore {
+    fn validate_impl(&self, ctx: &mut ValidationCtx) {
+        ctx.in_table("ItemVariationStore", |ctx| {
+            ctx.in_field("variation_region_list_offset", |ctx| {
+                self.variation_region_list_offset.validate_impl(ctx);
+            });
+            ctx.in_field("item_variation_data_offsets", |ctx| {
+                if self.item_variation_data_offsets.len() > (u16::MAX as usize) {
+                    ctx.report("array excedes max length");
+                }
+                self.item_variation_data_offsets.validate_impl(ctx);
+            });
+        })
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromObjRef<read_fonts::layout::ItemVariationStore<'a>> for ItemVariationStore {
+    fn from_obj_ref(obj: &read_fonts::layout::ItemVariationStore<'a>, _: FontData) -> Self {
+        ItemVariationStore {
+            variation_region_list_offset: obj.variation_region_list().into(),
+            item_variation_data_offsets: obj.item_variation_data().map(|x| x.into()).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromTableRef<read_fonts::layout::ItemVariationStore<'a>> for ItemVariationStore {}
+
+#[cfg(feature = "parsing")]
+impl<'a> FontRead<'a> for ItemVariationStore {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        <read_fonts::layout::ItemVariationStore as FontRead>::read(data).map(|x| x.to_owned_table())
+    }
+}
+
+/// [Variation Region List](https://learn.microsoft.com/en-us/typography/opentype/spec/otvarcommonformats#variation-regions)
+#[derive(Clone, Debug)]
+pub struct VariationRegionList {
+    /// Array of variation regions.
+    pub variation_regions: Vec<VariationRegion>,
+}
+
+impl FontWrite for VariationRegionList {
+    #[allow(clippy::unnecessary_cast)]
+    fn write_into(&self, writer: &mut TableWriter) {
+        (self.compute_axis_count() as u16).write_into(writer);
+        (array_len(&self.variation_regions).unwrap() as u16).write_into(writer);
+        self.variation_regions.write_into(writer);
+    }
+}
+
+impl Validate for VariationRegionList {
+    fn validate_impl(&self, ctx: &mut ValidationCtx) {
+        ctx.in_table("VariationRegionList", |ctx| {
+            ctx.in_field("variation_regions", |ctx| {
+                if self.variation_regions.len() > (u16::MAX as usize) {
+                    ctx.report("array excedes max length");
+                }
+                self.variation_regions.validate_impl(ctx);
+            });
+        })
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromObjRef<read_fonts::layout::VariationRegionList<'a>> for VariationRegionList {
+    fn from_obj_ref(obj: &read_fonts::layout::VariationRegionList<'a>, _: FontData) -> Self {
+        let offset_data = obj.offset_data();
+        VariationRegionList {
+            variation_regions: obj
+                .variation_regions()
+                .iter()
+                .filter_map(|x| x.map(|x| FromObjRef::from_obj_ref(&x, offset_data)).ok())
+                .collect(),
+        }
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromTableRef<read_fonts::layout::VariationRegionList<'a>> for VariationRegionList {}
+
+#[cfg(feature = "parsing")]
+impl<'a> FontRead<'a> for VariationRegionList {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        <read_fonts::layout::VariationRegionList as FontRead>::read(data)
+            .map(|x| x.to_owned_table())
+    }
+}
+
+/// The [VariationRegion](https://learn.microsoft.com/en-us/typography/opentype/spec/otvarcommonformats#variation-regions) record
+#[derive(Clone, Debug)]
+pub struct VariationRegion {
+    /// Array of region axis coordinates records, in the order of axes
+    /// given in the 'fvar' table.
+    pub region_axes: Vec<RegionAxisCoordinates>,
+}
+
+impl FontWrite for VariationRegion {
+    fn write_into(&self, writer: &mut TableWriter) {
+        self.region_axes.write_into(writer);
+    }
+}
+
+impl Validate for VariationRegion {
+    fn validate_impl(&self, ctx: &mut ValidationCtx) {
+        ctx.in_table("VariationRegion", |ctx| {
+            ctx.in_field("region_axes", |ctx| {
+                if self.region_axes.len() > (u16::MAX as usize) {
+                    ctx.report("array excedes max length");
+                }
+                self.region_axes.validate_impl(ctx);
+            });
+        })
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl FromObjRef<read_fonts::layout::VariationRegion<'_>> for VariationRegion {
+    fn from_obj_ref(obj: &read_fonts::layout::VariationRegion, offset_data: FontData) -> Self {
+        VariationRegion {
+            region_axes: obj
+                .region_axes()
+                .iter()
+                .map(|x| FromObjRef::from_obj_ref(x, offset_data))
+                .collect(),
+        }
+    }
+}
+
+/// The [RegionAxisCoordinates](https://learn.microsoft.com/en-us/typography/opentype/spec/otvarcommonformats#variation-regions) record
+#[derive(Clone, Debug)]
+pub struct RegionAxisCoordinates {
+    /// The region start coordinate value for the current axis.
+    pub start_coord: F2Dot14,
+    /// The region peak coordinate value for the current axis.
+    pub peak_coord: F2Dot14,
+    /// The region end coordinate value for the current axis.
+    pub end_coord: F2Dot14,
+}
+
+impl FontWrite for RegionAxisCoordinates {
+    fn write_into(&self, writer: &mut TableWriter) {
+        self.start_coord.write_into(writer);
+        self.peak_coord.write_into(writer);
+        self.end_coord.write_into(writer);
+    }
+}
+
+impl Validate for RegionAxisCoordinates {
+    fn validate_impl(&self, _ctx: &mut ValidationCtx) {}
+}
+
+#[cfg(feature = "parsing")]
+impl FromObjRef<read_fonts::layout::RegionAxisCoordinates> for RegionAxisCoordinates {
+    fn from_obj_ref(obj: &read_fonts::layout::RegionAxisCoordinates, _: FontData) -> Self {
+        RegionAxisCoordinates {
+            start_coord: obj.start_coord(),
+            peak_coord: obj.peak_coord(),
+            end_coord: obj.end_coord(),
+        }
+    }
+}
+
+/// The [ItemVariationData](https://learn.microsoft.com/en-us/typography/opentype/spec/otvarcommonformats#item-variation-store-header-and-item-variation-data-subtables) subtable
+#[derive(Clone, Debug)]
+pub struct ItemVariationData {
+    /// The number of delta sets for distinct items.
+    pub item_count: u16,
+    /// A packed field: the high bit is a flag indicating that deltas are
+    /// 32-bit (for the first word_count regions) or 16-bit (for the
+    /// remaining regions); the low 15 bits are the count of 'word' deltas.
+    pub word_delta_count: u16,
+    /// Array of indices into the variation region list for the regions
+    /// referenced by this item variation data table.
+    pub region_indexes: Vec<u16>,
+    /// Delta-set rows.
+    pub delta_sets: Vec<u8>,
+}
+
+impl FontWrite for ItemVariationData {
+    #[allow(clippy::unnecessary_cast)]
+    fn write_into(&self, writer: &mut TableWriter) {
+        self.item_count.write_into(writer);
+        self.word_delta_count.write_into(writer);
+        (array_len(&self.region_indexes).unwrap() as u16).write_into(writer);
+        self.region_indexes.write_into(writer);
+        self.delta_sets.write_into(writer);
+    }
+}
+
+impl Validate for ItemVariationData {
+    fn validate_impl(&self, ctx: &mut ValidationCtx) {
+        ctx.in_table("ItemVariationData", |ctx| {
+            ctx.in_field("region_indexes", |ctx| {
+                if self.region_indexes.len() > (u16::MAX as usize) {
+                    ctx.report("array excedes max length");
+                }
+            });
+        })
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromObjRef<read_fonts::layout::ItemVariationData<'a>> for ItemVariationData {
+    fn from_obj_ref(obj: &read_fonts::layout::ItemVariationData<'a>, _: FontData) -> Self {
+        ItemVariationData {
+            item_count: obj.item_count(),
+            word_delta_count: obj.word_delta_count(),
+            region_indexes: obj.region_indexes().iter().map(|x| x.get()).collect(),
+            delta_sets: obj.delta_sets().to_owned(),
+        }
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromTableRef<read_fonts::layout::ItemVariationData<'a>> for ItemVariationData {}
+
+#[cfg(feature = "parsing")]
+impl<'a> FontRead<'a> for ItemVariationData {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        <read_fonts::layout::ItemVariationData as FontRead>::read(data).map(|x| x.to_owned_table())
+    }
+}
+
+/// [DeltaSetIndexMap](https://learn.microsoft.com/en-us/typography/opentype/spec/otvarcommonformats#associating-target-items-to-variation-data)
+#[derive(Clone, Debug)]
+pub enum DeltaSetIndexMap {
+    Format0(DeltaSetIndexMapFormat0),
+    Format1(DeltaSetIndexMapFormat1),
+}
+
+impl FontWrite for DeltaSetIndexMap {
+    fn write_into(&self, writer: &mut TableWriter) {
+        match self {
+            Self::Format0(item) => item.write_into(writer),
+            Self::Format1(item) => item.write_into(writer),
+        }
+    }
+}
+
+impl Validate for DeltaSetIndexMap {
+    fn validate_impl(&self, ctx: &mut ValidationCtx) {
+        match self {
+            Self::Format0(item) => item.validate_impl(ctx),
+            Self::Format1(item) => item.validate_impl(ctx),
+        }
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl FromObjRef<read_fonts::layout::DeltaSetIndexMap<'_>> for DeltaSetIndexMap {
+    fn from_obj_ref(obj: &read_fonts::layout::DeltaSetIndexMap, _: FontData) -> Self {
+        use read_fonts::layout::DeltaSetIndexMap as ObjRefType;
+        match obj {
+            ObjRefType::Format0(item) => DeltaSetIndexMap::Format0(item.to_owned_table()),
+            ObjRefType::Format1(item) => DeltaSetIndexMap::Format1(item.to_owned_table()),
+        }
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl FromTableRef<read_fonts::layout::DeltaSetIndexMap<'_>> for DeltaSetIndexMap {}
+
+#[cfg(feature = "parsing")]
+impl<'a> FontRead<'a> for DeltaSetIndexMap {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        <read_fonts::layout::DeltaSetIndexMap as FontRead>::read(data).map(|x| x.to_owned_table())
+    }
+}
+
+/// The [DeltaSetIndexMap](https://learn.microsoft.com/en-us/typography/opentype/spec/otvarcommonformats#associating-target-items-to-variation-data) table format 0
+#[derive(Clone, Debug)]
+pub struct DeltaSetIndexMapFormat0 {
+    /// A packed field that describes the compressed representation of
+    /// delta-set indices.
+    pub entry_format: EntryFormat,
+    /// The number of mapping entries.
+    pub map_count: u16,
+    /// The delta-set index mapping data.
+    pub map_data: Vec<u8>,
+}
+
+impl FontWrite for DeltaSetIndexMapFormat0 {
+    #[allow(clippy::unnecessary_cast)]
+    fn write_into(&self, writer: &mut TableWriter) {
+        (0 as u8).write_into(writer);
+        self.entry_format.write_into(writer);
+        self.map_count.write_into(writer);
+        self.map_data.write_into(writer);
+    }
+}
+
+impl Validate for DeltaSetIndexMapFormat0 {
+    fn validate_impl(&self, _ctx: &mut ValidationCtx) {}
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromObjRef<read_fonts::layout::DeltaSetIndexMapFormat0<'a>> for DeltaSetIndexMapFormat0 {
+    fn from_obj_ref(obj: &read_fonts::layout::DeltaSetIndexMapFormat0<'a>, _: FontData) -> Self {
+        DeltaSetIndexMapFormat0 {
+            entry_format: convert_entry_format(obj.entry_format()),
+            map_count: obj.map_count(),
+            map_data: obj.map_data().to_owned(),
+        }
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromTableRef<read_fonts::layout::DeltaSetIndexMapFormat0<'a>> for DeltaSetIndexMapFormat0 {}
+
+#[cfg(feature = "parsing")]
+impl<'a> FontRead<'a> for DeltaSetIndexMapFormat0 {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        <read_fonts::layout::DeltaSetIndexMapFormat0 as FontRead>::read(data)
+            .map(|x| x.to_owned_table())
+    }
+}
+
+/// The [DeltaSetIndexMap](https://learn.microsoft.com/en-us/typography/opentype/spec/otvarcommonformats#associating-target-items-to-variation-data) table format 1
+#[derive(Clone, Debug)]
+pub struct DeltaSetIndexMapFormat1 {
+    /// A packed field that describes the compressed representation of
+    /// delta-set indices.
+    pub entry_format: EntryFormat,
+    /// The number of mapping entries.
+    pub map_count: u32,
+    /// The delta-set index mapping data.
+    pub map_data: Vec<u8>,
+}
+
+impl FontWrite for DeltaSetIndexMapFormat1 {
+    #[allow(clippy::unnecessary_cast)]
+    fn write_into(&self, writer: &mut TableWriter) {
+        (1 as u8).write_into(writer);
+        self.entry_format.write_into(writer);
+        self.map_count.write_into(writer);
+        self.map_data.write_into(writer);
+    }
+}
+
+impl Validate for DeltaSetIndexMapFormat1 {
+    fn validate_impl(&self, _ctx: &mut ValidationCtx) {}
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromObjRef<read_fonts::layout::DeltaSetIndexMapFormat1<'a>> for DeltaSetIndexMapFormat1 {
+    fn from_obj_ref(obj: &read_fonts::layout::DeltaSetIndexMapFormat1<'a>, _: FontData) -> Self {
+        DeltaSetIndexMapFormat1 {
+            entry_format: convert_entry_format(obj.entry_format()),
+            map_count: obj.map_count(),
+            map_data: obj.map_data().to_owned(),
+        }
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromTableRef<read_fonts::layout::DeltaSetIndexMapFormat1<'a>> for DeltaSetIndexMapFormat1 {}
+
+#[cfg(feature = "parsing")]
+impl<'a> FontRead<'a> for DeltaSetIndexMapFormat1 {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        <read_fonts::layout::DeltaSetIndexMapFormat1 as FontRead>::read(data)
+            .map(|x| x.to_owned_table())
+    }
+}
+
+bitflags::bitflags! { # [doc = " Entry format for a [DeltaSetIndexMap]."] pub struct EntryFormat : u8 { # [doc = " Mask for the low 4 bits, which give the count of bits minus one"] # [doc = " that are used in each entry for the inner-level index."] const INNER_INDEX_BIT_COUNT_MASK = 0x0F ; # [doc = " Mask for bits that indicate the size in bytes minus one of each"] # [doc = " entry."] const MAP_ENTRY_SIZE_MASK = 0x30 ; } }
+
+impl FontWrite for EntryFormat {
+    fn write_into(&self, writer: &mut TableWriter) {
+        writer.write_slice(&self.bits().to_be_bytes())
+    }
+}
@@ -0,0 +1,44 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+#[allow(unused_imports)]
+use crate::codegen_prelude::*;
+
+/// The [prep (Control Value Program)](https://learn.microsoft.com/en-us/typography/opentype/spec/prep) table
+#[derive(Clone, Debug)]
+pub struct Prep {
+    /// Set of instructions executed whenever point size or font or
+    /// transformation change. n is the number of uint8 items that fit
+    /// in the size of the table.
+    pub instructions: Vec<u8>,
+}
+
+impl FontWrite for Prep {
+    fn write_into(&self, writer: &mut TableWriter) {
+        self.instructions.write_into(writer);
+    }
+}
+
+impl Validate for Prep {
+    fn validate_impl(&self, _ctx: &mut ValidationCtx) {}
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromObjRef<read_fonts::tables::prep::Prep<'a>> for Prep {
+    fn from_obj_ref(obj: &read_fonts::tables::prep::Prep<'a>, _: FontData) -> Self {
+        Prep {
+            instructions: obj.instructions().to_owned(),
+        }
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromTableRef<read_fonts::tables::prep::Prep<'a>> for Prep {}
+
+#[cfg(feature = "parsing")]
+impl<'a> FontRead<'a> for Prep {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        <read_fonts::tables::prep::Prep as FontRead>::read(data).map(|x| x.to_owned_table())
+    }
+}
@@ -0,0 +1,84 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+#[allow(unused_imports)]
+use crate::codegen_prelude::*;
+
+/// The [vmtx (Vertical Metrics)](https://docs.microsoft.com/en-us/typography/opentype/spec/vmtx) table
+#[derive(Clone, Debug)]
+pub struct Vmtx {
+    /// Paired advance height and top side bearing values for each
+    /// glyph. Records are indexed by glyph ID.
+    pub v_metrics: Vec<LongVerMetric>,
+    /// Top side bearings for glyph IDs greater than or equal to
+    /// numberOfLongMetrics.
+    pub top_side_bearings: Vec<i16>,
+}
+
+impl FontWrite for Vmtx {
+    fn write_into(&self, writer: &mut TableWriter) {
+        self.v_metrics.write_into(writer);
+        self.top_side_bearings.write_into(writer);
+    }
+}
+
+impl Validate for Vmtx {
+    fn validate_impl(&self, ctx: &mut ValidationCtx) {
+        ctx.in_table("Vmtx", |ctx| {
+            ctx.in_field("v_metrics", |ctx| {
+                if self.v_metrics.len() > (u16::MAX as usize) {
+                    ctx.report("array excedes max length");
+                }
+                self.v_metrics.validate_impl(ctx);
+            });
+        })
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromObjRef<read_fonts::tables::vmtx::Vmtx<'a>> for Vmtx {
+    fn from_obj_ref(obj: &read_fonts::tables::vmtx::Vmtx<'a>, _: FontData) -> Self {
+        let offset_data = obj.offset_data();
+        Vmtx {
+            v_metrics: obj
+                .v_metrics()
+                .iter()
+                .map(|x| FromObjRef::from_obj_ref(x, offset_data))
+                .collect(),
+            top_side_bearings: obj.top_side_bearings().iter().map(|x| x.get()).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> FromTableRef<read_fonts::tables::vmtx::Vmtx<'a>> for Vmtx {}
+
+#[derive(Clone, Debug)]
+pub struct LongVerMetric {
+    /// Advance height, in font design units.
+    pub advance: u16,
+    /// Glyph top side bearing, in font design units.
+    pub side_bearing: i16,
+}
+
+impl FontWrite for LongVerMetric {
+    fn write_into(&self, writer: &mut TableWriter) {
+        self.advance.write_into(writer);
+        self.side_bearing.write_into(writer);
+    }
+}
+
+impl Validate for LongVerMetric {
+    fn validate_impl(&self, _ctx: &mut ValidationCtx) {}
+}
+
+#[cfg(feature = "parsing")]
+impl FromObjRef<read_fonts::tables::vmtx::LongVerMetric> for LongVerMetric {
+    fn from_obj_ref(obj: &read_fonts::tables::vmtx::LongVerMetric, _: FontData) -> Self {
+        LongVerMetric {
+            advance: obj.advance(),
+            side_bearing: obj.side_bearing(),
+        }
+    }
+}
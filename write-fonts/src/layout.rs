@@ -61,9 +61,11 @@ macro_rules! table_newtype {
     };
 }
 
+pub mod base;
 pub mod gdef;
 pub mod gpos;
 pub mod gsub;
+pub mod jstf;
 
 mod value_record;
 
@@ -436,6 +438,22 @@ fn convert_delta_format(from: read_fonts::layout::DeltaFormat) -> DeltaFormat {
     }
 }
 
+#[cfg(feature = "parsing")]
+fn convert_entry_format(from: read_fonts::layout::EntryFormat) -> EntryFormat {
+    EntryFormat::from_bits_truncate(from.bits())
+}
+
+impl VariationRegionList {
+    fn compute_axis_count(&self) -> usize {
+        self.variation_regions
+            .first()
+            .map(|region| region.region_axes.len())
+            .unwrap_or_default()
+    }
+}
+
+// the enum is generated, so we can't derive this
+#[allow(clippy::derivable_impls)]
 impl Default for DeltaFormat {
     fn default() -> Self {
         Self::Local2BitDeltas
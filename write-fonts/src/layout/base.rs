@@ -0,0 +1,92 @@
+//! the [BASE] table
+//!
+//! [BASE]: https://learn.microsoft.com/en-us/typography/opentype/spec/base
+
+use font_types::MajorMinor;
+
+use super::{Device, ItemVariationStore};
+
+include!("../../generated/generated_base.rs");
+
+impl Base {
+    fn compute_version(&self) -> MajorMinor {
+        if self.item_var_store_offset.is_some() {
+            MajorMinor::VERSION_1_1
+        } else {
+            MajorMinor::VERSION_1_0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::VariationRegionList;
+
+    fn horiz_axis() -> Axis {
+        Axis {
+            base_tag_list_offset: NullableOffsetMarker::new(Some(BaseTagList {
+                baseline_tags: vec![Tag::new(b"ideo"), Tag::new(b"romn")],
+            })),
+            base_script_list_offset: OffsetMarker::new(BaseScriptList {
+                base_script_records: vec![BaseScriptRecord {
+                    base_script_tag: Tag::new(b"latn"),
+                    base_script_offset: OffsetMarker::new(BaseScript {
+                        base_values_offset: NullableOffsetMarker::new(Some(BaseValues {
+                            default_baseline_index: 1,
+                            base_coord_offsets: vec![
+                                OffsetMarker::new(BaseCoord::Format1(BaseCoordFormat1 {
+                                    coordinate: -120,
+                                })),
+                                OffsetMarker::new(BaseCoord::Format1(BaseCoordFormat1 {
+                                    coordinate: 0,
+                                })),
+                            ],
+                        })),
+                        default_min_max_offset: NullableOffsetMarker::new(None),
+                        base_lang_sys_records: Vec::new(),
+                    }),
+                }],
+            }),
+        }
+    }
+
+    #[test]
+    fn version() {
+        let mut base = Base {
+            horiz_axis_offset: NullableOffsetMarker::new(Some(horiz_axis())),
+            vert_axis_offset: NullableOffsetMarker::new(None),
+            item_var_store_offset: NullableOffsetMarker::new(None),
+        };
+        assert_eq!(base.compute_version(), MajorMinor::VERSION_1_0);
+        let _dumped = crate::write::dump_table(&base).unwrap();
+        #[cfg(feature = "parsing")]
+        {
+            let data = FontData::new(&_dumped);
+            let loaded = read_fonts::tables::base::Base::read(data).unwrap();
+            assert_eq!(loaded.version(), MajorMinor::VERSION_1_0);
+            let axis = loaded.horiz_axis().unwrap().unwrap();
+            let ideo = axis
+                .baseline(Tag::new(b"latn"), Tag::new(b"ideo"))
+                .unwrap()
+                .unwrap();
+            assert_eq!(ideo.coordinate(), -120);
+        }
+
+        base.item_var_store_offset = NullableOffsetMarker::new(Some(ItemVariationStore {
+            variation_region_list_offset: OffsetMarker::new(VariationRegionList {
+                variation_regions: Vec::new(),
+            }),
+            item_variation_data_offsets: Vec::new(),
+        }));
+        assert_eq!(base.compute_version(), MajorMinor::VERSION_1_1);
+        let _dumped = crate::write::dump_table(&base).unwrap();
+        #[cfg(feature = "parsing")]
+        {
+            let data = FontData::new(&_dumped);
+            let loaded = read_fonts::tables::base::Base::read(data).unwrap();
+            assert_eq!(loaded.version(), MajorMinor::VERSION_1_1);
+            assert!(loaded.item_var_store().is_some());
+        }
+    }
+}
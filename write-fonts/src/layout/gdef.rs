@@ -4,7 +4,7 @@
 
 use font_types::MajorMinor;
 
-use super::{ClassDef, CoverageTable, Device};
+use super::{ClassDef, CoverageTable, Device, ItemVariationStore};
 
 include!("../../generated/generated_gdef.rs");
 
@@ -23,6 +23,7 @@ impl Gdef {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::layout::VariationRegionList;
 
     #[test]
     fn var_store_without_glyph_sets() {
@@ -33,12 +34,12 @@ mod tests {
             lig_caret_list_offset: NullableOffsetMarker::new(None),
             mark_attach_class_def_offset: NullableOffsetMarker::new(None),
             mark_glyph_sets_def_offset: NullableOffsetMarker::new(None),
-            item_var_store_offset: NullableOffsetMarker::new(Some(ClassDef::Format1(
-                crate::layout::ClassDefFormat1 {
-                    start_glyph_id: GlyphId::new(2),
-                    class_value_array: vec![1, 2, 0],
-                },
-            ))),
+            item_var_store_offset: NullableOffsetMarker::new(Some(ItemVariationStore {
+                variation_region_list_offset: OffsetMarker::new(VariationRegionList {
+                    variation_regions: Vec::new(),
+                }),
+                item_variation_data_offsets: Vec::new(),
+            })),
         };
 
         assert_eq!(gdef.compute_version(), MajorMinor::VERSION_1_3);
@@ -17,7 +17,7 @@ use super::{
 mod tests;
 
 /// A GPOS lookup list table.
-type PositionLookupList = LookupList<PositionLookup>;
+pub type PositionLookupList = LookupList<PositionLookup>;
 
 table_newtype!(
     PositionSequenceContext,
@@ -0,0 +1,68 @@
+//! the [JSTF] table
+//!
+//! [JSTF]: https://learn.microsoft.com/en-us/typography/opentype/spec/jstf
+
+include!("../../generated/generated_jstf.rs");
+
+use super::gpos::PositionLookupList;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::gpos::PositionLookup;
+    use crate::layout::Lookup;
+
+    #[test]
+    fn round_trip() {
+        let priority = JstfPriority {
+            gsub_shrinkage_enable_offset: NullableOffsetMarker::new(None),
+            gsub_shrinkage_disable_offset: NullableOffsetMarker::new(Some(JstfModList {
+                lookup_indices: vec![3, 5],
+            })),
+            gpos_shrinkage_enable_offset: NullableOffsetMarker::new(None),
+            gpos_shrinkage_disable_offset: NullableOffsetMarker::new(None),
+            shrinkage_jstf_max_offset: NullableOffsetMarker::new(None),
+            gsub_extension_enable_offset: NullableOffsetMarker::new(None),
+            gsub_extension_disable_offset: NullableOffsetMarker::new(None),
+            gpos_extension_enable_offset: NullableOffsetMarker::new(None),
+            gpos_extension_disable_offset: NullableOffsetMarker::new(None),
+            extension_jstf_max_offset: NullableOffsetMarker::new(Some(PositionLookupList {
+                lookup_offsets: vec![OffsetMarker::new(PositionLookup::Single(Lookup {
+                    lookup_flag: 0,
+                    subtable_offsets: Vec::new(),
+                    mark_filtering_set: 0,
+                }))],
+            })),
+        };
+        let jstf = Jstf {
+            jstf_script_records: vec![JstfScriptRecord {
+                jstf_script_tag: Tag::new(b"arab"),
+                jstf_script_offset: OffsetMarker::new(JstfScript {
+                    extender_glyph_offset: NullableOffsetMarker::new(Some(ExtenderGlyph {
+                        extender_glyphs: vec![GlyphId::new(7)],
+                    })),
+                    def_jstf_lang_sys_offset: NullableOffsetMarker::new(Some(JstfLangSys {
+                        jstf_priority_offsets: vec![OffsetMarker::new(priority)],
+                    })),
+                    jstf_lang_sys_records: Vec::new(),
+                }),
+            }],
+        };
+
+        let _dumped = crate::write::dump_table(&jstf).unwrap();
+        #[cfg(feature = "parsing")]
+        {
+            let data = FontData::new(&_dumped);
+            let loaded = read_fonts::tables::jstf::Jstf::read(data).unwrap();
+            assert_eq!(loaded.version(), MajorMinor::VERSION_1_0);
+            let script = loaded.jstf_script(Tag::new(b"arab")).unwrap().unwrap();
+            let lang_sys = script.jstf_lang_sys(Tag::new(b"URD")).unwrap().unwrap();
+            let priority = lang_sys.jstf_priority().next().unwrap().unwrap();
+            let disable = priority.gsub_shrinkage_disable().unwrap().unwrap();
+            assert_eq!(disable.lookup_indices(), [3, 5]);
+            assert!(priority.gpos_shrinkage_enable().is_none());
+            let max = priority.extension_jstf_max().unwrap().unwrap();
+            assert_eq!(max.lookup_count(), 1);
+        }
+    }
+}
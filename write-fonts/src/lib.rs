@@ -20,6 +20,7 @@ pub use write::{dump_table, FontWrite, TableWriter};
 pub(crate) mod codegen_prelude {
     use std::num::TryFromIntError;
 
+    #[allow(unused_imports)]
     pub use super::offsets::{NullableOffsetMarker, OffsetMarker, WIDTH_16, WIDTH_24, WIDTH_32};
     pub use super::validate::{Validate, ValidationCtx};
     pub use super::write::{FontWrite, TableWriter};
@@ -28,6 +29,7 @@ pub(crate) mod codegen_prelude {
     #[cfg(feature = "parsing")]
     pub use super::from_obj::{FromObjRef, FromTableRef, ToOwnedObj, ToOwnedTable};
     #[cfg(feature = "parsing")]
+    #[allow(unused_imports)]
     pub use read_fonts::{
         FontData, FontRead, FontReadWithArgs, ReadArgs, ReadError, ResolveOffset,
     };
@@ -1,7 +1,16 @@
 //! A directory of all the font tables.
 
+pub mod cvt;
+pub mod fpgm;
+pub mod gasp;
 pub mod head;
 pub mod hhea;
 pub mod hmtx;
 pub mod maxp;
-pub use crate::layout::{gdef, gpos, gsub};
+pub mod os2;
+pub mod prep;
+pub mod stat;
+pub mod vhea;
+pub mod vmtx;
+pub mod vorg;
+pub use crate::layout::{base, gdef, gpos, gsub, jstf};
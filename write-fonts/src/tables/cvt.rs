@@ -0,0 +1,3 @@
+//! The cvt table
+
+include!("../../generated/generated_cvt.rs");
@@ -0,0 +1,3 @@
+//! The fpgm table
+
+include!("../../generated/generated_fpgm.rs");
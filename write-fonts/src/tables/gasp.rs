@@ -0,0 +1,45 @@
+//! The gasp table
+
+include!("../../generated/generated_gasp.rs");
+
+#[cfg(feature = "parsing")]
+fn convert_gasp_behavior(from: read_fonts::tables::gasp::GaspRangeBehavior) -> GaspRangeBehavior {
+    GaspRangeBehavior::from_bits_truncate(from.bits())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn smoke_test() {
+        let gasp = Gasp {
+            version: 1,
+            gasp_ranges: vec![
+                GaspRange {
+                    range_max_ppem: 8,
+                    range_gasp_behavior: GaspRangeBehavior::GASP_DOGRAY,
+                },
+                GaspRange {
+                    range_max_ppem: 0xFFFF,
+                    range_gasp_behavior: GaspRangeBehavior::GASP_GRIDFIT
+                        | GaspRangeBehavior::GASP_DOGRAY,
+                },
+            ],
+        };
+
+        let _dumped = crate::write::dump_table(&gasp).unwrap();
+
+        #[cfg(feature = "parsing")]
+        {
+            let data = FontData::new(&_dumped);
+            let loaded = read_fonts::tables::gasp::Gasp::read(data).unwrap();
+            assert_eq!(loaded.num_ranges(), 2);
+            assert_eq!(
+                loaded.gasp_behavior(9),
+                read_fonts::tables::gasp::GaspRangeBehavior::GASP_GRIDFIT
+                    | read_fonts::tables::gasp::GaspRangeBehavior::GASP_DOGRAY
+            );
+        }
+    }
+}
@@ -0,0 +1,153 @@
+//! The OS/2 table
+
+include!("../../generated/generated_os2.rs");
+
+impl Os2 {
+    /// The length of the PANOSE classification array.
+    const PANOSE_LEN: usize = 10;
+
+    /// Computes the version from the version-dependent fields that are
+    /// present.
+    ///
+    /// Versions 2, 3 and 4 share a layout, and are written as version 4.
+    fn compute_version(&self) -> u16 {
+        if self.us_lower_optical_point_size.is_some() || self.us_upper_optical_point_size.is_some()
+        {
+            5
+        } else if self.sx_height.is_some()
+            || self.s_cap_height.is_some()
+            || self.us_default_char.is_some()
+            || self.us_break_char.is_some()
+            || self.us_max_context.is_some()
+        {
+            4
+        } else if self.ul_code_page_range_1.is_some() || self.ul_code_page_range_2.is_some() {
+            1
+        } else {
+            0
+        }
+    }
+
+    fn validate_panose(&self, ctx: &mut ValidationCtx) {
+        if self.panose_10.len() != Self::PANOSE_LEN {
+            ctx.report(format!(
+                "panose must have {} elements, found {}",
+                Self::PANOSE_LEN,
+                self.panose_10.len()
+            ));
+        }
+    }
+}
+
+#[cfg(feature = "parsing")]
+fn convert_selection_flags(from: read_fonts::tables::os2::SelectionFlags) -> SelectionFlags {
+    SelectionFlags::from_bits_truncate(from.bits())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_os2() -> Os2 {
+        Os2 {
+            x_avg_char_width: 500,
+            us_weight_class: 400,
+            us_width_class: 5,
+            fs_type: 0,
+            y_subscript_x_size: 650,
+            y_subscript_y_size: 600,
+            y_subscript_x_offset: 0,
+            y_subscript_y_offset: 75,
+            y_superscript_x_size: 650,
+            y_superscript_y_size: 600,
+            y_superscript_x_offset: 0,
+            y_superscript_y_offset: 350,
+            y_strikeout_size: 50,
+            y_strikeout_position: 300,
+            s_family_class: 0,
+            panose_10: vec![2, 11, 5, 2, 4, 5, 4, 2, 2, 4],
+            ul_unicode_range_1: 1,
+            ul_unicode_range_2: 0,
+            ul_unicode_range_3: 0,
+            ul_unicode_range_4: 0,
+            ach_vend_id: Tag::new(b"NONE"),
+            fs_selection: SelectionFlags::REGULAR | SelectionFlags::USE_TYPO_METRICS,
+            us_first_char_index: 0x20,
+            us_last_char_index: 0x7e,
+            s_typo_ascender: 800,
+            s_typo_descender: -200,
+            s_typo_line_gap: 0,
+            us_win_ascent: 900,
+            us_win_descent: 250,
+            ul_code_page_range_1: None,
+            ul_code_page_range_2: None,
+            sx_height: None,
+            s_cap_height: None,
+            us_default_char: None,
+            us_break_char: None,
+            us_max_context: None,
+            us_lower_optical_point_size: None,
+            us_upper_optical_point_size: None,
+        }
+    }
+
+    #[test]
+    fn version_0() {
+        let os2 = make_os2();
+        assert_eq!(os2.compute_version(), 0);
+        let dumped = crate::write::dump_table(&os2).unwrap();
+        assert_eq!(dumped.len(), 78);
+    }
+
+    #[test]
+    fn version_5_roundtrip() {
+        let os2 = Os2 {
+            ul_code_page_range_1: Some(1),
+            ul_code_page_range_2: Some(0),
+            sx_height: Some(500),
+            s_cap_height: Some(700),
+            us_default_char: Some(0),
+            us_break_char: Some(0x20),
+            us_max_context: Some(2),
+            us_lower_optical_point_size: Some(0),
+            us_upper_optical_point_size: Some(0xffff),
+            ..make_os2()
+        };
+        assert_eq!(os2.compute_version(), 5);
+        let _dumped = crate::write::dump_table(&os2).unwrap();
+        assert_eq!(_dumped.len(), 100);
+        #[cfg(feature = "parsing")]
+        {
+            let data = FontData::new(&_dumped);
+            let loaded = read_fonts::tables::os2::Os2::read(data).unwrap();
+            assert_eq!(loaded.version(), 5);
+            assert_eq!(loaded.sx_height(), Some(500));
+            assert_eq!(loaded.us_upper_optical_point_size(), Some(0xffff));
+            assert_eq!(loaded.panose_10(), os2.panose_10);
+
+            let owned = Os2::read(data).unwrap();
+            assert_eq!(owned.fs_selection, os2.fs_selection);
+            assert_eq!(owned.s_cap_height, Some(700));
+        }
+    }
+
+    #[test]
+    fn missing_versioned_fields() {
+        // version 5 fields require the version 1 and 2 fields
+        let os2 = Os2 {
+            us_lower_optical_point_size: Some(0),
+            us_upper_optical_point_size: Some(0xffff),
+            ..make_os2()
+        };
+        assert!(crate::write::dump_table(&os2).is_err());
+    }
+
+    #[test]
+    fn bad_panose() {
+        let os2 = Os2 {
+            panose_10: vec![0; 4],
+            ..make_os2()
+        };
+        assert!(crate::write::dump_table(&os2).is_err());
+    }
+}
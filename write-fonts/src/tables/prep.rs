@@ -0,0 +1,3 @@
+//! The prep table
+
+include!("../../generated/generated_prep.rs");
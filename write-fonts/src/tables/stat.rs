@@ -0,0 +1,93 @@
+//! The STAT table
+
+include!("../../generated/generated_stat.rs");
+
+impl Stat {
+    fn compute_design_axis_count(&self) -> u16 {
+        self.design_axes_offset
+            .get()
+            .map(|axes| axes.axis_records.len())
+            .unwrap_or_default()
+            .try_into()
+            .unwrap()
+    }
+
+    fn compute_axis_value_count(&self) -> u16 {
+        self.axis_value_array_offset
+            .get()
+            .map(|values| values.axis_value_offsets.len())
+            .unwrap_or_default()
+            .try_into()
+            .unwrap()
+    }
+}
+
+#[cfg(feature = "parsing")]
+fn convert_axis_value_flags(
+    from: read_fonts::tables::stat::AxisValueTableFlags,
+) -> AxisValueTableFlags {
+    AxisValueTableFlags::from_bits_truncate(from.bits())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn smoke_test() {
+        let stat = Stat {
+            design_axes_offset: NullableOffsetMarker::new(Some(AxisRecordArray {
+                axis_records: vec![AxisRecord {
+                    axis_tag: Tag::new(b"wght"),
+                    axis_name_id: 256,
+                    axis_ordering: 0,
+                }],
+            })),
+            axis_value_array_offset: NullableOffsetMarker::new(Some(AxisValueArray {
+                axis_value_offsets: vec![
+                    OffsetMarker::new(AxisValue::Format1(AxisValueFormat1 {
+                        axis_index: 0,
+                        flags: AxisValueTableFlags::ELIDABLE_AXIS_VALUE_NAME,
+                        value_name_id: 257,
+                        value: Fixed::from_i32(400),
+                    })),
+                    OffsetMarker::new(AxisValue::Format2(AxisValueFormat2 {
+                        axis_index: 0,
+                        flags: AxisValueTableFlags::empty(),
+                        value_name_id: 258,
+                        nominal_value: Fixed::from_i32(700),
+                        range_min_value: Fixed::from_i32(600),
+                        range_max_value: Fixed::from_i32(800),
+                    })),
+                ],
+            })),
+            elided_fallback_name_id: Some(2),
+        };
+
+        let _dumped = crate::write::dump_table(&stat).unwrap();
+        #[cfg(feature = "parsing")]
+        {
+            let data = FontData::new(&_dumped);
+            let loaded = read_fonts::tables::stat::Stat::read(data).unwrap();
+            assert_eq!(loaded.version(), MajorMinor::VERSION_1_2);
+            assert_eq!(loaded.design_axis_count(), 1);
+            assert_eq!(loaded.axis_value_count(), 2);
+            assert_eq!(loaded.elided_fallback_name_id(), Some(2));
+
+            let owned = Stat::read(data).unwrap();
+            let values = &owned
+                .axis_value_array_offset
+                .get()
+                .unwrap()
+                .axis_value_offsets;
+            let AxisValue::Format1(first) = values[0].get().unwrap() else {
+                panic!("wrong format");
+            };
+            assert_eq!(first.flags, AxisValueTableFlags::ELIDABLE_AXIS_VALUE_NAME);
+            let AxisValue::Format2(second) = values[1].get().unwrap() else {
+                panic!("wrong format");
+            };
+            assert_eq!(second.range_max_value, Fixed::from_i32(800));
+        }
+    }
+}
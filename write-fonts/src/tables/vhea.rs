@@ -0,0 +1,43 @@
+//! The vhea table
+
+include!("../../generated/generated_vhea.rs");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn smoke_test() {
+        let vhea = Vhea {
+            ascender: FWord::new(500),
+            descender: FWord::new(-500),
+            line_gap: FWord::new(0),
+            advance_height_max: UfWord::new(1000),
+            min_top_side_bearing: FWord::new(-20),
+            min_bottom_side_bearing: FWord::new(-30),
+            y_max_extent: FWord::new(1020),
+            caret_slope_rise: 0,
+            caret_slope_run: 1,
+            caret_offset: 0,
+            number_of_long_ver_metrics: 7,
+        };
+
+        let _dumped = crate::write::dump_table(&vhea).unwrap();
+        #[cfg(feature = "parsing")]
+        {
+            let data = FontData::new(&_dumped);
+            let loaded = read_fonts::tables::vhea::Vhea::read(data).unwrap();
+            assert_eq!(loaded.version(), Version16Dot16::VERSION_1_1);
+            assert_eq!(loaded.ascender(), vhea.ascender);
+            assert_eq!(loaded.descender(), vhea.descender);
+            assert_eq!(loaded.advance_height_max(), vhea.advance_height_max);
+            assert_eq!(loaded.min_top_side_bearing(), vhea.min_top_side_bearing);
+            assert_eq!(loaded.y_max_extent(), vhea.y_max_extent);
+            assert_eq!(loaded.caret_slope_run(), vhea.caret_slope_run);
+            assert_eq!(
+                loaded.number_of_long_ver_metrics(),
+                vhea.number_of_long_ver_metrics
+            );
+        }
+    }
+}
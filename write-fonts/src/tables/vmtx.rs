@@ -0,0 +1,30 @@
+//! The vmtx table
+
+include!("../../generated/generated_vmtx.rs");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn smoke_test() {
+        let vmtx = Vmtx {
+            v_metrics: vec![LongVerMetric {
+                advance: 1000,
+                side_bearing: 80,
+            }],
+            top_side_bearings: vec![40, 60],
+        };
+
+        let _dumped = crate::write::dump_table(&vmtx).unwrap();
+
+        #[cfg(feature = "parsing")]
+        {
+            let data = FontData::new(&_dumped);
+            let loaded = read_fonts::tables::vmtx::Vmtx::read_with_args(data, &(1, 3)).unwrap();
+            assert_eq!(loaded.v_metrics()[0].advance(), 1000);
+            assert_eq!(loaded.v_metrics()[0].side_bearing(), 80);
+            assert_eq!(loaded.top_side_bearings(), &vmtx.top_side_bearings);
+        }
+    }
+}
@@ -0,0 +1,37 @@
+//! The VORG table
+
+include!("../../generated/generated_vorg.rs");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn smoke_test() {
+        let vorg = Vorg {
+            default_vert_origin_y: 880,
+            vert_origin_y_metrics: vec![
+                VertOriginYMetrics {
+                    glyph_id: GlyphId::new(3),
+                    vert_origin_y: 900,
+                },
+                VertOriginYMetrics {
+                    glyph_id: GlyphId::new(7),
+                    vert_origin_y: -20,
+                },
+            ],
+        };
+
+        let _dumped = crate::write::dump_table(&vorg).unwrap();
+
+        #[cfg(feature = "parsing")]
+        {
+            let data = FontData::new(&_dumped);
+            let loaded = read_fonts::tables::vorg::Vorg::read(data).unwrap();
+            assert_eq!(loaded.version(), MajorMinor::VERSION_1_0);
+            assert_eq!(loaded.num_vert_origin_y_metrics(), 2);
+            assert_eq!(loaded.vertical_origin_y(GlyphId::new(7)), -20);
+            assert_eq!(loaded.vertical_origin_y(GlyphId::new(1)), 880);
+        }
+    }
+}